@@ -106,6 +106,8 @@ pub async fn entry(conf: Config) -> anyhow::Result<()> {
 
     let single_or_join = if conf.raft_config.single {
         "single".to_string()
+    } else if conf.raft_config.as_learner {
+        format!("join {:#?} as learner", conf.raft_config.join)
     } else {
         format!("join {:#?}", conf.raft_config.join)
     };
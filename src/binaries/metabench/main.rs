@@ -38,15 +38,19 @@ use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_client::ClientHandle;
 use common_meta_client::MetaGrpcClient;
+use common_meta_client::MetaGrpcReadReq;
+use common_meta_kvapi::kvapi::GetKVReq;
 use common_meta_kvapi::kvapi::KVApi;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
+use common_meta_types::ReadConsistency;
 use common_meta_types::TxnRequest;
 use common_tracing::init_logging;
 use common_tracing::FileConfig;
 use common_tracing::StderrConfig;
 use databend_meta::version::METASRV_COMMIT_VERSION;
+use futures::TryStreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -71,6 +75,8 @@ struct Config {
 
     /// The RPC to benchmark:
     /// "upsert_kv": send kv-api upsert_kv,
+    /// "get_kv": linearizable get_kv, served locally if the target is a follower, after waiting for the read index;
+    /// "get_kv_stale": stale get_kv, served by the local state machine of the target;
     /// "table": create db, table and upsert_table_option;
     /// "get_table": single get_table() rpc;
     /// "table_copy_file": upsert table with copy file.
@@ -141,6 +147,17 @@ async fn main() {
             for i in 0..config.number {
                 if cmd == "upsert_kv" {
                     benchmark_upsert(&client, prefix, client_num, i).await;
+                } else if cmd == "get_kv" {
+                    benchmark_get_kv(
+                        &client,
+                        prefix,
+                        client_num,
+                        i,
+                        ReadConsistency::Linearizable,
+                    )
+                    .await;
+                } else if cmd == "get_kv_stale" {
+                    benchmark_get_kv(&client, prefix, client_num, i, ReadConsistency::Stale).await;
                 } else if cmd == "table" {
                     benchmark_table(&client, prefix, client_num, i).await;
                 } else if cmd == "get_table" {
@@ -180,6 +197,32 @@ async fn benchmark_upsert(client: &Arc<ClientHandle>, prefix: u64, client_num: u
     print_res(i, "upsert_kv", &res);
 }
 
+/// Read the key written by `benchmark_upsert`.
+///
+/// To compare the latency of a linearizable read and a stale read on a follower,
+/// set `--grpc-api-address` to a follower.
+async fn benchmark_get_kv(
+    client: &Arc<ClientHandle>,
+    prefix: u64,
+    client_num: u64,
+    i: u64,
+    consistency: ReadConsistency,
+) {
+    let key = format!("{}-{}-{}", prefix, client_num, i);
+
+    let req = MetaGrpcReadReq::GetKV(GetKVReq { key });
+
+    let res = match client.read_with(req, consistency).await {
+        Ok(strm) => strm
+            .try_collect::<Vec<_>>()
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+
+    print_res(i, format!("get_kv({})", consistency), &res);
+}
+
 async fn benchmark_table(client: &Arc<ClientHandle>, prefix: u64, client_num: u64, i: u64) {
     let tenant = || format!("tenant-{}-{}", prefix, client_num);
     let db_name = || format!("db-{}-{}", prefix, client_num);
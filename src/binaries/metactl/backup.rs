@@ -0,0 +1,440 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Online incremental backup and point-in-time restore.
+//!
+//! A backup location contains two kinds of files, both in the export format,
+//! i.e., every line is a json of `(tree_name, RaftStoreEntry)`:
+//!
+//! - `base-<next_index>-<time_ms>.json`: a full export of a running meta-service node.
+//!   Its state machine includes all the logs before `next_index`.
+//!   `time_ms` is the wall clock time when the backup is taken.
+//!
+//! - `logs-<first_index>-<last_index>.json`: the applied raft logs in range `[first_index, last_index]`.
+//!
+//! A restore loads the state machine of a base backup, applies the following logs up to the specified point,
+//! and imports the result into a new raft dir.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
+use common_meta_client::ClientHandle;
+use common_meta_client::MetaGrpcClient;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
+use common_meta_raft_store::key_spaces::RaftStoreEntryCompat;
+use common_meta_raft_store::ondisk::TREE_HEADER;
+use common_meta_raft_store::sm_v002::SMV002;
+use common_meta_raft_store::state_machine::StateMachineMetaKey;
+use common_meta_raft_store::state_machine::StateMachineMetaValue;
+use common_meta_sled_store::openraft::compat::Upgrade;
+use common_meta_types::protobuf::Empty;
+use common_meta_types::protobuf::ExportLogsRequest;
+use common_meta_types::Entry;
+use common_meta_types::EntryPayload;
+use common_storage::init_operator;
+use common_storage::StorageConfig as InnerStorageConfig;
+use futures::TryStreamExt;
+use opendal::Operator;
+use tokio_stream::StreamExt;
+
+use crate::snapshot::import_into_dir;
+use crate::Config;
+
+/// The tree name of the state machine entries in exported data.
+const TREE_STATE_MACHINE: &str = "state_machine/0";
+
+/// Number of logs to apply in one batch when restoring.
+const APPLY_BATCH_SIZE: usize = 1024;
+
+/// Take an online backup from a running meta-service node into `--backup-dir`.
+///
+/// The first backup is a full one. The following ones only fetch the logs applied since the last backup.
+/// If the logs to fetch have been purged, it takes a full backup again.
+///
+/// With `--backup-interval`, it keeps backing up every `backup_interval` seconds.
+pub async fn backup(config: &Config) -> anyhow::Result<()> {
+    let op = backup_operator(config)?;
+
+    eprintln!("    From: online meta-service: {}", config.grpc_api_address);
+    eprintln!("    To:   Backup Dir: {}", config.backup_dir);
+
+    let client = MetaGrpcClient::try_create(
+        vec![config.grpc_api_address.clone()],
+        "root",
+        "xxx",
+        None,
+        None,
+        Duration::from_secs(10),
+        None,
+    )?;
+
+    loop {
+        let files = BackupFiles::load(&op).await?;
+
+        match files.next_log_index() {
+            None => {
+                backup_base(&op, &client).await?;
+            }
+            Some(start) => {
+                let res = backup_logs(&op, &client, start).await;
+                if let Err(status) = res {
+                    if status.code() != tonic::Code::OutOfRange {
+                        return Err(status.into());
+                    }
+
+                    eprintln!(
+                        "Logs since {} are purged: {}; take a full backup",
+                        start,
+                        status.message()
+                    );
+                    backup_base(&op, &client).await?;
+                }
+            }
+        }
+
+        if config.backup_interval == 0 {
+            return Ok(());
+        }
+
+        tokio::time::sleep(Duration::from_secs(config.backup_interval)).await;
+    }
+}
+
+/// Restore the state at a point in time from backups in `--backup-dir` into `--raft-dir`.
+///
+/// The point is specified by `--to-seq`, the index of the last log to apply,
+/// or `--to-time`, logs proposed after this time are not applied.
+/// Without them, it restores to the latest state in the backup.
+pub async fn restore(config: &Config) -> anyhow::Result<()> {
+    let op = backup_operator(config)?;
+
+    eprintln!("    From: Backup Dir: {}", config.backup_dir);
+
+    let to_time_ms = match &config.to_time {
+        None => None,
+        Some(t) => Some(DateTime::parse_from_rfc3339(t)?.timestamp_millis() as u64),
+    };
+
+    let files = BackupFiles::load(&op).await?;
+
+    let base = files
+        .bases
+        .iter()
+        .rev()
+        .find(|b| {
+            config.to_seq.map_or(true, |seq| b.next_index <= seq + 1)
+                && to_time_ms.map_or(true, |t| b.time_ms <= t)
+        })
+        .ok_or_else(|| anyhow!("no base backup found before the restore point"))?;
+
+    eprintln!("    Base: {}", base.path);
+
+    let base_lines = read_lines(&op, &base.path).await?;
+
+    let mut header_lines = vec![];
+    let mut sm_entries = vec![];
+    let mut logs = BTreeMap::new();
+
+    for line in base_lines {
+        let (tree_name, ent) = parse_line(&line)?;
+
+        if tree_name == TREE_HEADER {
+            header_lines.push(line);
+        } else if tree_name.starts_with("state_machine/") {
+            sm_entries.push(ent);
+        } else if let RaftStoreEntry::Logs { key: _, value } = ent {
+            logs.insert(value.log_id.index, value);
+        }
+    }
+
+    for seg in files.segments.iter().filter(|s| s.last >= base.next_index) {
+        for line in read_lines(&op, &seg.path).await? {
+            let (_tree_name, ent) = parse_line(&line)?;
+
+            // Logs in segments are applied ones and override the possibly uncommitted ones in the base.
+            if let RaftStoreEntry::Logs { key: _, value } = ent {
+                logs.insert(value.log_id.index, value);
+            }
+        }
+    }
+
+    let mut sm = SMV002::from_entries(sm_entries.into_iter())?;
+
+    let mut to_apply: Vec<Entry> = vec![];
+    let mut next_index = base.next_index;
+    let mut n_applied = 0;
+
+    for (index, ent) in logs.range(base.next_index..) {
+        if *index != next_index {
+            eprintln!(
+                "WARN: log {} is missing in backup, stop at {}",
+                next_index, index
+            );
+            break;
+        }
+
+        if let Some(seq) = config.to_seq {
+            if *index > seq {
+                break;
+            }
+        }
+
+        if let (Some(t), EntryPayload::Normal(log_entry)) = (to_time_ms, &ent.payload) {
+            if log_entry.time_ms.map_or(false, |x| x > t) {
+                break;
+            }
+        }
+
+        to_apply.push(ent.clone());
+        next_index += 1;
+
+        if to_apply.len() >= APPLY_BATCH_SIZE {
+            sm.apply_entries(to_apply.iter()).await?;
+            n_applied += to_apply.len();
+            to_apply.clear();
+        }
+    }
+
+    sm.apply_entries(to_apply.iter()).await?;
+    n_applied += to_apply.len();
+
+    eprintln!(
+        "    Applied {} logs to base, restored up to log index: {:?}",
+        n_applied,
+        next_index.checked_sub(1)
+    );
+
+    let mut snapshot_view = sm.full_snapshot_view();
+    snapshot_view.compact_mem_levels().await?;
+    let sm_entries: Vec<RaftStoreEntry> = snapshot_view.export().await?.try_collect().await?;
+
+    let mut lines = header_lines;
+    for ent in sm_entries {
+        lines.push(serde_json::to_string(&(TREE_STATE_MACHINE, ent))?);
+    }
+
+    import_into_dir(config, lines.into_iter().map(Ok::<_, io::Error>)).await
+}
+
+/// Take a full backup as a new base.
+async fn backup_base(op: &Operator, client: &Arc<ClientHandle>) -> anyhow::Result<()> {
+    let time_ms = Utc::now().timestamp_millis() as u64;
+
+    let (mut grpc_client, _server_version) = client.make_client().await?;
+    let mut strm = grpc_client
+        .export(tonic::Request::new(Empty {}))
+        .await?
+        .into_inner();
+
+    let mut lines = vec![];
+    let mut next_index = 0;
+
+    while let Some(chunk) = strm.next().await {
+        for line in chunk?.data {
+            let (tree_name, ent) = parse_line(&line)?;
+
+            if tree_name.starts_with("state_machine/") {
+                if let RaftStoreEntry::StateMachineMeta {
+                    key: StateMachineMetaKey::LastApplied,
+                    value: StateMachineMetaValue::LogId(log_id),
+                } = ent
+                {
+                    next_index = log_id.index + 1;
+                }
+            }
+
+            lines.push(line);
+        }
+    }
+
+    let path = format!("base-{:020}-{:020}.json", next_index, time_ms);
+    write_lines(op, &path, &lines).await?;
+
+    eprintln!("Backup base: {}, {} records", path, lines.len());
+    Ok(())
+}
+
+/// Fetch logs since `start` until all applied logs are fetched, and save them as log segments.
+async fn backup_logs(
+    op: &Operator,
+    client: &Arc<ClientHandle>,
+    mut start: u64,
+) -> Result<(), tonic::Status> {
+    let (mut grpc_client, _server_version) = client
+        .make_client()
+        .await
+        .map_err(|e| tonic::Status::unavailable(e.to_string()))?;
+
+    loop {
+        let mut strm = grpc_client
+            .export_logs(ExportLogsRequest { start_index: start })
+            .await?
+            .into_inner();
+
+        let mut lines = vec![];
+        while let Some(chunk) = strm.next().await {
+            lines.extend(chunk?.data);
+        }
+
+        if lines.is_empty() {
+            return Ok(());
+        }
+
+        let last = start + lines.len() as u64 - 1;
+        let path = format!("logs-{:020}-{:020}.json", start, last);
+
+        write_lines(op, &path, &lines)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        eprintln!("Backup logs: {}", path);
+        start = last + 1;
+    }
+}
+
+/// The backup files in a backup location, sorted by log index.
+struct BackupFiles {
+    bases: Vec<BaseFile>,
+    segments: Vec<LogSegmentFile>,
+}
+
+struct BaseFile {
+    path: String,
+    next_index: u64,
+    time_ms: u64,
+}
+
+struct LogSegmentFile {
+    path: String,
+    first: u64,
+    last: u64,
+}
+
+impl BackupFiles {
+    async fn load(op: &Operator) -> anyhow::Result<Self> {
+        let mut bases = vec![];
+        let mut segments = vec![];
+
+        for ent in op.list("/").await? {
+            let name = ent.name();
+            let parts = match name.strip_suffix(".json") {
+                None => continue,
+                Some(stem) => stem.split('-').collect::<Vec<_>>(),
+            };
+
+            match parts.as_slice() {
+                ["base", next_index, time_ms] => bases.push(BaseFile {
+                    path: name.to_string(),
+                    next_index: next_index.parse()?,
+                    time_ms: time_ms.parse()?,
+                }),
+                ["logs", first, last] => segments.push(LogSegmentFile {
+                    path: name.to_string(),
+                    first: first.parse()?,
+                    last: last.parse()?,
+                }),
+                _ => {}
+            }
+        }
+
+        bases.sort_by_key(|b| (b.next_index, b.time_ms));
+        segments.sort_by_key(|s| (s.first, s.last));
+
+        Ok(Self { bases, segments })
+    }
+
+    /// The index of the next log to back up, i.e., the end of the logs following the latest base.
+    ///
+    /// It returns `None` if there is no base yet.
+    fn next_log_index(&self) -> Option<u64> {
+        let base = self.bases.last()?;
+
+        let mut next = base.next_index;
+        for seg in self.segments.iter() {
+            if seg.first <= next && seg.last >= next {
+                next = seg.last + 1;
+            }
+        }
+
+        Some(next)
+    }
+}
+
+/// Build an operator to access the backup location, from the configured storage params.
+///
+/// For `fs` storage, `--backup-dir` is the local dir to store backups.
+/// For an object storage such as s3, gcs or azblob, it is the path under the configured root.
+fn backup_operator(config: &Config) -> anyhow::Result<Operator> {
+    if config.backup_dir.is_empty() {
+        return Err(anyhow!("--backup-dir is required"));
+    }
+
+    let storage: InnerStorageConfig = config.storage.clone().try_into()?;
+
+    let params = match storage.params {
+        StorageParams::Fs(_) => StorageParams::Fs(StorageFsConfig {
+            root: config.backup_dir.clone(),
+        }),
+        params => params.map_root(|root| {
+            format!(
+                "{}/{}/",
+                root.trim_end_matches('/'),
+                config.backup_dir.trim_matches('/')
+            )
+        }),
+    };
+
+    // The storage operator runs IO on the global IO runtime.
+    GlobalInstance::init_production();
+    GlobalIORuntime::init(storage.num_cpus as usize)?;
+
+    Ok(init_operator(&params)?)
+}
+
+async fn read_lines(op: &Operator, path: &str) -> anyhow::Result<Vec<String>> {
+    let data = op.read(path).await?;
+    let s = String::from_utf8(data)?;
+
+    Ok(s.lines().map(|l| l.to_string()).collect())
+}
+
+/// Write the file with a temp name first, then rename it, so that an incomplete file is never listed.
+async fn write_lines(op: &Operator, path: &str, lines: &[String]) -> anyhow::Result<()> {
+    let mut buf = String::new();
+    for line in lines {
+        buf.push_str(line);
+        buf.push('\n');
+    }
+
+    let tmp = format!("{}.tmp", path);
+    op.write(&tmp, buf.into_bytes()).await?;
+    op.rename(&tmp, path).await?;
+
+    Ok(())
+}
+
+fn parse_line(line: &str) -> anyhow::Result<(String, RaftStoreEntry)> {
+    let (tree_name, ent): (String, RaftStoreEntryCompat) = serde_json::from_str(line)?;
+    Ok((tree_name, ent.upgrade()))
+}
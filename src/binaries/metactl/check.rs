@@ -0,0 +1,248 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Check the consistency of the schema keyspaces of a running meta-service,
+//! and optionally remove the orphaned entries.
+//!
+//! Detected issues:
+//! - `__fd_database_id_to_name/<db_id>` without `__fd_database_by_id/<db_id>`.
+//! - `__fd_table_id_to_name/<table_id>` without `__fd_table_by_id/<table_id>`.
+//! - `__fd_table_by_id/<table_id>` that is not referenced by any table name or table id list.
+//! - `__fd_table_lock/<table_id>/<revision>` of a table that does not exist, or without expiration time.
+//! - `__fd_database/<tenant>/<db_name>` and `__fd_table/<db_id>/<table_name>`
+//!   pointing to an absent meta. These are reported but not fixed.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt;
+
+use common_meta_api::deserialize_struct;
+use common_meta_api::deserialize_u64;
+use common_meta_app::schema::DBIdTableName;
+use common_meta_app::schema::DatabaseId;
+use common_meta_app::schema::DatabaseIdToName;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::TableId;
+use common_meta_app::schema::TableIdList;
+use common_meta_app::schema::TableIdListKey;
+use common_meta_app::schema::TableIdToName;
+use common_meta_app::schema::TableLockKey;
+use common_meta_client::ClientHandle;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::kv::new_client;
+use crate::Config;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IssueKind {
+    DanglingDatabaseIdToName,
+    DanglingTableIdToName,
+    OrphanedTable,
+    StuckTableLock,
+    DanglingDatabaseName,
+    DanglingTableName,
+}
+
+impl IssueKind {
+    /// Whether the key of the issue can be removed safely.
+    pub fn fixable(&self) -> bool {
+        match self {
+            IssueKind::DanglingDatabaseIdToName
+            | IssueKind::DanglingTableIdToName
+            | IssueKind::OrphanedTable
+            | IssueKind::StuckTableLock => true,
+            IssueKind::DanglingDatabaseName | IssueKind::DanglingTableName => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Issue {
+    pub kind: IssueKind,
+    pub key: String,
+    /// The seq of the key when it is checked, a fix only applies if it is not changed.
+    pub seq: u64,
+    pub detail: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{:?}] {}(seq={}): {}",
+            self.kind, self.key, self.seq, self.detail
+        )
+    }
+}
+
+/// Check the schema keyspaces, remove orphaned entries if `--apply` is specified.
+pub async fn check(config: &Config) -> anyhow::Result<()> {
+    let client = new_client(config)?;
+
+    let issues = find_issues(&client).await?;
+    for issue in issues.iter() {
+        println!("{}", issue);
+    }
+
+    let fixable = issues.iter().filter(|x| x.kind.fixable()).count();
+    eprintln!("found {} issues, {} fixable", issues.len(), fixable);
+
+    if !config.apply {
+        if fixable > 0 {
+            eprintln!("dry run: re-run with --apply to remove the fixable entries");
+        }
+        return Ok(());
+    }
+
+    let mut fixed = 0;
+    for issue in issues.iter().filter(|x| x.kind.fixable()) {
+        let res = client
+            .upsert_kv(UpsertKVReq::new(
+                &issue.key,
+                MatchSeq::Exact(issue.seq),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
+        if res.is_changed() {
+            fixed += 1;
+            println!("removed: {}", issue.key);
+        } else {
+            println!("skipped: {}, it is changed since checked", issue.key);
+        }
+    }
+    eprintln!("removed {} entries", fixed);
+
+    Ok(())
+}
+
+async fn list(client: &ClientHandle, prefix: &str) -> anyhow::Result<Vec<(String, SeqV)>> {
+    let kvs = client.prefix_list_kv(&format!("{}/", prefix)).await?;
+    Ok(kvs)
+}
+
+pub async fn find_issues(client: &ClientHandle) -> anyhow::Result<Vec<Issue>> {
+    let mut issues = vec![];
+
+    // db_id -> seq of the DatabaseMeta
+    let mut dbs = BTreeMap::new();
+    for (k, v) in list(client, DatabaseId::PREFIX).await? {
+        let key = DatabaseId::from_str_key(&k)?;
+        dbs.insert(key.db_id, v.seq);
+    }
+
+    // table_id -> seq of the TableMeta
+    let mut tables = BTreeMap::new();
+    for (k, v) in list(client, TableId::PREFIX).await? {
+        let key = TableId::from_str_key(&k)?;
+        tables.insert(key.table_id, v.seq);
+    }
+
+    for (k, v) in list(client, DatabaseIdToName::PREFIX).await? {
+        let key = DatabaseIdToName::from_str_key(&k)?;
+        if !dbs.contains_key(&key.db_id) {
+            issues.push(Issue {
+                kind: IssueKind::DanglingDatabaseIdToName,
+                key: k,
+                seq: v.seq,
+                detail: format!("database {} does not exist", key.db_id),
+            });
+        }
+    }
+
+    for (k, v) in list(client, TableIdToName::PREFIX).await? {
+        let key = TableIdToName::from_str_key(&k)?;
+        if !tables.contains_key(&key.table_id) {
+            issues.push(Issue {
+                kind: IssueKind::DanglingTableIdToName,
+                key: k,
+                seq: v.seq,
+                detail: format!("table {} does not exist", key.table_id),
+            });
+        }
+    }
+
+    for (k, v) in list(client, DatabaseNameIdent::PREFIX).await? {
+        let db_id = *deserialize_u64(&v.data)?;
+        if !dbs.contains_key(&db_id) {
+            issues.push(Issue {
+                kind: IssueKind::DanglingDatabaseName,
+                key: k,
+                seq: v.seq,
+                detail: format!("database {} does not exist", db_id),
+            });
+        }
+    }
+
+    // All table ids that are referenced by a name or in the history of a name.
+    let mut referenced = BTreeSet::new();
+
+    for (k, v) in list(client, DBIdTableName::PREFIX).await? {
+        let table_id = *deserialize_u64(&v.data)?;
+        referenced.insert(table_id);
+        if !tables.contains_key(&table_id) {
+            issues.push(Issue {
+                kind: IssueKind::DanglingTableName,
+                key: k,
+                seq: v.seq,
+                detail: format!("table {} does not exist", table_id),
+            });
+        }
+    }
+
+    for (_k, v) in list(client, TableIdListKey::PREFIX).await? {
+        let id_list: TableIdList = deserialize_struct(&v.data)?;
+        referenced.extend(id_list.id_list);
+    }
+
+    for (table_id, seq) in tables.iter() {
+        if !referenced.contains(table_id) {
+            issues.push(Issue {
+                kind: IssueKind::OrphanedTable,
+                key: TableId {
+                    table_id: *table_id,
+                }
+                .to_string_key(),
+                seq: *seq,
+                detail: "not referenced by any table name or table id list".to_string(),
+            });
+        }
+    }
+
+    for (k, v) in list(client, TableLockKey::PREFIX).await? {
+        let key = TableLockKey::from_str_key(&k)?;
+        let detail = if !tables.contains_key(&key.table_id) {
+            format!("table {} does not exist", key.table_id)
+        } else if v.get_expire_at() == u64::MAX {
+            "lock without expiration time".to_string()
+        } else {
+            continue;
+        };
+
+        issues.push(Issue {
+            kind: IssueKind::StuckTableLock,
+            key: k,
+            seq: v.seq,
+            detail,
+        });
+    }
+
+    Ok(issues)
+}
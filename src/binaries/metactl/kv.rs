@@ -0,0 +1,286 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inspect and edit the keys of a running meta-service.
+//!
+//! Values of the known schema keys are decoded with `FromToProto` and displayed as json.
+//! Values of other keys are displayed as strings.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use common_meta_api::deserialize_struct;
+use common_meta_api::deserialize_u64;
+use common_meta_api::serialize_struct;
+use common_meta_api::serialize_u64;
+use common_meta_app::schema::CatalogId;
+use common_meta_app::schema::CatalogIdToName;
+use common_meta_app::schema::CatalogMeta;
+use common_meta_app::schema::CatalogNameIdent;
+use common_meta_app::schema::CountTablesKey;
+use common_meta_app::schema::DBIdTableName;
+use common_meta_app::schema::DatabaseId;
+use common_meta_app::schema::DatabaseIdToName;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DbIdList;
+use common_meta_app::schema::DbIdListKey;
+use common_meta_app::schema::IndexId;
+use common_meta_app::schema::IndexIdToName;
+use common_meta_app::schema::IndexMeta;
+use common_meta_app::schema::IndexNameIdent;
+use common_meta_app::schema::LeastVisibleTime;
+use common_meta_app::schema::LeastVisibleTimeKey;
+use common_meta_app::schema::LockMeta;
+use common_meta_app::schema::TableCopiedFileInfo;
+use common_meta_app::schema::TableCopiedFileNameIdent;
+use common_meta_app::schema::TableId;
+use common_meta_app::schema::TableIdList;
+use common_meta_app::schema::TableIdListKey;
+use common_meta_app::schema::TableIdToName;
+use common_meta_app::schema::TableLockKey;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::VirtualColumnMeta;
+use common_meta_app::schema::VirtualColumnNameIdent;
+use common_meta_client::ClientHandle;
+use common_meta_client::MetaGrpcClient;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use serde_json::json;
+
+use crate::Config;
+
+type DecodeFn = fn(&[u8]) -> anyhow::Result<serde_json::Value>;
+type EncodeFn = fn(serde_json::Value) -> anyhow::Result<Vec<u8>>;
+
+/// Build the decoder and encoder of a value type that is stored in protobuf.
+macro_rules! pb_codec {
+    ($t:ty) => {{
+        let decode: DecodeFn = |data| {
+            let v = deserialize_struct::<$t>(data)?;
+            Ok(serde_json::to_value(v)?)
+        };
+        let encode: EncodeFn = |v| {
+            let v = serde_json::from_value::<$t>(v)?;
+            Ok(serialize_struct(&v)?)
+        };
+        (decode, encode)
+    }};
+}
+
+/// Returns the codec of the value of `key`, or `None` if the value type of `key` is unknown.
+///
+/// The value type is determined by the first segment of the key.
+pub fn value_codec(key: &str) -> Option<(DecodeFn, EncodeFn)> {
+    let prefix = key.split('/').next().unwrap_or_default();
+
+    let id_prefixes = [
+        DatabaseNameIdent::PREFIX,
+        DBIdTableName::PREFIX,
+        CountTablesKey::PREFIX,
+        IndexNameIdent::PREFIX,
+        CatalogNameIdent::PREFIX,
+    ];
+
+    let codec = if id_prefixes.contains(&prefix) {
+        let decode: DecodeFn = |data| Ok(json!(*deserialize_u64(data)?));
+        let encode: EncodeFn = |v| {
+            let id = serde_json::from_value::<u64>(v)?;
+            Ok(serialize_u64(id)?)
+        };
+        (decode, encode)
+    } else if prefix == DatabaseId::PREFIX {
+        pb_codec!(DatabaseMeta)
+    } else if prefix == DatabaseIdToName::PREFIX {
+        pb_codec!(DatabaseNameIdent)
+    } else if prefix == DbIdListKey::PREFIX {
+        pb_codec!(DbIdList)
+    } else if prefix == TableId::PREFIX {
+        pb_codec!(TableMeta)
+    } else if prefix == TableIdToName::PREFIX {
+        pb_codec!(DBIdTableName)
+    } else if prefix == TableIdListKey::PREFIX {
+        pb_codec!(TableIdList)
+    } else if prefix == TableLockKey::PREFIX {
+        pb_codec!(LockMeta)
+    } else if prefix == TableCopiedFileNameIdent::PREFIX {
+        pb_codec!(TableCopiedFileInfo)
+    } else if prefix == LeastVisibleTimeKey::PREFIX {
+        pb_codec!(LeastVisibleTime)
+    } else if prefix == IndexId::PREFIX {
+        pb_codec!(IndexMeta)
+    } else if prefix == IndexIdToName::PREFIX {
+        pb_codec!(IndexNameIdent)
+    } else if prefix == CatalogId::PREFIX {
+        pb_codec!(CatalogMeta)
+    } else if prefix == CatalogIdToName::PREFIX {
+        pb_codec!(CatalogNameIdent)
+    } else if prefix == VirtualColumnNameIdent::PREFIX {
+        pb_codec!(VirtualColumnMeta)
+    } else {
+        return None;
+    };
+
+    Some(codec)
+}
+
+/// Decode the value of `key` into json.
+///
+/// A value that can not be decoded is displayed as a string, with the decoding error.
+pub fn decode_value(key: &str, data: &[u8]) -> serde_json::Value {
+    match value_codec(key) {
+        Some((decode, _)) => match decode(data) {
+            Ok(v) => v,
+            Err(e) => json!({
+                "raw": String::from_utf8_lossy(data),
+                "decode_error": e.to_string(),
+            }),
+        },
+        None => json!(String::from_utf8_lossy(data)),
+    }
+}
+
+/// Encode the value in `--value` for `key`.
+///
+/// For known schema keys `--value` is a json of the value type, otherwise it is stored as is.
+pub fn encode_value(key: &str, value: &str) -> anyhow::Result<Vec<u8>> {
+    match value_codec(key) {
+        Some((_, encode)) => {
+            let v: serde_json::Value = serde_json::from_str(value)
+                .map_err(|e| anyhow!("--value of {} must be a json: {}", key, e))?;
+            encode(v)
+        }
+        None => Ok(value.as_bytes().to_vec()),
+    }
+}
+
+pub fn seqv_to_json(key: &str, seqv: &SeqV) -> serde_json::Value {
+    json!({
+        "key": key,
+        "seq": seqv.seq,
+        "expire_at": seqv.meta.as_ref().and_then(|m| m.expire_at),
+        "value": decode_value(key, &seqv.data),
+    })
+}
+
+pub fn new_client(config: &Config) -> anyhow::Result<Arc<ClientHandle>> {
+    let client = MetaGrpcClient::try_create(
+        vec![config.grpc_api_address.clone()],
+        "root",
+        "xxx",
+        None,
+        None,
+        Duration::from_secs(10),
+        None,
+    )?;
+    Ok(client)
+}
+
+/// List the keys with `--prefix`, one json per line.
+pub async fn list(config: &Config) -> anyhow::Result<()> {
+    let client = new_client(config)?;
+
+    let kvs = client.prefix_list_kv(&config.prefix).await?;
+    for (key, seqv) in kvs.iter() {
+        println!("{}", seqv_to_json(key, seqv));
+    }
+    eprintln!("{} keys", kvs.len());
+
+    Ok(())
+}
+
+/// Get the value of `--key`.
+pub async fn get(config: &Config) -> anyhow::Result<()> {
+    let client = new_client(config)?;
+
+    let res = client.get_kv(&config.key).await?;
+    match res {
+        Some(seqv) => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&seqv_to_json(&config.key, &seqv))?
+            );
+            Ok(())
+        }
+        None => Err(anyhow!("key not found: {}", config.key)),
+    }
+}
+
+/// Put `--value` to `--key`.
+///
+/// The expiration time of an existing key is kept.
+/// It fails if the key is changed by others before it is put.
+pub async fn put(config: &Config) -> anyhow::Result<()> {
+    let client = new_client(config)?;
+
+    let value = encode_value(&config.key, &config.value)?;
+
+    let current = client.get_kv(&config.key).await?;
+    let (seq, meta) = match current {
+        Some(seqv) => (seqv.seq, seqv.meta),
+        None => (0, None),
+    };
+
+    let res = client
+        .upsert_kv(UpsertKVReq::new(
+            &config.key,
+            MatchSeq::Exact(seq),
+            Operation::Update(value),
+            meta,
+        ))
+        .await?;
+
+    if !res.is_changed() {
+        return Err(anyhow!(
+            "key {} is changed by others while putting, retry it",
+            config.key
+        ));
+    }
+
+    if let Some(prev) = &res.prev {
+        eprintln!("prev: {}", seqv_to_json(&config.key, prev));
+    }
+    if let Some(result) = &res.result {
+        println!("{}", seqv_to_json(&config.key, result));
+    }
+
+    Ok(())
+}
+
+/// Delete `--key`.
+pub async fn delete(config: &Config) -> anyhow::Result<()> {
+    let client = new_client(config)?;
+
+    let res = client
+        .upsert_kv(UpsertKVReq::new(
+            &config.key,
+            MatchSeq::GE(0),
+            Operation::Delete,
+            None,
+        ))
+        .await?;
+
+    match &res.prev {
+        Some(prev) => {
+            println!("deleted: {}", seqv_to_json(&config.key, prev));
+            Ok(())
+        }
+        None => Err(anyhow!("key not found: {}", config.key)),
+    }
+}
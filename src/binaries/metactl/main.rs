@@ -14,9 +14,12 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod backup;
+mod check;
 mod grpc;
 use grpc::export_meta;
 
+mod kv;
 mod snapshot;
 
 use std::collections::BTreeMap;
@@ -24,6 +27,7 @@ use std::time::Duration;
 
 use clap::Parser;
 use common_base::base::tokio;
+use common_config::StorageConfig;
 use common_meta_client::MetaGrpcClient;
 use common_meta_kvapi::kvapi::KVApi;
 use common_meta_raft_store::config::RaftConfig;
@@ -86,6 +90,47 @@ pub struct Config {
     #[clap(long, default_value = "0")]
     #[serde(alias = "kvsrv_id")]
     pub id: u64,
+
+    /// The dir to store backups, for `--cmd backup` and `--cmd restore`.
+    ///
+    /// With the default `fs` storage it is a local dir.
+    /// With an object storage, e.g. `--storage-type s3`, it is the path under the storage root.
+    #[clap(long, default_value = "")]
+    pub backup_dir: String,
+
+    /// The storage to store backups, e.g., `--storage-type s3 --storage-s3-bucket <bucket>`.
+    #[clap(flatten)]
+    pub storage: StorageConfig,
+
+    /// Keep taking incremental backups every `backup_interval` seconds.
+    /// If it is 0, `--cmd backup` backs up once and quits.
+    #[clap(long, default_value = "0")]
+    pub backup_interval: u64,
+
+    /// Restore the state up to and including the log of this index.
+    #[clap(long)]
+    pub to_seq: Option<u64>,
+
+    /// Restore the state at this time, in RFC 3339 format, e.g., `2023-10-01T08:00:00Z`.
+    #[clap(long)]
+    pub to_time: Option<String>,
+
+    /// The key to get, put or delete, for `--cmd get|put|delete`.
+    #[clap(long, default_value = "")]
+    pub key: String,
+
+    /// The value to put, for `--cmd put`.
+    /// For a known schema key, such as `__fd_table_by_id/<table_id>`, it is the json of the value type.
+    #[clap(long, default_value = "")]
+    pub value: String,
+
+    /// List the keys starting with this prefix, for `--cmd list`.
+    #[clap(long, default_value = "")]
+    pub prefix: String,
+
+    /// Remove the orphaned entries found by `--cmd check`. Without it `check` is a dry run.
+    #[clap(long)]
+    pub apply: bool,
 }
 
 impl From<Config> for RaftConfig {
@@ -162,11 +207,67 @@ async fn main() -> anyhow::Result<()> {
                 Ok(())
             }
 
+            "backup" => {
+                eprintln!();
+                eprintln!("Backup:");
+                backup::backup(&config).await
+            }
+
+            "restore" => {
+                eprintln!();
+                eprintln!("Restore:");
+                backup::restore(&config).await
+            }
+
+            "list" => kv::list(&config).await,
+            "get" => kv::get(&config).await,
+            "put" => kv::put(&config).await,
+            "delete" => kv::delete(&config).await,
+
+            "check" => {
+                eprintln!();
+                eprintln!("Check:");
+                check::check(&config).await
+            }
+
+            "promote" => {
+                eprintln!();
+                eprintln!("Promote:");
+                snapshot::promote(&config).await
+            }
+
             _ => {
                 eprintln!("valid commands are");
                 eprintln!("  --cmd bench-client-conn-num");
                 eprintln!("    Keep create new connections to metasrv.");
                 eprintln!("    Requires --grpc-api-address.");
+                eprintln!("  --cmd backup");
+                eprintln!("    Backup a running metasrv, incrementally if there is a base backup.");
+                eprintln!(
+                    "    Requires --grpc-api-address, --backup-dir; Optional --backup-interval, --storage-*."
+                );
+                eprintln!("  --cmd restore");
+                eprintln!("    Restore from backups into a raft dir at a point in time.");
+                eprintln!(
+                    "    Requires --backup-dir, --raft-dir; Optional --to-seq or --to-time, --id, --initial-cluster, --storage-*."
+                );
+                eprintln!("  --cmd list|get|put|delete");
+                eprintln!(
+                    "    Inspect or edit keys of a running metasrv, schema values are shown as json."
+                );
+                eprintln!(
+                    "    Requires --grpc-api-address; --prefix for list, --key for get/put/delete, --value for put."
+                );
+                eprintln!("  --cmd check");
+                eprintln!("    Check the consistency of schema keys of a running metasrv.");
+                eprintln!(
+                    "    Requires --grpc-api-address; Optional --apply to remove orphaned entries."
+                );
+                eprintln!("  --cmd promote");
+                eprintln!(
+                    "    Promote the raft dir of a stopped learner to a new standalone cluster."
+                );
+                eprintln!("    Requires --raft-dir, --id, --initial-cluster.");
 
                 Err(anyhow::anyhow!("unknown cmd: {}", config.cmd))
             }
@@ -19,7 +19,6 @@ use std::fs::File;
 use std::io;
 use std::io::BufRead;
 use std::io::BufReader;
-use std::io::Lines;
 use std::io::Write;
 use std::net::SocketAddr;
 use std::net::ToSocketAddrs;
@@ -75,7 +74,31 @@ pub async fn export_data(config: &Config) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Read every line from stdin or restore file and import them into `raft_dir`.
 pub async fn import_data(config: &Config) -> anyhow::Result<()> {
+    let restore = config.db.clone();
+
+    if restore.is_empty() {
+        let lines = io::stdin().lines();
+
+        import_into_dir(config, lines).await
+    } else {
+        let file = File::open(restore)?;
+        let reader = BufReader::new(file);
+        let lines = reader.lines();
+
+        import_into_dir(config, lines).await
+    }
+}
+
+/// Clear `raft_dir` and import lines of exported data into it.
+///
+/// Finally upgrade the data in raft_dir to the latest version,
+/// and initialize a new cluster if `--initial-cluster` is specified.
+pub async fn import_into_dir(
+    config: &Config,
+    lines: impl IntoIterator<Item = Result<String, io::Error>>,
+) -> anyhow::Result<()> {
     let raft_dir = config.raft_dir.clone().unwrap_or_default();
     eprintln!("    Into Meta Dir: '{}'", raft_dir);
 
@@ -84,7 +107,8 @@ pub async fn import_data(config: &Config) -> anyhow::Result<()> {
     init_sled_db(raft_dir.clone());
 
     clear(config)?;
-    let max_log_id = import_from_stdin_or_file(config).await?;
+    let max_log_id = import_lines(config, lines).await?;
+    upgrade(config).await?;
 
     if config.initial_cluster.is_empty() {
         return Ok(());
@@ -94,12 +118,65 @@ pub async fn import_data(config: &Config) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Promote the data in `raft_dir` of a stopped learner to a new standalone cluster.
+///
+/// The logs a learner has received but not known to be committed are discarded,
+/// because they may never be committed by the original cluster.
+/// Then a new cluster is initialized with `--initial-cluster`, in the same way as `--import` does.
+pub async fn promote(config: &Config) -> anyhow::Result<()> {
+    let raft_dir = config.raft_dir.clone().unwrap_or_default();
+    if raft_dir.is_empty() {
+        return Err(anyhow!("--raft-dir is required to promote a learner"));
+    }
+    if config.initial_cluster.is_empty() {
+        return Err(anyhow!(
+            "--initial-cluster is required to promote a learner"
+        ));
+    }
+    eprintln!("    Promote Meta Dir: '{}'", raft_dir);
+
+    let nodes = build_nodes(config.initial_cluster.clone(), config.id)?;
+
+    init_sled_db(raft_dir);
+    upgrade(config).await?;
+
+    let raft_config: RaftConfig = config.clone().into();
+    let committed = {
+        let mut sto = RaftStore::open_create(&raft_config, Some(()), None).await?;
+
+        let last_applied = {
+            let sm2 = sto.get_state_machine().await;
+            *sm2.sys_data_ref().last_applied_ref()
+        };
+        let committed = std::cmp::max(sto.read_committed().await?, last_applied);
+
+        let log_state = sto.get_log_state().await?;
+        eprintln!(
+            "    Committed: {:?}, LastLogId: {:?}",
+            committed, log_state.last_log_id
+        );
+
+        if let Some(last_log_id) = log_state.last_log_id {
+            let keep_index = committed.map(|x| x.index).unwrap_or_default();
+            if last_log_id.index > keep_index {
+                let since = LogId::new(last_log_id.leader_id, keep_index + 1);
+                eprintln!("    Discard uncommitted logs since: {}", since);
+                sto.delete_conflict_logs_since(since).await?;
+            }
+        }
+
+        committed
+    };
+
+    init_new_cluster(config, nodes, committed, config.id).await?;
+    Ok(())
+}
+
 /// Import from lines of exported data and Return the max log id that is found.
-async fn import_lines<B: BufRead + 'static>(
+async fn import_lines(
     config: &Config,
-    lines: Lines<B>,
+    lines: impl IntoIterator<Item = Result<String, io::Error>>,
 ) -> anyhow::Result<Option<LogId>> {
-    #[allow(clippy::useless_conversion)]
     let mut it = lines.into_iter().peekable();
     let first = it
         .peek()
@@ -261,30 +338,6 @@ async fn import_v002(
     Ok(max_log_id)
 }
 
-/// Read every line from stdin or restore file, deserialize it into tree_name, key and value.
-/// Insert them into sled db and flush.
-///
-/// Finally upgrade the data in raft_dir to the latest version.
-async fn import_from_stdin_or_file(config: &Config) -> anyhow::Result<Option<LogId>> {
-    let restore = config.db.clone();
-
-    let max_log_id = if restore.is_empty() {
-        let lines = io::stdin().lines();
-
-        import_lines(config, lines).await?
-    } else {
-        let file = File::open(restore)?;
-        let reader = BufReader::new(file);
-        let lines = reader.lines();
-
-        import_lines(config, lines).await?
-    };
-
-    upgrade(config).await?;
-
-    Ok(max_log_id)
-}
-
 /// Upgrade the data in raft_dir to the latest version.
 async fn upgrade(config: &Config) -> anyhow::Result<()> {
     let raft_config: RaftConfig = config.clone().into();
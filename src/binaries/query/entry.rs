@@ -29,6 +29,7 @@ use common_storage::DataOperator;
 use common_tracing::set_panic_hook;
 use databend_query::api::HttpService;
 use databend_query::api::RpcService;
+use databend_query::auto_compaction::AutoCompactionService;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::local;
 use databend_query::metrics::MetricService;
@@ -231,6 +232,12 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Auto compaction of the tables with `auto_compaction` enabled.
+    if conf.query.enable_auto_compaction {
+        AutoCompactionService::start(conf);
+        info!("Auto compaction service started.");
+    }
+
     // Print information to users.
     println!("Databend Query");
     println!();
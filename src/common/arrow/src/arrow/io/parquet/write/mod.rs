@@ -100,6 +100,7 @@ pub use file::FileWriter;
 pub use pages::array_to_columns;
 pub use pages::Nested;
 pub use row_group::row_group_iter;
+pub use row_group::row_group_iter_with_compressions;
 pub use row_group::RowGroupIterator;
 pub use schema::to_parquet_type;
 #[cfg(feature = "io_parquet_async")]
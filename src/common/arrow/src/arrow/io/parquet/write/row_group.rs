@@ -20,6 +20,7 @@ use parquet2::FallibleStreamingIterator;
 
 use super::array_to_columns;
 use super::to_parquet_schema;
+use super::CompressionOptions;
 use super::DynIter;
 use super::DynStreamingIterator;
 use super::Encoding;
@@ -43,16 +44,40 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
     encodings: Vec<Vec<Encoding>>,
     fields: Vec<ParquetType>,
     options: WriteOptions,
+) -> RowGroupIter<'static, Error> {
+    let compressions = vec![options.compression; fields.len()];
+    row_group_iter_with_compressions(chunk, encodings, fields, options, compressions)
+}
+
+/// Like [`row_group_iter`], but the columns of each field are compressed with
+/// the codec of the field in `compressions`.
+/// # Panics
+/// Iff
+/// * `encodings.len() != fields.len()` or
+/// * `encodings.len() != chunk.arrays().len()` or
+/// * `compressions.len() != fields.len()`
+pub fn row_group_iter_with_compressions<A: AsRef<dyn Array> + 'static + Send + Sync>(
+    chunk: Chunk<A>,
+    encodings: Vec<Vec<Encoding>>,
+    fields: Vec<ParquetType>,
+    options: WriteOptions,
+    compressions: Vec<CompressionOptions>,
 ) -> RowGroupIter<'static, Error> {
     assert_eq!(encodings.len(), fields.len());
     assert_eq!(encodings.len(), chunk.arrays().len());
+    assert_eq!(compressions.len(), fields.len());
     DynIter::new(
         chunk
             .into_arrays()
             .into_iter()
             .zip(fields)
             .zip(encodings)
-            .flat_map(move |((array, type_), encoding)| {
+            .zip(compressions)
+            .flat_map(move |(((array, type_), encoding), compression)| {
+                let options = WriteOptions {
+                    compression,
+                    ..options
+                };
                 let encoded_columns = array_to_columns(array, type_, options, &encoding).unwrap();
                 encoded_columns
                     .into_iter()
@@ -82,6 +107,7 @@ pub struct RowGroupIterator<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Re
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Vec<Encoding>>,
+    compressions: Vec<CompressionOptions>,
 }
 
 impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGroupIterator<A, I> {
@@ -103,15 +129,32 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
             ));
         }
         let parquet_schema = to_parquet_schema(schema)?;
+        let compressions = vec![options.compression; encodings.len()];
 
         Ok(Self {
             iter,
             options,
             parquet_schema,
             encodings,
+            compressions,
         })
     }
 
+    /// Compresses the columns of each field with its own codec, instead of the one of
+    /// the [`WriteOptions`].
+    ///
+    /// # Errors
+    /// Iff the length of the compressions is different from the number of fields in schema
+    pub fn with_compressions(mut self, compressions: Vec<CompressionOptions>) -> Result<Self> {
+        if compressions.len() != self.encodings.len() {
+            return Err(Error::InvalidArgumentError(
+                "The number of compressions must equal the number of fields".to_string(),
+            ));
+        }
+        self.compressions = compressions;
+        Ok(self)
+    }
+
     /// Returns the [`SchemaDescriptor`] of the [`RowGroupIterator`].
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
@@ -135,11 +178,12 @@ impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chun
                 ));
             };
             let encodings = self.encodings.clone();
-            Ok(row_group_iter(
+            Ok(row_group_iter_with_compressions(
                 chunk,
                 encodings,
                 self.parquet_schema.fields().to_vec(),
                 options,
+                self.compressions.clone(),
             ))
         })
     }
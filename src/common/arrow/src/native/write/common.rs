@@ -74,6 +74,11 @@ impl<W: Write> NativeWriter<W> {
             {
                 let start = self.writer.offset;
                 let leaf_array = leaf_array.to_boxed();
+                let options = self
+                    .column_options
+                    .get(&self.metas.len())
+                    .unwrap_or(&self.options)
+                    .clone();
 
                 let page_metas: Vec<PageMeta> = (0..length)
                     .step_by(page_size)
@@ -93,7 +98,7 @@ impl<W: Write> NativeWriter<W> {
                             &sub_nested,
                             type_.clone(),
                             length,
-                            self.options.clone(),
+                            options.clone(),
                             &mut self.scratch,
                         )
                         .unwrap();
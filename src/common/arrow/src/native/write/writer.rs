@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::io::Write;
 
 use super::super::ARROW_MAGIC;
@@ -41,6 +42,8 @@ pub struct NativeWriter<W: Write> {
     pub(crate) writer: OffsetWriter<W>,
     /// pa write options
     pub(crate) options: WriteOptions,
+    /// write options of the leaf columns which override `options`, keyed by the leaf index
+    pub(crate) column_options: HashMap<usize, WriteOptions>,
     /// A reference to the schema, used in validating record batches
     pub(crate) schema: Schema,
 
@@ -70,6 +73,7 @@ impl<W: Write> NativeWriter<W> {
                 offset: 0,
             },
             options,
+            column_options: HashMap::new(),
             schema,
             metas: Vec::with_capacity(num_cols),
             scratch: Vec::with_capacity(0),
@@ -77,6 +81,12 @@ impl<W: Write> NativeWriter<W> {
         }
     }
 
+    /// Overrides the write options of some leaf columns, keyed by the index of the leaf column.
+    pub fn with_column_options(mut self, column_options: HashMap<usize, WriteOptions>) -> Self {
+        self.column_options = column_options;
+        self
+    }
+
     /// Consumes itself into the inner writer
     pub fn into_inner(self) -> W {
         self.writer.w
@@ -149,6 +149,8 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    InvalidPartitionKey(1122),
+    InvalidTableTtl(1123),
 
     // Data Related Errors
 
@@ -214,6 +216,9 @@ build_exceptions! {
     DuplicatedUpsertFiles(2014),
     TableAlreadyLocked(2015),
     TableLockExpired(2016),
+    UnknownTableTag(2017),
+    TableTagAlreadyExists(2018),
+    UnknownTablePartition(2019),
 
     // User api error codes.
     UnknownUser(2201),
@@ -35,6 +35,8 @@ pub static SPILL_WRITE_BYTES: LazyLock<Family<VecLabels, Counter>> =
 pub static SPILL_WRITE_MILLISECONDS: LazyLock<Family<VecLabels, Histogram>> = LazyLock::new(|| {
     register_histogram_family_in_milliseconds("transform_spill_write_milliseconds")
 });
+pub static SPILL_TIER_WRITE_BYTES: LazyLock<Family<VecLabels, Counter>> =
+    LazyLock::new(|| register_counter_family("transform_spill_tier_write_bytes"));
 pub static SPILL_READ_COUNT: LazyLock<Family<VecLabels, Counter>> =
     LazyLock::new(|| register_counter_family("transform_spill_read_count"));
 pub static SPILL_READ_BYTES: LazyLock<Family<VecLabels, Counter>> =
@@ -74,6 +76,11 @@ pub fn metrics_inc_aggregate_partial_hashtable_allocated_bytes(c: u64) {
     AGGREGATE_PARTIAL_HASHTABLE_ALLOCATED_BYTES.inc_by(c);
 }
 
+pub fn metrics_inc_spill_tier_write_bytes(tier: String, c: u64) {
+    let labels = &vec![("tier", tier)];
+    SPILL_TIER_WRITE_BYTES.get_or_create(labels).inc_by(c);
+}
+
 pub fn metrics_inc_group_by_spill_write_count() {
     let labels = &vec![("spill", "group_by_spill".to_string())];
     SPILL_WRITE_COUNT.get_or_create(labels).inc();
@@ -57,6 +57,14 @@ pub mod parquet_rs;
 pub use parquet_rs::read_metadata_async;
 pub use parquet_rs::read_parquet_schema_async_rs;
 
+mod spill;
+pub use spill::local_spill_prefix;
+pub use spill::spill_source;
+pub use spill::spill_target;
+pub use spill::LocalSpillDisk;
+pub use spill::SpillTier;
+pub use spill::LOCAL_SPILL_PREFIX;
+
 mod stage;
 pub use stage::init_stage_operator;
 pub use stage::StageFileInfo;
@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
+use dashmap::DashMap;
+use log::info;
+use log::warn;
+use opendal::Operator;
+
+use crate::init_operator;
+
+/// Spilled files on local disk are placed under `{LOCAL_SPILL_PREFIX}/{tenant}/{query_id}/`.
+pub const LOCAL_SPILL_PREFIX: &str = "_local_spill";
+
+/// The directory of the spilled files of the tenant on local disk.
+pub fn local_spill_prefix(tenant: &str) -> String {
+    format!("{}/{}", LOCAL_SPILL_PREFIX, tenant)
+}
+
+/// Where the spilled data is stored.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SpillTier {
+    Local,
+    Remote,
+}
+
+impl SpillTier {
+    /// Get the tier of the spilled file by its location.
+    pub fn of(location: &str) -> SpillTier {
+        match location.starts_with(LOCAL_SPILL_PREFIX) {
+            true => SpillTier::Local,
+            false => SpillTier::Remote,
+        }
+    }
+}
+
+impl Display for SpillTier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpillTier::Local => write!(f, "Local"),
+            SpillTier::Remote => write!(f, "Remote"),
+        }
+    }
+}
+
+/// The local disk tier of spilled data.
+///
+/// Spilling prefers the local disk of the query node until `max_bytes` is used up, and falls
+/// back to the remote storage after that. The spilled files of a query are removed when the
+/// query ends, the files left by a crashed process are removed at startup.
+pub struct LocalSpillDisk {
+    root: PathBuf,
+    operator: Operator,
+    max_bytes: u64,
+    used_bytes: AtomicU64,
+    // The directory and bytes reserved by each query.
+    query_bytes: DashMap<String, (String, u64)>,
+}
+
+impl LocalSpillDisk {
+    pub fn init(path: &str, max_bytes: u64) -> Result<()> {
+        let disk = match path.is_empty() || max_bytes == 0 {
+            true => None,
+            false => Some(Arc::new(Self::try_create(path, max_bytes)?)),
+        };
+        GlobalInstance::set(disk);
+        Ok(())
+    }
+
+    /// Get the local disk tier, `None` if it's not configured.
+    pub fn instance() -> Option<Arc<LocalSpillDisk>> {
+        GlobalInstance::try_get::<Option<Arc<LocalSpillDisk>>>().flatten()
+    }
+
+    pub fn try_create(path: &str, max_bytes: u64) -> Result<LocalSpillDisk> {
+        let root = PathBuf::from(path).join(LOCAL_SPILL_PREFIX);
+        if root.exists() {
+            // Nobody can read the files spilled before restart.
+            info!("Remove the spilled files left in {:?}", root);
+            std::fs::remove_dir_all(&root)?;
+        }
+        std::fs::create_dir_all(&root)?;
+
+        let operator = init_operator(&StorageParams::Fs(StorageFsConfig {
+            root: path.to_string(),
+        }))?;
+
+        Ok(LocalSpillDisk {
+            root,
+            operator,
+            max_bytes,
+            used_bytes: AtomicU64::new(0),
+            query_bytes: DashMap::new(),
+        })
+    }
+
+    pub fn operator(&self) -> Operator {
+        self.operator.clone()
+    }
+
+    pub fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    pub fn used_bytes(&self) -> u64 {
+        self.used_bytes.load(Ordering::Acquire)
+    }
+
+    /// Reserve `bytes` of the quota for the query, returns false if the quota is used up.
+    pub fn try_reserve(&self, tenant: &str, query_id: &str, bytes: u64) -> bool {
+        let reserved = self
+            .used_bytes
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                match used.checked_add(bytes) {
+                    Some(total) if total <= self.max_bytes => Some(total),
+                    _ => None,
+                }
+            });
+        if reserved.is_err() {
+            return false;
+        }
+        self.query_bytes
+            .entry(query_id.to_string())
+            .or_insert_with(|| (format!("{}/{}", tenant, query_id), 0))
+            .1 += bytes;
+        true
+    }
+
+    /// Remove the spilled files of the query and release its quota.
+    pub fn cleanup_query(&self, query_id: &str) {
+        if let Some((_, (dir, bytes))) = self.query_bytes.remove(query_id) {
+            let dir = self.root.join(dir);
+            if let Err(cause) = std::fs::remove_dir_all(&dir) {
+                if cause.kind() != std::io::ErrorKind::NotFound {
+                    warn!("Failed to remove spilled files in {:?}: {:?}", dir, cause);
+                }
+            }
+            self.used_bytes.fetch_sub(bytes, Ordering::AcqRel);
+        }
+    }
+}
+
+/// Choose the operator and location to write a spilled file of `bytes`.
+///
+/// The file is written to the local disk if it has enough room, otherwise it's written
+/// under the `location_prefix` of the remote `operator`.
+pub fn spill_target(
+    tenant: &str,
+    query_id: &str,
+    operator: &Operator,
+    location_prefix: &str,
+    name: &str,
+    bytes: u64,
+) -> (Operator, String) {
+    if let Some(disk) = LocalSpillDisk::instance() {
+        if disk.try_reserve(tenant, query_id, bytes) {
+            let location = format!("{}/{}/{}", local_spill_prefix(tenant), query_id, name);
+            return (disk.operator(), location);
+        }
+    }
+    (operator.clone(), format!("{}/{}", location_prefix, name))
+}
+
+/// Get the operator to read the spilled file at `location`.
+pub fn spill_source(operator: &Operator, location: &str) -> Operator {
+    match SpillTier::of(location) {
+        SpillTier::Local => match LocalSpillDisk::instance() {
+            Some(disk) => disk.operator(),
+            None => operator.clone(),
+        },
+        SpillTier::Remote => operator.clone(),
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::audit::AppendAuditRecordReq;
+use common_meta_app::audit::AuditRecord;
+use common_meta_app::audit::ListAuditRecordsReq;
+
+use crate::kv_app_error::KVAppError;
+
+/// Audit log of the changes to the schema and the privileges.
+#[async_trait::async_trait]
+pub trait AuditApi: Send + Sync {
+    /// Append a record, which is removed when it expires.
+    async fn append_audit_record(&self, req: AppendAuditRecordReq) -> Result<(), KVAppError>;
+
+    /// List the unexpired records of a tenant, in the order they are created.
+    async fn list_audit_records(
+        &self,
+        req: ListAuditRecordsReq,
+    ) -> Result<Vec<AuditRecord>, KVAppError>;
+}
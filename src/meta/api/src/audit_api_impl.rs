@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::audit::AppendAuditRecordReq;
+use common_meta_app::audit::AuditRecord;
+use common_meta_app::audit::AuditRecordIdent;
+use common_meta_app::audit::ListAuditRecordsReq;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq::Any;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use log::as_debug;
+use log::debug;
+use minitrace::func_name;
+
+use crate::audit_api::AuditApi;
+use crate::deserialize_struct;
+use crate::kv_app_error::KVAppError;
+use crate::serialize_struct;
+
+/// AuditApi is implemented upon kvapi::KVApi.
+/// Thus every type that impl kvapi::KVApi impls AuditApi.
+#[tonic::async_trait]
+impl<KV: kvapi::KVApi<Error = MetaError>> AuditApi for KV {
+    #[minitrace::trace]
+    async fn append_audit_record(&self, req: AppendAuditRecordReq) -> Result<(), KVAppError> {
+        debug!(req = as_debug!(&req); "AuditApi: {}", func_name!());
+
+        let ident = AuditRecordIdent {
+            tenant: req.tenant.clone(),
+            created_on_ms: req.record.created_on.timestamp_millis() as u64,
+            query_id: req.record.query_id.clone(),
+        };
+
+        // A record is never updated, thus there is no need to check the seq.
+        self.upsert_kv(UpsertKVReq::new(
+            ident.to_string_key().as_str(),
+            Any,
+            Operation::Update(serialize_struct(&req.record)?),
+            Some(KVMeta {
+                expire_at: Some(req.expire_at),
+            }),
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    #[minitrace::trace]
+    async fn list_audit_records(
+        &self,
+        req: ListAuditRecordsReq,
+    ) -> Result<Vec<AuditRecord>, KVAppError> {
+        debug!(req = as_debug!(&req); "AuditApi: {}", func_name!());
+
+        let prefix = format!("{}/{}/", AuditRecordIdent::PREFIX, req.tenant);
+        let reply = self.prefix_list_kv(&prefix).await?;
+
+        let mut res = Vec::with_capacity(reply.len());
+        for (_k, v) in reply {
+            let record: AuditRecord = deserialize_struct(&v.data)?;
+            res.push(record);
+        }
+        Ok(res)
+    }
+}
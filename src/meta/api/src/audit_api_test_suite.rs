@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::audit::AppendAuditRecordReq;
+use common_meta_app::audit::AuditRecord;
+use common_meta_app::audit::ListAuditRecordsReq;
+use common_meta_kvapi::kvapi;
+use common_meta_types::MetaError;
+use log::info;
+
+use crate::audit_api::AuditApi;
+
+fn new_audit_record(query_id: &str, operation: &str, created_on_sec: i64) -> AuditRecord {
+    AuditRecord {
+        user: "root".to_string(),
+        client_address: "127.0.0.1:3307".to_string(),
+        query_id: query_id.to_string(),
+        operation: operation.to_string(),
+        object: "default.db1.t1".to_string(),
+        before: None,
+        after: None,
+        query_text: "".to_string(),
+        created_on: Utc.timestamp_opt(created_on_sec, 0).unwrap(),
+    }
+}
+
+/// Test suite of `AuditApi`.
+///
+/// It is not used by this crate, but is used by other crate that impl `AuditApi`,
+/// to ensure an impl works as expected,
+/// such as `meta/embedded` and `metasrv`.
+#[derive(Copy, Clone)]
+pub struct AuditApiTestSuite {}
+
+impl AuditApiTestSuite {
+    /// Test AuditApi on a single node
+    pub async fn test_single_node<B, MT>(b: B) -> anyhow::Result<()>
+    where
+        B: kvapi::ApiBuilder<MT>,
+        MT: AuditApi + kvapi::AsKVApi<Error = MetaError>,
+    {
+        let suite = AuditApiTestSuite {};
+
+        suite.append_list_audit_records(&b.build().await).await?;
+        Ok(())
+    }
+
+    #[minitrace::trace]
+    async fn append_list_audit_records<MT: AuditApi + kvapi::AsKVApi<Error = MetaError>>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let now = Utc::now().timestamp();
+        let expire_at = (now + 3600) as u64;
+
+        info!("--- list audit records when there is no record");
+        {
+            let req = ListAuditRecordsReq {
+                tenant: tenant.to_string(),
+            };
+            let records = mt.list_audit_records(req).await?;
+            assert!(records.is_empty());
+        }
+
+        info!("--- append audit records out of order");
+        let create = new_audit_record("q1", "CreateTable", now - 10);
+        let drop = new_audit_record("q2", "DropTable", now);
+        {
+            for record in [drop.clone(), create.clone()] {
+                mt.append_audit_record(AppendAuditRecordReq {
+                    tenant: tenant.to_string(),
+                    record,
+                    expire_at,
+                })
+                .await?;
+            }
+
+            mt.append_audit_record(AppendAuditRecordReq {
+                tenant: "tenant2".to_string(),
+                record: new_audit_record("q3", "DropTable", now),
+                expire_at,
+            })
+            .await?;
+        }
+
+        info!("--- records are listed in time order");
+        {
+            let req = ListAuditRecordsReq {
+                tenant: tenant.to_string(),
+            };
+            let records = mt.list_audit_records(req).await?;
+            assert_eq!(vec![create, drop], records);
+        }
+
+        Ok(())
+    }
+}
@@ -16,6 +16,9 @@
 #![allow(clippy::diverging_sub_expression)]
 extern crate common_meta_types;
 
+mod audit_api;
+mod audit_api_impl;
+mod audit_api_test_suite;
 mod background_api;
 mod background_api_impl;
 mod background_api_keys;
@@ -27,6 +30,7 @@ mod data_mask_api_keys;
 mod id;
 mod id_generator;
 pub mod kv_app_error;
+mod quota_util;
 pub mod reply;
 mod schema_api;
 mod schema_api_impl;
@@ -39,11 +43,17 @@ mod share_api_test_suite;
 pub(crate) mod testing;
 pub(crate) mod util;
 
+pub use audit_api::AuditApi;
+pub use audit_api_test_suite::AuditApiTestSuite;
 pub use background_api::BackgroundApi;
 pub use background_api_test_suite::BackgroundApiTestSuite;
 pub use data_mask_api::DatamaskApi;
 pub use id::Id;
 pub(crate) use id_generator::IdGenerator;
+pub use quota_util::check_create_quota;
+pub use quota_util::get_tenant_quota;
+pub use quota_util::release_meta_bytes;
+pub use quota_util::update_meta_bytes;
 pub use schema_api::SchemaApi;
 pub(crate) use schema_api_impl::get_db_or_err;
 pub use schema_api_test_suite::SchemaApiTestSuite;
@@ -53,6 +63,7 @@ pub use util::assert_table_exist;
 pub use util::convert_share_meta_to_spec;
 pub use util::db_has_to_exist;
 pub use util::deserialize_struct;
+pub use util::deserialize_u64;
 pub use util::fetch_id;
 pub use util::get_object_shared_by_share_ids;
 pub use util::get_pb_value;
@@ -71,6 +82,7 @@ pub use util::remove_db_from_share;
 pub use util::send_txn;
 pub use util::serialize_struct;
 pub use util::serialize_u64;
+pub use util::txn_cond_exists;
 pub use util::txn_cond_seq;
 pub use util::txn_op_del;
 pub use util::txn_op_put;
@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Enforce the quota of a tenant in the transactions that create objects.
+
+use common_meta_app::app_error::AppError;
+use common_meta_app::app_error::TenantQuotaExceeded;
+use common_meta_app::tenant::TenantMetaBytesIdent;
+use common_meta_app::tenant::TenantQuota;
+use common_meta_app::tenant::TenantQuotaIdent;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_types::ConditionResult::Eq;
+use common_meta_types::ConditionResult::Lt;
+use common_meta_types::InvalidReply;
+use common_meta_types::MetaError;
+use common_meta_types::TxnCondition;
+use common_meta_types::TxnOp;
+
+use crate::get_u64_value;
+use crate::kv_app_error::KVAppError;
+use crate::serialize_u64;
+use crate::txn_cond_seq;
+use crate::txn_op_put;
+
+/// Get the quota of a tenant and its seq.
+///
+/// If no quota is set for the tenant, it returns seq 0 and a quota without any limit.
+pub async fn get_tenant_quota(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+) -> Result<(u64, TenantQuota), KVAppError> {
+    let key = TenantQuotaIdent::new(tenant);
+    let res = kv_api.get_kv(&key.to_string_key()).await?;
+
+    match res {
+        Some(seq_v) => {
+            let quota = serde_json::from_slice(&seq_v.data)
+                .map_err(|e| InvalidReply::new("decode tenant quota", &e))?;
+            Ok((seq_v.seq, quota))
+        }
+        None => Ok((0, TenantQuota::default())),
+    }
+}
+
+/// Check the quota of `tenant` for creating an object whose key starts with `prefix`.
+///
+/// - The number of existing keys with `prefix` must be less than `max_count(quota)`, 0 means no limit.
+/// - If `meta_bytes` is not `None`, the metadata bytes used by the tenant is increased by it,
+///   and must not exceed `quota.max_meta_bytes`, 0 means no limit.
+///   The used bytes are tracked even without a limit, so that a quota set later applies
+///   to the existing objects.
+///
+/// It returns the conditions and operations to add to the transaction that creates the object,
+/// so that the quota is enforced atomically:
+/// the transaction fails if the quota, the number of keys or the used bytes is changed by others.
+pub async fn check_create_quota(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+    resource: &str,
+    max_count: fn(&TenantQuota) -> u32,
+    prefix: &str,
+    meta_bytes: Option<u64>,
+    context: &str,
+) -> Result<(Vec<TxnCondition>, Vec<TxnOp>), KVAppError> {
+    let (quota_seq, quota) = get_tenant_quota(kv_api, tenant).await?;
+
+    let mut conditions = vec![txn_cond_seq(&TenantQuotaIdent::new(tenant), Eq, quota_seq)];
+    let mut ops = vec![];
+
+    let limit = max_count(&quota) as u64;
+    if limit > 0 {
+        let count = kv_api.prefix_list_kv(prefix).await?.len() as u64;
+        if count >= limit {
+            return Err(quota_exceeded(tenant, resource, limit, context));
+        }
+
+        conditions.push(TxnCondition::keys_with_prefix(prefix, Lt, limit));
+    }
+
+    if let Some(bytes) = meta_bytes {
+        let (cond, op) = build_update_meta_bytes(kv_api, tenant, &quota, 0, bytes, context).await?;
+        conditions.push(cond);
+        ops.push(op);
+    }
+
+    Ok((conditions, ops))
+}
+
+/// Change the metadata bytes used by `tenant` from `old_bytes` to `new_bytes`,
+/// when the meta of an object is updated, such as a table schema change.
+///
+/// It returns the conditions and operation to add to the transaction that updates the meta.
+pub async fn update_meta_bytes(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+    old_bytes: u64,
+    new_bytes: u64,
+    context: &str,
+) -> Result<(Vec<TxnCondition>, TxnOp), KVAppError> {
+    let (quota_seq, quota) = get_tenant_quota(kv_api, tenant).await?;
+
+    let (cond, op) =
+        build_update_meta_bytes(kv_api, tenant, &quota, old_bytes, new_bytes, context).await?;
+
+    let quota_cond = txn_cond_seq(&TenantQuotaIdent::new(tenant), Eq, quota_seq);
+    Ok((vec![quota_cond, cond], op))
+}
+
+/// Decrease the metadata bytes used by `tenant`, when databases or tables are removed.
+///
+/// It returns the condition and operation to add to the transaction that removes them.
+pub async fn release_meta_bytes(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+    bytes: u64,
+) -> Result<(TxnCondition, TxnOp), KVAppError> {
+    // Releasing never exceeds a quota, the quota is not required.
+    let quota = TenantQuota::default();
+
+    build_update_meta_bytes(kv_api, tenant, &quota, bytes, 0, "release_meta_bytes").await
+}
+
+/// Build the condition and operation that change the used meta bytes by `new_bytes - old_bytes`.
+///
+/// An increase must not exceed `quota.max_meta_bytes`, 0 means no limit.
+async fn build_update_meta_bytes(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+    quota: &TenantQuota,
+    old_bytes: u64,
+    new_bytes: u64,
+    context: &str,
+) -> Result<(TxnCondition, TxnOp), KVAppError> {
+    let key = TenantMetaBytesIdent::new(tenant);
+    let (seq, used) = get_u64_value(kv_api, &key).await?;
+
+    // The size of a meta may change after it is accounted, the used bytes is an approximate value.
+    let new_used = used.saturating_add(new_bytes).saturating_sub(old_bytes);
+    if quota.max_meta_bytes > 0 && new_bytes > old_bytes && new_used > quota.max_meta_bytes {
+        return Err(quota_exceeded(
+            tenant,
+            "meta bytes",
+            quota.max_meta_bytes,
+            context,
+        ));
+    }
+
+    Ok((
+        txn_cond_seq(&key, Eq, seq),
+        txn_op_put(&key, serialize_u64(new_used)?),
+    ))
+}
+
+fn quota_exceeded(tenant: &str, resource: &str, quota: u64, context: &str) -> KVAppError {
+    KVAppError::AppError(AppError::TenantQuotaExceeded(TenantQuotaExceeded::new(
+        tenant, resource, quota, context,
+    )))
+}
@@ -35,6 +35,7 @@ use common_meta_app::app_error::ShareHasNoGrantedPrivilege;
 use common_meta_app::app_error::StreamAlreadyExists;
 use common_meta_app::app_error::StreamVersionMismatched;
 use common_meta_app::app_error::TableAlreadyExists;
+use common_meta_app::app_error::TableAlreadyLocked;
 use common_meta_app::app_error::TableLockExpired;
 use common_meta_app::app_error::TableVersionMismatched;
 use common_meta_app::app_error::TxnRetryMaxTimes;
@@ -44,6 +45,7 @@ use common_meta_app::app_error::UndropTableAlreadyExists;
 use common_meta_app::app_error::UndropTableHasNoHistory;
 use common_meta_app::app_error::UndropTableWithNoDropTime;
 use common_meta_app::app_error::UnknownCatalog;
+use common_meta_app::app_error::UnknownDatabaseId;
 use common_meta_app::app_error::UnknownIndex;
 use common_meta_app::app_error::UnknownStreamId;
 use common_meta_app::app_error::UnknownTable;
@@ -192,6 +194,7 @@ use minitrace::func_name;
 use ConditionResult::Eq;
 
 use crate::assert_table_exist;
+use crate::check_create_quota;
 use crate::convert_share_meta_to_spec;
 use crate::db_has_to_exist;
 use crate::deserialize_struct;
@@ -206,14 +209,17 @@ use crate::is_db_need_to_be_remove;
 use crate::kv_app_error::KVAppError;
 use crate::list_keys;
 use crate::list_u64_value;
+use crate::release_meta_bytes;
 use crate::remove_db_from_share;
 use crate::send_txn;
 use crate::serialize_struct;
 use crate::serialize_u64;
+use crate::txn_cond_exists;
 use crate::txn_cond_seq;
 use crate::txn_op_del;
 use crate::txn_op_put;
 use crate::txn_op_put_with_expire;
+use crate::update_meta_bytes;
 use crate::util::db_id_has_to_exist;
 use crate::util::deserialize_u64;
 use crate::util::get_index_metas_by_ids;
@@ -295,6 +301,24 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             // if create database from a share then also need to update these record:
             // share_id -> share_meta
 
+            let db_meta = serialize_struct(&req.meta)?;
+
+            // The quota of the tenant is checked again by the transaction.
+            let db_prefix = DatabaseNameIdent {
+                tenant: name_key.tenant.clone(),
+                db_name: "".to_string(),
+            };
+            let (quota_conditions, quota_ops) = check_create_quota(
+                self,
+                &name_key.tenant,
+                "databases",
+                |q| q.max_databases,
+                &db_prefix.to_string_key(),
+                Some(db_meta.len() as u64),
+                "create_database",
+            )
+            .await?;
+
             let db_id = fetch_id(self, IdGenerator::database_id()).await?;
             let id_key = DatabaseId { db_id };
             let id_to_name_key = DatabaseIdToName { db_id };
@@ -305,17 +329,20 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 // append db_id into db_id_list
                 db_id_list.append(db_id);
 
-                let condition = vec![
+                let mut condition = vec![
                     txn_cond_seq(name_key, Eq, 0),
                     txn_cond_seq(&id_to_name_key, Eq, 0),
                     txn_cond_seq(&dbid_idlist, Eq, db_id_list_seq),
                 ];
-                let if_then = vec![
+                condition.extend(quota_conditions);
+
+                let mut if_then = vec![
                     txn_op_put(name_key, serialize_u64(db_id)?), // (tenant, db_name) -> db_id
-                    txn_op_put(&id_key, serialize_struct(&req.meta)?), // (db_id) -> db_meta
+                    txn_op_put(&id_key, db_meta),                // (db_id) -> db_meta
                     txn_op_put(&dbid_idlist, serialize_struct(&db_id_list)?), /* _fd_db_id_list/<tenant>/<db_name> -> db_id_list */
                     txn_op_put(&id_to_name_key, serialize_struct(name_key)?), /* __fd_database_id_to_name/<db_id> -> (tenant,db_name) */
                 ];
+                if_then.extend(quota_ops);
 
                 let txn_req = TxnRequest {
                     condition,
@@ -1294,59 +1321,45 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
     ) -> Result<CreateVirtualColumnReply, KVAppError> {
         debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
 
-        let ctx = &func_name!();
-        let mut trials = txn_trials(None, ctx);
-        loop {
-            trials.next().unwrap()?;
-
-            let (_, old_virtual_column_opt): (_, Option<VirtualColumnMeta>) =
-                get_pb_value(self, &req.name_ident).await?;
-
-            if old_virtual_column_opt.is_some() {
-                return Err(KVAppError::AppError(AppError::VirtualColumnAlreadyExists(
-                    VirtualColumnAlreadyExists::new(
-                        req.name_ident.table_id,
-                        format!(
-                            "create virtual column with tenant: {} table_id: {}",
-                            req.name_ident.tenant, req.name_ident.table_id
-                        ),
-                    ),
-                )));
-            }
-            let virtual_column_meta = VirtualColumnMeta {
-                table_id: req.name_ident.table_id,
-                virtual_columns: req.virtual_columns.clone(),
-                created_on: Utc::now(),
-                updated_on: None,
-            };
+        let virtual_column_meta = VirtualColumnMeta {
+            table_id: req.name_ident.table_id,
+            virtual_columns: req.virtual_columns.clone(),
+            created_on: Utc::now(),
+            updated_on: None,
+        };
 
-            // Create virtual column by inserting this record:
-            // (tenant, table_id) -> virtual_column_meta
-            {
-                let condition = vec![txn_cond_seq(&req.name_ident, Eq, 0)];
-                let if_then = vec![txn_op_put(
-                    &req.name_ident,
-                    serialize_struct(&virtual_column_meta)?,
-                )];
+        // Create virtual column by inserting this record:
+        // (tenant, table_id) -> virtual_column_meta
+        let condition = vec![txn_cond_exists(&req.name_ident, false)];
+        let if_then = vec![txn_op_put(
+            &req.name_ident,
+            serialize_struct(&virtual_column_meta)?,
+        )];
 
-                let txn_req = TxnRequest {
-                    condition,
-                    if_then,
-                    else_then: vec![],
-                };
+        let txn_req = TxnRequest {
+            condition,
+            if_then,
+            else_then: vec![],
+        };
 
-                let (succ, _responses) = send_txn(self, txn_req).await?;
+        let (succ, _responses) = send_txn(self, txn_req).await?;
 
-                debug!(
-                    "req.name_ident" = as_debug!(&virtual_column_meta),
-                    succ = succ;
-                    "create_virtual_column"
-                );
+        debug!(
+            "req.name_ident" = as_debug!(&virtual_column_meta),
+            succ = succ;
+            "create_virtual_column"
+        );
 
-                if succ {
-                    break;
-                }
-            }
+        if !succ {
+            return Err(KVAppError::AppError(AppError::VirtualColumnAlreadyExists(
+                VirtualColumnAlreadyExists::new(
+                    req.name_ident.table_id,
+                    format!(
+                        "create virtual column with tenant: {} table_id: {}",
+                        req.name_ident.tenant, req.name_ident.table_id
+                    ),
+                ),
+            )));
         }
 
         Ok(CreateVirtualColumnReply {})
@@ -1414,34 +1427,28 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
 
         let ctx = &func_name!();
-        let mut trials = txn_trials(None, ctx);
-        loop {
-            trials.next().unwrap()?;
-
-            let (_, _) = get_virtual_column_by_id_or_err(self, &req.name_ident, ctx).await?;
 
-            // Drop virtual column by deleting this record:
-            // (tenant, table_id) -> virtual_column_meta
-            {
-                let if_then = vec![txn_op_del(&req.name_ident)];
-                let txn_req = TxnRequest {
-                    condition: vec![],
-                    if_then,
-                    else_then: vec![],
-                };
+        // Drop virtual column by deleting this record:
+        // (tenant, table_id) -> virtual_column_meta
+        let condition = vec![txn_cond_exists(&req.name_ident, true)];
+        let if_then = vec![txn_op_del(&req.name_ident)];
+        let txn_req = TxnRequest {
+            condition,
+            if_then,
+            else_then: vec![],
+        };
 
-                let (succ, _responses) = send_txn(self, txn_req).await?;
+        let (succ, _responses) = send_txn(self, txn_req).await?;
 
-                debug!(
-                    "req.name_ident" = as_debug!(&req.name_ident),
-                    succ = succ;
-                    "drop_virtual_column"
-                );
+        debug!(
+            "req.name_ident" = as_debug!(&req.name_ident),
+            succ = succ;
+            "drop_virtual_column"
+        );
 
-                if succ {
-                    break;
-                }
-            }
+        if !succ {
+            // Report the absent virtual column.
+            get_virtual_column_by_id_or_err(self, &req.name_ident, ctx).await?;
         }
 
         Ok(DropVirtualColumnReply {})
@@ -1588,6 +1595,25 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     (0, tb_count)
                 }
             };
+
+            let table_meta = serialize_struct(&req.table_meta)?;
+
+            // The quota of the tenant is checked again by the transaction.
+            let table_prefix = DBIdTableName {
+                db_id,
+                table_name: "".to_string(),
+            };
+            let (quota_conditions, quota_ops) = check_create_quota(
+                self,
+                &tenant_dbname.tenant,
+                "tables per database",
+                |q| q.max_tables_per_database,
+                &table_prefix.to_string_key(),
+                Some(table_meta.len() as u64),
+                "create_table",
+            )
+            .await?;
+
             // Create table by inserting these record:
             // (db_id, table_name) -> table_id
             // (table_id) -> table_meta
@@ -1615,29 +1641,35 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 // append new table_id into list
                 tb_id_list.append(table_id);
 
+                let mut condition = vec![
+                    // db has not to change, i.e., no new table is created.
+                    // Renaming db is OK and does not affect the seq of db_meta.
+                    txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
+                    // no other table with the same name is inserted.
+                    txn_cond_seq(&dbid_tbname, Eq, 0),
+                    // no other table id with the same name is append.
+                    txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq),
+                    // update table count atomically
+                    txn_cond_seq(&tb_count_key, Eq, tb_count_seq),
+                    txn_cond_seq(&table_id_to_name_key, Eq, 0),
+                ];
+                condition.extend(quota_conditions);
+
+                let mut if_then = vec![
+                    // Changing a table in a db has to update the seq of db_meta,
+                    // to block the batch-delete-tables when deleting a db.
+                    txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
+                    txn_op_put(&dbid_tbname, serialize_u64(table_id)?), /* (tenant, db_id, tb_name) -> tb_id */
+                    txn_op_put(&tbid, table_meta), // (tenant, db_id, tb_id) -> tb_meta
+                    txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?), /* _fd_table_id_list/db_id/table_name -> tb_id_list */
+                    txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?), /* _fd_table_count/tenant -> tb_count */
+                    txn_op_put(&table_id_to_name_key, serialize_struct(&db_id_table_name)?), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
+                ];
+                if_then.extend(quota_ops);
+
                 let txn_req = TxnRequest {
-                    condition: vec![
-                        // db has not to change, i.e., no new table is created.
-                        // Renaming db is OK and does not affect the seq of db_meta.
-                        txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
-                        // no other table with the same name is inserted.
-                        txn_cond_seq(&dbid_tbname, Eq, 0),
-                        // no other table id with the same name is append.
-                        txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq),
-                        // update table count atomically
-                        txn_cond_seq(&tb_count_key, Eq, tb_count_seq),
-                        txn_cond_seq(&table_id_to_name_key, Eq, 0),
-                    ],
-                    if_then: vec![
-                        // Changing a table in a db has to update the seq of db_meta,
-                        // to block the batch-delete-tables when deleting a db.
-                        txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
-                        txn_op_put(&dbid_tbname, serialize_u64(table_id)?), /* (tenant, db_id, tb_name) -> tb_id */
-                        txn_op_put(&tbid, serialize_struct(&req.table_meta)?), /* (tenant, db_id, tb_id) -> tb_meta */
-                        txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?), /* _fd_table_id_list/db_id/table_name -> tb_id_list */
-                        txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?), /* _fd_table_count/tenant -> tb_count */
-                        txn_op_put(&table_id_to_name_key, serialize_struct(&db_id_table_name)?), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
-                    ],
+                    condition,
+                    if_then,
                     else_then: vec![],
                 };
 
@@ -2576,29 +2608,14 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
 
         // 1. Grab a snapshot view of the copied files of a table.
         //
-        // If table seq is not changed before and after listing, we can be sure the list of copied
-        // files is consistent to this version of the table.
-
-        let (mut seq_1, _tb_meta) = get_table_by_id_or_err(self, &table_id, ctx).await?;
-
-        let mut trials = txn_trials(None, ctx);
-        let copied_files = loop {
-            trials.next().unwrap()?;
-
-            let copied_files = list_table_copied_files(self, table_id.table_id).await?;
+        // The copied files are listed in a single transaction,
+        // thus the list is consistent to the version of the table.
 
-            let (seq_2, _tb_meta) = get_table_by_id_or_err(self, &table_id, ctx).await?;
-
-            if seq_1 == seq_2 {
-                debug!(
-                    "list all copied file of table {}: {:?}",
-                    table_id.table_id, copied_files
-                );
-                break copied_files;
-            } else {
-                seq_1 = seq_2;
-            }
-        };
+        let copied_files = list_table_copied_files_in_txn(self, &table_id, ctx).await?;
+        debug!(
+            "list all copied file of table {}: {:?}",
+            table_id.table_id, copied_files
+        );
 
         // 2. Remove the copied files only when the seq of a copied file has not changed.
         //
@@ -2629,6 +2646,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 if_then.push(TxnOp::delete_exact(copied_str_key, Some(copied_seq)));
             }
 
+            // Re-writing the table meta requires the latest meta, thus retry on conflict.
             let mut trials = txn_trials(None, ctx);
             loop {
                 trials.next().unwrap()?;
@@ -2786,17 +2804,39 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 })),
             };
 
+            let new_table_meta = serialize_struct(&req.new_table_meta)?;
+
             let mut txn_req = TxnRequest {
                 condition: vec![
                     // table is not changed
                     txn_cond_seq(&tbid, Eq, tb_meta_seq),
                 ],
                 if_then: vec![
-                    txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?), // tb_id -> tb_meta
+                    txn_op_put(&tbid, new_table_meta.clone()), // tb_id -> tb_meta
                 ],
                 else_then: vec![get_table_meta],
             };
 
+            // A schema change, such as ALTER TABLE ADD COLUMN, changes the meta bytes used by the tenant.
+            // Other updates, such as a commit of new data, are not accounted.
+            let old_table_meta = table_meta.as_ref().unwrap();
+            if old_table_meta.schema != req.new_table_meta.schema {
+                let tenant = get_tenant_by_table_id(self, req.table_id).await?;
+                let old_bytes = serialize_struct(old_table_meta)?.len() as u64;
+
+                let (conditions, op) = update_meta_bytes(
+                    self,
+                    &tenant,
+                    old_bytes,
+                    new_table_meta.len() as u64,
+                    "update_table_meta",
+                )
+                .await?;
+
+                txn_req.condition.extend(conditions);
+                txn_req.if_then.push(op);
+            }
+
             if let Some(req) = &req.copied_files {
                 let (conditions, match_operations) =
                     build_upsert_table_copied_file_info_conditions(
@@ -3313,52 +3353,51 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         let key = lock_key.gen_key(revision);
 
         let ctx = &func_name!();
-        let mut trials = txn_trials(None, ctx);
-        loop {
-            trials.next().unwrap()?;
 
-            let (tb_meta_seq, _) = get_table_by_id_or_err(self, &tbid, ctx).await?;
-
-            let lock_meta = LockMeta {
-                user: req.user.clone(),
-                node: req.node.clone(),
-                query_id: req.query_id.clone(),
-                created_on: Utc::now(),
-                acquired_on: None,
-                lock_type: lock_type.clone(),
-                extra_info: extra_info.clone(),
-            };
+        let lock_meta = LockMeta {
+            user: req.user.clone(),
+            node: req.node.clone(),
+            query_id: req.query_id.clone(),
+            created_on: Utc::now(),
+            acquired_on: None,
+            lock_type,
+            extra_info,
+        };
 
-            let condition = vec![
-                // table is not changed
-                txn_cond_seq(&tbid, Eq, tb_meta_seq),
-                // assumes lock are absent.
-                txn_cond_seq(&key, Eq, 0),
-            ];
+        // The lock is only attached to the table, a change to the table does not affect it.
+        // Thus it is created in a single round trip, without retrying.
+        let condition = vec![
+            txn_cond_exists(&tbid, true),
+            // assumes lock are absent.
+            txn_cond_seq(&key, Eq, 0),
+        ];
 
-            let if_then = vec![txn_op_put_with_expire(
-                &key,
-                serialize_struct(&lock_meta)?,
-                SeqV::<()>::now_ms() / 1000 + req.expire_secs,
-            )];
+        let if_then = vec![txn_op_put_with_expire(
+            &key,
+            serialize_struct(&lock_meta)?,
+            SeqV::<()>::now_ms() / 1000 + req.expire_secs,
+        )];
 
-            let txn_req = TxnRequest {
-                condition,
-                if_then,
-                else_then: vec![],
-            };
+        let txn_req = TxnRequest {
+            condition,
+            if_then,
+            else_then: vec![],
+        };
 
-            let (succ, _responses) = send_txn(self, txn_req).await?;
+        let (succ, _responses) = send_txn(self, txn_req).await?;
 
-            debug!(
-                ident = as_display!(&tbid),
-                succ = succ;
-                "create_lock_revision"
-            );
+        debug!(
+            ident = as_display!(&tbid),
+            succ = succ;
+            "create_lock_revision"
+        );
 
-            if succ {
-                break;
-            }
+        if !succ {
+            // Report the absent table, otherwise the lock revision is already taken.
+            get_table_by_id_or_err(self, &tbid, ctx).await?;
+            return Err(KVAppError::AppError(AppError::TableAlreadyLocked(
+                TableAlreadyLocked::new(table_id, revision, ctx),
+            )));
         }
 
         Ok(CreateLockRevReply { revision })
@@ -3439,39 +3478,20 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         let table_id = lock_key.get_table_id();
         let tbid = TableId { table_id };
 
-        let ctx = &func_name!();
-        let mut trials = txn_trials(None, ctx);
-
-        loop {
-            trials.next().unwrap()?;
-
-            let (lock_seq, _): (_, Option<LockMeta>) = get_pb_value(self, &key).await?;
-            if lock_seq == 0 {
-                // The lock has been deleted.
-                break;
-            }
-
-            let condition = vec![txn_cond_seq(&key, Eq, lock_seq)];
-            let if_then = vec![txn_op_del(&key)];
-
-            let txn_req = TxnRequest {
-                condition,
-                if_then,
-                else_then: vec![],
-            };
+        // Deleting an absent lock is a no-op, no condition is required.
+        let txn_req = TxnRequest {
+            condition: vec![],
+            if_then: vec![txn_op_del(&key)],
+            else_then: vec![],
+        };
 
-            let (succ, _responses) = send_txn(self, txn_req).await?;
+        let (succ, _responses) = send_txn(self, txn_req).await?;
 
-            debug!(
-                ident = as_display!(&tbid),
-                succ = succ;
-                "delete_lock_revision"
-            );
-
-            if succ {
-                break;
-            }
-        }
+        debug!(
+            ident = as_display!(&tbid),
+            succ = succ;
+            "delete_lock_revision"
+        );
 
         Ok(())
     }
@@ -3486,76 +3506,64 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
 
         let name_key = &req.name_ident;
 
-        let ctx = &func_name!();
-
-        let mut trials = txn_trials(None, ctx);
-
-        let catalog_id = loop {
-            trials.next().unwrap()?;
-
-            // Get catalog by name to ensure absence
-            let (catalog_id_seq, catalog_id) = get_u64_value(self, name_key).await?;
-            debug!(
-                catalog_id_seq = catalog_id_seq,
-                catalog_id = catalog_id,
-                name_key = as_debug!(name_key);
-                "get_catalog"
-            );
-
-            if catalog_id_seq > 0 {
-                return if req.if_not_exists {
-                    Ok(CreateCatalogReply { catalog_id })
-                } else {
-                    Err(KVAppError::AppError(AppError::CatalogAlreadyExists(
-                        CatalogAlreadyExists::new(
-                            &name_key.catalog_name,
-                            format!("create catalog: tenant: {}", name_key.tenant),
-                        ),
-                    )))
-                };
-            }
+        // Create catalog by inserting these record:
+        // (tenant, catalog_name) -> catalog_id
+        // (catalog_id) -> catalog_meta
+        // (catalog_id) -> (tenant, catalog_name)
+        let catalog_id = fetch_id(self, IdGenerator::catalog_id()).await?;
+        let id_key = CatalogId { catalog_id };
+        let id_to_name_key = CatalogIdToName { catalog_id };
 
-            // Create catalog by inserting these record:
-            // (tenant, catalog_name) -> catalog_id
-            // (catalog_id) -> catalog_meta
-            // (catalog_id) -> (tenant, catalog_name)
-            let catalog_id = fetch_id(self, IdGenerator::catalog_id()).await?;
-            let id_key = CatalogId { catalog_id };
-            let id_to_name_key = CatalogIdToName { catalog_id };
+        debug!(catalog_id = catalog_id, name_key = as_debug!(name_key); "new catalog id");
 
-            debug!(catalog_id = catalog_id, name_key = as_debug!(name_key); "new catalog id");
+        let condition = vec![
+            txn_cond_exists(name_key, false),
+            txn_cond_exists(&id_to_name_key, false),
+        ];
+        let if_then = vec![
+            txn_op_put(name_key, serialize_u64(catalog_id)?), /* (tenant, catalog_name) -> catalog_id */
+            txn_op_put(&id_key, serialize_struct(&req.meta)?), // (catalog_id) -> catalog_meta
+            txn_op_put(&id_to_name_key, serialize_struct(name_key)?), /* __fd_catalog_id_to_name/<catalog_id> -> (tenant,catalog_name) */
+        ];
 
-            {
-                let condition = vec![
-                    txn_cond_seq(name_key, Eq, 0),
-                    txn_cond_seq(&id_to_name_key, Eq, 0),
-                ];
-                let if_then = vec![
-                    txn_op_put(name_key, serialize_u64(catalog_id)?), /* (tenant, catalog_name) -> catalog_id */
-                    txn_op_put(&id_key, serialize_struct(&req.meta)?), /* (catalog_id) -> catalog_meta */
-                    txn_op_put(&id_to_name_key, serialize_struct(name_key)?), /* __fd_catalog_id_to_name/<catalog_id> -> (tenant,catalog_name) */
-                ];
+        let txn_req = TxnRequest {
+            condition,
+            if_then,
+            // Return the existing catalog id if the name is taken.
+            else_then: vec![TxnOp {
+                request: Some(Request::Get(TxnGetRequest {
+                    key: name_key.to_string_key(),
+                })),
+            }],
+        };
 
-                let txn_req = TxnRequest {
-                    condition,
-                    if_then,
-                    else_then: vec![],
-                };
+        let (succ, responses) = send_txn(self, txn_req).await?;
 
-                let (succ, _) = send_txn(self, txn_req).await?;
+        debug!(
+            name = as_debug!(name_key),
+            id = as_debug!(&id_key),
+            succ = succ;
+            "create_catalog"
+        );
 
-                debug!(
-                    name = as_debug!(name_key),
-                    id = as_debug!(&id_key),
-                    succ = succ;
-                    "create_catalog"
-                );
+        if !succ {
+            let existing = match responses.first().and_then(|r| r.response.as_ref()) {
+                Some(Response::Get(get_resp)) => get_resp.value.as_ref(),
+                _ => None,
+            };
 
-                if succ {
-                    break catalog_id;
-                }
-            }
-        };
+            return match existing {
+                Some(seq_v) if req.if_not_exists => Ok(CreateCatalogReply {
+                    catalog_id: *deserialize_u64(&seq_v.data)?,
+                }),
+                _ => Err(KVAppError::AppError(AppError::CatalogAlreadyExists(
+                    CatalogAlreadyExists::new(
+                        &name_key.catalog_name,
+                        format!("create catalog: tenant: {}", name_key.tenant),
+                    ),
+                ))),
+            };
+        }
 
         Ok(CreateCatalogReply { catalog_id })
     }
@@ -3586,69 +3594,62 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
 
         let name_key = &req.name_ident;
 
-        let ctx = &func_name!();
+        let msg = format!("drop_catalog: {}", &name_key);
 
-        let mut trials = txn_trials(None, ctx);
+        let res = get_catalog_or_err(self, name_key, &msg).await;
 
-        loop {
-            trials.next().unwrap()?;
-
-            let res =
-                get_catalog_or_err(self, name_key, format!("drop_catalog: {}", &name_key)).await;
-
-            let (_, catalog_id, catalog_meta_seq, _) = match res {
-                Ok(x) => x,
-                Err(e) => {
-                    if let KVAppError::AppError(AppError::UnknownCatalog(_)) = e {
-                        if req.if_exists {
-                            return Ok(DropCatalogReply {});
-                        }
+        let (catalog_id_seq, catalog_id, _, _) = match res {
+            Ok(x) => x,
+            Err(e) => {
+                if let KVAppError::AppError(AppError::UnknownCatalog(_)) = e {
+                    if req.if_exists {
+                        return Ok(DropCatalogReply {});
                     }
-
-                    return Err(e);
                 }
-            };
 
-            // Delete catalog by deleting these record:
-            // (tenant, catalog_name) -> catalog_id
-            // (catalog_id) -> catalog_meta
-            // (catalog_id) -> (tenant, catalog_name)
-            let id_key = CatalogId { catalog_id };
-            let id_to_name_key = CatalogIdToName { catalog_id };
+                return Err(e);
+            }
+        };
 
-            debug!(
-                catalog_id = catalog_id,
-                name_key = as_debug!(&name_key);
-                "catalog keys to delete"
-            );
+        // Delete catalog by deleting these record:
+        // (tenant, catalog_name) -> catalog_id
+        // (catalog_id) -> catalog_meta
+        // (catalog_id) -> (tenant, catalog_name)
+        let id_key = CatalogId { catalog_id };
+        let id_to_name_key = CatalogIdToName { catalog_id };
 
-            {
-                let condition = vec![txn_cond_seq(&id_key, Eq, catalog_meta_seq)];
-                let if_then = vec![
-                    txn_op_del(name_key),        // (tenant, catalog_name) -> catalog_id
-                    txn_op_del(&id_key),         // (catalog_id) -> catalog_meta
-                    txn_op_del(&id_to_name_key), /* __fd_catalog_id_to_name/<catalog_id> -> (tenant,catalog_name) */
-                ];
+        debug!(
+            catalog_id = catalog_id,
+            name_key = as_debug!(&name_key);
+            "catalog keys to delete"
+        );
 
-                let txn_req = TxnRequest {
-                    condition,
-                    if_then,
-                    else_then: vec![],
-                };
+        // The name has to still point to the same catalog.
+        let condition = vec![txn_cond_seq(name_key, Eq, catalog_id_seq)];
+        let if_then = vec![
+            txn_op_del(name_key),        // (tenant, catalog_name) -> catalog_id
+            txn_op_del(&id_key),         // (catalog_id) -> catalog_meta
+            txn_op_del(&id_to_name_key), /* __fd_catalog_id_to_name/<catalog_id> -> (tenant,catalog_name) */
+        ];
 
-                let (succ, _) = send_txn(self, txn_req).await?;
+        let txn_req = TxnRequest {
+            condition,
+            if_then,
+            else_then: vec![],
+        };
 
-                debug!(
-                    name = as_debug!(&name_key),
-                    id = as_debug!(&id_key),
-                    succ = succ;
-                    "drop_catalog"
-                );
+        let (succ, _) = send_txn(self, txn_req).await?;
 
-                if succ {
-                    break;
-                }
-            }
+        debug!(
+            name = as_debug!(&name_key),
+            id = as_debug!(&id_key),
+            succ = succ;
+            "drop_catalog"
+        );
+
+        // The catalog is dropped by others.
+        if !succ && !req.if_exists {
+            catalog_has_to_exist(0, name_key, &msg)?;
         }
 
         Ok(DropCatalogReply {})
@@ -3720,6 +3721,9 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
 
         let table_id = req.table_id;
         let ctx = &func_name!();
+
+        // The new lvt is the max of the current one and the requested one,
+        // it can not be expressed with a txn condition, thus retry on conflict.
         let mut trials = txn_trials(None, ctx);
 
         loop {
@@ -3839,6 +3843,83 @@ async fn list_table_copied_files(
     Ok(copied_files)
 }
 
+/// Get the tenant a table belongs to, by the id of the table.
+async fn get_tenant_by_table_id(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    table_id: u64,
+) -> Result<String, KVAppError> {
+    let (_, table_name): (_, Option<DBIdTableName>) =
+        get_pb_value(kv_api, &TableIdToName { table_id }).await?;
+    let db_id = table_name
+        .ok_or_else(|| {
+            KVAppError::AppError(AppError::UnknownTableId(UnknownTableId::new(
+                table_id,
+                "get_tenant_by_table_id",
+            )))
+        })?
+        .db_id;
+
+    let (_, db_name): (_, Option<DatabaseNameIdent>) =
+        get_pb_value(kv_api, &DatabaseIdToName { db_id }).await?;
+    let db_name = db_name.ok_or_else(|| {
+        KVAppError::AppError(AppError::UnknownDatabaseId(UnknownDatabaseId::new(
+            db_id,
+            "get_tenant_by_table_id",
+        )))
+    })?;
+
+    Ok(db_name.tenant)
+}
+
+/// List the copied file identities belonging to a table with a range-get in a transaction.
+///
+/// It returns UnknownTableId error if the table does not exist.
+async fn list_table_copied_files_in_txn(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    table_id: &TableId,
+    ctx: &str,
+) -> Result<Vec<TableCopiedFileNameIdent>, KVAppError> {
+    let prefix = TableCopiedFileNameIdent {
+        table_id: table_id.table_id,
+        file: "".to_string(),
+    }
+    .to_string_key();
+
+    // Safe unwrap(): the prefix built from a table id is ascii.
+    let (start, end) = kvapi::prefix_to_range(&prefix).unwrap();
+
+    let txn_req = TxnRequest {
+        condition: vec![txn_cond_exists(table_id, true)],
+        if_then: vec![TxnOp::range_get(start, Some(end), 0)],
+        else_then: vec![],
+    };
+
+    let (succ, responses) = send_txn(kv_api, txn_req).await?;
+
+    if !succ {
+        // Report the absent table.
+        get_table_by_id_or_err(kv_api, table_id, ctx).await?;
+    }
+
+    let mut copied_files = vec![];
+
+    for resp in responses {
+        if let Some(Response::RangeGet(range_resp)) = resp.response {
+            for item in range_resp.items {
+                let ident = TableCopiedFileNameIdent::from_str_key(&item.key).map_err(|e| {
+                    KVAppError::MetaError(MetaError::from(InvalidReply::new(
+                        "list_table_copied_files",
+                        &e,
+                    )))
+                })?;
+                copied_files.push(ident);
+            }
+        }
+    }
+
+    Ok(copied_files)
+}
+
 // Return true if drop time is out of `DATA_RETENTION_TIME_IN_DAYS option,
 // use DEFAULT_DATA_RETENTION_SECONDS by default.
 fn is_drop_time_out_of_retention_time(
@@ -4096,6 +4177,7 @@ fn build_upsert_table_deduplicated_label(deduplicated_label: String) -> TxnOp {
             value: 1_i8.to_le_bytes().to_vec(),
             prev_value: false,
             expire_at,
+            lease_id: None,
         })),
     }
 }
@@ -4376,11 +4458,15 @@ async fn gc_dropped_db_by_id(
             continue;
         }
         let dbid = DatabaseId { db_id };
-        let (db_meta_seq, _db_meta): (_, Option<DatabaseMeta>) =
-            get_pb_value(kv_api, &dbid).await?;
+        let (db_meta_seq, db_meta): (_, Option<DatabaseMeta>) = get_pb_value(kv_api, &dbid).await?;
         if db_meta_seq == 0 {
             return Ok(());
         }
+        // The metadata bytes released from the tenant usage.
+        let mut meta_bytes = match &db_meta {
+            Some(db_meta) => serialize_struct(db_meta)?.len() as u64,
+            None => 0,
+        };
         let id_to_name = DatabaseIdToName { db_id };
         let (name_ident_seq, _name_ident): (_, Option<DatabaseNameIdent>) =
             get_pb_value(kv_api, &id_to_name).await?;
@@ -4420,7 +4506,14 @@ async fn gc_dropped_db_by_id(
                 };
 
                 for tb_id in tb_id_list.id_list {
-                    gc_dropped_table_data(kv_api, tb_id, &mut condition, &mut if_then).await?;
+                    gc_dropped_table_data(
+                        kv_api,
+                        tb_id,
+                        &mut condition,
+                        &mut if_then,
+                        &mut meta_bytes,
+                    )
+                    .await?;
                     gc_dropped_table_index(kv_api, &tenant, tb_id, &mut if_then).await?;
                 }
 
@@ -4447,6 +4540,12 @@ async fn gc_dropped_db_by_id(
         condition.push(txn_cond_seq(&id_to_name, Eq, name_ident_seq));
         if_then.push(txn_op_del(&id_to_name));
 
+        if meta_bytes > 0 {
+            let (cond, op) = release_meta_bytes(kv_api, &tenant, meta_bytes).await?;
+            condition.push(cond);
+            if_then.push(op);
+        }
+
         let txn_req = TxnRequest {
             condition,
             if_then,
@@ -4496,9 +4595,23 @@ async fn gc_dropped_table_by_id(
                 serialize_struct(&tb_id_list)?,
             ));
         }
-        gc_dropped_table_data(kv_api, table_id, &mut condition, &mut if_then).await?;
+        let mut meta_bytes = 0;
+        gc_dropped_table_data(
+            kv_api,
+            table_id,
+            &mut condition,
+            &mut if_then,
+            &mut meta_bytes,
+        )
+        .await?;
         gc_dropped_table_index(kv_api, &tenant, table_id, &mut if_then).await?;
 
+        if meta_bytes > 0 {
+            let (cond, op) = release_meta_bytes(kv_api, &tenant, meta_bytes).await?;
+            condition.push(cond);
+            if_then.push(op);
+        }
+
         let txn_req = TxnRequest {
             condition,
             if_then,
@@ -4511,11 +4624,14 @@ async fn gc_dropped_table_by_id(
     Ok(())
 }
 
+/// Remove the meta of a dropped table,
+/// and add the size of the removed table meta to `meta_bytes`.
 async fn gc_dropped_table_data(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     table_id: u64,
     condition: &mut Vec<TxnCondition>,
     if_then: &mut Vec<TxnOp>,
+    meta_bytes: &mut u64,
 ) -> Result<(), KVAppError> {
     let tbid = TableId { table_id };
     let id_to_name = TableIdToName { table_id };
@@ -4523,13 +4639,17 @@ async fn gc_dropped_table_data(
     // Get meta data
     let (tb_meta_seq, tb_meta): (_, Option<TableMeta>) = get_pb_value(kv_api, &tbid).await?;
 
-    if tb_meta_seq == 0 || tb_meta.is_none() {
-        error!(
-            "gc_dropped_table_by_id cannot find {:?} table_meta",
-            table_id
-        );
-        return Ok(());
-    }
+    let tb_meta = match tb_meta {
+        Some(tb_meta) if tb_meta_seq != 0 => tb_meta,
+        _ => {
+            error!(
+                "gc_dropped_table_by_id cannot find {:?} table_meta",
+                table_id
+            );
+            return Ok(());
+        }
+    };
+    *meta_bytes += serialize_struct(&tb_meta)?.len() as u64;
 
     // Get id -> name mapping
     let (name_seq, _name): (_, Option<DBIdTableName>) = get_pb_value(kv_api, &id_to_name).await?;
@@ -114,6 +114,9 @@ use common_meta_app::share::ShareGrantObjectPrivilege;
 use common_meta_app::share::ShareNameIdent;
 use common_meta_app::storage::StorageParams;
 use common_meta_app::storage::StorageS3Config;
+use common_meta_app::tenant::TenantMetaBytesIdent;
+use common_meta_app::tenant::TenantQuota;
+use common_meta_app::tenant::TenantQuotaIdent;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::Key;
 use common_meta_kvapi::kvapi::UpsertKVReq;
@@ -124,6 +127,8 @@ use common_meta_types::UpsertKV;
 use log::debug;
 use log::info;
 
+use crate::get_pb_value;
+use crate::get_u64_value;
 use crate::is_all_db_data_removed;
 use crate::kv_app_error::KVAppError;
 use crate::serialize_struct;
@@ -232,6 +237,7 @@ async fn upsert_test_data(
             seq: MatchSeq::GE(0),
             value: Operation::Update(value),
             value_meta: None,
+            lease: None,
         })
         .await?;
 
@@ -249,6 +255,7 @@ async fn delete_test_data(
             seq: MatchSeq::GE(0),
             value: Operation::Delete,
             value_meta: None,
+            lease: None,
         })
         .await?;
 
@@ -274,6 +281,10 @@ impl SchemaApiTestSuite {
             .await?;
         suite.database_list(&b.build().await).await?;
         suite.database_list_in_diff_tenant(&b.build().await).await?;
+        suite.tenant_quota_create_db_table(&b.build().await).await?;
+        suite
+            .tenant_quota_meta_bytes_set_later(&b.build().await)
+            .await?;
         suite.database_rename(&b.build().await).await?;
         suite
             .database_drop_undrop_list_history(&b.build().await)
@@ -954,6 +965,180 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn tenant_quota_create_db_table<MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant_quota_create_db_table";
+
+        info!("--- set quota: 2 databases, 1 table per database, 1MB meta bytes");
+        {
+            let quota = TenantQuota {
+                max_databases: 2,
+                max_tables_per_database: 1,
+                max_meta_bytes: 1 << 20,
+                ..Default::default()
+            };
+            upsert_test_data(
+                mt.as_kv_api(),
+                &TenantQuotaIdent::new(tenant),
+                serde_json::to_vec(&quota)?,
+            )
+            .await?;
+        }
+
+        info!("--- create databases exceeding the quota");
+        {
+            self.create_database(mt, tenant, "db1", "eng1").await?;
+            self.create_database(mt, tenant, "db2", "eng1").await?;
+
+            let req = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: "db3".to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            let err = mt.create_database(req).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::TenantQuotaExceeded("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            // Other tenants are not limited.
+            self.create_database(mt, "tenant_quota_other", "db3", "eng1")
+                .await?;
+        }
+
+        let table_id;
+
+        info!("--- create tables exceeding the quota");
+        {
+            let req = |table_name: &str| CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: "db1".to_string(),
+                    table_name: table_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+
+            let res = mt.create_table(req("t1")).await?;
+            table_id = res.table_id;
+
+            let err = mt.create_table(req("t2")).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::TenantQuotaExceeded("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- meta bytes are accounted");
+        let used = {
+            let (_, used) =
+                get_u64_value(mt.as_kv_api(), &TenantMetaBytesIdent::new(tenant)).await?;
+            assert!(used > 0);
+            used
+        };
+
+        info!("--- meta bytes are accounted without a quota");
+        {
+            let (seq, used) = get_u64_value(
+                mt.as_kv_api(),
+                &TenantMetaBytesIdent::new("tenant_quota_other"),
+            )
+            .await?;
+            assert!(seq > 0);
+            assert!(used > 0);
+        }
+
+        info!("--- a schema change is accounted");
+        {
+            let (table_version, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(mt.as_kv_api(), &TableId { table_id }).await?;
+            let mut new_table_meta = table_meta.unwrap();
+            new_table_meta.schema = Arc::new(TableSchema::new(vec![TableField::new(
+                "added_column",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]));
+
+            mt.update_table_meta(UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            })
+            .await?;
+
+            let (_, used_after) =
+                get_u64_value(mt.as_kv_api(), &TenantMetaBytesIdent::new(tenant)).await?;
+            assert!(used_after > used);
+        }
+
+        Ok(())
+    }
+
+    #[minitrace::trace]
+    async fn tenant_quota_meta_bytes_set_later<
+        MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>,
+    >(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant_quota_meta_bytes_set_later";
+
+        let req = |table_name: &str| CreateTableReq {
+            if_not_exists: false,
+            name_ident: TableNameIdent {
+                tenant: tenant.to_string(),
+                db_name: "db1".to_string(),
+                table_name: table_name.to_string(),
+            },
+            table_meta: TableMeta::default(),
+        };
+
+        info!("--- create objects without a quota");
+        let used = {
+            self.create_database(mt, tenant, "db1", "eng1").await?;
+            mt.create_table(req("t1")).await?;
+
+            let (_, used) =
+                get_u64_value(mt.as_kv_api(), &TenantMetaBytesIdent::new(tenant)).await?;
+            assert!(used > 0);
+            used
+        };
+
+        info!("--- set a quota of meta bytes that is already used up");
+        {
+            let quota = TenantQuota {
+                max_meta_bytes: used,
+                ..Default::default()
+            };
+            upsert_test_data(
+                mt.as_kv_api(),
+                &TenantQuotaIdent::new(tenant),
+                serde_json::to_vec(&quota)?,
+            )
+            .await?;
+        }
+
+        info!("--- the existing objects count towards the quota");
+        {
+            let err = mt.create_table(req("t2")).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::TenantQuotaExceeded("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn database_list_in_diff_tenant<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         info!("--- prepare db1 and db2");
@@ -234,6 +234,7 @@ pub async fn fetch_id<T: kvapi::Key>(
             seq: MatchSeq::GE(0),
             value: Operation::Update(b"".to_vec()),
             value_meta: None,
+            lease: None,
         })
         .await?;
 
@@ -324,6 +325,15 @@ pub fn txn_cond_seq(key: &impl kvapi::Key, op: ConditionResult, seq: u64) -> Txn
     }
 }
 
+/// Build a TxnCondition that checks if a record exists, or does not exist.
+pub fn txn_cond_exists(key: &impl kvapi::Key, exists: bool) -> TxnCondition {
+    TxnCondition {
+        key: key.to_string_key(),
+        expected: ConditionResult::Eq as i32,
+        target: Some(Target::Exists(exists)),
+    }
+}
+
 /// Build a txn operation that puts a record.
 pub fn txn_op_put(key: &impl kvapi::Key, value: Vec<u8>) -> TxnOp {
     TxnOp {
@@ -332,6 +342,7 @@ pub fn txn_op_put(key: &impl kvapi::Key, value: Vec<u8>) -> TxnOp {
             value,
             prev_value: true,
             expire_at: None,
+            lease_id: None,
         })),
     }
 }
@@ -344,6 +355,7 @@ pub fn txn_op_put_with_expire(key: &impl kvapi::Key, value: Vec<u8>, expire_at:
             value,
             prev_value: true,
             expire_at: Some(expire_at),
+            lease_id: None,
         })),
     }
 }
@@ -320,6 +320,31 @@ impl UnknownStreamId {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("TenantQuotaExceeded: `{tenant}` {resource} reaches the quota {quota} while `{context}`")]
+pub struct TenantQuotaExceeded {
+    tenant: String,
+    resource: String,
+    quota: u64,
+    context: String,
+}
+
+impl TenantQuotaExceeded {
+    pub fn new(
+        tenant: impl Into<String>,
+        resource: impl Into<String>,
+        quota: u64,
+        context: impl Into<String>,
+    ) -> Self {
+        Self {
+            tenant: tenant.into(),
+            resource: resource.into(),
+            quota,
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("DuplicatedUpsertFiles: {table_id} , in operation `{context}`")]
 pub struct DuplicatedUpsertFiles {
@@ -731,6 +756,24 @@ impl TableLockExpired {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("TableAlreadyLocked: `{table_id}` revision `{revision}` while `{context}`")]
+pub struct TableAlreadyLocked {
+    table_id: u64,
+    revision: u64,
+    context: String,
+}
+
+impl TableAlreadyLocked {
+    pub fn new(table_id: u64, revision: u64, context: impl Into<String>) -> Self {
+        Self {
+            table_id,
+            revision,
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error(
     "CannotShareDatabaseCreatedFromShare: cannot share database {database_name} which created from share while {context}"
@@ -982,6 +1025,9 @@ pub enum AppError {
     #[error(transparent)]
     TableLockExpired(#[from] TableLockExpired),
 
+    #[error(transparent)]
+    TableAlreadyLocked(#[from] TableAlreadyLocked),
+
     #[error(transparent)]
     CannotShareDatabaseCreatedFromShare(#[from] CannotShareDatabaseCreatedFromShare),
 
@@ -1032,6 +1078,9 @@ pub enum AppError {
 
     #[error(transparent)]
     UnknownStreamId(#[from] UnknownStreamId),
+
+    #[error(transparent)]
+    TenantQuotaExceeded(#[from] TenantQuotaExceeded),
 }
 
 impl AppErrorMessage for UnknownBackgroundJob {
@@ -1104,6 +1153,15 @@ impl AppErrorMessage for StreamVersionMismatched {}
 
 impl AppErrorMessage for UnknownStreamId {}
 
+impl AppErrorMessage for TenantQuotaExceeded {
+    fn message(&self) -> String {
+        format!(
+            "Tenant '{}' exceeds the quota of {}: {}",
+            self.tenant, self.resource, self.quota
+        )
+    }
+}
+
 impl AppErrorMessage for DuplicatedUpsertFiles {}
 
 impl AppErrorMessage for TableAlreadyExists {
@@ -1241,6 +1299,15 @@ impl AppErrorMessage for TableLockExpired {
     }
 }
 
+impl AppErrorMessage for TableAlreadyLocked {
+    fn message(&self) -> String {
+        format!(
+            "the lock revision '{}' of table '{}' has been taken",
+            self.revision, self.table_id
+        )
+    }
+}
+
 impl AppErrorMessage for CannotShareDatabaseCreatedFromShare {
     fn message(&self) -> String {
         format!(
@@ -1399,6 +1466,7 @@ impl From<AppError> for ErrorCode {
                 ErrorCode::StreamVersionMismatched(err.message())
             }
             AppError::UnknownStreamId(err) => ErrorCode::UnknownStreamId(err.message()),
+            AppError::TenantQuotaExceeded(err) => ErrorCode::TenantQuotaExceeded(err.message()),
             AppError::ShareAlreadyExists(err) => ErrorCode::ShareAlreadyExists(err.message()),
             AppError::UnknownShare(err) => ErrorCode::UnknownShare(err.message()),
             AppError::UnknownShareId(err) => ErrorCode::UnknownShareId(err.message()),
@@ -1423,6 +1491,7 @@ impl From<AppError> for ErrorCode {
                 ErrorCode::UnknownShareEndpointId(err.message())
             }
             AppError::TableLockExpired(err) => ErrorCode::TableLockExpired(err.message()),
+            AppError::TableAlreadyLocked(err) => ErrorCode::TableAlreadyLocked(err.message()),
             AppError::CannotShareDatabaseCreatedFromShare(err) => {
                 ErrorCode::CannotShareDatabaseCreatedFromShare(err.message())
             }
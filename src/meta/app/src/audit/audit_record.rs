@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A record of a change to the schema or the privileges, e.g., `DROP TABLE` or `GRANT`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AuditRecord {
+    pub user: String,
+    pub client_address: String,
+    pub query_id: String,
+
+    /// The kind of the operation, e.g., `DropTable`.
+    pub operation: String,
+
+    /// The object the operation is applied to, e.g., `default.db1.t1`.
+    pub object: String,
+
+    /// The state of the object before and after the operation, if it can be captured.
+    pub before: Option<String>,
+    pub after: Option<String>,
+
+    pub query_text: String,
+    pub created_on: DateTime<Utc>,
+}
+
+/// Audit records of a tenant are ordered by the time they are created.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditRecordIdent {
+    pub tenant: String,
+    pub created_on_ms: u64,
+    pub query_id: String,
+}
+
+impl Display for AuditRecordIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}'/{}/{}",
+            self.tenant, self.created_on_ms, self.query_id
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppendAuditRecordReq {
+    pub tenant: String,
+    pub record: AuditRecord,
+    /// The record is removed after this time, in seconds.
+    pub expire_at: u64,
+}
+
+impl Display for AppendAuditRecordReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "append_audit_record({}, {}, {})",
+            self.tenant, self.record.operation, self.record.object
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListAuditRecordsReq {
+    pub tenant: String,
+}
+
+impl Display for ListAuditRecordsReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "list_audit_records({})", self.tenant)
+    }
+}
+
+mod kvapi_key_impl {
+    use common_meta_kvapi::kvapi;
+
+    use crate::audit::AuditRecordIdent;
+
+    const PREFIX_AUDIT_LOG: &str = "__fd_audit_log";
+
+    /// <prefix>/<tenant>/<created_on_ms>/<query_id>
+    ///
+    /// `created_on_ms` is zero padded so that the records are listed in time order.
+    impl kvapi::Key for AuditRecordIdent {
+        const PREFIX: &'static str = PREFIX_AUDIT_LOG;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_raw(&format!("{:020}", self.created_on_ms))
+                .push_str(&self.query_id)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let created_on_ms = p.next_u64()?;
+            let query_id = p.next_str()?;
+            p.done()?;
+
+            Ok(AuditRecordIdent {
+                tenant,
+                created_on_ms,
+                query_id,
+            })
+        }
+    }
+}
@@ -22,6 +22,7 @@
 #![feature(no_sanitize)]
 
 pub mod app_error;
+pub mod audit;
 pub mod background;
 pub mod data_mask;
 pub mod principal;
@@ -13,5 +13,9 @@
 // limitations under the License.
 
 mod quota;
+mod usage;
 
 pub use quota::TenantQuota;
+pub use quota::TenantQuotaIdent;
+pub use usage::TenantMetaBytesIdent;
+pub use usage::TenantUsage;
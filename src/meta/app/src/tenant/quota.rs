@@ -31,6 +31,26 @@ pub struct TenantQuota {
 
     // The max number of users can be created in the tenant.
     pub max_users: u32,
+
+    // The max number of UDFs can be created in the tenant.
+    pub max_udfs: u32,
+
+    // The max bytes of the metadata of databases and tables in the tenant.
+    pub max_meta_bytes: u64,
+}
+
+/// The key of the quota of a tenant: `__fd_quotas/<tenant>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TenantQuotaIdent {
+    pub tenant: String,
+}
+
+impl TenantQuotaIdent {
+    pub fn new(tenant: impl ToString) -> Self {
+        Self {
+            tenant: tenant.to_string(),
+        }
+    }
 }
 
 impl TryFrom<Vec<u8>> for TenantQuota {
@@ -46,3 +66,31 @@ impl TryFrom<Vec<u8>> for TenantQuota {
         }
     }
 }
+
+mod kvapi_key_impl {
+    use common_meta_kvapi::kvapi;
+
+    use crate::tenant::TenantQuotaIdent;
+
+    const PREFIX_QUOTA: &str = "__fd_quotas";
+
+    /// <prefix>/<tenant>
+    impl kvapi::Key for TenantQuotaIdent {
+        const PREFIX: &'static str = PREFIX_QUOTA;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            p.done()?;
+
+            Ok(TenantQuotaIdent { tenant })
+        }
+    }
+}
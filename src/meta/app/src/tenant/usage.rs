@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+/// The resources a tenant has used, to compare with [`TenantQuota`](crate::tenant::TenantQuota).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TenantUsage {
+    pub databases: u64,
+
+    /// The number of tables in each database, by database name.
+    pub tables: BTreeMap<String, u64>,
+
+    pub stages: u64,
+
+    pub udfs: u64,
+
+    /// The approximate bytes of the metadata of databases and tables.
+    ///
+    /// It is the size of a database or table meta when it is created,
+    /// and is released when the dropped database or table is garbage collected.
+    pub meta_bytes: u64,
+}
+
+/// The key of the metadata bytes a tenant used: `__fd_tenant_meta_bytes/<tenant>`.
+///
+/// The value is an u64, updated along with creating and removing databases and tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TenantMetaBytesIdent {
+    pub tenant: String,
+}
+
+impl TenantMetaBytesIdent {
+    pub fn new(tenant: impl ToString) -> Self {
+        Self {
+            tenant: tenant.to_string(),
+        }
+    }
+}
+
+mod kvapi_key_impl {
+    use common_meta_kvapi::kvapi;
+
+    use crate::tenant::TenantMetaBytesIdent;
+
+    const PREFIX_TENANT_META_BYTES: &str = "__fd_tenant_meta_bytes";
+
+    /// <prefix>/<tenant>
+    impl kvapi::Key for TenantMetaBytesIdent {
+        const PREFIX: &'static str = PREFIX_TENANT_META_BYTES;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            p.done()?;
+
+            Ok(TenantMetaBytesIdent { tenant })
+        }
+    }
+}
@@ -18,6 +18,8 @@ use std::fmt::Debug;
 
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::LeaseReply;
+use common_meta_kvapi::kvapi::LeaseReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -26,6 +28,7 @@ use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::ClusterStatus;
+use common_meta_types::protobuf::LeaseKeepAliveResponse;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::StreamItem;
 use common_meta_types::protobuf::WatchRequest;
@@ -43,7 +46,9 @@ use crate::message::ExportReq;
 use crate::message::GetClientInfo;
 use crate::message::GetClusterStatus;
 use crate::message::GetEndpoints;
+use crate::message::LeaseKeepAlive;
 use crate::message::MakeClient;
+use crate::message::ReadWith;
 use crate::message::Streamed;
 
 /// Bind a request type to its corresponding response type.
@@ -58,6 +63,8 @@ pub enum MetaGrpcReq {
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+
+    Lease(LeaseReq),
 }
 
 impl TryInto<MetaGrpcReq> for Request<RaftRequest> {
@@ -126,6 +133,10 @@ impl RequestFor for MetaGrpcReadReq {
     type Reply = BoxStream<StreamItem>;
 }
 
+impl RequestFor for ReadWith {
+    type Reply = BoxStream<StreamItem>;
+}
+
 impl From<MetaGrpcReadReq> for MetaGrpcReq {
     fn from(v: MetaGrpcReadReq) -> Self {
         match v {
@@ -196,6 +207,14 @@ impl RequestFor for UpsertKVReq {
     type Reply = UpsertKVReply;
 }
 
+impl RequestFor for LeaseReq {
+    type Reply = LeaseReply;
+}
+
+impl RequestFor for LeaseKeepAlive {
+    type Reply = tonic::codec::Streaming<LeaseKeepAliveResponse>;
+}
+
 impl RequestFor for WatchRequest {
     type Reply = tonic::codec::Streaming<WatchResponse>;
 }
@@ -52,6 +52,8 @@ use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseKeepAliveResponse;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftRequest;
@@ -63,8 +65,10 @@ use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaHandshakeError;
 use common_meta_types::MetaNetworkError;
+use common_meta_types::ReadConsistency;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
+use common_meta_types::WatchCompacted;
 use futures::stream::StreamExt;
 use log::as_debug;
 use log::as_display;
@@ -246,6 +250,18 @@ impl ClientHandle {
         UnlimitedFuture::create(request_future).await
     }
 
+    /// Read with the specified consistency.
+    ///
+    /// A [`ReadConsistency::Linearizable`] read can be served by a follower after it catches up with the leader;
+    /// a [`ReadConsistency::Stale`] read is served by whichever node receives it and may miss the latest writes.
+    pub async fn read_with(
+        &self,
+        req: MetaGrpcReadReq,
+        consistency: ReadConsistency,
+    ) -> Result<BoxStream<pb::StreamItem>, MetaError> {
+        self.request(message::ReadWith::new(req, consistency)).await
+    }
+
     pub async fn get_cluster_status(&self) -> Result<ClusterStatus, MetaError> {
         self.request(message::GetClusterStatus {}).await
     }
@@ -440,7 +456,10 @@ impl MetaGrpcClient {
                     }
                     message::Request::StreamGet(r) => {
                         let strm = self
-                            .kv_read_v1(MetaGrpcReadReq::GetKV(r.into_inner()))
+                            .kv_read_v1(
+                                MetaGrpcReadReq::GetKV(r.into_inner()),
+                                ReadConsistency::default(),
+                            )
                             .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_read_v1(GetKV)"))
                             .await;
                         message::Response::StreamGet(strm)
@@ -454,7 +473,10 @@ impl MetaGrpcClient {
                     }
                     message::Request::StreamMGet(r) => {
                         let strm = self
-                            .kv_read_v1(MetaGrpcReadReq::MGetKV(r.into_inner()))
+                            .kv_read_v1(
+                                MetaGrpcReadReq::MGetKV(r.into_inner()),
+                                ReadConsistency::default(),
+                            )
                             .timed_ge(
                                 threshold(),
                                 info_spent("MetaGrpcClient::kv_read_v1(MGetKV)"),
@@ -471,7 +493,10 @@ impl MetaGrpcClient {
                     }
                     message::Request::StreamList(r) => {
                         let strm = self
-                            .kv_read_v1(MetaGrpcReadReq::ListKV(r.into_inner()))
+                            .kv_read_v1(
+                                MetaGrpcReadReq::ListKV(r.into_inner()),
+                                ReadConsistency::default(),
+                            )
                             .timed_ge(
                                 threshold(),
                                 info_spent("MetaGrpcClient::kv_read_v1(ListKV)"),
@@ -479,6 +504,13 @@ impl MetaGrpcClient {
                             .await;
                         message::Response::StreamMGet(strm)
                     }
+                    message::Request::ReadWith(r) => {
+                        let strm = self
+                            .kv_read_v1(r.req, r.consistency)
+                            .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_read_v1"))
+                            .await;
+                        message::Response::ReadWith(strm)
+                    }
                     message::Request::Upsert(r) => {
                         let resp = self
                             .kv_api(r)
@@ -497,6 +529,17 @@ impl MetaGrpcClient {
                         let resp = self.watch(r).await;
                         message::Response::Watch(resp)
                     }
+                    message::Request::Lease(r) => {
+                        let resp = self
+                            .kv_api(r)
+                            .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                            .await;
+                        message::Response::Lease(resp)
+                    }
+                    message::Request::LeaseKeepAlive(r) => {
+                        let resp = self.lease_keep_alive(r).await;
+                        message::Response::LeaseKeepAlive(resp)
+                    }
                     message::Request::Export(r) => {
                         let resp = self.export(r).await;
                         message::Response::Export(resp)
@@ -926,7 +969,42 @@ impl MetaGrpcClient {
         );
 
         let (mut client, _sver) = self.make_client().await?;
-        let res = client.watch(watch_request).await?;
+        let res =
+            client.watch(watch_request).await.map_err(
+                |status| match WatchCompacted::from_status(&status) {
+                    Some(compacted) => MetaError::from(MetaClientError::from(compacted)),
+                    None => MetaError::from(status),
+                },
+            )?;
+        Ok(res.into_inner())
+    }
+
+    /// Create a stream that keeps a lease alive by sending a keep-alive request every interval.
+    ///
+    /// The server replies the refreshed lease for every request; the lease is no longer refreshed
+    /// once the returned stream is dropped.
+    #[minitrace::trace]
+    pub(crate) async fn lease_keep_alive(
+        &self,
+        req: message::LeaseKeepAlive,
+    ) -> Result<tonic::codec::Streaming<LeaseKeepAliveResponse>, MetaError> {
+        debug!(
+            req = as_debug!(&req);
+            "MetaGrpcClient worker: handle lease keep-alive request"
+        );
+
+        let lease_id = req.lease_id;
+        let interval = req.interval;
+
+        let outbound = futures::stream::unfold(true, move |first| async move {
+            if !first {
+                sleep(interval).await;
+            }
+            Some((LeaseKeepAliveRequest { lease_id }, false))
+        });
+
+        let (mut client, _sver) = self.make_client().await?;
+        let res = client.lease_keep_alive(outbound).await?;
         Ok(res.into_inner())
     }
 
@@ -1031,6 +1109,7 @@ impl MetaGrpcClient {
     pub(crate) async fn kv_read_v1(
         &self,
         grpc_req: MetaGrpcReadReq,
+        consistency: ReadConsistency,
     ) -> Result<BoxStream<pb::StreamItem>, MetaError> {
         debug!(
             req = as_debug!(&grpc_req);
@@ -1093,7 +1172,11 @@ impl MetaGrpcClient {
             }
 
             let raft_req: RaftRequest = grpc_req.clone().into();
-            let req = traced_req(raft_req.clone());
+            let mut req = traced_req(raft_req.clone());
+            req.metadata_mut().insert(
+                ReadConsistency::METADATA_KEY,
+                MetadataValue::from_static(consistency.as_str()),
+            );
 
             let result = client
                 .kv_read_v1(req)
@@ -16,6 +16,8 @@ use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
 use common_meta_kvapi::kvapi::KVStream;
+use common_meta_kvapi::kvapi::LeaseReply;
+use common_meta_kvapi::kvapi::LeaseReq;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
@@ -74,4 +76,10 @@ impl kvapi::KVApi for ClientHandle {
         let reply = self.request(txn).await?;
         Ok(reply)
     }
+
+    #[minitrace::trace]
+    async fn lease(&self, req: LeaseReq) -> Result<LeaseReply, Self::Error> {
+        let reply = self.request(req).await?;
+        Ok(reply)
+    }
 }
@@ -20,6 +20,7 @@ mod grpc_client;
 mod grpc_metrics;
 mod kv_api_impl;
 mod message;
+mod resumable_watch;
 
 use std::sync::LazyLock;
 
@@ -31,7 +32,10 @@ pub use grpc_action::RequestFor;
 pub use grpc_client::ClientHandle;
 pub use grpc_client::MetaGrpcClient;
 pub use message::ClientWorkerRequest;
+pub use message::LeaseKeepAlive;
+pub use message::ReadWith;
 pub use message::Streamed;
+pub use resumable_watch::ResumableWatchStream;
 use semver::BuildMetadata;
 use semver::Prerelease;
 use semver::Version;
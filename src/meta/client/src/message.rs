@@ -14,10 +14,13 @@
 
 use std::fmt;
 use std::fmt::Formatter;
+use std::time::Duration;
 
 use common_base::base::tokio::sync::oneshot::Sender;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::LeaseReply;
+use common_meta_kvapi::kvapi::LeaseReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -27,16 +30,19 @@ use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::ExportedChunk;
+use common_meta_types::protobuf::LeaseKeepAliveResponse;
 use common_meta_types::protobuf::StreamItem;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
+use common_meta_types::ReadConsistency;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use minitrace::Span;
 use tonic::codegen::BoxStream;
 
+use crate::grpc_action::MetaGrpcReadReq;
 use crate::grpc_client::RealClient;
 
 /// A request that is sent by a meta-client handle to its worker.
@@ -93,6 +99,9 @@ pub enum Request {
     /// List KVs by key prefix, returning a stream.
     StreamList(Streamed<ListKVReq>),
 
+    /// Read with a specified consistency, returning a stream.
+    ReadWith(ReadWith),
+
     /// Update or insert KV
     Upsert(UpsertKVReq),
 
@@ -102,6 +111,12 @@ pub enum Request {
     /// Watch KV changes, expecting a Stream that reports KV change events
     Watch(WatchRequest),
 
+    /// Grant, keep alive or revoke a lease
+    Lease(LeaseReq),
+
+    /// Keep a lease alive periodically, expecting a Stream that reports the refreshed lease
+    LeaseKeepAlive(LeaseKeepAlive),
+
     /// Export all data
     Export(ExportReq),
 
@@ -127,9 +142,12 @@ impl Request {
             Request::StreamGet(_) => "StreamGet",
             Request::StreamMGet(_) => "StreamMGet",
             Request::StreamList(_) => "StreamList",
+            Request::ReadWith(_) => "ReadWith",
             Request::Upsert(_) => "Upsert",
             Request::Txn(_) => "Txn",
             Request::Watch(_) => "Watch",
+            Request::Lease(_) => "Lease",
+            Request::LeaseKeepAlive(_) => "LeaseKeepAlive",
             Request::Export(_) => "Export",
             Request::MakeClient(_) => "MakeClient",
             Request::GetEndpoints(_) => "GetEndpoints",
@@ -148,9 +166,12 @@ pub enum Response {
     StreamGet(Result<BoxStream<StreamItem>, MetaError>),
     StreamMGet(Result<BoxStream<StreamItem>, MetaError>),
     StreamList(Result<BoxStream<StreamItem>, MetaError>),
+    ReadWith(Result<BoxStream<StreamItem>, MetaError>),
     Upsert(Result<UpsertKVReply, MetaError>),
     Txn(Result<TxnReply, MetaError>),
     Watch(Result<tonic::codec::Streaming<WatchResponse>, MetaError>),
+    Lease(Result<LeaseReply, MetaError>),
+    LeaseKeepAlive(Result<tonic::codec::Streaming<LeaseKeepAliveResponse>, MetaError>),
     Export(Result<tonic::codec::Streaming<ExportedChunk>, MetaError>),
     MakeClient(Result<(RealClient, u64), MetaClientError>),
     GetEndpoints(Result<Vec<String>, MetaError>),
@@ -179,6 +200,9 @@ impl fmt::Debug for Response {
             Response::StreamList(x) => {
                 write!(f, "StreamList({:?})", x.as_ref().map(|_s| "<stream>"))
             }
+            Response::ReadWith(x) => {
+                write!(f, "ReadWith({:?})", x.as_ref().map(|_s| "<stream>"))
+            }
             Response::Upsert(x) => {
                 write!(f, "Upsert({:?})", x)
             }
@@ -188,6 +212,12 @@ impl fmt::Debug for Response {
             Response::Watch(x) => {
                 write!(f, "Watch({:?})", x)
             }
+            Response::Lease(x) => {
+                write!(f, "Lease({:?})", x)
+            }
+            Response::LeaseKeepAlive(x) => {
+                write!(f, "LeaseKeepAlive({:?})", x)
+            }
             Response::Export(x) => {
                 write!(f, "Export({:?})", x)
             }
@@ -234,6 +264,10 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::ReadWith(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
             Response::Upsert(res) => res
                 .as_ref()
                 .err()
@@ -246,6 +280,14 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::Lease(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::LeaseKeepAlive(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
             Response::Export(res) => res
                 .as_ref()
                 .err()
@@ -292,3 +334,23 @@ pub struct GetClusterStatus {}
 /// Get info about client
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct GetClientInfo {}
+
+/// A read request with the consistency it requires.
+#[derive(Clone, Debug)]
+pub struct ReadWith {
+    pub req: MetaGrpcReadReq,
+    pub consistency: ReadConsistency,
+}
+
+impl ReadWith {
+    pub fn new(req: MetaGrpcReadReq, consistency: ReadConsistency) -> Self {
+        Self { req, consistency }
+    }
+}
+
+/// Keep a lease alive by refreshing it every `interval`, until the returned stream is dropped.
+#[derive(Clone, Debug)]
+pub struct LeaseKeepAlive {
+    pub lease_id: u64,
+    pub interval: Duration,
+}
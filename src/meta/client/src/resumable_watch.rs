@@ -0,0 +1,202 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio::time::sleep;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::MetaClientError;
+use common_meta_types::MetaError;
+use futures::Stream;
+use log::info;
+use log::warn;
+use tonic::codec::Streaming;
+
+use crate::ClientHandle;
+
+/// Max number of consecutive failures to re-establish a watch, before giving up.
+const RESUME_RETRIES: usize = 10;
+
+pub type ResumableWatchStream =
+    Pin<Box<dyn Stream<Item = Result<WatchResponse, MetaError>> + Send + 'static>>;
+
+/// A watch that re-connects when the stream is broken, e.g., the leader changes,
+/// and resumes from the revision it has received.
+///
+/// The meta-service retains a bounded history of changes.
+/// If the changes to resume from are no longer retained,
+/// the stream yields a [`MetaClientError::WatchCompacted`] error and ends.
+struct ResumableWatch {
+    client: Arc<ClientHandle>,
+    req: WatchRequest,
+    inner: Option<Streaming<WatchResponse>>,
+
+    /// The revision to start from when re-connecting.
+    resume_from: Option<u64>,
+
+    /// The revision of the last received change, and the number of received changes with this revision.
+    ///
+    /// A raft log may make several changes with the same revision.
+    /// When resuming from it, the received ones are skipped.
+    last_revision: Option<u64>,
+    received_at_last: u64,
+
+    /// Number of the replayed changes to skip.
+    to_skip: u64,
+
+    failures: usize,
+    done: bool,
+}
+
+impl ResumableWatch {
+    fn new(client: Arc<ClientHandle>, req: WatchRequest) -> Self {
+        let resume_from = req.start_revision;
+        Self {
+            client,
+            req,
+            inner: None,
+            resume_from,
+            last_revision: None,
+            received_at_last: 0,
+            to_skip: 0,
+            failures: 0,
+            done: false,
+        }
+    }
+
+    async fn next(&mut self) -> Option<Result<WatchResponse, MetaError>> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            if self.inner.is_none() {
+                if let Err(e) = self.connect().await {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+
+            let strm = self.inner.as_mut().unwrap();
+
+            let resp = match strm.message().await {
+                Ok(Some(resp)) => resp,
+                Ok(None) => {
+                    info!(
+                        "watch stream closed by server, resume from {:?}",
+                        self.resume_from
+                    );
+                    self.inner = None;
+                    self.failures += 1;
+                    continue;
+                }
+                Err(status) => {
+                    warn!(
+                        "watch stream broken: {}, resume from {:?}",
+                        status, self.resume_from
+                    );
+                    self.inner = None;
+                    self.failures += 1;
+                    continue;
+                }
+            };
+
+            self.failures = 0;
+
+            if resp.event.is_none() {
+                // The watch receives the changes after this revision.
+                self.resume_from = Some(resp.revision + 1);
+                self.last_revision = None;
+                self.received_at_last = 0;
+                return Some(Ok(resp));
+            }
+
+            if self.to_skip > 0 && Some(resp.revision) == self.last_revision {
+                self.to_skip -= 1;
+                continue;
+            }
+            self.to_skip = 0;
+
+            if Some(resp.revision) == self.last_revision {
+                self.received_at_last += 1;
+            } else {
+                self.last_revision = Some(resp.revision);
+                self.received_at_last = 1;
+            }
+            self.resume_from = Some(resp.revision);
+
+            return Some(Ok(resp));
+        }
+    }
+
+    /// Establish a watch stream, from the revision to resume if there is one.
+    async fn connect(&mut self) -> Result<(), MetaError> {
+        loop {
+            if self.failures > 0 {
+                sleep(Duration::from_millis(200 * self.failures as u64)).await;
+            }
+
+            let mut req = self.req.clone();
+            req.start_revision = self.resume_from;
+
+            let res: Result<Streaming<WatchResponse>, MetaError> = self.client.request(req).await;
+
+            match res {
+                Ok(strm) => {
+                    self.to_skip =
+                        if self.resume_from.is_some() && self.resume_from == self.last_revision {
+                            self.received_at_last
+                        } else {
+                            0
+                        };
+                    self.inner = Some(strm);
+                    return Ok(());
+                }
+                Err(e) => {
+                    if let MetaError::ClientError(MetaClientError::WatchCompacted(_)) = &e {
+                        return Err(e);
+                    }
+
+                    self.failures += 1;
+                    if self.failures > RESUME_RETRIES {
+                        return Err(e);
+                    }
+
+                    warn!(
+                        "fail to establish watch, retry {}/{}: {}",
+                        self.failures, RESUME_RETRIES, e
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl ClientHandle {
+    /// Watch kv changes, and transparently resume the watch when the connection is broken,
+    /// e.g., when the leader changes.
+    pub fn watch_with_resume(self: &Arc<Self>, req: WatchRequest) -> ResumableWatchStream {
+        let watch = ResumableWatch::new(self.clone(), req);
+
+        let strm = futures::stream::unfold(watch, |mut watch| async move {
+            let item = watch.next().await?;
+            Some((item, watch))
+        });
+
+        Box::pin(strm)
+    }
+}
@@ -24,8 +24,11 @@ use common_meta_types::protobuf::meta_service_server::MetaServiceServer;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::Empty;
+use common_meta_types::protobuf::ExportLogsRequest;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseKeepAliveResponse;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
@@ -91,6 +94,16 @@ impl MetaService for GrpcServiceForTestImpl {
         unimplemented!()
     }
 
+    type ExportLogsStream =
+        Pin<Box<dyn Stream<Item = Result<ExportedChunk, tonic::Status>> + Send + 'static>>;
+
+    async fn export_logs(
+        &self,
+        _request: Request<ExportLogsRequest>,
+    ) -> Result<Response<Self::ExportLogsStream>, Status> {
+        unimplemented!()
+    }
+
     type WatchStream =
         Pin<Box<dyn Stream<Item = Result<WatchResponse, tonic::Status>> + Send + 'static>>;
 
@@ -101,6 +114,16 @@ impl MetaService for GrpcServiceForTestImpl {
         unimplemented!()
     }
 
+    type LeaseKeepAliveStream =
+        Pin<Box<dyn Stream<Item = Result<LeaseKeepAliveResponse, tonic::Status>> + Send + 'static>>;
+
+    async fn lease_keep_alive(
+        &self,
+        _request: Request<Streaming<LeaseKeepAliveRequest>>,
+    ) -> Result<Response<Self::LeaseKeepAliveStream>, Status> {
+        unimplemented!()
+    }
+
     async fn transaction(
         &self,
         _request: Request<TxnRequest>,
@@ -16,6 +16,8 @@ use async_trait::async_trait;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::KVStream;
+use common_meta_kvapi::kvapi::LeaseReply;
+use common_meta_kvapi::kvapi::LeaseReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
@@ -59,4 +61,10 @@ impl kvapi::KVApi for MetaEmbedded {
         let sm = self.inner.lock().await;
         sm.transaction(txn).await
     }
+
+    #[minitrace::trace]
+    async fn lease(&self, req: LeaseReq) -> Result<LeaseReply, Self::Error> {
+        let sm = self.inner.lock().await;
+        sm.lease(req).await
+    }
 }
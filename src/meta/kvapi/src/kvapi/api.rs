@@ -25,6 +25,8 @@ use log::debug;
 
 use crate::kvapi;
 use crate::kvapi::GetKVReply;
+use crate::kvapi::LeaseReply;
+use crate::kvapi::LeaseReq;
 use crate::kvapi::ListKVReply;
 use crate::kvapi::MGetKVReply;
 use crate::kvapi::UpsertKVReply;
@@ -94,6 +96,27 @@ pub trait KVApi: Send + Sync {
 
     /// Run transaction: update one or more records if specified conditions are met.
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error>;
+
+    /// Grant, keep alive or revoke a lease.
+    ///
+    /// Keys are attached to a lease by `UpsertKVReq::with_lease()`,
+    /// and are removed when the lease expires or is revoked.
+    async fn lease(&self, req: LeaseReq) -> Result<LeaseReply, Self::Error>;
+
+    /// Grant a lease that expires after `ttl_sec` seconds if it is not kept alive.
+    async fn grant_lease(&self, ttl_sec: u64) -> Result<LeaseReply, Self::Error> {
+        self.lease(LeaseReq::Grant { ttl_sec }).await
+    }
+
+    /// Refresh the expiration time of a lease, returns `None` if the lease has expired.
+    async fn keep_alive_lease(&self, lease_id: u64) -> Result<LeaseReply, Self::Error> {
+        self.lease(LeaseReq::KeepAlive { lease_id }).await
+    }
+
+    /// Revoke a lease and remove all the keys attached to it.
+    async fn revoke_lease(&self, lease_id: u64) -> Result<LeaseReply, Self::Error> {
+        self.lease(LeaseReq::Revoke { lease_id }).await
+    }
 }
 
 #[async_trait]
@@ -123,6 +146,10 @@ impl<U: kvapi::KVApi, T: Deref<Target = U> + Send + Sync> kvapi::KVApi for T {
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         self.deref().transaction(txn).await
     }
+
+    async fn lease(&self, req: LeaseReq) -> Result<LeaseReply, Self::Error> {
+        self.deref().lease(req).await
+    }
 }
 
 pub trait AsKVApi {
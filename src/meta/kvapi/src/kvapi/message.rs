@@ -12,12 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+
 use common_meta_types::Change;
+use common_meta_types::LeaseInfo;
 use common_meta_types::SeqV;
 use common_meta_types::UpsertKV;
 
 pub type UpsertKVReq = UpsertKV;
 
+/// Grant, keep alive or revoke a lease.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum LeaseReq {
+    /// Grant a lease that expires after `ttl_sec` seconds if it is not kept alive.
+    Grant { ttl_sec: u64 },
+
+    /// Refresh the expiration time of a lease to `ttl_sec` later.
+    ///
+    /// It is served by the leader and does not write a raft log.
+    KeepAlive { lease_id: u64 },
+
+    /// Revoke a lease and remove all the attached keys.
+    Revoke { lease_id: u64 },
+}
+
+impl fmt::Display for LeaseReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaseReq::Grant { ttl_sec } => write!(f, "grant(ttl: {}s)", ttl_sec),
+            LeaseReq::KeepAlive { lease_id } => write!(f, "keep_alive({})", lease_id),
+            LeaseReq::Revoke { lease_id } => write!(f, "revoke({})", lease_id),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetKVReq {
     pub key: String,
@@ -37,3 +65,5 @@ pub type UpsertKVReply = Change<Vec<u8>>;
 pub type GetKVReply = Option<SeqV<Vec<u8>>>;
 pub type MGetKVReply = Vec<Option<SeqV<Vec<u8>>>>;
 pub type ListKVReply = Vec<(String, SeqV<Vec<u8>>)>;
+/// The lease after the operation, `None` if the lease does not exist.
+pub type LeaseReply = Option<LeaseInfo>;
@@ -31,6 +31,8 @@ pub use key_builder::KeyBuilder;
 pub use key_parser::KeyParser;
 pub use message::GetKVReply;
 pub use message::GetKVReq;
+pub use message::LeaseReply;
+pub use message::LeaseReq;
 pub use message::ListKVReply;
 pub use message::ListKVReq;
 pub use message::MGetKVReply;
@@ -71,6 +71,9 @@ impl kvapi::TestSuite {
             .await?;
         self.kv_delete_by_prefix_transaction(&builder.build().await)
             .await?;
+        self.kv_transaction_predicates_and_range_get(&builder.build().await)
+            .await?;
+        self.kv_lease(&builder.build().await).await?;
 
         // Run cross node test on every 2 adjacent nodes
         let mut i = 0;
@@ -193,6 +196,70 @@ impl kvapi::TestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    pub async fn kv_lease<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        info!("--- kvapi::KVApiTestSuite::kv_lease() start");
+
+        let lease = kv.grant_lease(60).await?.unwrap();
+        assert_eq!(60, lease.ttl_sec);
+
+        info!("--- attach keys to the lease");
+        {
+            kv.upsert_kv(UpsertKVReq::update("lease_k1", b"v1").with_lease(lease.lease_id))
+                .await?;
+            kv.upsert_kv(UpsertKVReq::update("lease_k2", b"v2").with_lease(lease.lease_id))
+                .await?;
+            assert!(kv.get_kv("lease_k1").await?.is_some());
+
+            // Written without lease, it is detached from the lease.
+            kv.upsert_kv(UpsertKVReq::update("lease_k2", b"v3")).await?;
+        }
+
+        info!("--- can not attach to an absent lease");
+        {
+            let res = kv
+                .upsert_kv(UpsertKVReq::update("lease_k3", b"v1").with_lease(lease.lease_id + 100))
+                .await?;
+            assert!(res.result.is_none());
+            assert!(kv.get_kv("lease_k3").await?.is_none());
+        }
+
+        info!("--- keep alive");
+        {
+            let kept = kv.keep_alive_lease(lease.lease_id).await?.unwrap();
+            assert_eq!(lease.lease_id, kept.lease_id);
+            assert!(kept.expire_at >= lease.expire_at);
+        }
+
+        info!("--- revoke removes attached keys");
+        {
+            let revoked = kv.revoke_lease(lease.lease_id).await?;
+            assert!(revoked.is_some());
+
+            assert!(kv.get_kv("lease_k1").await?.is_none());
+            let k2 = kv.get_kv("lease_k2").await?.unwrap();
+            assert_eq!(b"v3".to_vec(), k2.data);
+
+            assert!(kv.keep_alive_lease(lease.lease_id).await?.is_none());
+            assert!(kv.revoke_lease(lease.lease_id).await?.is_none());
+        }
+
+        info!("--- expired lease removes attached keys");
+        {
+            let lease = kv.grant_lease(1).await?.unwrap();
+            kv.upsert_kv(UpsertKVReq::update("lease_k4", b"v4").with_lease(lease.lease_id))
+                .await?;
+
+            // The leader revokes an expired lease without any other write.
+            tokio::time::sleep(tokio::time::Duration::from_millis(5000)).await;
+
+            assert!(kv.get_kv("lease_k4").await?.is_none());
+            assert!(kv.keep_alive_lease(lease.lease_id).await?.is_none());
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     pub async fn kv_update<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         info!("--- kvapi::KVApiTestSuite::kv_update() start");
@@ -506,6 +573,7 @@ impl kvapi::TestSuite {
                 value: b"new_v1".to_vec(),
                 prev_value: true,
                 expire_at: None,
+                lease_id: None,
             })),
         }];
 
@@ -643,6 +711,115 @@ impl kvapi::TestSuite {
         Ok(())
     }
 
+    pub async fn kv_transaction_predicates_and_range_get<KV: kvapi::KVApi>(
+        &self,
+        kv: &KV,
+    ) -> anyhow::Result<()> {
+        info!("--- kvapi::KVApiTestSuite::kv_transaction_predicates_and_range_get() start");
+
+        for key in ["pred/a", "pred/b", "pred/c", "pred_d"] {
+            kv.upsert_kv(UpsertKVReq::update(key, key.as_bytes()))
+                .await?;
+        }
+
+        info!("--- exists and keys_with_prefix conditions");
+        {
+            let txn = TxnRequest {
+                condition: vec![
+                    TxnCondition::exists("pred/a", true),
+                    TxnCondition::exists("pred/absent", false),
+                    TxnCondition::keys_with_prefix("pred/", ConditionResult::Eq, 3),
+                    TxnCondition::keys_with_prefix("pred", ConditionResult::Gt, 3),
+                ],
+                if_then: vec![TxnOp::put("pred/e", b"pred/e".to_vec())],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+            assert!(resp.success);
+            assert!(kv.get_kv("pred/e").await?.is_some());
+
+            // There are 4 keys with prefix "pred/" now
+            let txn = TxnRequest {
+                condition: vec![TxnCondition::keys_with_prefix(
+                    "pred/",
+                    ConditionResult::Le,
+                    3,
+                )],
+                if_then: vec![],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+            assert!(!resp.success);
+
+            let txn = TxnRequest {
+                condition: vec![TxnCondition::exists("pred/absent", true)],
+                if_then: vec![],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+            assert!(!resp.success);
+        }
+
+        info!("--- range get");
+        {
+            let txn = TxnRequest {
+                condition: vec![],
+                if_then: vec![
+                    TxnOp::range_get("pred/b", Some("pred/e".to_string()), 0),
+                    TxnOp::range_get("pred/", None, 2),
+                ],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+            assert!(resp.success);
+
+            let keys_of = |i: usize| match &resp.responses[i].response {
+                Some(txn_op_response::Response::RangeGet(r)) => (
+                    r.items.iter().map(|x| x.key.clone()).collect::<Vec<_>>(),
+                    r.more,
+                ),
+                _ => unreachable!("expect RangeGet response"),
+            };
+
+            assert_eq!(
+                (vec!["pred/b".to_string(), "pred/c".to_string()], false),
+                keys_of(0)
+            );
+            assert_eq!(
+                (vec!["pred/a".to_string(), "pred/b".to_string()], true),
+                keys_of(1)
+            );
+        }
+
+        info!("--- put with lease");
+        {
+            let lease = kv.grant_lease(60).await?.unwrap();
+
+            let txn = TxnRequest {
+                condition: vec![TxnCondition::exists("pred_lease", false)],
+                if_then: vec![TxnOp::put_with_lease(
+                    "pred_lease",
+                    b"v".to_vec(),
+                    lease.lease_id,
+                )],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+            assert!(resp.success);
+            assert!(kv.get_kv("pred_lease").await?.is_some());
+
+            kv.revoke_lease(lease.lease_id).await?;
+            assert!(kv.get_kv("pred_lease").await?.is_none());
+        }
+
+        Ok(())
+    }
+
     pub async fn kv_transaction<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         info!("--- kvapi::KVApiTestSuite::kv_transaction() start");
         // first case: get and set one key transaction
@@ -667,6 +844,7 @@ impl kvapi::TestSuite {
                     value: b"new_v1".to_vec(),
                     prev_value: true,
                     expire_at: None,
+                    lease_id: None,
                 })),
             }];
 
@@ -719,6 +897,7 @@ impl kvapi::TestSuite {
                     value: b"new_v1".to_vec(),
                     prev_value: true,
                     expire_at: None,
+                    lease_id: None,
                 })),
             }];
 
@@ -774,6 +953,7 @@ impl kvapi::TestSuite {
                         value: val1_new.to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        lease_id: None,
                     })),
                 },
                 // change k2
@@ -783,6 +963,7 @@ impl kvapi::TestSuite {
                         value: b"new_v2".to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        lease_id: None,
                     })),
                 },
                 // get k1
@@ -892,6 +1073,7 @@ impl kvapi::TestSuite {
                         value: val1_new.to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        lease_id: None,
                     })),
                 },
                 // get k1
@@ -104,6 +104,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
             RaftStoreEntry::Sequences { .. } => Ok(None),
             RaftStoreEntry::ClientLastResps { .. } => Ok(None),
             RaftStoreEntry::LogMeta { .. } => Ok(None),
+            RaftStoreEntry::Leases { .. } => Ok(None),
         }
     }
 
@@ -127,6 +128,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
         match log_entry.cmd {
             Cmd::AddNode { .. } => Ok(None),
             Cmd::RemoveNode { .. } => Ok(None),
+            Cmd::Lease(_) => Ok(None),
             Cmd::UpsertKV(ups) => {
                 let x = LogEntry {
                     txid: log_entry.txid,
@@ -174,6 +176,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
                     seq: ups.seq,
                     value: Operation::Update(buf),
                     value_meta: ups.value_meta,
+                    lease: ups.lease,
                 }))
             }
             Operation::Delete => Ok(None),
@@ -205,6 +208,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
             }
             Request::Delete(_) => {}
             Request::DeleteByPrefix(_) => {}
+            Request::RangeGet(_) => {}
         }
 
         Ok(TxnOp { request: Some(req) })
@@ -218,6 +222,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
             value,
             prev_value: p.prev_value,
             expire_at: p.expire_at,
+            lease_id: p.lease_id,
         };
 
         Ok(pr)
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_meta_app::audit as mt;
+use common_protos::pb;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::AuditRecord {
+    type PB = pb::AuditRecord;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AuditRecord) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            user: p.user,
+            client_address: p.client_address,
+            query_id: p.query_id,
+            operation: p.operation,
+            object: p.object,
+            before: p.before,
+            after: p.after,
+            query_text: p.query_text,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+        };
+
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::AuditRecord, Incompatible> {
+        let p = pb::AuditRecord {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            user: self.user.clone(),
+            client_address: self.client_address.clone(),
+            query_id: self.query_id.clone(),
+            operation: self.operation.clone(),
+            object: self.object.clone(),
+            before: self.before.clone(),
+            after: self.after.clone(),
+            query_text: self.query_text.clone(),
+            created_on: self.created_on.to_pb()?,
+        };
+        Ok(p)
+    }
+}
@@ -60,6 +60,7 @@
 //! - `reader.MIN_MSG_VER` is the minimal message version this program can read.
 //! - `message.MIN_READER_VER` is the minimal reader(program) version that can read this message.
 
+mod audit_from_to_protobuf_impl;
 mod background_job_from_to_protobuf_impl;
 mod background_task_from_to_protobuf_impl;
 mod catalog_from_to_protobuf_impl;
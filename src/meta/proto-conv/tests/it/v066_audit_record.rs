@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::audit::AuditRecord;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v66_audit_record() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 4, 114, 111, 111, 116, 18, 14, 49, 50, 55, 46, 48, 46, 48, 46, 49, 58, 51, 51, 48, 55,
+        26, 5, 113, 117, 101, 114, 121, 34, 9, 68, 114, 111, 112, 84, 97, 98, 108, 101, 42, 14,
+        100, 101, 102, 97, 117, 108, 116, 46, 100, 98, 49, 46, 116, 49, 50, 8, 123, 34, 105, 100,
+        34, 58, 49, 125, 66, 17, 68, 82, 79, 80, 32, 84, 65, 66, 76, 69, 32, 100, 98, 49, 46, 116,
+        49, 74, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32,
+        85, 84, 67, 160, 6, 66, 168, 6, 24,
+    ];
+
+    let want = || AuditRecord {
+        user: "root".to_string(),
+        client_address: "127.0.0.1:3307".to_string(),
+        query_id: "query".to_string(),
+        operation: "DropTable".to_string(),
+        object: "default.db1.t1".to_string(),
+        before: Some(r#"{"id":1}"#.to_string()),
+        after: None,
+        query_text: "DROP TABLE db1.t1".to_string(),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 66, want())?;
+    Ok(())
+}
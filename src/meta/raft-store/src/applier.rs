@@ -27,6 +27,8 @@ use common_meta_types::ConditionResult;
 use common_meta_types::Entry;
 use common_meta_types::EntryPayload;
 use common_meta_types::KVMeta;
+use common_meta_types::LeaseCmd;
+use common_meta_types::LeaseInfo;
 use common_meta_types::MatchSeq;
 use common_meta_types::Node;
 use common_meta_types::SeqV;
@@ -43,6 +45,8 @@ use common_meta_types::TxnOp;
 use common_meta_types::TxnOpResponse;
 use common_meta_types::TxnPutRequest;
 use common_meta_types::TxnPutResponse;
+use common_meta_types::TxnRangeGetRequest;
+use common_meta_types::TxnRangeGetResponse;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKV;
@@ -55,7 +59,9 @@ use log::error;
 use log::info;
 use num::FromPrimitive;
 
+use crate::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
 use crate::sm_v002::SMV002;
+use crate::state_machine::LeaseValue;
 
 /// A helper that applies raft log `Entry` to the state machine.
 pub struct Applier<'a> {
@@ -63,6 +69,9 @@ pub struct Applier<'a> {
 
     /// The changes has been made by the applying one log entry
     changes: Vec<Change<Vec<u8>, String>>,
+
+    /// The proposing time of the log being applied, in millisecond.
+    log_time_ms: u64,
 }
 
 impl<'a> Applier<'a> {
@@ -70,6 +79,7 @@ impl<'a> Applier<'a> {
         Self {
             sm,
             changes: Vec::new(),
+            log_time_ms: 0,
         }
     }
 
@@ -82,6 +92,7 @@ impl<'a> Applier<'a> {
 
         let log_id = &entry.log_id;
         let log_time_ms = Self::get_log_time(entry);
+        self.log_time_ms = log_time_ms;
 
         self.clean_expired_kvs(log_time_ms).await?;
 
@@ -111,7 +122,7 @@ impl<'a> Applier<'a> {
         // Send queued change events to subscriber
         if let Some(subscriber) = &self.sm.subscriber {
             for event in self.changes.drain(..) {
-                subscriber.kv_changed(event);
+                subscriber.kv_changed(log_id.index, event);
             }
         }
 
@@ -139,6 +150,8 @@ impl<'a> Applier<'a> {
             Cmd::UpsertKV(ref upsert_kv) => self.apply_upsert_kv(upsert_kv).await?,
 
             Cmd::Transaction(txn) => self.apply_txn(txn).await?,
+
+            Cmd::Lease(lease) => self.apply_lease(lease).await?,
         };
 
         info!("apply_result: cmd: {}; res: {}", cmd, res);
@@ -208,6 +221,14 @@ impl<'a> Applier<'a> {
     ) -> Result<(Option<SeqV>, Option<SeqV>), io::Error> {
         debug!(upsert_kv = as_debug!(upsert_kv); "upsert_kv");
 
+        if let Some(lease_id) = upsert_kv.lease {
+            if !self.sm.sys_data_ref().leases_ref().contains_key(&lease_id) {
+                // A key can not be attached to an absent lease.
+                let prev = self.sm.get_maybe_expired_kv(&upsert_kv.key).await?;
+                return Ok((prev.clone(), prev));
+            }
+        }
+
         let (prev, result) = self.sm.upsert_kv_primary_index(upsert_kv).await?;
 
         self.sm
@@ -224,9 +245,67 @@ impl<'a> Applier<'a> {
 
         self.push_change(&upsert_kv.key, prev.clone(), result.clone());
 
+        if let (Some(lease_id), Some(seq_v)) = (upsert_kv.lease, &result) {
+            if prev.as_ref().map(|x| x.seq) != Some(seq_v.seq) {
+                // Record the seq of the attached key, the key is detached by a later write.
+                if let Some(lease) = self.sm.sys_data_mut().leases_mut().get_mut(&lease_id) {
+                    lease.attached.insert(upsert_kv.key.clone(), seq_v.seq);
+                }
+            }
+        }
+
         Ok((prev, result))
     }
 
+    #[minitrace::trace]
+    async fn apply_lease(&mut self, cmd: &LeaseCmd) -> Result<AppliedState, io::Error> {
+        debug!(lease = as_display!(cmd); "apply lease cmd");
+
+        // The state machine only records the leases and the keys attached to them.
+        // When a lease expires is tracked by the leader, which proposes a revoke.
+        let lease = match cmd {
+            LeaseCmd::Grant { ttl_sec } => {
+                let lease_id = self.sm.sys_data_mut().next_seq();
+                self.sm
+                    .sys_data_mut()
+                    .leases_mut()
+                    .insert(lease_id, LeaseValue::new(*ttl_sec));
+                Some(LeaseInfo {
+                    lease_id,
+                    ttl_sec: *ttl_sec,
+                    expire_at: 0,
+                })
+            }
+            LeaseCmd::Revoke { lease_id } => self.revoke_lease(*lease_id).await?,
+        };
+
+        Ok(AppliedState::Lease(lease))
+    }
+
+    /// Remove the lease and the keys still attached to it.
+    ///
+    /// A key is still attached if it has not been written since it is attached.
+    async fn revoke_lease(&mut self, lease_id: u64) -> Result<Option<LeaseInfo>, io::Error> {
+        let lease = match self.sm.sys_data_mut().leases_mut().remove(&lease_id) {
+            None => return Ok(None),
+            Some(lease) => lease,
+        };
+
+        for (key, attached_seq) in lease.attached.iter() {
+            let curr = self.sm.get_maybe_expired_kv(key).await?;
+            if curr.map(|x| x.seq) == Some(*attached_seq) {
+                info!("revoke lease {}: remove attached key: {}", lease_id, key);
+                self.upsert_kv(&UpsertKV::delete(key)).await?;
+            }
+        }
+
+        Ok(Some(LeaseInfo {
+            lease_id,
+            ttl_sec: lease.ttl_sec,
+            expire_at: 0,
+        }))
+    }
+
     #[minitrace::trace]
     async fn apply_txn(&mut self, req: &TxnRequest) -> Result<AppliedState, io::Error> {
         debug!(txn = as_display!(req); "apply txn cmd");
@@ -303,6 +382,15 @@ impl<'a> Applier<'a> {
                     false
                 }
             }
+            txn_condition::Target::KeysWithPrefix(right) => {
+                // `key` is a prefix, the number of keys with it is compared.
+                let strm = self.sm.list_kv(key).await?;
+                let count = strm.try_collect::<Vec<_>>().await?.len() as u64;
+                Self::eval_seq_condition(count, cond.expected, right)
+            }
+            txn_condition::Target::Exists(right) => {
+                Self::eval_exists_condition(seqv.is_some(), cond.expected, *right)
+            }
         };
         Ok(positive)
     }
@@ -319,6 +407,14 @@ impl<'a> Applier<'a> {
         }
     }
 
+    fn eval_exists_condition(left: bool, op: i32, right: bool) -> bool {
+        match FromPrimitive::from_i32(op) {
+            Some(ConditionResult::Eq) => left == right,
+            Some(ConditionResult::Ne) => left != right,
+            _ => false,
+        }
+    }
+
     fn eval_value_condition(left: &Vec<u8>, op: i32, right: &Vec<u8>) -> bool {
         match FromPrimitive::from_i32(op) {
             Some(ConditionResult::Eq) => left == right,
@@ -352,6 +448,9 @@ impl<'a> Applier<'a> {
                 self.txn_execute_delete_by_prefix(delete_by_prefix, resp)
                     .await?;
             }
+            Some(txn_op::Request::RangeGet(range_get)) => {
+                self.txn_execute_range_get(range_get, resp).await?;
+            }
             None => {}
         }
         Ok(())
@@ -381,9 +480,10 @@ impl<'a> Applier<'a> {
         put: &TxnPutRequest,
         resp: &mut TxnReply,
     ) -> Result<(), io::Error> {
-        let upsert = UpsertKV::update(&put.key, &put.value).with(KVMeta {
+        let mut upsert = UpsertKV::update(&put.key, &put.value).with(KVMeta {
             expire_at: put.expire_at,
         });
+        upsert.lease = put.lease_id;
 
         let (prev, _result) = self.upsert_kv(&upsert).await?;
 
@@ -461,6 +561,50 @@ impl<'a> Applier<'a> {
         Ok(())
     }
 
+    async fn txn_execute_range_get(
+        &self,
+        range_get: &TxnRangeGetRequest,
+        resp: &mut TxnReply,
+    ) -> Result<(), io::Error> {
+        let end = if range_get.end.is_empty() {
+            None
+        } else {
+            Some(range_get.end.as_str())
+        };
+
+        // Fetch one more entry to tell if there are more than `limit`.
+        let limit = if range_get.limit > 0 {
+            Some(range_get.limit as usize + 1)
+        } else {
+            None
+        };
+
+        let mut strm = self
+            .sm
+            .range_kv(&range_get.start, end, limit, self.log_time_ms)
+            .await?;
+        let mut items = vec![];
+        let mut more = false;
+
+        while let Some((key, seq_v)) = strm.try_next().await? {
+            if range_get.limit > 0 && items.len() as u64 >= range_get.limit {
+                more = true;
+                break;
+            }
+            items.push(TxnGetResponse {
+                key,
+                value: Some(pb::SeqV::from(seq_v)),
+            });
+        }
+
+        let range_resp = TxnRangeGetResponse { items, more };
+
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::RangeGet(range_resp)),
+        });
+        Ok(())
+    }
+
     /// Before applying, list expired keys to clean.
     ///
     /// All expired keys will be removed before applying a log.
@@ -82,6 +82,15 @@ pub struct RaftConfig {
     /// The size of chunk for transmitting snapshot. The default is 64MB
     pub snapshot_chunk_size: u64,
 
+    /// Where to store the state machine data: `mem` or `disk`.
+    ///
+    /// With `mem`, all of the data is kept in memory and is rebuilt from the last snapshot when starting up.
+    /// With `disk`, the compacted data is kept on disk and only the recent changes are kept in memory.
+    ///
+    /// In both cases a snapshot is transferred to a follower in full:
+    /// incremental snapshot transfer is not supported yet.
+    pub sm_storage: String,
+
     /// Single node metasrv. It creates a single node cluster if meta data is not initialized.
     /// Otherwise it opens the previous one.
     /// This is mainly for testing purpose.
@@ -92,6 +101,12 @@ pub struct RaftConfig {
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `join` request.
     pub join: Vec<String>,
 
+    /// Join the cluster as a learner, which replicates logs asynchronously and never votes.
+    ///
+    /// A learner can be placed in another region to serve stale reads and be promoted to
+    /// a standalone cluster with `databend-metactl --cmd promote` if the original cluster is lost.
+    pub as_learner: bool,
+
     /// Do not run databend-meta, but just remove a node from its cluster.
     ///
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `leave` request.
@@ -142,8 +157,10 @@ impl Default for RaftConfig {
             install_snapshot_timeout: 4000,
             max_applied_log_to_keep: 1000,
             snapshot_chunk_size: 4194304, // 4MB
+            sm_storage: "mem".to_string(),
             single: false,
             join: vec![],
+            as_learner: false,
             leave_via: vec![],
             leave_id: None,
             id: 0,
@@ -224,6 +241,19 @@ impl RaftConfig {
             )));
         }
 
+        if self.as_learner && self.join.is_empty() {
+            return Err(MetaStartupError::InvalidConfig(String::from(
+                "`as_learner` requires `join` to be set",
+            )));
+        }
+
+        if self.sm_storage != "mem" && self.sm_storage != "disk" {
+            return Err(MetaStartupError::InvalidConfig(format!(
+                "invalid sm_storage: {}, expect `mem` or `disk`",
+                self.sm_storage
+            )));
+        }
+
         let self_addr = self.raft_api_listen_host_string();
         if self.join.contains(&self_addr) {
             return Err(MetaStartupError::InvalidConfig(String::from(
@@ -233,6 +263,11 @@ impl RaftConfig {
         Ok(())
     }
 
+    /// Returns true if the compacted state machine data is stored on disk.
+    pub fn is_sm_on_disk(&self) -> bool {
+        self.sm_storage == "disk"
+    }
+
     /// Create a unique sled::Tree name by prepending a unique prefix.
     /// So that multiple instance that depends on a sled::Tree can be used in one process.
     /// sled does not allow to open multiple `sled::Db` in one process.
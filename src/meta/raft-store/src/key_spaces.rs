@@ -36,6 +36,7 @@ use crate::state::RaftStateValue;
 use crate::state_machine::ClientLastRespValue;
 use crate::state_machine::ExpireKey;
 use crate::state_machine::ExpireValue;
+use crate::state_machine::LeaseValue;
 use crate::state_machine::LogMetaKey;
 use crate::state_machine::LogMetaValue;
 use crate::state_machine::StateMachineMetaKey;
@@ -132,6 +133,15 @@ impl SledKeySpace for DataHeader {
     type V = Header;
 }
 
+/// Stores leases that keys can be attached to, by lease id.
+pub struct Leases {}
+impl SledKeySpace for Leases {
+    const PREFIX: u8 = 14;
+    const NAME: &'static str = "leases";
+    type K = u64;
+    type V = LeaseValue;
+}
+
 /// Enum of key-value pairs that are used in the raft storage impl for meta-service.
 #[rustfmt::skip]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -146,6 +156,7 @@ pub enum RaftStoreEntry {
     Sequences        { key: <Sequences        as SledKeySpace>::K, value: <Sequences        as SledKeySpace>::V, },
     ClientLastResps  { key: <ClientLastResps  as SledKeySpace>::K, value: <ClientLastResps  as SledKeySpace>::V, },
     LogMeta          { key: <LogMeta          as SledKeySpace>::K, value: <LogMeta          as SledKeySpace>::V, },
+    Leases           { key: <Leases           as SledKeySpace>::K, value: <Leases           as SledKeySpace>::V, },
 }
 
 impl RaftStoreEntry {
@@ -169,6 +180,7 @@ impl RaftStoreEntry {
             Self::Sequences        { key, value } => ser!(Sequences,        key, value),
             Self::ClientLastResps  { key, value } => ser!(ClientLastResps,  key, value),
             Self::LogMeta          { key, value } => ser!(LogMeta,          key, value),
+            Self::Leases           { key, value } => ser!(Leases,           key, value),
         }
     }
 
@@ -212,7 +224,8 @@ impl RaftStoreEntry {
             GenericKV,
             Sequences,
             ClientLastResps,
-            LogMeta
+            LogMeta,
+            Leases
         );
 
         unreachable!("unknown prefix: {}", prefix);
@@ -234,6 +247,7 @@ pub enum RaftStoreEntryCompat {
     Sequences        { key: <Sequences        as SledKeySpace>::K, value: <Sequences as SledKeySpace>::V,         },
     ClientLastResps  { key: <ClientLastResps  as SledKeySpace>::K, value: <ClientLastResps as SledKeySpace>::V,   },
     LogMeta          { key: <LogMeta          as SledKeySpace>::K, value: crate::compat07::LogMetaValue,          },
+    Leases           { key: <Leases           as SledKeySpace>::K, value: <Leases as SledKeySpace>::V,            },
 }
 
 impl openraft::compat::Upgrade<RaftStoreEntry> for RaftStoreEntryCompat {
@@ -250,6 +264,7 @@ impl openraft::compat::Upgrade<RaftStoreEntry> for RaftStoreEntryCompat {
             RaftStoreEntryCompat::Sequences        { key, value } => RaftStoreEntry::Sequences        { key, value, },
             RaftStoreEntryCompat::ClientLastResps  { key, value } => RaftStoreEntry::ClientLastResps  { key, value, },
             RaftStoreEntryCompat::LogMeta          { key, value } => RaftStoreEntry::LogMeta          { key, value: value.upgrade(), },
+            RaftStoreEntryCompat::Leases           { key, value } => RaftStoreEntry::Leases           { key, value, },
         }
     }
 }
@@ -299,7 +314,8 @@ impl RaftStoreEntryCompat {
             GenericKV,
             Sequences,
             ClientLastResps,
-            LogMeta
+            LogMeta,
+            Leases
         );
 
         unreachable!("unknown prefix: {}", prefix);
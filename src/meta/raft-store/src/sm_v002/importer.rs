@@ -20,6 +20,8 @@ use common_meta_types::LogId;
 use common_meta_types::StoredMembership;
 
 use crate::key_spaces::RaftStoreEntry;
+use crate::sm_v002::leveled_store::disk_level::DiskLevel;
+use crate::sm_v002::leveled_store::disk_level::DiskLevelWriter;
 use crate::sm_v002::leveled_store::level::Level;
 use crate::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
 use crate::sm_v002::marked::Marked;
@@ -27,6 +29,9 @@ use crate::state_machine::ExpireKey;
 use crate::state_machine::StateMachineMetaKey;
 
 /// A container of temp data that are imported to a LevelData.
+///
+/// If a [`DiskLevelWriter`] is provided, kv and expire entries are written to disk
+/// instead of being kept in memory.
 #[derive(Debug, Default)]
 pub struct Importer {
     level_data: Level,
@@ -34,10 +39,20 @@ pub struct Importer {
     kv: BTreeMap<String, Marked>,
     expire: BTreeMap<ExpireKey, Marked<String>>,
 
+    disk: Option<DiskLevelWriter>,
+
     greatest_seq: u64,
 }
 
 impl Importer {
+    /// Create an importer that writes kv and expire entries to a new on-disk level.
+    pub fn new_with_disk(writer: DiskLevelWriter) -> Self {
+        Self {
+            disk: Some(writer),
+            ..Default::default()
+        }
+    }
+
     pub fn import(&mut self, entry: RaftStoreEntry) -> Result<(), io::Error> {
         let d = &mut self.level_data;
 
@@ -60,6 +75,9 @@ impl Importer {
             RaftStoreEntry::Nodes { key, value } => {
                 d.sys_data_mut().nodes_mut().insert(key, value);
             }
+            RaftStoreEntry::Leases { key, value } => {
+                d.sys_data_mut().leases_mut().insert(key, value);
+            }
             RaftStoreEntry::StateMachineMeta { key, value } => {
                 match key {
                     StateMachineMetaKey::LastApplied => {
@@ -99,11 +117,21 @@ impl Importer {
                 }
 
                 self.greatest_seq = std::cmp::max(self.greatest_seq, value.seq);
-                self.expire.insert(key, Marked::from(value));
+
+                if let Some(w) = &mut self.disk {
+                    w.insert_expire(&key, &value)?;
+                } else {
+                    self.expire.insert(key, Marked::from(value));
+                }
             }
             RaftStoreEntry::GenericKV { key, value } => {
                 self.greatest_seq = std::cmp::max(self.greatest_seq, value.seq);
-                self.kv.insert(key, Marked::from(value));
+
+                if let Some(w) = &mut self.disk {
+                    w.insert_kv(&key, &value)?;
+                } else {
+                    self.kv.insert(key, Marked::from(value));
+                }
             }
             RaftStoreEntry::Sequences { key: _, value } => d.sys_data_mut().update_seq(value.0),
         }
@@ -117,13 +145,32 @@ impl Importer {
         d.replace_kv(self.kv);
         d.replace_expire(self.expire);
 
+        self.assert_seq();
+
+        self.level_data
+    }
+
+    /// Commit the imported data to the on-disk level.
+    ///
+    /// It returns a level containing only the system data, and the on-disk level containing the data.
+    /// It panics if this importer is not created with [`Importer::new_with_disk`].
+    pub fn commit_disk(mut self) -> Result<(Level, DiskLevel), io::Error> {
+        self.assert_seq();
+
+        let writer = self.disk.take().expect("importer is not created with disk");
+        let disk_level = writer.commit(self.level_data.sys_data_ref())?;
+
+        Ok((self.level_data, disk_level))
+    }
+
+    fn assert_seq(&self) {
+        let curr_seq = self.level_data.curr_seq();
+
         assert!(
-            self.greatest_seq <= d.curr_seq(),
+            self.greatest_seq <= curr_seq,
             "greatest_seq {} must be LE curr_seq {}, otherwise seq may be reused",
             self.greatest_seq,
-            d.curr_seq()
+            curr_seq
         );
-
-        self.level_data
     }
 }
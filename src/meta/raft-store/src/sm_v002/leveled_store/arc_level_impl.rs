@@ -64,7 +64,7 @@ impl MapApiRO<String> for Arc<Level> {
     async fn get<Q>(&self, key: &Q) -> Result<Marked<<String as MapKey>::V>, io::Error>
     where
         String: Borrow<Q>,
-        Q: Ord + Send + Sync + ?Sized,
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = String>,
     {
         // get() is just delegated
         self.as_ref().str_map().get(key).await
@@ -82,7 +82,7 @@ impl MapApiRO<ExpireKey> for Arc<Level> {
     async fn get<Q>(&self, key: &Q) -> Result<MarkedOf<ExpireKey>, io::Error>
     where
         ExpireKey: Borrow<Q>,
-        Q: Ord + Send + Sync + ?Sized,
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = ExpireKey>,
     {
         // get() is just delegated
         self.as_ref().expire_map().get(key).await
@@ -0,0 +1,538 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::io;
+use std::ops::RangeBounds;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use common_meta_sled_store::sled;
+use common_meta_sled_store::SledKeySpace;
+use common_meta_stoerr::MetaStorageError;
+use common_meta_types::anyerror::AnyError;
+use common_meta_types::LogId;
+use common_meta_types::SeqNum;
+use common_meta_types::SeqV;
+use common_meta_types::StoredMembership;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+
+use crate::config::RaftConfig;
+use crate::key_spaces::DataHeader;
+use crate::key_spaces::Expire;
+use crate::key_spaces::GenericKV;
+use crate::key_spaces::Leases;
+use crate::key_spaces::Nodes;
+use crate::key_spaces::Sequences;
+use crate::key_spaces::StateMachineMeta;
+use crate::ondisk::Header;
+use crate::ondisk::OnDisk;
+use crate::sm_v002::leveled_store::map_api::AsMap;
+use crate::sm_v002::leveled_store::map_api::KVResultStream;
+use crate::sm_v002::leveled_store::map_api::MapApiRO;
+use crate::sm_v002::leveled_store::map_api::MapKey;
+use crate::sm_v002::leveled_store::map_api::MarkedOf;
+use crate::sm_v002::leveled_store::static_levels::StaticLevels;
+use crate::sm_v002::leveled_store::sys_data::SysData;
+use crate::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
+use crate::sm_v002::marked::Marked;
+use crate::state_machine::ExpireKey;
+use crate::state_machine::ExpireValue;
+use crate::state_machine::StateMachineMetaKey;
+use crate::state_machine::StateMachineMetaValue;
+
+/// Number of entries to write to disk in one batch when importing.
+const IMPORT_BATCH_SIZE: usize = 4096;
+
+/// The key used to store the sequence number, the same as the one used in a snapshot.
+const SEQUENCE_KEY: &str = "generic-kv";
+
+/// A [`MapKey`] that is stored in a sled key space in a [`DiskLevel`].
+pub(in crate::sm_v002) trait DiskKey: MapKey {
+    type KeySpace: SledKeySpace<K = Self>;
+
+    fn to_marked(v: <Self::KeySpace as SledKeySpace>::V) -> MarkedOf<Self>;
+
+    /// Convert a marked value to the value to store, `None` if it is a tombstone.
+    fn from_marked(v: MarkedOf<Self>) -> Option<<Self::KeySpace as SledKeySpace>::V>;
+}
+
+impl DiskKey for String {
+    type KeySpace = GenericKV;
+
+    fn to_marked(v: SeqV) -> MarkedOf<Self> {
+        Marked::from(v)
+    }
+
+    fn from_marked(v: MarkedOf<Self>) -> Option<SeqV> {
+        v.into()
+    }
+}
+
+impl DiskKey for ExpireKey {
+    type KeySpace = Expire;
+
+    fn to_marked(v: ExpireValue) -> MarkedOf<Self> {
+        Marked::from(v)
+    }
+
+    fn from_marked(v: MarkedOf<Self>) -> Option<ExpireValue> {
+        v.into()
+    }
+}
+
+/// The bottom level of the state machine data, that is stored on disk.
+///
+/// It contains the compacted data without tombstone,
+/// in the same key spaces as the sled based state machine and the snapshot use.
+/// It is updated only when the in-memory levels above it are compacted,
+/// thus the state machine does not need to keep all of the data in memory,
+/// and when restarting, it does not need to rebuild the state machine from a snapshot.
+///
+/// The data on disk may be newer than the in-memory levels above it while they are being flushed.
+/// It is safe because these levels contain the same data that overrides the data on disk,
+/// and snapshots are built one by one, no other snapshot view refers to an older state of it.
+pub struct DiskLevel {
+    db: sled::Db,
+    tree: sled::Tree,
+    sync: bool,
+
+    /// Whether this level is replaced by a newer one.
+    ///
+    /// An obsolete level is removed from disk when it is dropped,
+    /// i.e., when no snapshot view refers to it any more.
+    obsolete: AtomicBool,
+}
+
+impl fmt::Debug for DiskLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiskLevel")
+            .field("name", &self.name())
+            .field("obsolete", &self.obsolete.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+impl Drop for DiskLevel {
+    fn drop(&mut self) {
+        if !self.obsolete.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let name = self.name();
+        match self.db.drop_tree(self.tree.name()) {
+            Ok(_) => info!("removed obsolete state machine level: {}", name),
+            Err(e) => warn!(
+                "fail to remove obsolete state machine level: {}: {}",
+                name, e
+            ),
+        }
+    }
+}
+
+impl DiskLevel {
+    pub(in crate::sm_v002) fn name(&self) -> String {
+        String::from_utf8_lossy(&self.tree.name()).to_string()
+    }
+
+    /// Mark this level as replaced, its data will be removed when it is dropped.
+    pub(in crate::sm_v002) fn mark_obsolete(&self) {
+        self.obsolete.store(true, Ordering::Relaxed);
+    }
+
+    /// Load the system data, such as the last applied log id, stored along with the data.
+    pub(in crate::sm_v002) fn load_sys_data(&self) -> Result<SysData, io::Error> {
+        let mut sys_data = SysData::default();
+
+        let last_applied = get::<StateMachineMeta>(&self.tree, &StateMachineMetaKey::LastApplied)?;
+        if let Some(v) = last_applied {
+            let log_id: LogId = v.try_into().map_err(invalid_data)?;
+            *sys_data.last_applied_mut() = Some(log_id);
+        }
+
+        let membership = get::<StateMachineMeta>(&self.tree, &StateMachineMetaKey::LastMembership)?;
+        if let Some(v) = membership {
+            let membership: StoredMembership = v.try_into().map_err(invalid_data)?;
+            *sys_data.last_membership_mut() = membership;
+        }
+
+        if let Some(seq) = get::<Sequences>(&self.tree, &SEQUENCE_KEY.to_string())? {
+            sys_data.update_seq(seq.0);
+        }
+
+        let range = Nodes::serialize_range(&(..)).map_err(to_io)?;
+        for item in self.tree.range(range) {
+            let (k, v) = item.map_err(to_io)?;
+            let node_id = Nodes::deserialize_key(k).map_err(to_io)?;
+            let node = Nodes::deserialize_value(v).map_err(to_io)?;
+            sys_data.nodes_mut().insert(node_id, node);
+        }
+
+        let range = Leases::serialize_range(&(..)).map_err(to_io)?;
+        for item in self.tree.range(range) {
+            let (k, v) = item.map_err(to_io)?;
+            let lease_id = Leases::deserialize_key(k).map_err(to_io)?;
+            let lease = Leases::deserialize_value(v).map_err(to_io)?;
+            sys_data.leases_mut().insert(lease_id, lease);
+        }
+
+        Ok(sys_data)
+    }
+
+    /// Write the changes in the in-memory `levels` to disk.
+    ///
+    /// Only the changes are written, the data already on disk is not rewritten.
+    /// The changes and the system data of the newest level are written atomically.
+    pub(in crate::sm_v002) async fn flush(&self, levels: &StaticLevels) -> Result<(), io::Error> {
+        let mut batch = sled::Batch::default();
+
+        // `range()` of multiple levels returns the latest value of each key, including tombstones.
+        let strm = levels.str_map().range(..).await?;
+        batch_changes(&mut batch, strm).await?;
+
+        let strm = levels.expire_map().range(..).await?;
+        batch_changes(&mut batch, strm).await?;
+
+        if let Some(newest) = levels.newest() {
+            batch_sys_data(&self.tree, &mut batch, newest.sys_data_ref())?;
+        }
+
+        self.tree.apply_batch(batch).map_err(to_io)?;
+        if self.sync {
+            self.tree.flush_async().await.map_err(to_io)?;
+        }
+
+        info!(
+            "flushed state machine levels to disk: {}, last_applied: {:?}",
+            self.name(),
+            levels.newest().map(|l| *l.last_applied_ref())
+        );
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<K> MapApiRO<K> for DiskLevel
+where K: DiskKey
+{
+    async fn get<Q>(&self, key: &Q) -> Result<MarkedOf<K>, io::Error>
+    where
+        K: Borrow<Q>,
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = K>,
+    {
+        let got = get::<K::KeySpace>(&self.tree, &key.to_owned())?;
+        let marked = got.map(K::to_marked).unwrap_or(Marked::empty());
+        Ok(marked)
+    }
+
+    async fn range<R>(&self, range: R) -> Result<KVResultStream<K>, io::Error>
+    where R: RangeBounds<K> + Clone + Send + Sync + 'static {
+        let range = K::KeySpace::serialize_range(&range).map_err(to_io)?;
+
+        // sled::Iter does not borrow the tree, the returned stream is static.
+        let it = self.tree.range(range).map(|item| {
+            let (k, v) = item.map_err(to_io)?;
+            let key = K::KeySpace::deserialize_key(k).map_err(to_io)?;
+            let value = K::KeySpace::deserialize_value(v).map_err(to_io)?;
+            Ok((key, K::to_marked(value)))
+        });
+
+        Ok(futures::stream::iter(it).boxed())
+    }
+}
+
+/// Builds a new [`DiskLevel`] by writing data in batches.
+///
+/// The level is complete only after it is committed.
+/// An incomplete level is removed when the [`DiskStore`] is opened.
+pub struct DiskLevelWriter {
+    db: sled::Db,
+    tree: sled::Tree,
+    sync: bool,
+
+    batch: sled::Batch,
+    batch_size: usize,
+}
+
+impl fmt::Debug for DiskLevelWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiskLevelWriter")
+            .field("name", &String::from_utf8_lossy(&self.tree.name()))
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl DiskLevelWriter {
+    pub(in crate::sm_v002) fn insert_kv(
+        &mut self,
+        key: &String,
+        value: &SeqV,
+    ) -> Result<(), io::Error> {
+        self.insert::<GenericKV>(key, value)
+    }
+
+    pub(in crate::sm_v002) fn insert_expire(
+        &mut self,
+        key: &ExpireKey,
+        value: &ExpireValue,
+    ) -> Result<(), io::Error> {
+        self.insert::<Expire>(key, value)
+    }
+
+    fn insert<KS: SledKeySpace>(&mut self, key: &KS::K, value: &KS::V) -> Result<(), io::Error> {
+        let k = KS::serialize_key(key).map_err(to_io)?;
+        let v = KS::serialize_value(value).map_err(to_io)?;
+        self.batch.insert(k, v);
+        self.batch_size += 1;
+
+        if self.batch_size >= IMPORT_BATCH_SIZE {
+            let batch = std::mem::take(&mut self.batch);
+            self.tree.apply_batch(batch).map_err(to_io)?;
+            self.batch_size = 0;
+        }
+        Ok(())
+    }
+
+    /// Write the system data and mark the level as complete.
+    pub(in crate::sm_v002) fn commit(mut self, sys_data: &SysData) -> Result<DiskLevel, io::Error> {
+        let mut batch = std::mem::take(&mut self.batch);
+        batch_sys_data(&self.tree, &mut batch, sys_data)?;
+
+        // The header is written last, to indicate the level is complete.
+        let k = DataHeader::serialize_key(&OnDisk::KEY_HEADER.to_string()).map_err(to_io)?;
+        let v = DataHeader::serialize_value(&Header::this_version()).map_err(to_io)?;
+        batch.insert(k, v);
+
+        self.tree.apply_batch(batch).map_err(to_io)?;
+        if self.sync {
+            self.tree.flush().map_err(to_io)?;
+        }
+
+        let level = DiskLevel {
+            db: self.db,
+            tree: self.tree,
+            sync: self.sync,
+            obsolete: AtomicBool::new(false),
+        };
+
+        info!(
+            "state machine level is written to disk: {}, last_applied: {:?}",
+            level.name(),
+            sys_data.last_applied_ref()
+        );
+
+        Ok(level)
+    }
+}
+
+/// Manages the on-disk levels of the state machine.
+///
+/// Every level is stored in a sled tree named `<prefix>state_machine_v002/<generation>`.
+/// A new generation is created when a snapshot is installed,
+/// and only the latest complete one is used.
+#[derive(Debug, Clone)]
+pub struct DiskStore {
+    db: sled::Db,
+    tree_prefix: String,
+    sync: bool,
+}
+
+impl DiskStore {
+    pub fn new(db: sled::Db, config: &RaftConfig) -> Self {
+        Self {
+            db,
+            tree_prefix: config.tree_name("state_machine_v002/"),
+            sync: config.is_sync(),
+        }
+    }
+
+    /// Open the latest complete level, and remove all others.
+    pub fn open_latest(&self) -> Result<Option<DiskLevel>, io::Error> {
+        let mut latest = None;
+
+        for (generation, name) in self.generations().into_iter().rev() {
+            let tree = self.db.open_tree(&name).map_err(to_io)?;
+
+            let complete = get::<DataHeader>(&tree, &OnDisk::KEY_HEADER.to_string())?.is_some();
+
+            if latest.is_none() && complete {
+                info!("open state machine level: {}", name);
+                latest = Some(DiskLevel {
+                    db: self.db.clone(),
+                    tree,
+                    sync: self.sync,
+                    obsolete: AtomicBool::new(false),
+                });
+                continue;
+            }
+
+            info!(
+                "remove state machine level: {}, generation: {}, complete: {}",
+                name, generation, complete
+            );
+            drop(tree);
+            self.db.drop_tree(&name).map_err(to_io)?;
+        }
+
+        Ok(latest)
+    }
+
+    /// Create a writer to build a new level, of the next generation.
+    pub fn new_writer(&self) -> Result<DiskLevelWriter, io::Error> {
+        let generation = self
+            .generations()
+            .last()
+            .map(|(g, _)| g + 1)
+            .unwrap_or_default();
+
+        let name = format!("{}{}", self.tree_prefix, generation);
+        let tree = self.db.open_tree(&name).map_err(to_io)?;
+        tree.clear().map_err(to_io)?;
+
+        Ok(DiskLevelWriter {
+            db: self.db.clone(),
+            tree,
+            sync: self.sync,
+            batch: Default::default(),
+            batch_size: 0,
+        })
+    }
+
+    /// Returns the generation and name of all of the levels, in ascending order.
+    fn generations(&self) -> Vec<(u64, String)> {
+        let mut res = vec![];
+
+        for name in self.db.tree_names() {
+            let name = String::from_utf8_lossy(&name).to_string();
+            let generation = name
+                .strip_prefix(&self.tree_prefix)
+                .and_then(|g| g.parse::<u64>().ok());
+
+            if let Some(g) = generation {
+                res.push((g, name));
+            }
+        }
+
+        res.sort();
+        res
+    }
+}
+
+/// Add the changes in a stream to a batch: write normal entries and remove tombstones.
+async fn batch_changes<K: DiskKey>(
+    batch: &mut sled::Batch,
+    mut strm: KVResultStream<K>,
+) -> Result<(), io::Error> {
+    while let Some((k, marked)) = strm.try_next().await? {
+        let key = K::KeySpace::serialize_key(&k).map_err(to_io)?;
+
+        match K::from_marked(marked) {
+            Some(v) => {
+                let value = K::KeySpace::serialize_value(&v).map_err(to_io)?;
+                batch.insert(key, value);
+            }
+            None => batch.remove(key),
+        }
+    }
+
+    Ok(())
+}
+
+/// Add system data to a batch, and remove the nodes and leases that no longer exist.
+fn batch_sys_data(
+    tree: &sled::Tree,
+    batch: &mut sled::Batch,
+    sys_data: &SysData,
+) -> Result<(), io::Error> {
+    macro_rules! insert {
+        ($ks:ty, $key:expr, $value:expr) => {
+            batch.insert(
+                <$ks>::serialize_key($key).map_err(to_io)?,
+                <$ks>::serialize_value($value).map_err(to_io)?,
+            )
+        };
+    }
+
+    if let Some(last_applied) = sys_data.last_applied_ref() {
+        insert!(
+            StateMachineMeta,
+            &StateMachineMetaKey::LastApplied,
+            &StateMachineMetaValue::LogId(*last_applied)
+        );
+    }
+
+    insert!(
+        StateMachineMeta,
+        &StateMachineMetaKey::LastMembership,
+        &StateMachineMetaValue::Membership(sys_data.last_membership_ref().clone())
+    );
+
+    insert!(
+        Sequences,
+        &SEQUENCE_KEY.to_string(),
+        &SeqNum(sys_data.curr_seq())
+    );
+
+    let range = Nodes::serialize_range(&(..)).map_err(to_io)?;
+    for item in tree.range(range) {
+        let (k, _) = item.map_err(to_io)?;
+        let node_id = Nodes::deserialize_key(&k).map_err(to_io)?;
+        if !sys_data.nodes_ref().contains_key(&node_id) {
+            batch.remove(k);
+        }
+    }
+
+    for (node_id, node) in sys_data.nodes_ref().iter() {
+        insert!(Nodes, node_id, node);
+    }
+
+    let range = Leases::serialize_range(&(..)).map_err(to_io)?;
+    for item in tree.range(range) {
+        let (k, _) = item.map_err(to_io)?;
+        let lease_id = Leases::deserialize_key(&k).map_err(to_io)?;
+        if !sys_data.leases_ref().contains_key(&lease_id) {
+            batch.remove(k);
+        }
+    }
+
+    for (lease_id, lease) in sys_data.leases_ref().iter() {
+        insert!(Leases, lease_id, lease);
+    }
+
+    Ok(())
+}
+
+fn get<KS: SledKeySpace>(tree: &sled::Tree, key: &KS::K) -> Result<Option<KS::V>, io::Error> {
+    let k = KS::serialize_key(key).map_err(to_io)?;
+    let got = tree.get(k).map_err(to_io)?;
+
+    match got {
+        Some(v) => Ok(Some(KS::deserialize_value(v).map_err(to_io)?)),
+        None => Ok(None),
+    }
+}
+
+fn to_io(e: impl Into<MetaStorageError>) -> io::Error {
+    io::Error::from(e.into())
+}
+
+fn invalid_data(e: impl fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, AnyError::error(e))
+}
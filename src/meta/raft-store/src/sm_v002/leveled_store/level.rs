@@ -94,7 +94,7 @@ impl MapApiRO<String> for Level {
     async fn get<Q>(&self, key: &Q) -> Result<Marked<<String as MapKey>::V>, io::Error>
     where
         String: Borrow<Q>,
-        Q: Ord + Send + Sync + ?Sized,
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = String>,
     {
         let got = self.kv.get(key).cloned().unwrap_or(Marked::empty());
         Ok(got)
@@ -144,7 +144,7 @@ impl MapApiRO<ExpireKey> for Level {
     async fn get<Q>(&self, key: &Q) -> Result<MarkedOf<ExpireKey>, io::Error>
     where
         ExpireKey: Borrow<Q>,
-        Q: Ord + Send + Sync + ?Sized,
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = ExpireKey>,
     {
         let got = self.expire.get(key).cloned().unwrap_or(Marked::empty());
         Ok(got)
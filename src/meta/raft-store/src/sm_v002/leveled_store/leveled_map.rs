@@ -20,9 +20,8 @@ use std::sync::Arc;
 
 use common_meta_types::KVMeta;
 
+use crate::sm_v002::leveled_store::disk_level::DiskLevel;
 use crate::sm_v002::leveled_store::level::Level;
-use crate::sm_v002::leveled_store::map_api::compacted_get;
-use crate::sm_v002::leveled_store::map_api::compacted_range;
 use crate::sm_v002::leveled_store::map_api::KVResultStream;
 use crate::sm_v002::leveled_store::map_api::MapApi;
 use crate::sm_v002::leveled_store::map_api::MapApiRO;
@@ -57,20 +56,6 @@ impl LeveledMap {
         }
     }
 
-    /// Return an iterator of all levels in reverse order.
-    pub(in crate::sm_v002) fn iter_levels(&self) -> impl Iterator<Item = &Level> {
-        [&self.writable]
-            .into_iter()
-            .chain(self.frozen.iter_levels())
-    }
-
-    /// Return the top level and an iterator of all frozen levels, in newest to oldest order.
-    pub(in crate::sm_v002) fn iter_shared_levels(
-        &self,
-    ) -> (Option<&Level>, impl Iterator<Item = &Arc<Level>>) {
-        (Some(&self.writable), self.frozen.iter_arc_levels())
-    }
-
     /// Freeze the current writable level and create a new empty writable level.
     pub fn freeze_writable(&mut self) -> &StaticLevels {
         let new_writable = self.writable.new_level();
@@ -96,6 +81,11 @@ impl LeveledMap {
         &self.frozen
     }
 
+    /// Set the bottom level that is stored on disk.
+    pub(crate) fn set_base(&mut self, base: Arc<DiskLevel>) {
+        self.frozen.set_base(Some(base));
+    }
+
     /// Replace all immutable levels with the given one.
     pub(crate) fn replace_frozen(&mut self, b: StaticLevels) {
         self.frozen = b;
@@ -105,7 +95,6 @@ impl LeveledMap {
         RefMut::new(&mut self.writable, &self.frozen)
     }
 
-    #[allow(dead_code)]
     pub(crate) fn to_ref(&self) -> Ref {
         Ref::new(Some(&self.writable), &self.frozen)
     }
@@ -116,21 +105,19 @@ impl<K> MapApiRO<K> for LeveledMap
 where
     K: MapKey + fmt::Debug,
     Level: MapApiRO<K>,
-    Arc<Level>: MapApiRO<K>,
+    StaticLevels: MapApiRO<K>,
 {
     async fn get<Q>(&self, key: &Q) -> Result<Marked<K::V>, io::Error>
     where
         K: Borrow<Q>,
-        Q: Ord + Send + Sync + ?Sized,
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = K>,
     {
-        let levels = self.iter_levels();
-        compacted_get(key, levels).await
+        self.to_ref().get(key).await
     }
 
     async fn range<R>(&self, range: R) -> Result<KVResultStream<K>, io::Error>
     where R: RangeBounds<K> + Clone + Send + Sync + 'static {
-        let (top, levels) = self.iter_shared_levels();
-        compacted_range(range, top, levels).await
+        self.to_ref().range(range).await
     }
 }
 
@@ -139,7 +126,7 @@ impl<K> MapApi<K> for LeveledMap
 where
     K: MapKey,
     Level: MapApi<K>,
-    Arc<Level>: MapApiRO<K>,
+    StaticLevels: MapApiRO<K>,
 {
     async fn set(
         &mut self,
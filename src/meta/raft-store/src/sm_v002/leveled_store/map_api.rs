@@ -82,10 +82,15 @@ pub(in crate::sm_v002) trait MapApiRO<K>: Send + Sync
 where K: MapKey
 {
     /// Get an entry by key.
+    ///
+    /// The key has to be converted to an owned one,
+    /// for a level that can not look up by a borrowed key, e.g., [`DiskLevel`].
+    ///
+    /// [`DiskLevel`]: crate::sm_v002::leveled_store::disk_level::DiskLevel
     async fn get<Q>(&self, key: &Q) -> Result<MarkedOf<K>, io::Error>
     where
         K: Borrow<Q>,
-        Q: Ord + Send + Sync + ?Sized;
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = K>;
 
     /// Iterate over a range of entries by keys.
     ///
@@ -204,7 +209,7 @@ pub(in crate::sm_v002) async fn compacted_get<'d, K, Q, L>(
 where
     K: MapKey,
     K: Borrow<Q>,
-    Q: Ord + Send + Sync + ?Sized,
+    Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = K>,
     L: MapApiRO<K> + 'static,
 {
     for lvl in levels {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arc_level_impl;
+pub mod disk_level;
 pub mod level;
 pub mod leveled_map;
 pub mod map_api;
@@ -16,10 +16,8 @@ use std::borrow::Borrow;
 use std::fmt;
 use std::io;
 use std::ops::RangeBounds;
-use std::sync::Arc;
 
 use crate::sm_v002::leveled_store::level::Level;
-use crate::sm_v002::leveled_store::map_api::compacted_get;
 use crate::sm_v002::leveled_store::map_api::compacted_range;
 use crate::sm_v002::leveled_store::map_api::KVResultStream;
 use crate::sm_v002::leveled_store::map_api::MapApiRO;
@@ -44,17 +42,6 @@ impl<'d> Ref<'d> {
     ) -> Ref<'d> {
         Self { writable, frozen }
     }
-
-    /// Return an iterator of all levels in reverse order.
-    pub(in crate::sm_v002) fn iter_levels(&self) -> impl Iterator<Item = &'d Level> + 'd {
-        self.writable.into_iter().chain(self.frozen.iter_levels())
-    }
-
-    pub(in crate::sm_v002) fn iter_shared_levels(
-        &self,
-    ) -> (Option<&Level>, impl Iterator<Item = &Arc<Level>>) {
-        (self.writable, self.frozen.iter_arc_levels())
-    }
 }
 
 #[async_trait::async_trait]
@@ -62,20 +49,26 @@ impl<'d, K> MapApiRO<K> for Ref<'d>
 where
     K: MapKey + fmt::Debug,
     Level: MapApiRO<K>,
-    Arc<Level>: MapApiRO<K>,
+    StaticLevels: MapApiRO<K>,
 {
     async fn get<Q>(&self, key: &Q) -> Result<Marked<K::V>, io::Error>
     where
         K: Borrow<Q>,
-        Q: Ord + Send + Sync + ?Sized,
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = K>,
     {
-        let levels = self.iter_levels();
-        compacted_get(key, levels).await
+        if let Some(w) = self.writable {
+            let got = w.get(key).await?;
+            if !got.not_found() {
+                return Ok(got);
+            }
+        }
+
+        // The frozen levels include the on-disk base level, if there is one.
+        self.frozen.get(key).await
     }
 
     async fn range<R>(&self, range: R) -> Result<KVResultStream<K>, io::Error>
     where R: RangeBounds<K> + Clone + Send + Sync + 'static {
-        let (top, levels) = self.iter_shared_levels();
-        compacted_range(range, top, levels).await
+        compacted_range(range, self.writable, [self.frozen]).await
     }
 }
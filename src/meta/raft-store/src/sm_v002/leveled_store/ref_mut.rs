@@ -13,15 +13,13 @@
 // limitations under the License.
 
 use std::borrow::Borrow;
+use std::fmt;
 use std::io;
 use std::ops::RangeBounds;
-use std::sync::Arc;
 
 use common_meta_types::KVMeta;
 
 use crate::sm_v002::leveled_store::level::Level;
-use crate::sm_v002::leveled_store::map_api::compacted_get;
-use crate::sm_v002::leveled_store::map_api::compacted_range;
 use crate::sm_v002::leveled_store::map_api::KVResultStream;
 use crate::sm_v002::leveled_store::map_api::MapApi;
 use crate::sm_v002::leveled_store::map_api::MapApiRO;
@@ -47,23 +45,9 @@ impl<'d> RefMut<'d> {
         Self { writable, frozen }
     }
 
-    #[allow(dead_code)]
     pub(in crate::sm_v002) fn to_ref(&self) -> Ref {
         Ref::new(Some(&*self.writable), self.frozen)
     }
-
-    /// Return an iterator of all levels in new-to-old order.
-    pub(in crate::sm_v002) fn iter_levels(&self) -> impl Iterator<Item = &'_ Level> + '_ {
-        [&*self.writable]
-            .into_iter()
-            .chain(self.frozen.iter_levels())
-    }
-
-    pub(in crate::sm_v002) fn iter_shared_levels(
-        &self,
-    ) -> (Option<&Level>, impl Iterator<Item = &Arc<Level>>) {
-        (Some(self.writable), self.frozen.iter_arc_levels())
-    }
 }
 
 // Because `LeveledRefMut` has a mut ref of lifetime 'd,
@@ -71,32 +55,30 @@ impl<'d> RefMut<'d> {
 #[async_trait::async_trait]
 impl<'d, K> MapApiRO<K> for RefMut<'d>
 where
-    K: MapKey,
+    K: MapKey + fmt::Debug,
     Level: MapApiRO<K>,
-    Arc<Level>: MapApiRO<K>,
+    StaticLevels: MapApiRO<K>,
 {
     async fn get<Q>(&self, key: &Q) -> Result<Marked<K::V>, io::Error>
     where
         K: Borrow<Q>,
-        Q: Ord + Send + Sync + ?Sized,
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = K>,
     {
-        let levels = self.iter_levels();
-        compacted_get(key, levels).await
+        self.to_ref().get(key).await
     }
 
     async fn range<R>(&self, range: R) -> Result<KVResultStream<K>, io::Error>
     where R: RangeBounds<K> + Clone + Send + Sync + 'static {
-        let (top, levels) = self.iter_shared_levels();
-        compacted_range(range, top, levels).await
+        self.to_ref().range(range).await
     }
 }
 
 #[async_trait::async_trait]
 impl<'d, K> MapApi<K> for RefMut<'d>
 where
-    K: MapKey,
+    K: MapKey + fmt::Debug,
     Level: MapApi<K>,
-    Arc<Level>: MapApiRO<K>,
+    StaticLevels: MapApiRO<K>,
 {
     async fn set(
         &mut self,
@@ -17,6 +17,7 @@ use std::io;
 use std::ops::RangeBounds;
 use std::sync::Arc;
 
+use crate::sm_v002::leveled_store::disk_level::DiskLevel;
 use crate::sm_v002::leveled_store::level::Level;
 use crate::sm_v002::leveled_store::map_api::compacted_get;
 use crate::sm_v002::leveled_store::map_api::compacted_range;
@@ -31,25 +32,33 @@ use crate::sm_v002::marked::Marked;
 pub struct StaticLevels {
     /// From oldest to newest, i.e., levels[0] is the oldest
     levels: Vec<Arc<Level>>,
+
+    /// The bottom level stored on disk, which is older than all of the in-memory levels.
+    base: Option<Arc<DiskLevel>>,
 }
 
 impl StaticLevels {
     pub(in crate::sm_v002) fn new(levels: impl IntoIterator<Item = Arc<Level>>) -> Self {
         Self {
             levels: levels.into_iter().collect(),
+            base: None,
         }
     }
 
+    /// Return the bottom level stored on disk, if there is one.
+    pub(in crate::sm_v002) fn base(&self) -> Option<&Arc<DiskLevel>> {
+        self.base.as_ref()
+    }
+
+    pub(in crate::sm_v002) fn set_base(&mut self, base: Option<Arc<DiskLevel>>) {
+        self.base = base;
+    }
+
     /// Return an iterator of all Arc of levels from newest to oldest.
     pub(in crate::sm_v002) fn iter_arc_levels(&self) -> impl Iterator<Item = &Arc<Level>> {
         self.levels.iter().rev()
     }
 
-    /// Return an iterator of all levels from newest to oldest.
-    pub(in crate::sm_v002) fn iter_levels(&self) -> impl Iterator<Item = &Level> {
-        self.levels.iter().map(|x| x.as_ref()).rev()
-    }
-
     pub(in crate::sm_v002) fn newest(&self) -> Option<&Arc<Level>> {
         self.levels.last()
     }
@@ -74,19 +83,25 @@ where
     K: MapKey,
     Level: MapApiRO<K>,
     Arc<Level>: MapApiRO<K>,
+    DiskLevel: MapApiRO<K>,
 {
     async fn get<Q>(&self, key: &Q) -> Result<Marked<K::V>, io::Error>
     where
         K: Borrow<Q>,
-        Q: Ord + Send + Sync + ?Sized,
+        Q: Ord + Send + Sync + ?Sized + ToOwned<Owned = K>,
     {
         let levels = self.iter_arc_levels();
-        compacted_get(key, levels).await
+        let got = compacted_get(key, levels).await?;
+
+        match &self.base {
+            Some(base) if got.not_found() => base.get(key).await,
+            _ => Ok(got),
+        }
     }
 
     async fn range<R>(&self, range: R) -> Result<KVResultStream<K>, io::Error>
     where R: RangeBounds<K> + Clone + Send + Sync + 'static {
         let levels = self.iter_arc_levels();
-        compacted_range::<_, _, _, Level>(range, None, levels).await
+        compacted_range(range, self.base.as_deref(), levels).await
     }
 }
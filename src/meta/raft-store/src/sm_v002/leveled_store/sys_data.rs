@@ -21,6 +21,7 @@ use common_meta_types::StoredMembership;
 use log::debug;
 
 use crate::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
+use crate::state_machine::LeaseValue;
 
 /// System data(non-user data).
 ///
@@ -40,6 +41,9 @@ pub struct SysData {
     ///
     /// A seq is globally unique and monotonically increasing.
     sequence: u64,
+
+    /// The leases that keys can be attached to, by lease id.
+    leases: BTreeMap<u64, LeaseValue>,
 }
 
 impl SysDataApiRO for SysData {
@@ -58,6 +62,10 @@ impl SysDataApiRO for SysData {
     fn nodes_ref(&self) -> &BTreeMap<NodeId, Node> {
         &self.nodes
     }
+
+    fn leases_ref(&self) -> &BTreeMap<u64, LeaseValue> {
+        &self.leases
+    }
 }
 
 impl<T> SysDataApiRO for T
@@ -78,6 +86,10 @@ where T: AsRef<SysData>
     fn nodes_ref(&self) -> &BTreeMap<NodeId, Node> {
         self.as_ref().nodes_ref()
     }
+
+    fn leases_ref(&self) -> &BTreeMap<u64, LeaseValue> {
+        self.as_ref().leases_ref()
+    }
 }
 
 impl SysData {
@@ -105,4 +117,8 @@ impl SysData {
     pub fn nodes_mut(&mut self) -> &mut BTreeMap<NodeId, Node> {
         &mut self.nodes
     }
+
+    pub fn leases_mut(&mut self) -> &mut BTreeMap<u64, LeaseValue> {
+        &mut self.leases
+    }
 }
@@ -30,7 +30,9 @@ where
 {
     match (r1, r2) {
         (Ok((k1, v1)), Ok((k2, v2))) => {
-            assert_ne!((k1, v1.internal_seq()), (k2, v2.internal_seq()));
+            // The same record may appear in both an in-memory level and the on-disk base level,
+            // while the in-memory level is being flushed to disk. They are identical and will be
+            // coalesced.
 
             // Put entries with the same key together, smaller internal-seq first
             // Tombstone is always greater.
@@ -22,12 +22,15 @@ mod writer_v002;
 
 mod importer;
 
+#[cfg(test)]
+mod sm_v002_disk_test;
 #[cfg(test)]
 mod sm_v002_test;
 #[cfg(test)]
 mod snapshot_view_v002_test;
 
 pub use importer::Importer;
+pub use leveled_store::disk_level::DiskStore;
 pub use sm_v002::SMV002;
 pub use snapshot_store::SnapshotStoreError;
 pub use snapshot_store::SnapshotStoreV002;
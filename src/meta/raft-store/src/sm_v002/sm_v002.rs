@@ -20,6 +20,8 @@ use std::sync::Arc;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::KVStream;
+use common_meta_kvapi::kvapi::LeaseReply;
+use common_meta_kvapi::kvapi::LeaseReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
@@ -48,6 +50,7 @@ use tokio::sync::RwLock;
 
 use crate::applier::Applier;
 use crate::key_spaces::RaftStoreEntry;
+use crate::sm_v002::leveled_store::disk_level::DiskStore;
 use crate::sm_v002::leveled_store::level::Level;
 use crate::sm_v002::leveled_store::leveled_map::LeveledMap;
 use crate::sm_v002::leveled_store::map_api::AsMap;
@@ -116,6 +119,10 @@ impl<'a> kvapi::KVApi for SMV002KVApi<'a> {
     async fn transaction(&self, _txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         unreachable!("write operation SM2KVApi::transaction is disabled")
     }
+
+    async fn lease(&self, _req: LeaseReq) -> Result<LeaseReply, Self::Error> {
+        unreachable!("write operation SM2KVApi::lease is disabled")
+    }
 }
 
 impl<'a> SMV002KVApi<'a> {
@@ -137,11 +144,62 @@ pub struct SMV002 {
     /// The expiration key since which for next clean.
     pub(in crate::sm_v002) expire_cursor: ExpireKey,
 
+    /// Where to store the bottom level of data on disk.
+    ///
+    /// If it is `None`, all of the data is kept in memory.
+    disk: Option<DiskStore>,
+
     /// subscriber of state machine data
     pub(crate) subscriber: Option<Box<dyn StateMachineSubscriber>>,
 }
 
 impl SMV002 {
+    /// Open a state machine whose data is stored on disk, with the latest level in `disk`.
+    ///
+    /// If there is no level on disk yet, an empty one is created.
+    /// Only the changes since the last compaction are kept in memory.
+    pub fn open_disk(disk: DiskStore) -> Result<Self, io::Error> {
+        let base = match disk.open_latest()? {
+            Some(base) => base,
+            None => disk.new_writer()?.commit(&SysData::default())?,
+        };
+
+        let mut level_data = Level::default();
+        *level_data.sys_data_mut() = base.load_sys_data()?;
+
+        let mut levels = LeveledMap::new(level_data);
+        levels.freeze_writable();
+        levels.set_base(Arc::new(base));
+
+        info!(
+            "opened on-disk state machine, last_applied: {:?}",
+            levels.writable_ref().last_applied_ref()
+        );
+
+        Ok(Self {
+            levels,
+            disk: Some(disk),
+            ..Default::default()
+        })
+    }
+
+    /// Discard all of the data, and return an empty state machine that stores data in the same place.
+    pub fn into_empty(self) -> Self {
+        if let Some(base) = self.levels.frozen_ref().base() {
+            base.mark_obsolete();
+        }
+
+        Self {
+            disk: self.disk,
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if the data of this state machine is stored on disk.
+    pub fn is_on_disk(&self) -> bool {
+        self.disk.is_some()
+    }
+
     pub fn kv_api(&self) -> SMV002KVApi {
         SMV002KVApi { sm: self }
     }
@@ -157,7 +215,13 @@ impl SMV002 {
         let data_size = data.data_size().await?;
         info!("snapshot data len: {}", data_size);
 
-        let mut importer = sm_v002::SMV002::new_importer();
+        let disk = state_machine.read().await.disk.clone();
+
+        // A state machine on disk imports the data into a new on-disk level.
+        let mut importer = match &disk {
+            Some(d) => Importer::new_with_disk(d.new_writer()?),
+            None => sm_v002::SMV002::new_importer(),
+        };
 
         let br = BufReader::new(data);
         let mut lines = AsyncBufReadExt::lines(br);
@@ -169,7 +233,12 @@ impl SMV002 {
             importer.import(ent)?;
         }
 
-        let level_data = importer.commit();
+        let (level_data, base) = if disk.is_some() {
+            let (level_data, base) = importer.commit_disk()?;
+            (level_data, Some(Arc::new(base)))
+        } else {
+            (importer.commit(), None)
+        };
         let new_last_applied = *level_data.last_applied_ref();
 
         {
@@ -191,6 +260,10 @@ impl SMV002 {
                     new_last_applied,
                     sm.sys_data_ref().last_applied_ref()
                 );
+
+                if let Some(base) = base {
+                    base.mark_obsolete();
+                }
                 return Ok(());
             }
 
@@ -201,6 +274,10 @@ impl SMV002 {
             // See the MapApiRO::range() implementation for Level.
             levels.freeze_writable();
 
+            if let Some(base) = base {
+                levels.set_base(base);
+            }
+
             sm.replace(levels);
         }
 
@@ -222,6 +299,16 @@ impl SMV002 {
         Ok(importer.commit())
     }
 
+    /// Build a state machine from exported entries, e.g., the state machine part of a backup.
+    pub fn from_entries(data: impl Iterator<Item = RaftStoreEntry>) -> Result<Self, io::Error> {
+        let level_data = Self::import(data)?;
+
+        let mut sm = Self::default();
+        sm.replace(LeveledMap::new(level_data));
+
+        Ok(sm)
+    }
+
     pub fn new_importer() -> Importer {
         Importer::default()
     }
@@ -294,6 +381,44 @@ impl SMV002 {
         Ok(strm.boxed())
     }
 
+    /// List at most `limit` kv entries in range `[start, end)`.
+    ///
+    /// `end` of `None` means the range is unbounded, `limit` of `None` means no limit.
+    /// If a value is expired at `now_ms`, it is not returned.
+    pub async fn range_kv(
+        &self,
+        start: &str,
+        end: Option<&str>,
+        limit: Option<usize>,
+        now_ms: u64,
+    ) -> Result<ResultStream<(String, SeqV)>, io::Error> {
+        let end = end.map(|x| x.to_string());
+
+        let strm = self.levels.str_map().range(start.to_string()..).await?;
+
+        let strm = strm
+            .try_take_while(move |(k, _)| {
+                let in_range = end.as_ref().map(|e| k < e).unwrap_or(true);
+                future::ready(Ok(in_range))
+            })
+            // Skip tombstone
+            .try_filter_map(|(k, marked)| {
+                let seqv = Into::<Option<SeqV>>::into(marked);
+                let res = seqv.map(|x| (k, x));
+                future::ready(Ok(res))
+            })
+            .try_filter(move |(_k, v)| future::ready(!v.is_expired(now_ms)))
+            // Stop reading the levels once enough entries are found.
+            .take(limit.unwrap_or(usize::MAX));
+
+        // Make it static
+
+        let vs = strm.collect::<Vec<_>>().await;
+        let strm = futures::stream::iter(vs);
+
+        Ok(strm.boxed())
+    }
+
     pub(crate) fn update_expire_cursor(&mut self, log_time_ms: u64) {
         if log_time_ms < self.expire_cursor.time_ms {
             warn!(
@@ -332,9 +457,17 @@ impl SMV002 {
     }
 
     pub fn set_subscriber(&mut self, subscriber: Box<dyn StateMachineSubscriber>) {
+        subscriber.history_reset(self.last_applied_index());
         self.subscriber = Some(subscriber);
     }
 
+    fn last_applied_index(&self) -> u64 {
+        self.sys_data_ref()
+            .last_applied_ref()
+            .map(|log_id| log_id.index)
+            .unwrap_or_default()
+    }
+
     /// Creates a snapshot view that contains the latest state.
     ///
     /// Internally, the state machine creates a new empty writable level and makes all current states immutable.
@@ -359,11 +492,24 @@ impl SMV002 {
             new_applied
         );
 
+        // The replaced on-disk level is removed when no snapshot refers to it any more.
+        if let Some(old_base) = self.levels.frozen_ref().base() {
+            let new_base = level.frozen_ref().base();
+            if !new_base.map(|b| Arc::ptr_eq(b, old_base)).unwrap_or(false) {
+                old_base.mark_obsolete();
+            }
+        }
+
         self.levels = level;
 
         // The installed data may not cleaned up all expired keys, if it is built with an older state machine.
         // So we need to reset the cursor then the next time applying a log it will cleanup all expired.
         self.expire_cursor = ExpireKey::new(0, 0);
+
+        // The changes made by the logs in the installed data are not sent to the subscriber.
+        if let Some(subscriber) = &self.subscriber {
+            subscriber.history_reset(self.last_applied_index());
+        }
     }
 
     /// Keep the top(writable) level, replace all the frozen levels.
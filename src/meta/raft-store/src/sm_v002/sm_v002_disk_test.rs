@@ -0,0 +1,250 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Run the same cases against the in-memory state machine and the one with an on-disk base level.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use common_meta_sled_store::sled;
+use common_meta_types::KVMeta;
+use common_meta_types::SeqV;
+use common_meta_types::SnapshotData;
+use common_meta_types::UpsertKV;
+use futures_util::TryStreamExt;
+use openraft::testing::log_id;
+use pretty_assertions::assert_eq;
+use tokio::sync::RwLock;
+
+use crate::config::RaftConfig;
+use crate::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
+use crate::sm_v002::DiskStore;
+use crate::sm_v002::SnapshotViewV002;
+use crate::sm_v002::SMV002;
+use crate::state_machine::ExpireKey;
+
+#[tokio::test]
+async fn test_disk_upsert_get_list_across_compaction() -> anyhow::Result<()> {
+    let db = temp_db()?;
+
+    for mut sm in [SMV002::default(), open_disk(&db)?] {
+        let mut a = sm.new_applier();
+        a.upsert_kv(&UpsertKV::update("a", b"a0")).await?;
+        a.upsert_kv(&UpsertKV::update("b", b"b0")).await?;
+        a.upsert_kv(&UpsertKV::update("c", b"c0")).await?;
+
+        compact(&mut sm).await?;
+
+        let mut a = sm.new_applier();
+        a.upsert_kv(&UpsertKV::delete("b")).await?;
+        a.upsert_kv(&UpsertKV::update("c", b"c1")).await?;
+
+        assert_eq!(
+            sm.get_maybe_expired_kv("a").await?,
+            Some(SeqV::new(1, b("a0")))
+        );
+        assert_eq!(sm.get_maybe_expired_kv("b").await?, None);
+
+        let got = sm.list_kv("").await?.try_collect::<Vec<_>>().await?;
+        assert_eq!(got, vec![
+            (s("a"), SeqV::new(1, b("a0"))),
+            (s("c"), SeqV::new(4, b("c1"))),
+        ]);
+
+        compact(&mut sm).await?;
+
+        assert_eq!(sm.get_maybe_expired_kv("b").await?, None);
+
+        let got = sm.list_kv("").await?.try_collect::<Vec<_>>().await?;
+        assert_eq!(got, vec![
+            (s("a"), SeqV::new(1, b("a0"))),
+            (s("c"), SeqV::new(4, b("c1"))),
+        ]);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_disk_expire_index() -> anyhow::Result<()> {
+    let db = temp_db()?;
+
+    for mut sm in [SMV002::default(), open_disk(&db)?] {
+        let mut a = sm.new_applier();
+        a.upsert_kv(&UpsertKV::update("a", b"a0").with_expire_sec(10))
+            .await?;
+        a.upsert_kv(&UpsertKV::update("b", b"b0").with_expire_sec(5))
+            .await?;
+
+        compact(&mut sm).await?;
+
+        let mut a = sm.new_applier();
+        a.upsert_kv(&UpsertKV::update("a", b"a1").with_expire_sec(15))
+            .await?;
+
+        compact(&mut sm).await?;
+
+        let got = sm
+            .list_expire_index()
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(got, vec![
+            (ExpireKey::new(5_000, 2), s("b")),
+            (ExpireKey::new(15_000, 3), s("a")),
+        ]);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_disk_export_same_as_mem() -> anyhow::Result<()> {
+    let db = temp_db()?;
+
+    let mut exported = vec![];
+
+    for mut sm in [SMV002::default(), open_disk(&db)?] {
+        let mut a = sm.new_applier();
+        a.upsert_kv(&UpsertKV::update("a", b"a0").with_expire_sec(10))
+            .await?;
+        a.upsert_kv(&UpsertKV::update("b", b"b0")).await?;
+
+        compact(&mut sm).await?;
+
+        let mut a = sm.new_applier();
+        a.upsert_kv(&UpsertKV::delete("a")).await?;
+        a.upsert_kv(&UpsertKV::update("c", b"c0")).await?;
+        *sm.sys_data_mut().last_applied_mut() = Some(log_id(1, 1, 5));
+
+        let view = compact(&mut sm).await?;
+        exported.push(export(&view).await?);
+    }
+
+    assert_eq!(exported[0], exported[1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_disk_install_snapshot_and_reopen() -> anyhow::Result<()> {
+    let db = temp_db()?;
+
+    // Build a snapshot with an in-memory state machine.
+    let mut sm = SMV002::default();
+    let mut a = sm.new_applier();
+    a.upsert_kv(&UpsertKV::update("a", b"a0")).await?;
+    a.upsert_kv(&UpsertKV::update("b", b"b0").with_expire_sec(5))
+        .await?;
+    *sm.sys_data_mut().last_applied_mut() = Some(log_id(1, 1, 2));
+
+    let view = compact(&mut sm).await?;
+    let lines = export(&view).await?;
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("snapshot");
+    {
+        let mut f = std::fs::File::create(&path)?;
+        for l in lines.iter() {
+            writeln!(f, "{}", l)?;
+        }
+    }
+
+    let data = SnapshotData::open(path.to_string_lossy().to_string())?;
+
+    let sm = Arc::new(RwLock::new(open_disk(&db)?));
+    SMV002::install_snapshot(sm.clone(), Box::new(data)).await?;
+
+    {
+        let mut sm = sm.write().await;
+        assert!(sm.is_on_disk());
+        assert_eq!(
+            sm.get_maybe_expired_kv("b").await?,
+            Some(SeqV::with_meta(2, Some(KVMeta::new_expire(5)), b("b0")))
+        );
+
+        let mut a = sm.new_applier();
+        a.upsert_kv(&UpsertKV::update("c", b"c0")).await?;
+        *sm.sys_data_mut().last_applied_mut() = Some(log_id(1, 1, 3));
+
+        compact(&mut sm).await?;
+    }
+    drop(sm);
+
+    // Reopen: the data is loaded from disk, without a snapshot.
+    let sm = open_disk(&db)?;
+    assert_eq!(sm.sys_data_ref().last_applied_ref(), &Some(log_id(1, 1, 3)));
+    assert_eq!(sm.sys_data_ref().curr_seq(), 3);
+
+    let got = sm.list_kv("").await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(got, vec![
+        (s("a"), SeqV::new(1, b("a0"))),
+        (
+            s("b"),
+            SeqV::with_meta(2, Some(KVMeta::new_expire(5)), b("b0"))
+        ),
+        (s("c"), SeqV::new(3, b("c0"))),
+    ]);
+
+    // Only the installed level is kept on disk.
+    let names = db
+        .tree_names()
+        .into_iter()
+        .map(|x| String::from_utf8_lossy(&x).to_string())
+        .filter(|x| x.starts_with("test-state_machine_v002/"))
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec![s("test-state_machine_v002/1")]);
+
+    Ok(())
+}
+
+fn temp_db() -> anyhow::Result<sled::Db> {
+    let db = sled::Config::new().temporary(true).open()?;
+    Ok(db)
+}
+
+fn open_disk(db: &sled::Db) -> anyhow::Result<SMV002> {
+    let config = RaftConfig {
+        sled_tree_prefix: s("test-"),
+        ..Default::default()
+    };
+    let sm = SMV002::open_disk(DiskStore::new(db.clone(), &config))?;
+    Ok(sm)
+}
+
+/// Build a snapshot view, compact it and replace the frozen levels, like building a snapshot does.
+async fn compact(sm: &mut SMV002) -> anyhow::Result<SnapshotViewV002> {
+    let mut view = sm.full_snapshot_view();
+    view.compact_mem_levels().await?;
+    sm.replace_frozen(&view);
+    Ok(view)
+}
+
+async fn export(view: &SnapshotViewV002) -> anyhow::Result<Vec<String>> {
+    let lines = view
+        .export()
+        .await?
+        .map_ok(|x| serde_json::to_string(&x).unwrap())
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(lines)
+}
+
+fn s(x: impl ToString) -> String {
+    x.to_string()
+}
+
+fn b(x: impl ToString) -> Vec<u8> {
+    x.to_string().as_bytes().to_vec()
+}
@@ -86,7 +86,25 @@ impl SnapshotViewV002 {
     }
 
     /// Compact into one level and remove all tombstone record.
+    ///
+    /// If there is an on-disk base level, all in-memory levels are flushed to it,
+    /// and only an empty level with the system data is left in memory.
     pub async fn compact_mem_levels(&mut self) -> Result<(), io::Error> {
+        if let Some(base) = self.compacted.base().cloned() {
+            let mut mem = self.compacted.clone();
+            mem.set_base(None);
+
+            base.flush(&mem).await?;
+
+            let data = mem.newest().unwrap().new_level();
+
+            let mut compacted = StaticLevels::new([Arc::new(data)]);
+            compacted.set_base(Some(base));
+
+            self.compacted = compacted;
+            return Ok(());
+        }
+
         if self.compacted.len() <= 1 {
             return Ok(());
         }
@@ -165,6 +183,15 @@ impl SnapshotViewV002 {
             })
         }
 
+        // Leases
+
+        for (lease_id, lease) in d.leases_ref().iter() {
+            sm_meta.push(RaftStoreEntry::Leases {
+                key: *lease_id,
+                value: lease.clone(),
+            })
+        }
+
         // kv
 
         let strm = self.compacted.str_map().range(..).await?;
@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod defines a key space in state machine to store leases: `lease_id -> LeaseValue`.
+//!
+//! Leases are not user data, they are not visible to `get_kv()` or `list_kv()`.
+
+use std::collections::BTreeMap;
+
+use common_meta_sled_store::SledBytesError;
+use common_meta_sled_store::SledSerde;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A lease stored in the state machine.
+///
+/// The expiration time is not stored, it is tracked by the leader in memory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LeaseValue {
+    /// Time to live in seconds.
+    pub ttl_sec: u64,
+
+    /// The keys attached to the lease and the `seq` when they are attached.
+    ///
+    /// A key is detached if it is written again, i.e., its current `seq` is different.
+    pub attached: BTreeMap<String, u64>,
+}
+
+impl LeaseValue {
+    pub fn new(ttl_sec: u64) -> Self {
+        Self {
+            ttl_sec,
+            attached: BTreeMap::new(),
+        }
+    }
+}
+
+impl SledSerde for LeaseValue {
+    fn de<T: AsRef<[u8]>>(v: T) -> Result<Self, SledBytesError>
+    where Self: Sized {
+        let s = serde_json::from_slice(v.as_ref())?;
+        Ok(s)
+    }
+}
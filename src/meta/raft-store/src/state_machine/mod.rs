@@ -15,6 +15,7 @@
 pub use client_last_resp::ClientLastRespValue;
 pub use expire::ExpireKey;
 pub use expire::ExpireValue;
+pub use lease::LeaseValue;
 pub use log_meta::LogMetaKey;
 pub use log_meta::LogMetaValue;
 pub use sm::SerializableSnapshot;
@@ -28,6 +29,7 @@ pub use state_machine_meta::StateMachineMetaValue;
 
 pub mod client_last_resp;
 mod expire;
+mod lease;
 pub mod log_meta;
 pub mod sm;
 mod sm_kv_api_impl;
@@ -27,6 +27,7 @@ use common_meta_sled_store::SledTree;
 use common_meta_sled_store::Store;
 use common_meta_sled_store::TransactionSledTree;
 use common_meta_stoerr::MetaStorageError;
+use common_meta_types::anyerror::AnyError;
 use common_meta_types::protobuf as pb;
 use common_meta_types::txn_condition;
 use common_meta_types::txn_op;
@@ -57,6 +58,8 @@ use common_meta_types::TxnOp;
 use common_meta_types::TxnOpResponse;
 use common_meta_types::TxnPutRequest;
 use common_meta_types::TxnPutResponse;
+use common_meta_types::TxnRangeGetRequest;
+use common_meta_types::TxnRangeGetResponse;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKV;
@@ -93,7 +96,12 @@ const TREE_STATE_MACHINE: &str = "state_machine";
 
 /// StateMachine subscriber trait
 pub trait StateMachineSubscriber: Debug + Sync + Send {
-    fn kv_changed(&self, change: Change<Vec<u8>, String>);
+    /// A kv record is changed by the raft log at index `revision`.
+    fn kv_changed(&self, revision: u64, change: Change<Vec<u8>, String>);
+
+    /// The state machine is rebuilt up to the raft log at index `revision`,
+    /// the changes before it will never be sent.
+    fn history_reset(&self, revision: u64);
 }
 
 /// The state machine of the `MemStore`.
@@ -313,7 +321,7 @@ impl StateMachine {
         // Send queued change events to subscriber
         if let Some(subscriber) = &self.subscriber {
             for event in changes {
-                subscriber.kv_changed(event);
+                subscriber.kv_changed(log_id.index, event);
             }
         }
 
@@ -388,6 +396,14 @@ impl StateMachine {
         Ok((prev, None).into())
     }
 
+    /// Leases are only supported by the `SMV002` state machine: revoking a lease requires
+    /// listing the attached keys, which is not possible in a sled-txn tree.
+    fn lease_unsupported() -> MetaStorageError {
+        MetaStorageError::SledError(AnyError::error(
+            "lease is not supported by sled based state machine",
+        ))
+    }
+
     #[minitrace::trace]
     fn apply_update_kv_cmd(
         &self,
@@ -397,6 +413,10 @@ impl StateMachine {
     ) -> Result<AppliedState, MetaStorageError> {
         debug!(upsert_kv = as_debug!(upsert_kv); "apply_update_kv_cmd");
 
+        if upsert_kv.lease.is_some() {
+            return Err(Self::lease_unsupported());
+        }
+
         let (expired, prev, result) = Self::txn_upsert_kv(txn_tree, upsert_kv, log_time_ms)?;
 
         debug!("applied UpsertKV: {:?} {:?}", upsert_kv, result);
@@ -479,6 +499,34 @@ impl StateMachine {
                         return Ok(false);
                     }
                 }
+                txn_condition::Target::KeysWithPrefix(target_count) => {
+                    // A sled transaction can not iterate,
+                    // scan the committed keys and check each of them in the transaction,
+                    // because expired keys have been removed in this transaction.
+                    let mut count = 0;
+                    for (k, _) in self.kvs().scan_prefix(&key)? {
+                        if sub_tree.get(&k)?.is_some() {
+                            count += 1;
+                        }
+                    }
+                    return Ok(match FromPrimitive::from_i32(cond.expected) {
+                        Some(ConditionResult::Eq) => count == *target_count,
+                        Some(ConditionResult::Gt) => count > *target_count,
+                        Some(ConditionResult::Lt) => count < *target_count,
+                        Some(ConditionResult::Ne) => count != *target_count,
+                        Some(ConditionResult::Ge) => count >= *target_count,
+                        Some(ConditionResult::Le) => count <= *target_count,
+                        _ => false,
+                    });
+                }
+                txn_condition::Target::Exists(target_exists) => {
+                    let exists = sv.is_some();
+                    return Ok(match FromPrimitive::from_i32(cond.expected) {
+                        Some(ConditionResult::Eq) => exists == *target_exists,
+                        Some(ConditionResult::Ne) => exists != *target_exists,
+                        _ => false,
+                    });
+                }
             }
         };
 
@@ -523,6 +571,53 @@ impl StateMachine {
         Ok(())
     }
 
+    /// Get the records in a range.
+    ///
+    /// A sled transaction can not iterate,
+    /// thus the keys inserted by the preceding operations in this transaction are not returned.
+    fn txn_execute_range_get_operation(
+        &self,
+        txn_tree: &TransactionSledTree,
+        range_get: &TxnRangeGetRequest,
+        resp: &mut TxnReply,
+    ) -> Result<(), MetaStorageError> {
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+
+        let mut items = vec![];
+        let mut more = false;
+
+        for item in self.kvs().range(range_get.start.clone()..)? {
+            let key = item?.key()?;
+            if !range_get.end.is_empty() && key >= range_get.end {
+                break;
+            }
+
+            let sv = match sub_tree.get(&key)? {
+                Some(sv) => sv,
+                None => continue,
+            };
+
+            if range_get.limit > 0 && items.len() as u64 >= range_get.limit {
+                more = true;
+                break;
+            }
+
+            items.push(TxnGetResponse {
+                key,
+                value: Some(pb::SeqV::from(sv)),
+            });
+        }
+
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::RangeGet(TxnRangeGetResponse {
+                items,
+                more,
+            })),
+        });
+
+        Ok(())
+    }
+
     fn txn_execute_put_operation(
         &self,
         txn_tree: &mut TransactionSledTree,
@@ -530,6 +625,10 @@ impl StateMachine {
         resp: &mut TxnReply,
         log_time_ms: u64,
     ) -> Result<(), MetaStorageError> {
+        if put.lease_id.is_some() {
+            return Err(Self::lease_unsupported());
+        }
+
         let (expired, prev, result) = Self::txn_upsert_kv(
             txn_tree,
             &UpsertKV::update(&put.key, &put.value).with(KVMeta {
@@ -666,6 +765,9 @@ impl StateMachine {
                     log_time_ms,
                 )?;
             }
+            Some(txn_op::Request::RangeGet(range_get)) => {
+                self.txn_execute_range_get_operation(txn_tree, range_get, resp)?;
+            }
             None => {}
         }
 
@@ -748,6 +850,14 @@ impl StateMachine {
             }
 
             Cmd::Transaction(txn) => self.apply_txn_cmd(txn, txn_tree, kv_pairs, log_time_ms),
+
+            Cmd::Lease(lease) => {
+                error!(
+                    "lease is not supported by sled based state machine: {}",
+                    lease
+                );
+                Err(Self::lease_unsupported())
+            }
         };
 
         let elapsed = now.elapsed().as_micros();
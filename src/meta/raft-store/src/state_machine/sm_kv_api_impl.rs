@@ -15,9 +15,13 @@
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::KVStream;
+use common_meta_kvapi::kvapi::LeaseReply;
+use common_meta_kvapi::kvapi::LeaseReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_stoerr::MetaStorageError;
+use common_meta_types::anyerror::AnyError;
 use common_meta_types::protobuf::StreamItem;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
@@ -41,12 +45,11 @@ impl kvapi::KVApi for StateMachine {
             seq: act.seq,
             value: act.value,
             value_meta: act.value_meta,
+            lease: act.lease,
         });
 
         let res = self.sm_tree.txn(true, |mut txn_sled_tree| {
-            let r = self
-                .apply_cmd(&cmd, &mut txn_sled_tree, None, SeqV::<()>::now_ms())
-                .unwrap();
+            let r = self.apply_cmd(&cmd, &mut txn_sled_tree, None, SeqV::<()>::now_ms())?;
             Ok(r)
         })?;
 
@@ -74,6 +77,14 @@ impl kvapi::KVApi for StateMachine {
         }
     }
 
+    async fn lease(&self, req: LeaseReq) -> Result<LeaseReply, Self::Error> {
+        let err = MetaStorageError::SledError(AnyError::error(format!(
+            "lease is not supported by sled based state machine: {}",
+            req
+        )));
+        Err(err.into())
+    }
+
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
         let sv = self.kvs().get(&key.to_string())?;
         debug!("get_kv sv:{:?}", sv);
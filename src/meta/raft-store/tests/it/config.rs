@@ -53,5 +53,21 @@ fn test_raft_config() -> anyhow::Result<()> {
         )
     }
 
+    {
+        let raft_config = &RaftConfig {
+            single: true,
+            as_learner: true,
+            ..Default::default()
+        };
+        let r = raft_config.check();
+
+        assert_eq!(
+            r,
+            Err(MetaStartupError::InvalidConfig(String::from(
+                "`as_learner` requires `join` to be set",
+            )))
+        )
+    }
+
     Ok(())
 }
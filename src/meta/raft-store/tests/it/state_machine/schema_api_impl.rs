@@ -16,6 +16,7 @@ use std::sync::Arc;
 use std::sync::Mutex;
 
 use async_trait::async_trait;
+use common_meta_api::AuditApiTestSuite;
 use common_meta_api::BackgroundApiTestSuite;
 use common_meta_api::SchemaApiTestSuite;
 use common_meta_api::ShareApiTestSuite;
@@ -59,7 +60,8 @@ async fn test_meta_embedded_single() -> anyhow::Result<()> {
 
     SchemaApiTestSuite::test_single_node(builder.clone()).await?;
     ShareApiTestSuite::test_single_node_share(builder.clone()).await?;
-    BackgroundApiTestSuite::test_single_node(builder).await?;
+    BackgroundApiTestSuite::test_single_node(builder.clone()).await?;
+    AuditApiTestSuite::test_single_node(builder).await?;
 
     Ok(())
 }
@@ -28,9 +28,12 @@ use common_meta_types::protobuf::meta_service_server::MetaService;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::Empty;
+use common_meta_types::protobuf::ExportLogsRequest;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
 use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseKeepAliveResponse;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
@@ -38,6 +41,7 @@ use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::StreamItem;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::ReadConsistency;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_metrics::count::Count;
@@ -61,7 +65,6 @@ use tonic::Status;
 use tonic::Streaming;
 
 use crate::grpc_helper::GrpcHelper;
-use crate::message::ForwardRequest;
 use crate::meta_service::MetaNode;
 use crate::metrics::network_metrics;
 use crate::metrics::RequestInFlight;
@@ -71,6 +74,12 @@ use crate::version::METASRV_SEMVER;
 use crate::version::MIN_METACLI_SEMVER;
 use crate::watcher::WatchStream;
 
+/// Number of exported lines in one `ExportedChunk`.
+const EXPORT_CHUNK_SIZE: usize = 32;
+
+/// Max number of logs to export in one `ExportLogs` call.
+const MAX_EXPORT_LOGS: u64 = 10_000;
+
 pub struct MetaServiceImpl {
     token: GrpcToken,
     pub(crate) meta_node: Arc<MetaNode>,
@@ -122,6 +131,10 @@ impl MetaServiceImpl {
                 let res = m.prefix_list_kv(&a.prefix).await;
                 RaftReply::from(res)
             }
+            MetaGrpcReq::Lease(a) => {
+                let res = m.lease(a.clone()).await;
+                RaftReply::from(res)
+            }
         };
         let elapsed = t0.elapsed();
         info!("Handled(elapsed: {:?}) MetaGrpcReq: {:?}", elapsed, req);
@@ -136,20 +149,21 @@ impl MetaServiceImpl {
         &self,
         request: Request<RaftRequest>,
     ) -> Result<BoxStream<StreamItem>, Status> {
+        let consistency = Self::read_consistency(request.metadata())?;
         let req: MetaGrpcReadReq = GrpcHelper::parse_req(request)?;
 
-        info!("{}: Received ReadRequest: {:?}", func_name!(), req);
-
-        let req = ForwardRequest {
-            forward_to_leader: 1,
-            body: req,
-        };
+        info!(
+            "{}: Received ReadRequest: {:?}, consistency: {}",
+            func_name!(),
+            req,
+            consistency
+        );
 
         let t0 = Instant::now();
 
         let res = self
             .meta_node
-            .handle_forwardable_request::<MetaGrpcReadReq>(req.clone())
+            .kv_read(req.clone(), consistency)
             .await
             .map_err(GrpcHelper::internal_err);
 
@@ -160,6 +174,22 @@ impl MetaServiceImpl {
         res
     }
 
+    /// Get the read consistency a client asks for, default to linearizable.
+    fn read_consistency(metadata: &MetadataMap) -> Result<ReadConsistency, Status> {
+        let v = match metadata.get(ReadConsistency::METADATA_KEY) {
+            None => return Ok(ReadConsistency::default()),
+            Some(v) => v,
+        };
+
+        let s = v.to_str().map_err(|e| {
+            Status::invalid_argument(format!("invalid {}: {}", ReadConsistency::METADATA_KEY, e))
+        })?;
+
+        ReadConsistency::parse(s).ok_or_else(|| {
+            Status::invalid_argument(format!("unknown {}: {}", ReadConsistency::METADATA_KEY, s))
+        })
+    }
+
     #[minitrace::trace]
     async fn handle_txn(&self, request: Request<TxnRequest>) -> Result<TxnReply, Status> {
         let request = request.into_inner();
@@ -315,7 +345,7 @@ impl MetaService for MetaServiceImpl {
         let meta_node = &self.meta_node;
         let strm = meta_node.sto.inner().export();
 
-        let chunk_size = 32;
+        let chunk_size = EXPORT_CHUNK_SIZE;
         // - Chunk up upto 32 Ok items inside a Vec<String>;
         // - Convert Vec<String> to ExportedChunk;
         // - Convert TryChunkError<_, io::Error> to Status;
@@ -327,6 +357,53 @@ impl MetaService for MetaServiceImpl {
         Ok(Response::new(Box::pin(s)))
     }
 
+    type ExportLogsStream =
+        Pin<Box<dyn Stream<Item = Result<ExportedChunk, Status>> + Send + 'static>>;
+
+    /// Export applied raft logs since a log index, for incremental backup.
+    ///
+    /// At most [`MAX_EXPORT_LOGS`] logs are exported in one call.
+    async fn export_logs(
+        &self,
+        request: Request<ExportLogsRequest>,
+    ) -> Result<Response<Self::ExportLogsStream>, Status> {
+        let _guard = RequestInFlight::guard();
+
+        let start = request.into_inner().start_index;
+
+        let meta_node = &self.meta_node;
+
+        // Only applied logs are committed and will not be truncated.
+        let last_applied = meta_node.raft.metrics().borrow().last_applied;
+        let end = match last_applied {
+            None => return Ok(Response::new(Box::pin(futures::stream::empty()))),
+            Some(log_id) => std::cmp::min(log_id.index, start.saturating_add(MAX_EXPORT_LOGS - 1)),
+        };
+
+        let lines = meta_node
+            .sto
+            .inner()
+            .export_logs(start, end)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let lines = lines.map_err(|purged| {
+            Status::out_of_range(format!(
+                "logs since {} are not available, last purged: {}",
+                start, purged
+            ))
+        })?;
+
+        info!("export logs [{}, {}]: {} logs", start, end, lines.len());
+
+        let chunks = lines
+            .chunks(EXPORT_CHUNK_SIZE)
+            .map(|c| Ok(ExportedChunk { data: c.to_vec() }))
+            .collect::<Vec<_>>();
+
+        Ok(Response::new(Box::pin(futures::stream::iter(chunks))))
+    }
+
     type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchResponse, Status>> + Send + 'static>>;
 
     #[minitrace::trace]
@@ -341,17 +418,53 @@ impl MetaService for MetaServiceImpl {
         let add_res = mn.add_watcher(request.into_inner(), tx).await;
 
         match add_res {
-            Ok(watcher) => {
+            Ok((watcher, init_responses)) => {
                 let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
-                Ok(Response::new(Box::pin(stream) as Self::WatchStream))
-            }
-            Err(e) => {
-                // TODO: test error return.
-                Err(Status::invalid_argument(e))
+                let init = futures::stream::iter(init_responses.into_iter().map(Ok));
+                Ok(Response::new(
+                    Box::pin(init.chain(stream)) as Self::WatchStream
+                ))
             }
+            Err(status) => Err(status),
         }
     }
 
+    type LeaseKeepAliveStream =
+        Pin<Box<dyn Stream<Item = Result<LeaseKeepAliveResponse, Status>> + Send + 'static>>;
+
+    /// Keep leases alive.
+    ///
+    /// Every request in the input stream refreshes the lease and is replied with the refreshed
+    /// lease, or with a zero `ttl_sec` if the lease has already expired or been revoked.
+    async fn lease_keep_alive(
+        &self,
+        request: Request<Streaming<LeaseKeepAliveRequest>>,
+    ) -> Result<Response<Self::LeaseKeepAliveStream>, Status> {
+        self.check_token(request.metadata())?;
+
+        let meta_node = self.meta_node.clone();
+        let strm = request.into_inner().and_then(move |req| {
+            let meta_node = meta_node.clone();
+            async move {
+                let _guard = RequestInFlight::guard();
+
+                let lease_id = req.lease_id;
+                let res = meta_node
+                    .keep_alive_lease(lease_id)
+                    .await
+                    .map_err(|e| Status::internal(e.to_string()))?;
+
+                let resp = match res {
+                    Some(lease) => LeaseKeepAliveResponse::from(lease),
+                    None => LeaseKeepAliveResponse::expired(lease_id),
+                };
+                Ok(resp)
+            }
+        });
+
+        Ok(Response::new(Box::pin(strm)))
+    }
+
     async fn member_list(
         &self,
         request: Request<MemberListRequest>,
@@ -289,8 +289,10 @@ pub struct ConfigViaEnv {
     pub kvsrv_wait_leader_timeout: u64,
     pub raft_max_applied_log_to_keep: u64,
     pub raft_snapshot_chunk_size: u64,
+    pub raft_sm_storage: String,
     pub kvsrv_single: bool,
     pub metasrv_join: Vec<String>,
+    pub metasrv_as_learner: bool,
     pub kvsrv_id: u64,
     pub sled_tree_prefix: String,
     pub cluster_name: String,
@@ -334,8 +336,10 @@ impl From<Config> for ConfigViaEnv {
             kvsrv_wait_leader_timeout: cfg.raft_config.wait_leader_timeout,
             raft_max_applied_log_to_keep: cfg.raft_config.max_applied_log_to_keep,
             raft_snapshot_chunk_size: cfg.raft_config.snapshot_chunk_size,
+            raft_sm_storage: cfg.raft_config.sm_storage,
             kvsrv_single: cfg.raft_config.single,
             metasrv_join: cfg.raft_config.join,
+            metasrv_as_learner: cfg.raft_config.as_learner,
             kvsrv_id: cfg.raft_config.id,
             sled_tree_prefix: cfg.raft_config.sled_tree_prefix,
             cluster_name: cfg.raft_config.cluster_name,
@@ -360,8 +364,10 @@ impl Into<Config> for ConfigViaEnv {
             wait_leader_timeout: self.kvsrv_wait_leader_timeout,
             max_applied_log_to_keep: self.raft_max_applied_log_to_keep,
             snapshot_chunk_size: self.raft_snapshot_chunk_size,
+            sm_storage: self.raft_sm_storage,
             single: self.kvsrv_single,
             join: self.metasrv_join,
+            as_learner: self.metasrv_as_learner,
             // Do not allow to leave via environment variable
             leave_via: vec![],
             // Do not allow to leave via environment variable
@@ -466,6 +472,13 @@ pub struct RaftConfig {
     #[clap(long, default_value = "4194304")]
     pub snapshot_chunk_size: u64,
 
+    /// Where to store the state machine data: `mem` or `disk`.
+    ///
+    /// With `mem`, all of the data is kept in memory and is rebuilt from the last snapshot when starting up.
+    /// With `disk`, the compacted data is kept on disk and only the recent changes are kept in memory.
+    #[clap(long, default_value = "mem")]
+    pub sm_storage: String,
+
     /// Start databend-meta in single node mode.
     /// It initialize a single node cluster, if meta data is not initialized.
     /// If on-disk data is already initialized, this argument has no effect.
@@ -481,6 +494,14 @@ pub struct RaftConfig {
     #[clap(long)]
     pub join: Vec<String>,
 
+    /// Join the cluster as a learner instead of a voter.
+    ///
+    /// A learner receives logs from the leader asynchronously and does not vote,
+    /// thus it can be deployed in a remote region without slowing down writes.
+    /// It can serve stale reads and can be promoted to a standalone cluster with `databend-metactl --cmd promote`.
+    #[clap(long)]
+    pub as_learner: bool,
+
     /// Do not run databend-meta, but just remove a node from its cluster via the provided endpoints.
     ///
     /// This node will be removed by `id`.
@@ -531,8 +552,10 @@ impl From<RaftConfig> for InnerRaftConfig {
             install_snapshot_timeout: x.install_snapshot_timeout,
             max_applied_log_to_keep: x.max_applied_log_to_keep,
             snapshot_chunk_size: x.snapshot_chunk_size,
+            sm_storage: x.sm_storage,
             single: x.single,
             join: x.join,
+            as_learner: x.as_learner,
             leave_via: x.leave_via,
             leave_id: x.leave_id,
             id: x.id,
@@ -557,8 +580,10 @@ impl From<InnerRaftConfig> for RaftConfig {
             install_snapshot_timeout: inner.install_snapshot_timeout,
             max_applied_log_to_keep: inner.max_applied_log_to_keep,
             snapshot_chunk_size: inner.snapshot_chunk_size,
+            sm_storage: inner.sm_storage,
             single: inner.single,
             join: inner.join,
+            as_learner: inner.as_learner,
             leave_via: inner.leave_via,
             leave_id: inner.leave_id,
             id: inner.id,
@@ -16,6 +16,7 @@ use anyerror::AnyError;
 use common_meta_client::MetaGrpcReadReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::LeaseReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -37,6 +38,12 @@ pub struct JoinRequest {
     pub grpc_api_addr: String,
 
     pub grpc_api_advertise_address: Option<String>,
+
+    /// Join as a learner that replicates logs but does not vote.
+    ///
+    /// Absent in requests sent by an older version, which always join as a voter.
+    #[serde(default)]
+    pub as_learner: bool,
 }
 
 impl JoinRequest {
@@ -52,6 +59,11 @@ impl JoinRequest {
             ..Default::default()
         }
     }
+
+    pub fn with_as_learner(mut self, as_learner: bool) -> Self {
+        self.as_learner = as_learner;
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -80,6 +92,14 @@ pub enum ForwardRequestBody {
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+
+    /// Ask the leader for the index a linearizable read has to wait for.
+    #[from(ignore)]
+    GetReadIndex,
+
+    /// Refresh the expiration time of a lease, which is tracked by the leader in memory.
+    #[from(ignore)]
+    KeepAliveLease(u64),
 }
 
 /// A request that is forwarded from one raft node to another
@@ -139,6 +159,18 @@ pub enum ForwardResponse {
     GetKV(GetKVReply),
     MGetKV(MGetKVReply),
     ListKV(ListKVReply),
+
+    ReadIndex(ReadIndex),
+
+    Lease(LeaseReply),
+}
+
+/// The log index a linearizable read has to wait for the local state machine to apply.
+///
+/// It is the committed index confirmed by a leader that is still granted by a quorum.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadIndex {
+    pub index: u64,
 }
 
 impl tonic::IntoRequest<RaftRequest> for ForwardRequest<ForwardRequestBody> {
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_meta_raft_store::state_machine::LeaseValue;
+
+/// Tracks when the leases expire.
+///
+/// The state machine only stores the leases and the keys attached to them.
+/// The expiration time is tracked in memory by the leader, so that keeping a lease alive
+/// does not need to write a raft log. When a lease expires, the leader proposes a revoke.
+///
+/// A new leader does not know when the leases expire on the previous leader,
+/// it gives every lease a full `ttl` since it becomes the leader.
+#[derive(Debug, Default)]
+pub struct LeaseKeeper {
+    deadlines: Mutex<BTreeMap<u64, Instant>>,
+}
+
+impl LeaseKeeper {
+    /// Refresh the expiration time of a lease to `ttl_sec` seconds later.
+    pub fn keep_alive(&self, lease_id: u64, ttl_sec: u64) {
+        let deadline = Instant::now() + Duration::from_secs(ttl_sec);
+        self.deadlines.lock().unwrap().insert(lease_id, deadline);
+    }
+
+    /// Forget all the deadlines, e.g., when this node is no longer the leader.
+    pub fn clear(&self) {
+        self.deadlines.lock().unwrap().clear();
+    }
+
+    /// Sync the deadlines with the leases in the state machine and return the expired lease ids.
+    ///
+    /// A lease that is not tracked yet, e.g., just granted, expires `ttl` later.
+    /// A lease that is removed from the state machine is no longer tracked.
+    pub fn expired(&self, leases: &BTreeMap<u64, LeaseValue>, now: Instant) -> Vec<u64> {
+        let mut deadlines = self.deadlines.lock().unwrap();
+
+        deadlines.retain(|lease_id, _| leases.contains_key(lease_id));
+
+        let mut expired = vec![];
+        for (lease_id, lease) in leases.iter() {
+            let deadline = deadlines
+                .entry(*lease_id)
+                .or_insert_with(|| now + Duration::from_secs(lease.ttl_sec));
+
+            if *deadline <= now {
+                expired.push(*lease_id);
+            }
+        }
+        expired
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio::sync::RwLockReadGuard;
+use common_meta_client::MetaGrpcReadReq;
+use common_meta_kvapi::kvapi::GetKVReply;
+use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::ListKVReply;
+use common_meta_kvapi::kvapi::ListKVReq;
+use common_meta_kvapi::kvapi::MGetKVReply;
+use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_raft_store::sm_v002::SMV002;
+use common_meta_types::protobuf::StreamItem;
+use futures::StreamExt;
+use tonic::codegen::BoxStream;
+
+use crate::message::ForwardRequestBody;
+use crate::message::ForwardResponse;
+use crate::store::RaftStore;
+
+/// Serves reads with the local state machine.
+///
+/// It does not check whether the local state machine is up to date.
+/// For a linearizable read, the caller has to wait for the read index first,
+/// see [`MetaNode::wait_read_index()`](crate::meta_service::MetaNode::wait_read_index).
+pub struct LocalReader<'a> {
+    sto: &'a RaftStore,
+}
+
+impl<'a> LocalReader<'a> {
+    pub fn new(sto: &'a RaftStore) -> Self {
+        Self { sto }
+    }
+
+    pub async fn get_kv(&self, req: &GetKVReq) -> GetKVReply {
+        let sm = self.get_state_machine().await;
+        // safe unwrap(): Infallible
+        sm.kv_api().get_kv(&req.key).await.unwrap()
+    }
+
+    pub async fn mget_kv(&self, req: &MGetKVReq) -> MGetKVReply {
+        let sm = self.get_state_machine().await;
+        // safe unwrap(): Infallible
+        sm.kv_api().mget_kv(&req.keys).await.unwrap()
+    }
+
+    pub async fn list_kv(&self, req: &ListKVReq) -> ListKVReply {
+        let sm = self.get_state_machine().await;
+        // safe unwrap(): Infallible
+        sm.kv_api().prefix_list_kv(&req.prefix).await.unwrap()
+    }
+
+    /// Serve a read request body, returns `None` if it is not a read.
+    pub async fn read(&self, body: &ForwardRequestBody) -> Option<ForwardResponse> {
+        let resp = match body {
+            ForwardRequestBody::GetKV(req) => ForwardResponse::GetKV(self.get_kv(req).await),
+            ForwardRequestBody::MGetKV(req) => ForwardResponse::MGetKV(self.mget_kv(req).await),
+            ForwardRequestBody::ListKV(req) => ForwardResponse::ListKV(self.list_kv(req).await),
+            _ => return None,
+        };
+        Some(resp)
+    }
+
+    /// Serve a read request and return the result in a stream.
+    pub async fn read_stream(&self, req: MetaGrpcReadReq) -> BoxStream<StreamItem> {
+        match req {
+            MetaGrpcReadReq::GetKV(req) => {
+                let got = self.get_kv(&req).await;
+
+                let item = StreamItem::from((req.key.clone(), got));
+                futures::stream::iter([Ok(item)]).boxed()
+            }
+
+            MetaGrpcReadReq::MGetKV(req) => {
+                let values = self.mget_kv(&req).await;
+
+                let kv_iter = req
+                    .keys
+                    .into_iter()
+                    .zip(values)
+                    .map(|(k, v)| Ok(StreamItem::from((k, v))));
+
+                futures::stream::iter(kv_iter).boxed()
+            }
+
+            MetaGrpcReadReq::ListKV(req) => {
+                let kvs = self.list_kv(&req).await;
+
+                let kv_iter = kvs.into_iter().map(|kv| Ok(StreamItem::from(kv)));
+
+                futures::stream::iter(kv_iter).boxed()
+            }
+        }
+    }
+
+    async fn get_state_machine(&self) -> RwLockReadGuard<'_, SMV002> {
+        self.sto.state_machine.read().await
+    }
+}
@@ -16,7 +16,7 @@ use std::collections::BTreeSet;
 
 use common_base::base::tokio::sync::RwLockReadGuard;
 use common_meta_client::MetaGrpcReadReq;
-use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::LeaseReply;
 use common_meta_raft_store::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
 use common_meta_raft_store::sm_v002::SMV002;
 use common_meta_sled_store::openraft::ChangeMembers;
@@ -25,6 +25,7 @@ use common_meta_types::protobuf::StreamItem;
 use common_meta_types::AppliedState;
 use common_meta_types::ClientWriteError;
 use common_meta_types::Cmd;
+use common_meta_types::LeaseInfo;
 use common_meta_types::LogEntry;
 use common_meta_types::MembershipNode;
 use common_meta_types::MetaDataError;
@@ -35,7 +36,6 @@ use common_meta_types::NodeId;
 use common_meta_types::RaftError;
 use common_meta_types::SeqV;
 use common_metrics::count::Count;
-use futures::StreamExt;
 use log::as_debug;
 use log::debug;
 use log::info;
@@ -48,6 +48,9 @@ use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::ReadIndex;
+use crate::meta_service::lease_keeper::LeaseKeeper;
+use crate::meta_service::local_reader::LocalReader;
 use crate::meta_service::meta_node::MetaRaft;
 use crate::meta_service::MetaNode;
 use crate::metrics::server_metrics;
@@ -62,6 +65,7 @@ use crate::store::RaftStore;
 pub struct MetaLeader<'a> {
     sto: &'a RaftStore,
     raft: &'a MetaRaft,
+    lease_keeper: &'a LeaseKeeper,
 }
 
 #[async_trait::async_trait]
@@ -90,20 +94,25 @@ impl<'a> Handler<ForwardRequestBody> for MetaLeader<'a> {
             }
 
             ForwardRequestBody::GetKV(req) => {
-                let sm = self.get_state_machine().await;
-                let res = sm.kv_api().get_kv(&req.key).await.unwrap();
+                let res = LocalReader::new(self.sto).get_kv(&req).await;
                 Ok(ForwardResponse::GetKV(res))
             }
             ForwardRequestBody::MGetKV(req) => {
-                let sm = self.get_state_machine().await;
-                let res = sm.kv_api().mget_kv(&req.keys).await.unwrap();
+                let res = LocalReader::new(self.sto).mget_kv(&req).await;
                 Ok(ForwardResponse::MGetKV(res))
             }
             ForwardRequestBody::ListKV(req) => {
-                let sm = self.get_state_machine().await;
-                let res = sm.kv_api().prefix_list_kv(&req.prefix).await.unwrap();
+                let res = LocalReader::new(self.sto).list_kv(&req).await;
                 Ok(ForwardResponse::ListKV(res))
             }
+            ForwardRequestBody::GetReadIndex => {
+                let res = self.read_index().await?;
+                Ok(ForwardResponse::ReadIndex(res))
+            }
+            ForwardRequestBody::KeepAliveLease(lease_id) => {
+                let res = self.keep_alive_lease(lease_id).await;
+                Ok(ForwardResponse::Lease(res))
+            }
         }
     }
 }
@@ -117,47 +126,8 @@ impl<'a> Handler<MetaGrpcReadReq> for MetaLeader<'a> {
     ) -> Result<BoxStream<StreamItem>, MetaOperationError> {
         debug!(req = as_debug!(&req); "handle(MetaGrpcReadReq)");
 
-        let sm = self.get_state_machine().await;
-        let kv_api = sm.kv_api();
-
-        match req.body {
-            MetaGrpcReadReq::GetKV(req) => {
-                // safe unwrap(): Infallible
-                let got = kv_api.get_kv(&req.key).await.unwrap();
-
-                let item = StreamItem::from((req.key.clone(), got));
-                let strm = futures::stream::iter([Ok(item)]);
-
-                Ok(strm.boxed())
-            }
-
-            MetaGrpcReadReq::MGetKV(req) => {
-                // safe unwrap(): Infallible
-                let values = kv_api.mget_kv(&req.keys).await.unwrap();
-
-                let kv_iter = req
-                    .keys
-                    .clone()
-                    .into_iter()
-                    .zip(values)
-                    .map(|(k, v)| Ok(StreamItem::from((k, v))));
-
-                let strm = futures::stream::iter(kv_iter);
-
-                Ok(strm.boxed())
-            }
-
-            MetaGrpcReadReq::ListKV(req) => {
-                // safe unwrap(): Infallible
-                let kvs = kv_api.prefix_list_kv(&req.prefix).await.unwrap();
-
-                let kv_iter = kvs.into_iter().map(|kv| Ok(StreamItem::from(kv)));
-
-                let strm = futures::stream::iter(kv_iter);
-
-                Ok(strm.boxed())
-            }
-        }
+        let strm = LocalReader::new(self.sto).read_stream(req.body).await;
+        Ok(strm)
     }
 }
 
@@ -166,13 +136,15 @@ impl<'a> MetaLeader<'a> {
         MetaLeader {
             sto: &meta_node.sto,
             raft: &meta_node.raft,
+            lease_keeper: &meta_node.lease_keeper,
         }
     }
 
     /// Join a new node to the cluster.
     ///
     /// - Adds the node to cluster as a non-voter persistently and starts replication.
-    /// - Adds the node to membership to let it become a voter.
+    /// - Adds the node to membership to let it become a voter,
+    ///   unless it asks to join as a learner, in which case it stays a non-voter.
     ///
     /// If the node is already in cluster membership, it still returns Ok.
     #[minitrace::trace]
@@ -188,6 +160,10 @@ impl<'a> MetaLeader<'a> {
             return Ok(());
         }
 
+        if req.as_learner && membership.learner_ids().any(|id| id == node_id) {
+            return Ok(());
+        }
+
         let ent = LogEntry {
             txid: None,
             time_ms: None,
@@ -200,6 +176,15 @@ impl<'a> MetaLeader<'a> {
         };
         self.write(ent).await?;
 
+        if req.as_learner {
+            // Do not block until the learner catches up:
+            // a remote learner may take a long time to receive a snapshot.
+            self.raft
+                .add_learner(node_id, MembershipNode {}, false)
+                .await?;
+            return Ok(());
+        }
+
         self.raft
             .change_membership(
                 ChangeMembers::AddVoters(btreemap! {node_id=>MembershipNode{}}),
@@ -279,6 +264,36 @@ impl<'a> MetaLeader<'a> {
         }
     }
 
+    /// Confirm it is still the leader granted by a quorum, and return the index of the log
+    /// that a linearizable read has to wait for the state machine to apply.
+    #[minitrace::trace]
+    pub async fn read_index(&self) -> Result<ReadIndex, MetaOperationError> {
+        let read_log_id = self.raft.ensure_linearizable().await?;
+        let index = read_log_id.map(|log_id| log_id.index).unwrap_or_default();
+
+        debug!("read index: {}", index);
+        Ok(ReadIndex { index })
+    }
+
+    /// Refresh the expiration time of a lease, without writing a raft log.
+    ///
+    /// It returns `None` if the lease has expired or been revoked.
+    pub async fn keep_alive_lease(&self, lease_id: u64) -> LeaseReply {
+        let ttl_sec = {
+            let sm = self.get_state_machine().await;
+            sm.sys_data_ref().leases_ref().get(&lease_id)?.ttl_sec
+        };
+
+        self.lease_keeper.keep_alive(lease_id, ttl_sec);
+
+        debug!("keep alive lease: {}, ttl: {}s", lease_id, ttl_sec);
+        Some(LeaseInfo {
+            lease_id,
+            ttl_sec,
+            expire_at: SeqV::<()>::now_ms() / 1000 + ttl_sec,
+        })
+    }
+
     /// Check if a node is allowed to leave the cluster.
     ///
     /// A cluster must have at least one node in it.
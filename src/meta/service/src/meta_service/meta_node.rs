@@ -22,6 +22,7 @@ use std::time::Duration;
 
 use anyerror::AnyError;
 use common_base::base::tokio;
+use common_base::base::tokio::select;
 use common_base::base::tokio::sync::watch;
 use common_base::base::tokio::sync::watch::error::RecvError;
 use common_base::base::tokio::sync::Mutex;
@@ -31,6 +32,7 @@ use common_base::base::tokio::time::Instant;
 use common_grpc::ConnectionFactory;
 use common_grpc::DNSResolver;
 use common_meta_client::reply_to_api_result;
+use common_meta_client::MetaGrpcReadReq;
 use common_meta_client::RequestFor;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_raft_store::ondisk::DataVersion;
@@ -42,7 +44,9 @@ use common_meta_sled_store::openraft::ChangeMembers;
 use common_meta_stoerr::MetaStorageError;
 use common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use common_meta_types::protobuf::raft_service_server::RaftServiceServer;
+use common_meta_types::protobuf::StreamItem;
 use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::CommittedLeaderId;
@@ -51,10 +55,13 @@ use common_meta_types::ForwardRPCError;
 use common_meta_types::ForwardToLeader;
 use common_meta_types::GrpcConfig;
 use common_meta_types::InvalidReply;
+use common_meta_types::LeaseCmd;
 use common_meta_types::LogEntry;
 use common_meta_types::LogId;
 use common_meta_types::MembershipNode;
 use common_meta_types::MetaAPIError;
+use common_meta_types::MetaDataError;
+use common_meta_types::MetaDataReadError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaManagementError;
 use common_meta_types::MetaNetworkError;
@@ -63,6 +70,7 @@ use common_meta_types::MetaStartupError;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
 use common_meta_types::RaftMetrics;
+use common_meta_types::ReadConsistency;
 use common_meta_types::TypeConfig;
 use futures::channel::oneshot;
 use itertools::Itertools;
@@ -79,6 +87,8 @@ use openraft::Config;
 use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
+use tonic::codegen::BoxStream;
+use tonic::Status;
 
 use crate::configs::Config as MetaConfig;
 use crate::message::ForwardRequest;
@@ -86,10 +96,14 @@ use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::ReadIndex;
 use crate::meta_service::errors::grpc_error_to_network_err;
 use crate::meta_service::forwarder::MetaForwarder;
+use crate::meta_service::lease_keeper::LeaseKeeper;
+use crate::meta_service::local_reader::LocalReader;
 use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::RaftServiceImpl;
+use crate::metrics::raft_metrics;
 use crate::metrics::server_metrics;
 use crate::network::Network;
 use crate::request_handling::Forwarder;
@@ -166,6 +180,12 @@ pub type SMStore = Adaptor<TypeConfig, RaftStore>;
 /// MetaRaft is a implementation of the generic Raft handling meta data R/W.
 pub type MetaRaft = Raft<TypeConfig>;
 
+/// Max time for the local state machine to catch up with the read index of a linearizable read.
+const READ_INDEX_TIMEOUT: Duration = Duration::from_millis(3_000);
+
+/// Interval for the leader to check if any lease expires.
+const LEASE_CHECK_INTERVAL: Duration = Duration::from_millis(1_000);
+
 /// MetaNode is the container of meta data related components and threads, such as storage, the raft node and a raft-state monitor.
 pub struct MetaNode {
     pub sto: RaftStore,
@@ -175,6 +195,7 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+    pub lease_keeper: LeaseKeeper,
 }
 
 impl Opened for MetaNode {
@@ -232,6 +253,7 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            lease_keeper: LeaseKeeper::default(),
         });
 
         if self.monitor_metrics {
@@ -239,6 +261,8 @@ impl MetaNodeBuilder {
             MetaNode::subscribe_metrics(mn.clone(), metrics_rx).await;
         }
 
+        MetaNode::spawn_lease_revoker(mn.clone()).await;
+
         let endpoint = if let Some(a) = self.endpoint.take() {
             a
         } else {
@@ -495,6 +519,7 @@ impl MetaNode {
 
         let fut = async move {
             let mut last_leader: Option<u64> = None;
+            let mut last_replication_targets = BTreeSet::new();
 
             loop {
                 let changed = metrics_rx.changed().await;
@@ -510,7 +535,9 @@ impl MetaNode {
                 // Report metrics about server state and role.
 
                 server_metrics::set_node_is_health(
-                    mm.state == ServerState::Follower || mm.state == ServerState::Leader,
+                    mm.state == ServerState::Follower
+                        || mm.state == ServerState::Leader
+                        || mm.state == ServerState::Learner,
                 );
 
                 if mm.current_leader.is_some() && mm.current_leader != last_leader {
@@ -526,6 +553,26 @@ impl MetaNode {
                 server_metrics::set_proposals_applied(mm.last_applied.unwrap_or_default().index);
                 server_metrics::set_last_seq(meta_node.get_last_seq().await);
 
+                // Only the leader has replication metrics: the lag of every follower and learner.
+                // The lag of a node that is removed, or of every node when it is no longer the leader, is cleared.
+
+                let mut replication_targets = BTreeSet::new();
+                if let Some(replication) = &mm.replication {
+                    let last_log_index = mm.last_log_index.unwrap_or_default();
+                    for (target, matched) in replication.iter() {
+                        let matched_index = matched.map(|log_id| log_id.index).unwrap_or_default();
+                        raft_metrics::network::set_replication_lag(
+                            target,
+                            last_log_index.saturating_sub(matched_index),
+                        );
+                        replication_targets.insert(*target);
+                    }
+                }
+                for target in last_replication_targets.difference(&replication_targets) {
+                    raft_metrics::network::remove_replication_lag(target);
+                }
+                last_replication_targets = replication_targets;
+
                 last_leader = mm.current_leader;
             }
 
@@ -539,6 +586,60 @@ impl MetaNode {
         }
     }
 
+    /// Spawn a task that revokes the expired leases, if this node is the leader.
+    pub async fn spawn_lease_revoker(mn: Arc<Self>) {
+        let meta_node = mn.clone();
+        let mut running_rx = mn.running_rx.clone();
+
+        let fut = async move {
+            loop {
+                select! {
+                    _ = running_rx.changed() => {
+                        // Shutting down.
+                        break;
+                    }
+                    _ = sleep(LEASE_CHECK_INTERVAL) => {}
+                }
+
+                meta_node.revoke_expired_leases().await;
+            }
+
+            Ok::<(), AnyError>(())
+        };
+        let h = tokio::task::spawn(fut.in_span(Span::enter_with_local_parent("revoke-leases")));
+
+        {
+            let mut jh = mn.join_handles.lock().await;
+            jh.push(h);
+        }
+    }
+
+    /// Propose a revoke for every expired lease.
+    ///
+    /// Only the leader tracks when the leases expire, a follower forgets all of them.
+    async fn revoke_expired_leases(&self) {
+        let is_leader = self.raft.metrics().borrow().current_leader == Some(self.sto.id);
+        if !is_leader {
+            self.lease_keeper.clear();
+            return;
+        }
+
+        let expired = {
+            let sm = self.sto.state_machine.read().await;
+            self.lease_keeper
+                .expired(sm.sys_data_ref().leases_ref(), std::time::Instant::now())
+        };
+
+        for lease_id in expired {
+            info!("revoke expired lease: {}", lease_id);
+
+            let ent = LogEntry::new(Cmd::Lease(LeaseCmd::Revoke { lease_id }));
+            if let Err(e) = MetaLeader::new(self).write(ent).await {
+                warn!("failed to revoke expired lease: {}: {}", lease_id, e);
+            }
+        }
+    }
+
     /// Start MetaNode in either `boot`, `single`, `join` or `open` mode,
     /// according to config.
     #[minitrace::trace]
@@ -641,7 +742,7 @@ impl MetaNode {
         // Try to join a cluster only when this node has no log.
         // Joining a node with log has risk messing up the data in this node and in the target cluster.
         let in_cluster = self
-            .is_in_cluster()
+            .is_in_cluster(conf.as_learner)
             .await
             .map_err(|e| MetaManagementError::Join(AnyError::new(&e)))?;
 
@@ -728,11 +829,14 @@ impl MetaNode {
 
         let req = ForwardRequest {
             forward_to_leader: 1,
-            body: ForwardRequestBody::Join(JoinRequest::new(
-                conf.id,
-                advertise_endpoint.clone(),
-                grpc_api_advertise_address.clone(),
-            )),
+            body: ForwardRequestBody::Join(
+                JoinRequest::new(
+                    conf.id,
+                    advertise_endpoint.clone(),
+                    grpc_api_advertise_address.clone(),
+                )
+                .with_as_learner(conf.as_learner),
+            ),
         };
 
         let join_res = raft_client.forward(req.clone()).await;
@@ -785,7 +889,12 @@ impl MetaNode {
     ///   Then the next leader does not know about this new node.
     ///
     ///   Only when the membership is committed, this node can be sure it is in a cluster.
-    async fn is_in_cluster(&self) -> Result<Result<String, String>, MetaStorageError> {
+    ///
+    /// A node started with `--as-learner` is in a cluster once it is a learner in the committed membership.
+    async fn is_in_cluster(
+        &self,
+        as_learner: bool,
+    ) -> Result<Result<String, String>, MetaStorageError> {
         let membership = {
             let sm = self.sto.get_state_machine().await;
             sm.sys_data_ref().last_membership_ref().membership().clone()
@@ -798,6 +907,13 @@ impl MetaNode {
             return Ok(Ok(format!("node {} already in cluster", self.sto.id)));
         }
 
+        if as_learner && membership.learner_ids().any(|id| id == self.sto.id) {
+            return Ok(Ok(format!(
+                "node {} already in cluster as learner",
+                self.sto.id
+            )));
+        }
+
         Ok(Err(format!(
             "node {} has membership but not in it",
             self.sto.id
@@ -984,12 +1100,18 @@ impl MetaNode {
         ForwardResponse: TryInto<Reply>,
         <ForwardResponse as TryInto<Reply>>::Error: std::fmt::Display,
     {
-        let res = self
-            .handle_forwardable_request(ForwardRequest {
-                forward_to_leader: 1,
-                body: req.into(),
-            })
-            .await;
+        let body: ForwardRequestBody = req.into();
+
+        let res = match self.wait_read_index().await {
+            Ok(()) => match LocalReader::new(&self.sto).read(&body).await {
+                Some(resp) => Ok(resp),
+                None => self.forward_read(body).await,
+            },
+            Err(e) => {
+                warn!("fail to wait for read index: {}, forward read to leader", e);
+                self.forward_read(body).await
+            }
+        };
 
         match res {
             Err(e) => {
@@ -1011,6 +1133,86 @@ impl MetaNode {
         }
     }
 
+    async fn forward_read(
+        &self,
+        body: ForwardRequestBody,
+    ) -> Result<ForwardResponse, MetaAPIError> {
+        self.handle_forwardable_request(ForwardRequest {
+            forward_to_leader: 1,
+            body,
+        })
+        .await
+    }
+
+    /// Serve a read with the local state machine, with the specified consistency.
+    ///
+    /// A [`ReadConsistency::Linearizable`] read waits for the local state machine to catch up
+    /// with the read index, thus it can be served by a follower.
+    /// A [`ReadConsistency::Stale`] read is served immediately and may not see the latest writes.
+    #[minitrace::trace]
+    pub async fn kv_read(
+        &self,
+        req: MetaGrpcReadReq,
+        consistency: ReadConsistency,
+    ) -> Result<BoxStream<StreamItem>, MetaAPIError> {
+        match consistency {
+            ReadConsistency::Linearizable => {
+                if let Err(e) = self.wait_read_index().await {
+                    // E.g., the leader is of an older version that does not support read index.
+                    warn!("fail to wait for read index: {}, forward read to leader", e);
+
+                    return self
+                        .handle_forwardable_request(ForwardRequest {
+                            forward_to_leader: 1,
+                            body: req,
+                        })
+                        .await;
+                }
+            }
+            ReadConsistency::Stale => {}
+        }
+
+        let strm = LocalReader::new(&self.sto).read_stream(req).await;
+        Ok(strm)
+    }
+
+    /// Wait until the local state machine applies the logs up to the read index.
+    ///
+    /// The read index is the last committed log index, confirmed by the leader with a quorum.
+    /// After this, a read on the local state machine is linearizable.
+    #[minitrace::trace]
+    pub async fn wait_read_index(&self) -> Result<(), MetaAPIError> {
+        let res = self
+            .handle_forwardable_request(ForwardRequest {
+                forward_to_leader: 1,
+                body: ForwardRequestBody::GetReadIndex,
+            })
+            .await?;
+
+        let read_index: ReadIndex = res.try_into().map_err(|e| {
+            let invalid_reply =
+                InvalidReply::new("expect reply type to be ReadIndex", &AnyError::error(e));
+            MetaNetworkError::from(invalid_reply)
+        })?;
+
+        debug!("wait for read index: {}", read_index.index);
+
+        self.raft
+            .wait(Some(READ_INDEX_TIMEOUT))
+            .applied_index_at_least(Some(read_index.index), "read index")
+            .await
+            .map_err(|e| {
+                let read_err = MetaDataReadError::new(
+                    "wait_read_index",
+                    format!("read index: {}", read_index.index),
+                    &e,
+                );
+                MetaAPIError::DataError(MetaDataError::ReadError(read_err))
+            })?;
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     pub async fn handle_forwardable_request<Req>(
         &self,
@@ -1188,11 +1390,12 @@ impl MetaNode {
         }
     }
 
+    /// Add a watcher, returns the watcher and the responses to send before the new changes.
     pub(crate) async fn add_watcher(
         &self,
         request: WatchRequest,
         tx: WatcherSender,
-    ) -> Result<Watcher, &'static str> {
+    ) -> Result<(Watcher, Vec<WatchResponse>), Status> {
         let (resp_tx, resp_rx) = oneshot::channel();
 
         self.dispatcher_handle.request(|d: &mut EventDispatcher| {
@@ -1203,7 +1406,7 @@ impl MetaNode {
         let recv_res = resp_rx.await;
         match recv_res {
             Ok(add_res) => add_res,
-            Err(_e) => Err("dispatcher closed"),
+            Err(_e) => Err(Status::unavailable("dispatcher closed")),
         }
     }
 }
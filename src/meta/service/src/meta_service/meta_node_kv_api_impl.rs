@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyerror::AnyError;
 use async_trait::async_trait;
 use common_meta_client::MetaGrpcReadReq;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
 use common_meta_kvapi::kvapi::KVStream;
+use common_meta_kvapi::kvapi::LeaseReply;
+use common_meta_kvapi::kvapi::LeaseReq;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
@@ -25,9 +28,14 @@ use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
+use common_meta_types::InvalidReply;
+use common_meta_types::LeaseCmd;
+use common_meta_types::LeaseInfo;
 use common_meta_types::LogEntry;
 use common_meta_types::MetaAPIError;
 use common_meta_types::MetaNetworkError;
+use common_meta_types::ReadConsistency;
+use common_meta_types::SeqV;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKV;
@@ -36,6 +44,7 @@ use futures::TryStreamExt;
 use log::info;
 
 use crate::message::ForwardRequest;
+use crate::message::ForwardRequestBody;
 use crate::meta_service::MetaNode;
 
 /// Impl kvapi::KVApi for MetaNode.
@@ -53,6 +62,7 @@ impl kvapi::KVApi for MetaNode {
             seq: act.seq,
             value: act.value,
             value_meta: act.value_meta,
+            lease: act.lease,
         }));
         let rst = self.write(ent).await?;
 
@@ -93,10 +103,7 @@ impl kvapi::KVApi for MetaNode {
         };
 
         let strm = self
-            .handle_forwardable_request(ForwardRequest {
-                forward_to_leader: 1,
-                body: MetaGrpcReadReq::ListKV(req),
-            })
+            .kv_read(MetaGrpcReadReq::ListKV(req), ReadConsistency::Linearizable)
             .await?;
 
         let strm =
@@ -117,4 +124,43 @@ impl kvapi::KVApi for MetaNode {
             }
         }
     }
+
+    #[minitrace::trace]
+    async fn lease(&self, req: LeaseReq) -> Result<LeaseReply, Self::Error> {
+        info!("MetaNode::lease(): {}", req);
+
+        // Keeping a lease alive is served by the leader in memory, without writing a raft log.
+        let cmd = match req {
+            LeaseReq::Grant { ttl_sec } => LeaseCmd::Grant { ttl_sec },
+            LeaseReq::Revoke { lease_id } => LeaseCmd::Revoke { lease_id },
+            LeaseReq::KeepAlive { lease_id } => {
+                let res = self
+                    .handle_forwardable_request(ForwardRequest {
+                        forward_to_leader: 1,
+                        body: ForwardRequestBody::KeepAliveLease(lease_id),
+                    })
+                    .await?;
+
+                let reply: LeaseReply = res.try_into().map_err(|e| {
+                    let invalid_reply =
+                        InvalidReply::new("expect reply type to be Lease", &AnyError::error(e));
+                    MetaNetworkError::from(invalid_reply)
+                })?;
+                return Ok(reply);
+            }
+        };
+
+        let rst = self.write(LogEntry::new(Cmd::Lease(cmd))).await?;
+
+        match rst {
+            AppliedState::Lease(x) => Ok(x.map(|lease| LeaseInfo {
+                // The lease expires `ttl` later since the leader sees it.
+                expire_at: SeqV::<()>::now_ms() / 1000 + lease.ttl_sec,
+                ..lease
+            })),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::Lease")
+            }
+        }
+    }
 }
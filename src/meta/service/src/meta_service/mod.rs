@@ -23,6 +23,8 @@ pub use crate::message::LeaveRequest;
 
 mod errors;
 mod forwarder;
+pub mod lease_keeper;
+pub mod local_reader;
 pub mod meta_leader;
 pub mod meta_node;
 mod meta_node_kv_api_impl;
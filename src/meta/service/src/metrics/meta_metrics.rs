@@ -243,6 +243,7 @@ pub mod raft_metrics {
             snapshot_recv_seconds: Family<FromLabels, Histogram>,
             snapshot_recv_success: Family<FromLabels, Counter>,
             snapshot_recv_failures: Family<FromLabels, Counter>,
+            replication_lag: Family<ToLabels, Gauge>,
         }
 
         impl RaftMetrics {
@@ -265,6 +266,7 @@ pub mod raft_metrics {
                     }), // 1s ~ 1024s
                     snapshot_recv_success: Family::default(),
                     snapshot_recv_failures: Family::default(),
+                    replication_lag: Family::default(),
                 };
 
                 let mut registry = crate::metrics::registry::load_global_registry();
@@ -325,6 +327,11 @@ pub mod raft_metrics {
                     "snapshot recv failures",
                     metrics.snapshot_recv_failures.clone(),
                 );
+                registry.register(
+                    key!("replication_lag"),
+                    "number of logs a follower or learner lags behind the leader",
+                    metrics.replication_lag.clone(),
+                );
                 metrics
             }
         }
@@ -434,6 +441,19 @@ pub mod raft_metrics {
             .get_or_create(&FromLabels { from: addr })
             .inc();
         }
+
+        pub fn set_replication_lag(id: &NodeId, lag: u64) {
+            let to = id.to_string();
+            RAFT_METRICS
+                .replication_lag
+                .get_or_create(&ToLabels { to })
+                .set(lag as i64);
+        }
+
+        pub fn remove_replication_lag(id: &NodeId) {
+            let to = id.to_string();
+            RAFT_METRICS.replication_lag.remove(&ToLabels { to });
+        }
     }
 
     pub mod storage {
@@ -30,6 +30,7 @@ use common_meta_raft_store::log::RaftLog;
 use common_meta_raft_store::ondisk::DATA_VERSION;
 use common_meta_raft_store::ondisk::TREE_HEADER;
 use common_meta_raft_store::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
+use common_meta_raft_store::sm_v002::DiskStore;
 use common_meta_raft_store::sm_v002::SnapshotStoreError;
 use common_meta_raft_store::sm_v002::SnapshotStoreV002;
 use common_meta_raft_store::sm_v002::SnapshotViewV002;
@@ -151,19 +152,55 @@ impl StoreInner {
             .await
             .map_err(to_startup_err)?;
 
+        // A state machine on disk does not need to be rebuilt from the last snapshot,
+        // if it is already up to date with it.
+        let sm = if config.is_sm_on_disk() {
+            let disk = DiskStore::new(db.clone(), config);
+            let sm = SMV002::open_disk(disk).map_err(to_startup_err)?;
+            Some(sm)
+        } else {
+            None
+        };
+
         let (sm, stored_snapshot) = if let Some((id, snapshot)) = last {
-            let (sm, meta) = Self::rebuild_state_machine(&id, snapshot)
-                .await
-                .map_err(to_startup_err)?;
-
-            info!(
-                "rebuilt state machine from last snapshot({:?}), meta: {:?}",
-                id, meta
-            );
+            let up_to_date = sm
+                .as_ref()
+                .map(|sm| sm.sys_data_ref().last_applied_ref() == &id.last_applied)
+                .unwrap_or(false);
+
+            let (sm, meta) = if up_to_date {
+                let sm = sm.unwrap();
+                let meta = SnapshotMeta {
+                    snapshot_id: id.to_string(),
+                    last_log_id: *sm.sys_data_ref().last_applied_ref(),
+                    last_membership: sm.sys_data_ref().last_membership_ref().clone(),
+                };
+
+                info!(
+                    "opened state machine on disk, up to date with last snapshot({:?})",
+                    id
+                );
+                (Arc::new(RwLock::new(sm)), meta)
+            } else {
+                // The data on disk is not consistent with the snapshot, e.g., writing the snapshot failed.
+                // Discard it and install the snapshot.
+                let sm = sm.map(|sm| sm.into_empty()).unwrap_or_default();
+
+                let (sm, meta) = Self::rebuild_state_machine(&id, snapshot, sm)
+                    .await
+                    .map_err(to_startup_err)?;
+
+                info!(
+                    "rebuilt state machine from last snapshot({:?}), meta: {:?}",
+                    id, meta
+                );
+                (sm, meta)
+            };
+
             (sm, Some(StoredSnapshot { meta }))
         } else {
             info!("No snapshot, skip rebuilding state machine");
-            (Default::default(), None)
+            (Arc::new(RwLock::new(sm.unwrap_or_default())), None)
         };
 
         Ok(Self {
@@ -183,13 +220,15 @@ impl StoreInner {
         SnapshotStoreV002::new(DATA_VERSION, self.config.clone())
     }
 
+    /// Rebuild the state machine `sm` from the last snapshot.
     async fn rebuild_state_machine(
         id: &MetaSnapshotId,
         snapshot: SnapshotData,
+        sm: SMV002,
     ) -> Result<(Arc<RwLock<SMV002>>, SnapshotMeta), io::Error> {
         info!("rebuild state machine from last snapshot({:?})", id);
 
-        let sm = Arc::new(RwLock::new(SMV002::default()));
+        let sm = Arc::new(RwLock::new(sm));
 
         SMV002::install_snapshot(sm.clone(), Box::new(snapshot)).await?;
 
@@ -511,6 +550,45 @@ impl StoreInner {
         }
     }
 
+    /// Export logs in range `[start, end]` in the same format as [`Self::export()`], for incremental backup.
+    ///
+    /// It returns `Ok(Err(last_purged))` if some of the logs in the range have been purged.
+    pub async fn export_logs(
+        &self,
+        start: u64,
+        end: u64,
+    ) -> Result<Result<Vec<String>, LogId>, io::Error> {
+        fn invalid_data(e: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+            io::Error::new(ErrorKind::InvalidData, e)
+        }
+
+        let log = self.log.read().await;
+
+        let last_purged = log.get_last_purged().map_err(invalid_data)?;
+        if let Some(purged) = last_purged {
+            if purged.index >= start {
+                return Ok(Err(purged));
+            }
+        }
+
+        let tree_name = &log.inner.name;
+
+        let entries = log.range_values(start..=end).map_err(invalid_data)?;
+
+        let mut lines = Vec::with_capacity(entries.len());
+        for ent in entries {
+            let kv_entry = RaftStoreEntry::Logs {
+                key: ent.log_id.index,
+                value: ent,
+            };
+
+            let line = serde_json::to_string(&(tree_name, kv_entry)).map_err(invalid_data)?;
+            lines.push(line);
+        }
+
+        Ok(Ok(lines))
+    }
+
     pub async fn get_node(&self, node_id: &NodeId) -> Option<Node> {
         let sm = self.state_machine.read().await;
         let n = sm.sys_data_ref().nodes_ref().get(node_id).cloned();
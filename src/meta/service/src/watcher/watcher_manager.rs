@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use core::ops::Range;
+use std::collections::VecDeque;
 
 use common_base::base::tokio;
 use common_base::base::tokio::sync::mpsc;
@@ -26,6 +27,7 @@ use common_meta_types::protobuf::Event;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::Change;
+use common_meta_types::WatchCompacted;
 use log::info;
 use log::warn;
 use prost::Message;
@@ -38,6 +40,9 @@ use crate::watcher::Watcher;
 
 pub type WatcherId = i64;
 
+/// The max number of kv change events retained by [`EventDispatcher`] for a watch to resume from.
+pub const WATCH_HISTORY_SIZE: usize = 10_000;
+
 /// A sender for dispatcher to send event to interested watchers.
 pub type WatcherSender = mpsc::Sender<Result<WatchResponse, Status>>;
 
@@ -47,8 +52,14 @@ pub(crate) struct DispatcherSender(pub(crate) mpsc::UnboundedSender<WatchEvent>)
 
 /// An event sent to EventDispatcher.
 pub(crate) enum WatchEvent {
-    /// Submit a kv change event to dispatcher
-    KVChange(Change<Vec<u8>, String>),
+    /// Submit a kv change event made by the raft log at index `revision` to dispatcher
+    KVChange {
+        revision: u64,
+        change: Change<Vec<u8>, String>,
+    },
+
+    /// The state machine is rebuilt up to `revision`, the retained history is no longer complete.
+    HistoryReset { revision: u64 },
 
     /// Send a request to EventDispatcher.
     ///
@@ -97,6 +108,15 @@ pub struct EventDispatcher {
     watcher_range_map: RangeMap<String, WatcherId, WatchStreamHandle>,
 
     current_watcher_id: WatcherId,
+
+    /// The recent kv changes and their revisions, in the order they are applied.
+    history: VecDeque<(u64, Change<Vec<u8>, String>)>,
+
+    /// The changes with a revision less than or equal to it may be absent in `history`.
+    compacted_revision: u64,
+
+    /// The greatest revision this dispatcher has seen.
+    last_revision: u64,
 }
 
 impl EventDispatcher {
@@ -108,6 +128,9 @@ impl EventDispatcher {
             event_rx,
             watcher_range_map: RangeMap::new(),
             current_watcher_id: 1,
+            history: VecDeque::new(),
+            compacted_revision: 0,
+            last_revision: 0,
         };
 
         let _h = tokio::spawn(dispatcher.main());
@@ -120,8 +143,12 @@ impl EventDispatcher {
         loop {
             if let Some(event) = self.event_rx.recv().await {
                 match event {
-                    WatchEvent::KVChange(kv_change) => {
-                        self.dispatch_event(kv_change).await;
+                    WatchEvent::KVChange { revision, change } => {
+                        self.dispatch_event(revision, change.clone()).await;
+                        self.append_history(revision, change);
+                    }
+                    WatchEvent::HistoryReset { revision } => {
+                        self.reset_history(revision);
                     }
                     WatchEvent::Request { req } => req(&mut self),
                 }
@@ -132,8 +159,28 @@ impl EventDispatcher {
         }
     }
 
+    /// Retain a change for watchers to resume from, and discard the oldest if there are too many.
+    fn append_history(&mut self, revision: u64, change: Change<Vec<u8>, String>) {
+        self.last_revision = std::cmp::max(self.last_revision, revision);
+        self.history.push_back((revision, change));
+
+        while self.history.len() > WATCH_HISTORY_SIZE {
+            if let Some((rev, _)) = self.history.pop_front() {
+                self.compacted_revision = std::cmp::max(self.compacted_revision, rev);
+            }
+        }
+    }
+
+    fn reset_history(&mut self, revision: u64) {
+        info!("reset watch history at revision: {}", revision);
+
+        self.history.clear();
+        self.compacted_revision = std::cmp::max(self.compacted_revision, revision);
+        self.last_revision = std::cmp::max(self.last_revision, revision);
+    }
+
     /// Dispatch a kv change event to interested watchers.
-    async fn dispatch_event(&mut self, change: Change<Vec<u8>, String>) {
+    async fn dispatch_event(&mut self, revision: u64, change: Change<Vec<u8>, String>) {
         let k = change.ident.as_ref().unwrap();
         let set = self.watcher_range_map.get_by_point(k);
         if set.is_empty() {
@@ -165,6 +212,7 @@ impl EventDispatcher {
                     current: current.clone().map(pb::SeqV::from),
                     prev: prev.clone().map(pb::SeqV::from),
                 }),
+                revision,
             };
 
             network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
@@ -187,22 +235,39 @@ impl EventDispatcher {
         }
     }
 
+    /// Add a watcher and returns the responses it should receive before the new changes.
+    ///
+    /// If the watch starts from a revision, these are the retained changes since then.
+    /// Otherwise it is a single response without event, that tells the revision the watch starts after.
     #[minitrace::trace]
     pub fn add_watcher(
         &mut self,
         create: WatchRequest,
         tx: WatcherSender,
-    ) -> Result<Watcher, &'static str> {
+    ) -> Result<(Watcher, Vec<WatchResponse>), Status> {
         info!("add_watcher: {:?}", create);
 
         let range = match EventDispatcher::build_key_range(create.key.clone(), &create.key_end) {
             Ok(range) => range,
-            Err(e) => return Err(e),
+            Err(e) => return Err(Status::invalid_argument(e)),
+        };
+        let filter: FilterType = create.filter_type();
+
+        let init_responses = match create.start_revision {
+            Some(start) => {
+                if start <= self.compacted_revision {
+                    return Err(WatchCompacted::new(start, self.compacted_revision).to_status());
+                }
+                self.history_since(start, &range, filter)
+            }
+            None => vec![WatchResponse {
+                event: None,
+                revision: self.last_revision,
+            }],
         };
 
         self.current_watcher_id += 1;
         let watcher_id = self.current_watcher_id;
-        let filter: FilterType = create.filter_type();
 
         let watcher = Watcher::new(watcher_id, filter, range.clone());
         let stream_handle = WatchStreamHandle::new(watcher.clone(), tx);
@@ -212,7 +277,51 @@ impl EventDispatcher {
 
         server_metrics::incr_watchers(1);
 
-        Ok(watcher)
+        Ok((watcher, init_responses))
+    }
+
+    /// Build responses of the retained changes since `start` revision that match the key range and filter.
+    fn history_since(
+        &self,
+        start: u64,
+        range: &Range<String>,
+        filter: FilterType,
+    ) -> Vec<WatchResponse> {
+        let mut responses = vec![];
+
+        for (revision, change) in self.history.iter() {
+            if *revision < start {
+                continue;
+            }
+
+            let k = change.ident.as_ref().unwrap();
+            let in_range = if range.start == range.end {
+                k == &range.start
+            } else {
+                range.contains(k)
+            };
+            if !in_range {
+                continue;
+            }
+
+            let is_delete_event = change.result.is_none();
+            if (filter == FilterType::Delete && !is_delete_event)
+                || (filter == FilterType::Update && is_delete_event)
+            {
+                continue;
+            }
+
+            responses.push(WatchResponse {
+                event: Some(Event {
+                    key: k.to_string(),
+                    current: change.result.clone().map(pb::SeqV::from),
+                    prev: change.prev.clone().map(pb::SeqV::from),
+                }),
+                revision: *revision,
+            });
+        }
+
+        responses
     }
 
     #[minitrace::trace]
@@ -246,7 +355,11 @@ impl EventDispatcher {
 }
 
 impl StateMachineSubscriber for DispatcherSender {
-    fn kv_changed(&self, change: Change<Vec<u8>, String>) {
-        let _ = self.0.send(WatchEvent::KVChange(change));
+    fn kv_changed(&self, revision: u64, change: Change<Vec<u8>, String>) {
+        let _ = self.0.send(WatchEvent::KVChange { revision, change });
+    }
+
+    fn history_reset(&self, revision: u64) {
+        let _ = self.0.send(WatchEvent::HistoryReset { revision });
     }
 }
@@ -17,6 +17,7 @@
 use std::sync::Arc;
 
 use common_meta_client::ClientHandle;
+use common_meta_client::MetaGrpcReadReq;
 use common_meta_client::Streamed;
 use common_meta_kvapi::kvapi::GetKVReq;
 use common_meta_kvapi::kvapi::KVApi;
@@ -26,6 +27,7 @@ use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf as pb;
 use common_meta_types::protobuf::KvMeta;
 use common_meta_types::KVMeta;
+use common_meta_types::ReadConsistency;
 use common_meta_types::SeqV;
 use common_meta_types::With;
 use futures::stream::StreamExt;
@@ -72,6 +74,54 @@ async fn test_kv_read_v1_on_follower() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_kv_read_v1_consistency_on_follower() -> anyhow::Result<()> {
+    let tcs = crate::tests::start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let leader = tcs[0].grpc_client().await?;
+    let follower = tcs[1].grpc_client().await?;
+
+    let get = |key: &str| MetaGrpcReadReq::GetKV(GetKVReq { key: s(key) });
+
+    for i in 0..10 {
+        let key = format!("k{}", i);
+
+        info!("--- write {} on leader", key);
+        leader
+            .upsert_kv(UpsertKVReq::insert(&key, &b(&key)))
+            .await?;
+
+        info!("--- linearizable read on follower sees the write at once");
+        let strm = follower
+            .read_with(get(&key), ReadConsistency::Linearizable)
+            .await?;
+        let got = strm.map_err(|e| e.to_string()).collect::<Vec<_>>().await;
+        assert_eq!(
+            vec![Ok(pb::StreamItem::new(
+                key.clone(),
+                Some(pb::SeqV::new(i + 1, b(&key)))
+            ))],
+            got
+        );
+
+        info!("--- stale read on follower is served by the applied state machine");
+        let strm = follower
+            .read_with(get(&key), ReadConsistency::Stale)
+            .await?;
+        let got = strm.map_err(|e| e.to_string()).collect::<Vec<_>>().await;
+        assert_eq!(
+            vec![Ok(pb::StreamItem::new(
+                key.clone(),
+                Some(pb::SeqV::new(i + 1, b(&key)))
+            ))],
+            got
+        );
+    }
+
+    Ok(())
+}
+
 /// Initialize kv store for test.
 ///
 /// Insert keys:
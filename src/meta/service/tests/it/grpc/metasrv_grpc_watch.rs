@@ -133,6 +133,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key: "a".to_string(),
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
+            start_revision: None,
         };
 
         let key_a = s("a");
@@ -191,6 +192,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: None,
             // filter only delete events
             filter_type: FilterType::Delete.into(),
+            start_revision: None,
         };
 
         let key = s(key_str);
@@ -254,6 +256,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            start_revision: None,
         };
 
         let conditions = vec![TxnCondition {
@@ -269,6 +272,7 @@ async fn test_watch() -> anyhow::Result<()> {
                     value: txn_val.clone(),
                     prev_value: true,
                     expire_at: None,
+                    lease_id: None,
                 })),
             },
             TxnOp {
@@ -378,10 +382,17 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            start_revision: None,
         };
         watch_client.request(watch).await?
     };
 
+    info!("--- the first response tells the revision the watch starts after");
+    {
+        let msg = client_stream.message().await?.unwrap();
+        assert!(msg.event.is_none());
+    }
+
     info!("--- sleep {} for expiration", expire - now_sec);
     tokio::time::sleep(Duration::from_secs(expire - now_sec)).await;
 
@@ -463,6 +474,7 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
         key: "a".to_string(),
         key_end: Some("z".to_string()),
         filter_type: FilterType::All.into(),
+        start_revision: None,
     };
 
     let client1 = make_client(&addr)?;
@@ -520,6 +532,72 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_watch_from_revision() -> anyhow::Result<()> {
+    // - Write some data.
+    // - Watch from the revision of the first change, the retained changes are received.
+    // - Watch from a revision in the future, only the new changes are received.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = make_client(&addr)?;
+
+    let watch_req = |start_revision| WatchRequest {
+        key: "a".to_string(),
+        key_end: Some("z".to_string()),
+        filter_type: FilterType::All.into(),
+        start_revision,
+    };
+
+    info!("--- get the current revision");
+    let start = {
+        let mut strm = client.request(watch_req(None)).await?;
+        let msg = strm.message().await?.unwrap();
+        assert!(msg.event.is_none());
+        msg.revision + 1
+    };
+
+    client.upsert_kv(UpsertKVReq::update("a", &b("a"))).await?;
+    client.upsert_kv(UpsertKVReq::update("b", &b("b"))).await?;
+    client.upsert_kv(UpsertKVReq::delete("a")).await?;
+
+    info!(
+        "--- watch from revision {}, receive the retained changes",
+        start
+    );
+    let last_revision = {
+        let mut strm = client.request(watch_req(Some(start))).await?;
+
+        let msg_a = strm.message().await?.unwrap();
+        assert_eq!(Some(add_event("a", 1, "a", None)), msg_a.event);
+        assert!(msg_a.revision >= start);
+
+        let msg_b = strm.message().await?.unwrap();
+        assert_eq!(Some(add_event("b", 2, "b", None)), msg_b.event);
+        assert!(msg_b.revision > msg_a.revision);
+
+        let msg_del = strm.message().await?.unwrap();
+        assert_eq!(Some(del_event("a", 1, "a", None)), msg_del.event);
+        assert!(msg_del.revision > msg_b.revision);
+
+        msg_del.revision
+    };
+
+    info!("--- watch from the next revision, receive only new changes");
+    {
+        let mut strm = client.request(watch_req(Some(last_revision + 1))).await?;
+
+        client.upsert_kv(UpsertKVReq::update("c", &b("c"))).await?;
+
+        let msg = strm.message().await?.unwrap();
+        assert_eq!(Some(add_event("c", 3, "c", None)), msg.event);
+        assert!(msg.revision > last_revision);
+    }
+
+    Ok(())
+}
+
 fn s(x: &str) -> String {
     x.to_string()
 }
@@ -193,6 +193,74 @@ async fn test_meta_node_join() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_join_as_learner() -> anyhow::Result<()> {
+    // - Bring up a single node cluster.
+    // - Join a new node as learner.
+    // - The learner replicates logs but does not become a voter.
+    // - Joining again is a no-op.
+
+    let (_nid0, tc0) = start_meta_node_leader().await?;
+    let mn0 = tc0.meta_node();
+
+    info!("--- bring up node-1");
+
+    let node_id = 1;
+    let tc1 = MetaSrvTestContext::new(node_id);
+    let mn1 = MetaNode::open_create(&tc1.config.raft_config, None, Some(())).await?;
+
+    info!("--- join node-1 as learner");
+
+    let req = ForwardRequest {
+        forward_to_leader: 0,
+        body: ForwardRequestBody::Join(
+            JoinRequest::new(
+                node_id,
+                tc1.config.raft_config.raft_api_addr().await?,
+                tc1.config.grpc_api_advertise_address(),
+            )
+            .with_as_learner(true),
+        ),
+    };
+    mn0.handle_forwardable_request(req.clone()).await?;
+
+    let all = vec![mn0.clone(), mn1.clone()];
+
+    info!("--- check node-1 is a learner on every node");
+    for mn in all.iter() {
+        mn.raft
+            .wait(timeout())
+            .metrics(
+                |m| {
+                    let membership = m.membership_config.membership();
+                    membership.learner_ids().any(|id| id == node_id)
+                        && !membership.voter_ids().any(|id| id == node_id)
+                },
+                format!("node-1 is learner: {}", mn.sto.id),
+            )
+            .await?;
+    }
+
+    mn1.raft
+        .wait(timeout())
+        .state(ServerState::Learner, "node-1 is learner")
+        .await?;
+
+    info!("--- learner replicates logs");
+    assert_upsert_kv_synced(all.clone(), "learner_key").await?;
+
+    info!("--- join again is a no-op");
+    mn0.handle_forwardable_request(req).await?;
+    let membership = mn0.raft.metrics().borrow().membership_config.clone();
+    assert_eq!(
+        vec![0],
+        membership.membership().voter_ids().collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[minitrace::trace]
 async fn test_meta_node_join_rejoin() -> anyhow::Result<()> {
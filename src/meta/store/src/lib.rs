@@ -24,6 +24,8 @@ use common_meta_embedded::MetaEmbedded;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::KVStream;
+use common_meta_kvapi::kvapi::LeaseReply;
+use common_meta_kvapi::kvapi::LeaseReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
@@ -73,13 +75,11 @@ impl MetaStore {
         }
     }
 
+    /// Watch kv changes, the watch is resumed from the last received revision if the connection is broken.
     pub async fn watch(&self, request: WatchRequest) -> Result<WatchStream, MetaError> {
         match self {
             MetaStore::L(_) => unreachable!(),
-            MetaStore::R(grpc_client) => {
-                let streaming = grpc_client.request(request).await?;
-                Ok(Box::pin(WatchResponseStream::create(streaming)))
-            }
+            MetaStore::R(grpc_client) => Ok(grpc_client.watch_with_resume(request)),
         }
     }
 }
@@ -122,6 +122,13 @@ impl kvapi::KVApi for MetaStore {
             MetaStore::R(x) => x.transaction(txn).await,
         }
     }
+
+    async fn lease(&self, req: LeaseReq) -> Result<LeaseReply, MetaError> {
+        match self {
+            MetaStore::L(x) => x.lease(req).await,
+            MetaStore::R(x) => x.lease(req).await,
+        }
+    }
 }
 
 impl MetaStoreProvider {
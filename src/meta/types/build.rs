@@ -63,6 +63,10 @@ fn build_proto() {
             "TxnDeleteByPrefixRequest",
             "#[derive(Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]",
         )
+        .type_attribute(
+            "TxnRangeGetRequest",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
+        )
         .type_attribute(
             "TxnCondition.ConditionResult",
             "#[derive(serde::Serialize, serde::Deserialize, num_derive::FromPrimitive)]",
@@ -103,6 +107,10 @@ fn build_proto() {
             "TxnDeleteByPrefixResponse",
             "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
         )
+        .type_attribute(
+            "TxnRangeGetResponse",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
+        )
         .type_attribute(
             "TxnOpResponse.response",
             "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
@@ -17,6 +17,7 @@ use std::fmt::Formatter;
 
 use crate::protobuf::RaftReply;
 use crate::Change;
+use crate::LeaseInfo;
 use crate::Node;
 use crate::TxnReply;
 
@@ -42,6 +43,9 @@ pub enum AppliedState {
 
     TxnReply(TxnReply),
 
+    /// The lease after applying, `None` if the lease does not exist.
+    Lease(Option<LeaseInfo>),
+
     #[try_into(ignore)]
     None,
 }
@@ -59,6 +63,10 @@ impl fmt::Display for AppliedState {
             AppliedState::TxnReply(txnreply) => {
                 write!(f, "Txn: {}", txnreply)
             }
+            AppliedState::Lease(lease) => match lease {
+                Some(lease) => write!(f, "Lease: {}", lease),
+                None => write!(f, "Lease: None"),
+            },
             AppliedState::None => {
                 write!(f, "None")
             }
@@ -77,6 +85,7 @@ impl AppliedState {
             AppliedState::KV(ref ch) => ch.is_changed(),
             AppliedState::None => false,
             AppliedState::TxnReply(txn) => txn.success,
+            AppliedState::Lease(lease) => lease.is_some(),
         }
     }
 
@@ -102,6 +111,7 @@ impl AppliedState {
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(_txn) => true,
+            AppliedState::Lease(_lease) => true,
         }
     }
 
@@ -111,6 +121,7 @@ impl AppliedState {
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(txn) => !txn.success,
+            AppliedState::Lease(lease) => lease.is_none(),
         }
     }
 }
@@ -19,6 +19,7 @@ use serde::Serialize;
 
 use crate::with::With;
 use crate::KVMeta;
+use crate::LeaseCmd;
 use crate::MatchSeq;
 use crate::Node;
 use crate::NodeId;
@@ -46,6 +47,9 @@ pub enum Cmd {
 
     /// Update one or more kv with a transaction.
     Transaction(TxnRequest),
+
+    /// Grant, keep alive or revoke a lease.
+    Lease(LeaseCmd),
 }
 
 /// Update or insert a general purpose kv store
@@ -64,6 +68,10 @@ pub struct UpsertKV {
 
     /// Meta data of a value.
     pub value_meta: Option<KVMeta>,
+
+    /// Attach the key to a lease, the key is removed when the lease expires or is revoked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease: Option<u64>,
 }
 
 impl fmt::Display for Cmd {
@@ -89,6 +97,9 @@ impl fmt::Display for Cmd {
             Cmd::Transaction(txn) => {
                 write!(f, "txn:{}", txn)
             }
+            Cmd::Lease(lease) => {
+                write!(f, "lease:{}", lease)
+            }
         }
     }
 }
@@ -99,7 +110,11 @@ impl fmt::Display for UpsertKV {
             f,
             "{}({:?}) = {:?} ({:?})",
             self.key, self.seq, self.value, self.value_meta
-        )
+        )?;
+        if let Some(lease) = self.lease {
+            write!(f, " lease: {}", lease)?;
+        }
+        Ok(())
     }
 }
 
@@ -115,6 +130,7 @@ impl UpsertKV {
             seq,
             value,
             value_meta,
+            lease: None,
         }
     }
 
@@ -124,6 +140,7 @@ impl UpsertKV {
             seq: MatchSeq::Exact(0),
             value: Operation::Update(value.to_vec()),
             value_meta: None,
+            lease: None,
         }
     }
 
@@ -133,6 +150,7 @@ impl UpsertKV {
             seq: MatchSeq::GE(0),
             value: Operation::Update(value.to_vec()),
             value_meta: None,
+            lease: None,
         }
     }
 
@@ -142,6 +160,7 @@ impl UpsertKV {
             seq: MatchSeq::GE(0),
             value: Operation::Delete,
             value_meta: None,
+            lease: None,
         }
     }
 
@@ -151,6 +170,12 @@ impl UpsertKV {
         })
     }
 
+    /// Attach the key to a lease.
+    pub fn with_lease(mut self, lease_id: u64) -> Self {
+        self.lease = Some(lease_id);
+        self
+    }
+
     pub fn get_expire_at_ms(&self) -> Option<u64> {
         if let Some(meta) = &self.value_meta {
             meta.expire_at.map(|x| x * 1000)
@@ -31,6 +31,9 @@ pub enum MetaClientError {
 
     #[error(transparent)]
     HandshakeError(MetaHandshakeError),
+
+    #[error(transparent)]
+    WatchCompacted(#[from] WatchCompacted),
 }
 
 impl MetaClientError {
@@ -40,6 +43,45 @@ impl MetaClientError {
             MetaClientError::ConfigError(_) => "ConfigError",
             MetaClientError::NetworkError(err) => err.name(),
             MetaClientError::HandshakeError(_) => "MetaHandshakeError",
+            MetaClientError::WatchCompacted(_) => "WatchCompacted",
+        }
+    }
+}
+
+/// A watch can not start from the requested revision,
+/// because the changes since then are no longer retained by the server.
+///
+/// The watcher has to re-read the full state and start a new watch.
+#[derive(thiserror::Error, serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error(
+    "watch history is compacted: start_revision {start_revision} <= compacted revision {compacted_revision}"
+)]
+pub struct WatchCompacted {
+    pub start_revision: u64,
+
+    /// Changes with a revision less than or equal to it may be missing.
+    pub compacted_revision: u64,
+}
+
+impl WatchCompacted {
+    pub fn new(start_revision: u64, compacted_revision: u64) -> Self {
+        Self {
+            start_revision,
+            compacted_revision,
+        }
+    }
+
+    /// Encode it into a `OutOfRange` status to send it to the client.
+    pub fn to_status(&self) -> tonic::Status {
+        let msg = serde_json::to_string(self).expect("fail to serialize WatchCompacted");
+        tonic::Status::out_of_range(msg)
+    }
+
+    /// Decode it from a status returned by the server, `None` if the status is not about compaction.
+    pub fn from_status(status: &tonic::Status) -> Option<Self> {
+        if status.code() != tonic::Code::OutOfRange {
+            return None;
         }
+        serde_json::from_str(status.message()).ok()
     }
 }
@@ -17,8 +17,10 @@ pub use openraft::error::EmptyMembership;
 pub use openraft::error::InProgress;
 pub use openraft::error::InitializeError;
 
+use crate::raft_types::CheckIsLeaderError;
 use crate::raft_types::ClientWriteError;
 use crate::MetaDataError;
+use crate::MetaDataReadError;
 use crate::MetaOperationError;
 use crate::RaftError;
 
@@ -43,3 +45,15 @@ impl From<RaftError<ClientWriteError>> for MetaOperationError {
         }
     }
 }
+
+impl From<RaftError<CheckIsLeaderError>> for MetaOperationError {
+    fn from(e: RaftError<CheckIsLeaderError>) -> Self {
+        match e {
+            RaftError::APIError(CheckIsLeaderError::ForwardToLeader(to_leader)) => to_leader.into(),
+            RaftError::APIError(CheckIsLeaderError::QuorumNotEnough(q)) => {
+                MetaDataReadError::new("ensure_linearizable", "", &q).into()
+            }
+            RaftError::Fatal(f) => MetaDataReadError::new("ensure_linearizable", "", &f).into(),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A lease with a TTL that many keys can be attached to.
+///
+/// When the lease expires or is revoked, all the attached keys are removed in one raft log.
+/// Only granting and revoking a lease are replicated: the expiration time is tracked in memory
+/// by the leader, a lease is kept alive without writing any log.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaseInfo {
+    pub lease_id: u64,
+
+    /// Time to live in seconds.
+    pub ttl_sec: u64,
+
+    /// Expiration time in seconds since 1970, estimated by the leader that serves the request.
+    pub expire_at: u64,
+}
+
+impl fmt::Display for LeaseInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "lease({}, ttl: {}s, expire_at: {})",
+            self.lease_id, self.ttl_sec, self.expire_at
+        )
+    }
+}
+
+/// A raft log command that changes leases.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum LeaseCmd {
+    /// Grant a lease, the lease id is allocated by the state machine.
+    Grant { ttl_sec: u64 },
+
+    /// Revoke a lease and remove all the attached keys.
+    ///
+    /// It is proposed by a client, or by the leader when the lease expires.
+    Revoke { lease_id: u64 },
+}
+
+impl fmt::Display for LeaseCmd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaseCmd::Grant { ttl_sec } => write!(f, "grant(ttl: {}s)", ttl_sec),
+            LeaseCmd::Revoke { lease_id } => write!(f, "revoke({})", lease_id),
+        }
+    }
+}
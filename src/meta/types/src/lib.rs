@@ -26,6 +26,7 @@ pub mod config;
 mod endpoint;
 pub mod errors;
 mod grpc_config;
+mod lease;
 mod log_entry;
 mod match_seq;
 mod message;
@@ -33,6 +34,7 @@ mod operation;
 mod raft_snapshot_data;
 mod raft_txid;
 mod raft_types;
+mod read_consistency;
 mod seq_errors;
 mod seq_num;
 mod seq_value;
@@ -64,6 +66,7 @@ pub use errors::meta_api_errors::MetaDataError;
 pub use errors::meta_api_errors::MetaDataReadError;
 pub use errors::meta_api_errors::MetaOperationError;
 pub use errors::meta_client_errors::MetaClientError;
+pub use errors::meta_client_errors::WatchCompacted;
 pub use errors::meta_errors::MetaError;
 pub use errors::meta_handshake_errors::MetaHandshakeError;
 pub use errors::meta_management_error::MetaManagementError;
@@ -75,6 +78,8 @@ pub use errors::meta_network_errors::MetaNetworkResult;
 pub use errors::meta_startup_errors::MetaStartupError;
 pub use errors::rpc_errors::ForwardRPCError;
 pub use grpc_config::GrpcConfig;
+pub use lease::LeaseCmd;
+pub use lease::LeaseInfo;
 pub use log_entry::LogEntry;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
@@ -95,9 +100,12 @@ pub use protobuf::TxnOp;
 pub use protobuf::TxnOpResponse;
 pub use protobuf::TxnPutRequest;
 pub use protobuf::TxnPutResponse;
+pub use protobuf::TxnRangeGetRequest;
+pub use protobuf::TxnRangeGetResponse;
 pub use protobuf::TxnReply;
 pub use protobuf::TxnRequest;
 pub use raft_txid::RaftTxId;
+pub use read_consistency::ReadConsistency;
 pub use seq_errors::ConflictSeq;
 pub use seq_num::SeqNum;
 pub use seq_value::IntoSeqV;
@@ -112,6 +120,7 @@ pub use crate::raft_types::new_log_id;
 pub use crate::raft_types::AppendEntriesRequest;
 pub use crate::raft_types::AppendEntriesResponse;
 pub use crate::raft_types::ChangeMembershipError;
+pub use crate::raft_types::CheckIsLeaderError;
 pub use crate::raft_types::ClientWriteError;
 pub use crate::raft_types::CommittedLeaderId;
 pub use crate::raft_types::Entry;
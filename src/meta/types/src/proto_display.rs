@@ -33,6 +33,8 @@ use crate::TxnOp;
 use crate::TxnOpResponse;
 use crate::TxnPutRequest;
 use crate::TxnPutResponse;
+use crate::TxnRangeGetRequest;
+use crate::TxnRangeGetResponse;
 use crate::TxnReply;
 use crate::TxnRequest;
 
@@ -132,6 +134,9 @@ impl Display for txn_op::Request {
             Request::DeleteByPrefix(r) => {
                 write!(f, "DeleteByPrefix({})", r)
             }
+            Request::RangeGet(r) => {
+                write!(f, "RangeGet({})", r)
+            }
         }
     }
 }
@@ -152,6 +157,9 @@ impl Display for TxnPutRequest {
         if let Some(expire_at) = self.expire_at {
             write!(f, " expire at: {}", expire_at)?;
         }
+        if let Some(lease_id) = self.lease_id {
+            write!(f, " lease: {}", lease_id)?;
+        }
         Ok(())
     }
 }
@@ -168,6 +176,16 @@ impl Display for TxnDeleteByPrefixRequest {
     }
 }
 
+impl Display for TxnRangeGetRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RangeGet start={}, end={}, limit={}",
+            self.start, self.end, self.limit
+        )
+    }
+}
+
 impl Display for Target {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -177,6 +195,12 @@ impl Display for Target {
             Target::Seq(seq) => {
                 write!(f, "seq({})", seq)
             }
+            Target::KeysWithPrefix(n) => {
+                write!(f, "keys_with_prefix({})", n)
+            }
+            Target::Exists(b) => {
+                write!(f, "exists({})", b)
+            }
         }
     }
 }
@@ -216,6 +240,9 @@ impl Display for Response {
             Response::DeleteByPrefix(r) => {
                 write!(f, "DeleteByPrefix: {}", r)
             }
+            Response::RangeGet(r) => {
+                write!(f, "RangeGet: {}", r)
+            }
         }
     }
 }
@@ -261,3 +288,14 @@ impl Display for TxnDeleteByPrefixResponse {
         )
     }
 }
+
+impl Display for TxnRangeGetResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RangeGet-resp: count={}, more={}",
+            self.items.len(),
+            self.more
+        )
+    }
+}
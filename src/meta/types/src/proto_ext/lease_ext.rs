@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::protobuf as pb;
+use crate::LeaseInfo;
+
+impl pb::LeaseKeepAliveResponse {
+    /// Build a response for a lease that has expired or been revoked.
+    pub fn expired(lease_id: u64) -> Self {
+        pb::LeaseKeepAliveResponse {
+            lease_id,
+            ttl_sec: 0,
+            expire_at: 0,
+        }
+    }
+
+    /// Convert back to a lease, `None` if the lease has expired or been revoked.
+    pub fn into_lease(self) -> Option<LeaseInfo> {
+        if self.ttl_sec == 0 {
+            return None;
+        }
+        Some(LeaseInfo {
+            lease_id: self.lease_id,
+            ttl_sec: self.ttl_sec,
+            expire_at: self.expire_at,
+        })
+    }
+}
+
+impl From<LeaseInfo> for pb::LeaseKeepAliveResponse {
+    fn from(lease: LeaseInfo) -> Self {
+        pb::LeaseKeepAliveResponse {
+            lease_id: lease.lease_id,
+            ttl_sec: lease.ttl_sec,
+            expire_at: lease.expire_at,
+        }
+    }
+}
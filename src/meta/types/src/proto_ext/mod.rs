@@ -14,6 +14,7 @@
 
 //! Extend protobuf generated code with some useful methods.
 
+mod lease_ext;
 mod seq_v_ext;
 mod snapshot_chunk_request_ext;
 mod stream_item_ext;
@@ -23,6 +23,28 @@ impl pb::TxnCondition {
             target: Some(pb::txn_condition::Target::Seq(seq)),
         }
     }
+
+    /// Create a txn condition that checks if the key exists, or does not exist.
+    pub fn exists(key: impl ToString, exists: bool) -> Self {
+        Self {
+            key: key.to_string(),
+            expected: pb::txn_condition::ConditionResult::Eq as i32,
+            target: Some(pb::txn_condition::Target::Exists(exists)),
+        }
+    }
+
+    /// Create a txn condition that compares the number of keys with `prefix` to `count`.
+    pub fn keys_with_prefix(
+        prefix: impl ToString,
+        op: pb::txn_condition::ConditionResult,
+        count: u64,
+    ) -> Self {
+        Self {
+            key: prefix.to_string(),
+            expected: op as i32,
+            target: Some(pb::txn_condition::Target::KeysWithPrefix(count)),
+        }
+    }
 }
 
 impl pb::TxnOp {
@@ -43,6 +65,33 @@ impl pb::TxnOp {
                 value,
                 prev_value: true,
                 expire_at,
+                lease_id: None,
+            })),
+        }
+    }
+
+    /// Create a txn operation that puts a record attached to a lease.
+    pub fn put_with_lease(key: impl ToString, value: Vec<u8>, lease_id: u64) -> pb::TxnOp {
+        pb::TxnOp {
+            request: Some(pb::txn_op::Request::Put(pb::TxnPutRequest {
+                key: key.to_string(),
+                value,
+                prev_value: true,
+                expire_at: None,
+                lease_id: Some(lease_id),
+            })),
+        }
+    }
+
+    /// Create a txn operation that gets the records in range `[start, end)`.
+    ///
+    /// `end` of `None` means the range is unbounded, `limit` of 0 means no limit.
+    pub fn range_get(start: impl ToString, end: Option<String>, limit: u64) -> pb::TxnOp {
+        pb::TxnOp {
+            request: Some(pb::txn_op::Request::RangeGet(pb::TxnRangeGetRequest {
+                start: start.to_string(),
+                end: end.unwrap_or_default(),
+                limit,
             })),
         }
     }
@@ -65,6 +65,7 @@ pub type StorageIOError = openraft::StorageIOError<NodeId>;
 pub type ForwardToLeader = openraft::error::ForwardToLeader<NodeId, MembershipNode>;
 pub type Fatal = openraft::error::Fatal<NodeId>;
 pub type ChangeMembershipError = openraft::error::ChangeMembershipError<NodeId>;
+pub type CheckIsLeaderError = openraft::error::CheckIsLeaderError<NodeId, MembershipNode>;
 pub type ClientWriteError = openraft::error::ClientWriteError<NodeId, MembershipNode>;
 pub type InitializeError = openraft::error::InitializeError<NodeId, MembershipNode>;
 
@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The consistency a read request to the meta-service requires.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ReadConsistency {
+    /// The read sees every write committed before it is received.
+    ///
+    /// The node receiving it asks the leader for a read index, i.e., the committed log index
+    /// confirmed by a quorum, and serves it locally once its state machine has applied up to it.
+    #[default]
+    Linearizable,
+
+    /// The read is served by the local state machine of the node receiving it, without contacting the leader.
+    ///
+    /// It may not see the latest writes.
+    Stale,
+}
+
+impl ReadConsistency {
+    /// The gRPC metadata key to carry the consistency of a read request.
+    pub const METADATA_KEY: &'static str = "read-consistency";
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReadConsistency::Linearizable => "linearizable",
+            ReadConsistency::Stale => "stale",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "linearizable" => Some(ReadConsistency::Linearizable),
+            "stale" => Some(ReadConsistency::Stale),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ReadConsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -1335,6 +1335,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::with_children(cluster_by_format_ctx, cluster_by_children);
             children.push(cluster_by_node);
         }
+        if let Some(partition_by) = &stmt.partition_by {
+            self.visit_expr(partition_by);
+            let partition_by_child = self.children.pop().unwrap();
+            let partition_by_name = "PartitionBy".to_string();
+            let partition_by_format_ctx = AstFormatContext::with_children(partition_by_name, 1);
+            let partition_by_node =
+                FormatTreeNode::with_children(partition_by_format_ctx, vec![partition_by_child]);
+            children.push(partition_by_node);
+        }
+        if let Some(ttl) = &stmt.ttl {
+            self.visit_expr(ttl);
+            let ttl_child = self.children.pop().unwrap();
+            let ttl_format_ctx = AstFormatContext::with_children("Ttl".to_string(), 1);
+            let ttl_node = FormatTreeNode::with_children(ttl_format_ctx, vec![ttl_child]);
+            children.push(ttl_node);
+        }
         if !stmt.table_options.is_empty() {
             let mut table_options_children = Vec::with_capacity(stmt.table_options.len());
             for (k, v) in stmt.table_options.iter() {
@@ -1387,6 +1403,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                ..
+            } => {
+                self.visit_table_ref(catalog, database, table);
+                let child = self.children.pop().unwrap();
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, 1);
+                let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1528,6 +1557,30 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::with_children(action_name, 1);
                 FormatTreeNode::with_children(action_format_ctx, vec![point_node])
             }
+            AlterTableAction::RestoreTo { point } => {
+                self.visit_time_travel_point(point);
+                let point_node = self.children.pop().unwrap();
+                let action_name = "Action RestoreTo".to_string();
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![point_node])
+            }
+            AlterTableAction::CreateTag { tag, .. } => {
+                let action_name = format!("Action CreateTag {tag}");
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropTag { tag } => {
+                let action_name = format!("Action DropTag {tag}");
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropPartition { partition } => {
+                self.visit_expr(partition);
+                let partition_child = self.children.pop().unwrap();
+                let action_name = "Action DropPartition".to_string();
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![partition_child])
+            }
             AlterTableAction::SetOptions { set_options } => {
                 let mut action_name = "Action Set Option: ".to_string();
                 for (key, value) in set_options.iter() {
@@ -2995,6 +3048,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            TimeTravelPoint::Tag(tag) => {
+                let name = format!("Tag {}", tag);
+                let format_ctx = AstFormatContext::new(name);
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
         }
     }
 
@@ -80,6 +80,20 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if let Some(partition_by) = stmt.partition_by {
+            RcDoc::line()
+                .append(RcDoc::text("PARTITION BY "))
+                .append(parenthesized(pretty_expr(partition_by)))
+        } else {
+            RcDoc::nil()
+        })
+        .append(if let Some(ttl) = stmt.ttl {
+            RcDoc::line()
+                .append(RcDoc::text("TTL "))
+                .append(pretty_expr(ttl))
+        } else {
+            RcDoc::nil()
+        })
         .append(if !stmt.table_options.is_empty() {
             RcDoc::line()
                 .append(interweave_comma(stmt.table_options.iter().map(|(k, v)| {
@@ -134,6 +148,30 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(if let Some(travel_point) = travel_point {
+                RcDoc::text(format!(" AT{travel_point}"))
+            } else {
+                RcDoc::nil()
+            }),
     }
 }
 
@@ -218,7 +256,24 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::RevertTo { point } => match point {
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
+            TimeTravelPoint::Tag(tag) => RcDoc::text(format!(" AT (TAG => '{tag}')")),
         },
+        AlterTableAction::RestoreTo { point } => {
+            RcDoc::line().append(RcDoc::text(format!("RESTORE TO{point}")))
+        }
+        AlterTableAction::CreateTag { tag, retain_days } => RcDoc::line()
+            .append(RcDoc::text(format!("CREATE TAG '{tag}'")))
+            .append(if let Some(retain_days) = retain_days {
+                RcDoc::text(format!(" RETAIN {retain_days} DAYS"))
+            } else {
+                RcDoc::nil()
+            }),
+        AlterTableAction::DropTag { tag } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP TAG '{tag}'")))
+        }
+        AlterTableAction::DropPartition { partition } => RcDoc::line()
+            .append(RcDoc::text("DROP PARTITION "))
+            .append(pretty_expr(partition)),
         AlterTableAction::SetOptions { set_options } => {
             let mut doc = RcDoc::line();
             doc = doc.append(RcDoc::text("SET OPTIONS: "));
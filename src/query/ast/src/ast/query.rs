@@ -243,6 +243,7 @@ pub enum Indirection {
 pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
+    Tag(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -470,6 +471,10 @@ impl Display for TableReference {
                     write!(f, " AT (TIMESTAMP => {ts})")?;
                 }
 
+                if let Some(TimeTravelPoint::Tag(tag)) = travel_point {
+                    write!(f, " AT (TAG => '{tag}')")?;
+                }
+
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
@@ -805,6 +810,9 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Timestamp(ts) => {
                 write!(f, " (TIMESTAMP => {ts})")?;
             }
+            TimeTravelPoint::Tag(tag) => {
+                write!(f, " (TAG => '{tag}')")?;
+            }
         }
 
         Ok(())
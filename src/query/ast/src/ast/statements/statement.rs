@@ -305,6 +305,16 @@ impl Statement {
                 attach_clone.uri_location.connection = attach_clone.uri_location.connection.mask();
                 format!("{}", Statement::AttachTable(attach_clone))
             }
+            Statement::CreateUser(create_user) => {
+                let mut create_user_clone = create_user.clone();
+                create_user_clone.auth_option = create_user_clone.auth_option.mask();
+                format!("{}", Statement::CreateUser(create_user_clone))
+            }
+            Statement::AlterUser(alter_user) => {
+                let mut alter_user_clone = alter_user.clone();
+                alter_user_clone.auth_option = alter_user_clone.auth_option.map(|o| o.mask());
+                format!("{}", Statement::AlterUser(alter_user_clone))
+            }
             _ => format!("{}", self),
         }
     }
@@ -133,6 +133,8 @@ pub struct CreateTableStmt {
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
     pub cluster_by: Vec<Expr>,
+    pub partition_by: Option<Expr>,
+    pub ttl: Option<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
@@ -174,6 +176,14 @@ impl Display for CreateTableStmt {
             write!(f, ")")?
         }
 
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY ({partition_by})")?;
+        }
+
+        if let Some(ttl) = &self.ttl {
+            write!(f, " TTL {ttl}")?;
+        }
+
         // Format table options
         write_comma_separated_map(f, &self.table_options)?;
         if let Some(as_query) = &self.as_query {
@@ -222,6 +232,12 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -240,6 +256,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -362,6 +391,19 @@ pub enum AlterTableAction {
     RevertTo {
         point: TimeTravelPoint,
     },
+    RestoreTo {
+        point: TimeTravelPoint,
+    },
+    CreateTag {
+        tag: String,
+        retain_days: Option<u64>,
+    },
+    DropTag {
+        tag: String,
+    },
+    DropPartition {
+        partition: Expr,
+    },
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
@@ -419,6 +461,21 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::RestoreTo { point } => {
+                write!(f, "RESTORE TO {}", point)?;
+            }
+            AlterTableAction::CreateTag { tag, retain_days } => {
+                write!(f, "CREATE TAG '{tag}'")?;
+                if let Some(retain_days) = retain_days {
+                    write!(f, " RETAIN {retain_days} DAYS")?;
+                }
+            }
+            AlterTableAction::DropTag { tag } => {
+                write!(f, "DROP TAG '{tag}'")?;
+            }
+            AlterTableAction::DropPartition { partition } => {
+                write!(f, "DROP PARTITION {partition}")?;
+            }
         };
         Ok(())
     }
@@ -696,6 +753,7 @@ pub enum OptimizeTableAction {
     All,
     Purge { before: Option<TimeTravelPoint> },
     Compact { target: CompactTarget },
+    Ttl,
 }
 
 impl Display for OptimizeTableAction {
@@ -720,6 +778,7 @@ impl Display for OptimizeTableAction {
                 }
                 Ok(())
             }
+            OptimizeTableAction::Ttl => write!(f, "TTL"),
         }
     }
 }
@@ -761,6 +820,8 @@ pub struct ColumnDefinition {
     pub expr: Option<ColumnExpr>,
     pub comment: Option<String>,
     pub nullable_constraint: Option<NullableConstraint>,
+    pub compression: Option<String>,
+    pub encoding: Option<String>,
 }
 
 impl Display for ColumnDefinition {
@@ -777,6 +838,12 @@ impl Display for ColumnDefinition {
         if let Some(expr) = &self.expr {
             write!(f, "{expr}")?;
         }
+        if let Some(compression) = &self.compression {
+            write!(f, " COMPRESSION '{compression}'")?;
+        }
+        if let Some(encoding) = &self.encoding {
+            write!(f, " ENCODING '{encoding}'")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
         }
@@ -15,6 +15,7 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use common_base::base::mask_string;
 use common_meta_app::principal::AuthType;
 use common_meta_app::principal::PrincipalIdentity;
 use common_meta_app::principal::UserIdentity;
@@ -57,6 +58,16 @@ pub struct AuthOption {
     pub password: Option<String>,
 }
 
+impl AuthOption {
+    /// Mask the password, to not expose it in the query log.
+    pub fn mask(&self) -> Self {
+        Self {
+            auth_type: self.auth_type.clone(),
+            password: self.password.as_ref().map(|p| mask_string(p, 0)),
+        }
+    }
+}
+
 impl Display for AuthOption {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if let Some(auth_type) = &self.auth_type {
@@ -677,6 +677,8 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             ~ ( #engine )?
             ~ ( #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#expr ~ ^")" )?
+            ~ ( TTL ~ ^#expr )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -690,6 +692,8 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             engine,
             uri_location,
             opt_cluster_by,
+            opt_partition_by,
+            opt_ttl,
             opt_table_options,
             opt_as_query,
         )| {
@@ -704,6 +708,8 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 cluster_by: opt_cluster_by
                     .map(|(_, _, _, exprs, _)| exprs)
                     .unwrap_or_default(),
+                partition_by: opt_partition_by.map(|(_, _, _, expr, _)| expr),
+                ttl: opt_ttl.map(|(_, expr)| expr),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 transient: opt_transient.is_some(),
@@ -1984,12 +1990,28 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        Compression(String),
+        Encoding(String),
     }
 
     let nullable = alt((
         value(ColumnConstraint::Nullable(true), rule! { NULL }),
         value(ColumnConstraint::Nullable(false), rule! { NOT ~ ^NULL }),
     ));
+    let storage_option = alt((
+        map(
+            rule! {
+                COMPRESSION ~ ^#literal_string
+            },
+            |(_, compression)| ColumnConstraint::Compression(compression),
+        ),
+        map(
+            rule! {
+                ENCODING ~ ^#literal_string
+            },
+            |(_, encoding)| ColumnConstraint::Encoding(encoding),
+        ),
+    ));
     let expr = alt((
         map(
             rule! {
@@ -2022,9 +2044,9 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         rule! {
             #ident
             ~ #type_name
-            ~ ( #nullable | #expr )*
+            ~ ( #nullable | #expr | #storage_option )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMPRESSION '<compression>'] [ENCODING '<encoding>'] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let def = ColumnDefinition {
@@ -2033,6 +2055,8 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
                 expr: None,
                 comment,
                 nullable_constraint: None,
+                compression: None,
+                encoding: None,
             };
             (def, constraints)
         },
@@ -2062,6 +2086,8 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::Compression(compression) => def.compression = Some(compression),
+            ColumnConstraint::Encoding(encoding) => def.encoding = Some(encoding),
         }
     }
 
@@ -2350,10 +2376,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -2410,6 +2448,8 @@ pub fn modify_column_type(i: Input) -> IResult<ColumnDefinition> {
                 expr: None,
                 comment,
                 nullable_constraint: None,
+                compression: None,
+                encoding: None,
             };
             for constraint in constraints {
                 match constraint {
@@ -2547,6 +2587,37 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, point)| AlterTableAction::RevertTo { point },
     );
 
+    let restore_table = map(
+        rule! {
+            RESTORE ~ TO ~ #travel_point
+        },
+        |(_, _, point)| AlterTableAction::RestoreTo { point },
+    );
+
+    let create_tag = map(
+        rule! {
+            CREATE ~ TAG ~ #literal_string ~ ( RETAIN ~ ^#literal_u64 ~ ^DAYS )?
+        },
+        |(_, _, tag, opt_retain)| AlterTableAction::CreateTag {
+            tag,
+            retain_days: opt_retain.map(|(_, days, _)| days),
+        },
+    );
+
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ #literal_string
+        },
+        |(_, _, tag)| AlterTableAction::DropTag { tag },
+    );
+
+    let drop_partition = map(
+        rule! {
+            DROP ~ PARTITION ~ ^#expr
+        },
+        |(_, _, partition)| AlterTableAction::DropPartition { partition },
+    );
+
     let set_table_options = map(
         rule! {
             SET ~ OPTIONS ~ "(" ~ #set_table_option ~ ")"
@@ -2564,6 +2635,10 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #drop_table_cluster_key
         | #recluster_table
         | #revert_table
+        | #restore_table
+        | #create_tag
+        | #drop_tag
+        | #drop_partition
         | #set_table_options
     )(i)
 }
@@ -2687,6 +2762,7 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
                 target: opt_segment.map_or(CompactTarget::Block, |_| CompactTarget::Segment),
             }
         }),
+        value(OptimizeTableAction::Ttl, rule! { TTL }),
     ))(i)
 }
 
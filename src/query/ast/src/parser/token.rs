@@ -324,6 +324,8 @@ pub enum TokenKind {
     AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
     ANY,
+    #[token("APPEND_ONLY", ignore(ascii_case))]
+    APPEND_ONLY,
     #[token("ARGS", ignore(ascii_case))]
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
@@ -386,6 +388,8 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -452,6 +456,8 @@ pub enum TokenKind {
     DATETIME,
     #[token("DAY", ignore(ascii_case))]
     DAY,
+    #[token("DAYS", ignore(ascii_case))]
+    DAYS,
     #[token("DECADE", ignore(ascii_case))]
     DECADE,
     #[token("DECIMAL", ignore(ascii_case))]
@@ -498,6 +504,8 @@ pub enum TokenKind {
     ELSE,
     #[token("ENABLE_VIRTUAL_HOST_STYLE", ignore(ascii_case))]
     ENABLE_VIRTUAL_HOST_STYLE,
+    #[token("ENCODING", ignore(ascii_case))]
+    ENCODING,
     #[token("END", ignore(ascii_case))]
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
@@ -819,6 +827,8 @@ pub enum TokenKind {
     QUALIFY,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RESTORE", ignore(ascii_case))]
+    RESTORE,
     #[token("RETAIN", ignore(ascii_case))]
     RETAIN,
     #[token("REVOKE", ignore(ascii_case))]
@@ -929,6 +939,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("TENANTSETTING", ignore(ascii_case))]
@@ -967,6 +979,8 @@ pub enum TokenKind {
     TRY_CAST,
     #[token("TSV", ignore(ascii_case))]
     TSV,
+    #[token("TTL", ignore(ascii_case))]
+    TTL,
     #[token("TUPLE", ignore(ascii_case))]
     TUPLE,
     #[token("TYPE", ignore(ascii_case))]
@@ -289,7 +289,7 @@ pub fn walk_table_reference<'a, V: Visitor<'a>>(visitor: &mut V, table_ref: &'a
 
 pub fn walk_time_travel_point<'a, V: Visitor<'a>>(visitor: &mut V, time: &'a TimeTravelPoint) {
     match time {
-        TimeTravelPoint::Snapshot(_) => {}
+        TimeTravelPoint::Snapshot(_) | TimeTravelPoint::Tag(_) => {}
         TimeTravelPoint::Timestamp(expr) => visitor.visit_expr(expr),
     }
 }
@@ -323,7 +323,7 @@ pub fn walk_table_reference_mut<V: VisitorMut>(visitor: &mut V, table_ref: &mut
 
 pub fn walk_time_travel_point_mut<V: VisitorMut>(visitor: &mut V, time: &mut TimeTravelPoint) {
     match time {
-        TimeTravelPoint::Snapshot(_) => {}
+        TimeTravelPoint::Snapshot(_) | TimeTravelPoint::Tag(_) => {}
         TimeTravelPoint::Timestamp(expr) => visitor.visit_expr(expr),
     }
 }
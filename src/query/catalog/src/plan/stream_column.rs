@@ -96,6 +96,8 @@ impl StreamTablePart {
 pub struct StreamColumnMeta {
     pub block_id: i128,
     pub inner: Option<BlockMetaInfoPtr>,
+    // The offsets in the block of the rows, if some rows of the block are skipped.
+    pub offsets: Option<Vec<usize>>,
 }
 
 #[typetag::serde(name = "stream_column_meta")]
@@ -127,10 +129,10 @@ impl StreamColumnMeta {
     }
 
     pub fn build_origin_block_row_num(&self, num_rows: usize) -> Value<AnyType> {
-        let mut row_ids = Vec::with_capacity(num_rows);
-        for i in 0..num_rows {
-            row_ids.push(i as u64);
-        }
+        let row_ids = match &self.offsets {
+            Some(offsets) => offsets.iter().map(|i| *i as u64).collect::<Vec<_>>(),
+            None => (0..num_rows as u64).collect::<Vec<_>>(),
+        };
         let column = UInt64Type::from_data(row_ids);
         Value::Column(Column::Nullable(Box::new(NullableColumn {
             column,
@@ -245,7 +247,12 @@ pub fn block_id_from_location(path: &str) -> Result<i128> {
 pub fn gen_mutation_stream_meta(
     inner: Option<BlockMetaInfoPtr>,
     path: &str,
+    offsets: Option<Vec<usize>>,
 ) -> Result<StreamColumnMeta> {
     let block_id = block_id_from_location(path)?;
-    Ok(StreamColumnMeta { block_id, inner })
+    Ok(StreamColumnMeta {
+        block_id,
+        inner,
+        offsets,
+    })
 }
@@ -419,6 +419,7 @@ impl<T: ?Sized> TableExt for T where T: Table {}
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    Tag(String),
 }
 
 #[derive(Debug, Copy, Clone, Default)]
@@ -1590,6 +1590,23 @@ pub struct QueryConfig {
 
     #[clap(long)]
     pub cloud_control_grpc_server_address: Option<String>,
+
+    /// Local directory of spilled data, spill to the remote storage directly if it's empty.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub spill_local_disk_path: String,
+
+    /// Max bytes of spilled data on local disk, spill to the remote storage if it's used up.
+    #[clap(long, value_name = "VALUE", default_value = "21474836480")]
+    pub spill_local_disk_max_bytes: u64,
+
+    /// Compact the fuse tables with `auto_compaction` enabled and remove the expired rows of
+    /// the tables with a TTL in the background. Enable it on one query node of a cluster.
+    #[clap(long, value_name = "VALUE", default_value = "false")]
+    pub enable_auto_compaction: bool,
+
+    /// How often the tables are checked for auto compaction, in seconds.
+    #[clap(long, value_name = "VALUE", default_value = "60")]
+    pub auto_compaction_check_interval_secs: u64,
 }
 
 impl Default for QueryConfig {
@@ -1667,6 +1684,10 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             enable_udf_server: self.enable_udf_server,
             udf_server_allow_list: self.udf_server_allow_list,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
+            spill_local_disk_path: self.spill_local_disk_path,
+            spill_local_disk_max_bytes: self.spill_local_disk_max_bytes,
+            enable_auto_compaction: self.enable_auto_compaction,
+            auto_compaction_check_interval_secs: self.auto_compaction_check_interval_secs,
         })
     }
 }
@@ -1754,6 +1775,10 @@ impl From<InnerQueryConfig> for QueryConfig {
             enable_udf_server: inner.enable_udf_server,
             udf_server_allow_list: inner.udf_server_allow_list,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
+            spill_local_disk_path: inner.spill_local_disk_path,
+            spill_local_disk_max_bytes: inner.spill_local_disk_max_bytes,
+            enable_auto_compaction: inner.enable_auto_compaction,
+            auto_compaction_check_interval_secs: inner.auto_compaction_check_interval_secs,
         }
     }
 }
@@ -223,6 +223,16 @@ pub struct QueryConfig {
     pub udf_server_allow_list: Vec<String>,
 
     pub cloud_control_grpc_server_address: Option<String>,
+
+    /// Local directory of spilled data, spill to the remote storage directly if it's empty.
+    pub spill_local_disk_path: String,
+    /// Max bytes of spilled data on local disk, spill to the remote storage if it's used up.
+    pub spill_local_disk_max_bytes: u64,
+
+    /// Run the auto compaction service on this node.
+    pub enable_auto_compaction: bool,
+    /// How often the tables are checked for auto compaction, in seconds.
+    pub auto_compaction_check_interval_secs: u64,
 }
 
 impl Default for QueryConfig {
@@ -290,6 +300,10 @@ impl Default for QueryConfig {
             enable_udf_server: false,
             udf_server_allow_list: Vec::new(),
             cloud_control_grpc_server_address: None,
+            spill_local_disk_path: "".to_string(),
+            spill_local_disk_max_bytes: 21474836480,
+            enable_auto_compaction: false,
+            auto_compaction_check_interval_secs: 60,
         }
     }
 }
@@ -29,6 +29,7 @@ use common_storages_fuse::io::SnapshotLiteExtended;
 use common_storages_fuse::io::SnapshotsIO;
 use common_storages_fuse::io::TableMetaLocationGenerator;
 use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_TBL_DELETION_VECTOR_PREFIX;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 
@@ -41,6 +42,7 @@ pub struct SnapshotReferencedFiles {
     pub segments: HashSet<String>,
     pub blocks: HashSet<String>,
     pub blocks_index: HashSet<String>,
+    pub deletion_vectors: HashSet<String>,
 }
 
 impl SnapshotReferencedFiles {
@@ -55,6 +57,9 @@ impl SnapshotReferencedFiles {
         for file in &self.blocks_index {
             files.push(file.clone());
         }
+        for file in &self.deletion_vectors {
+            files.push(file.clone());
+        }
         files
     }
 }
@@ -129,13 +134,53 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+    let mut blocks = locations_referenced.block_location;
+    let mut blocks_index = locations_referenced.bloom_location;
+
+    // 3. Files still referenced by the clones of the table are not orphans.
+    let shared_locations = fuse_table.read_shared_locations(ctx).await?;
+    segments.extend(shared_locations.segments().iter().cloned());
+    blocks.extend(shared_locations.blocks().iter().cloned());
+    blocks_index.extend(shared_locations.blooms().iter().cloned());
+
+    // 4. The deletion vectors are collected along with the block indexes, they are
+    // under a prefix of their own.
+    let deletion_vector_prefix = deletion_vector_prefix(fuse_table);
+    let (deletion_vectors, blocks_index) = blocks_index
+        .into_iter()
+        .partition(|location| location.starts_with(&deletion_vector_prefix));
+
     Ok(Some(SnapshotReferencedFiles {
         segments,
-        blocks: locations_referenced.block_location,
-        blocks_index: locations_referenced.bloom_location,
+        blocks,
+        blocks_index,
+        deletion_vectors,
     }))
 }
 
+fn deletion_vector_prefix(fuse_table: &FuseTable) -> String {
+    format!(
+        "{}/{}/",
+        fuse_table.meta_location_generator().prefix(),
+        FUSE_TBL_DELETION_VECTOR_PREFIX
+    )
+}
+
+// return the orphan files under the prefix to be purged
+#[async_backtrace::framed]
+async fn list_orphan_files(
+    fuse_table: &FuseTable,
+    prefix: String,
+    referenced_files: &HashSet<String>,
+    retention_time: DateTime<Utc>,
+) -> Result<Vec<String>> {
+    fuse_table
+        .list_files(prefix, |location, modified| {
+            modified <= retention_time && !referenced_files.contains(&location)
+        })
+        .await
+}
+
 // return orphan files to be purged
 #[async_backtrace::framed]
 async fn get_orphan_files_to_be_purged(
@@ -143,15 +188,18 @@ async fn get_orphan_files_to_be_purged(
     referenced_files: HashSet<String>,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
-    let files_to_be_purged = match referenced_files.iter().next().cloned() {
+    // A cloned table references files of its source, only list the files
+    // under the storage prefix of the table itself.
+    let table_prefix = format!("{}/", fuse_table.meta_location_generator().prefix());
+    let files_to_be_purged = match referenced_files
+        .iter()
+        .find(|location| location.starts_with(&table_prefix))
+        .cloned()
+    {
         Some(location) => {
             let prefix = SnapshotsIO::get_s3_prefix_from_file(&location);
             if let Some(prefix) = prefix {
-                fuse_table
-                    .list_files(prefix, |location, modified| {
-                        modified <= retention_time && !referenced_files.contains(&location)
-                    })
-                    .await?
+                list_orphan_files(fuse_table, prefix, &referenced_files, retention_time).await?
             } else {
                 vec![]
             }
@@ -266,6 +314,38 @@ pub async fn do_gc_orphan_files(
     );
     ctx.set_status_info(&status);
 
+    // 5. Purge orphan deletion vector files.
+    // 5.1 Get orphan deletion vector files to be purged, the prefix is listed even if
+    // no deletion vector is referenced, e.g. all of them are merged away by compaction.
+    let deletion_vector_locations_to_be_purged = list_orphan_files(
+        fuse_table,
+        deletion_vector_prefix(fuse_table),
+        &referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "gc orphan: read deletion_vector_locations_to_be_purged:{}, cost:{} sec",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
+    // 5.2 Delete all the orphan deletion vector files to be purged
+    let purged_file_num = deletion_vector_locations_to_be_purged.len();
+    fuse_table
+        .try_purge_location_files(
+            ctx.clone(),
+            HashSet::from_iter(deletion_vector_locations_to_be_purged.into_iter()),
+        )
+        .await?;
+    let status = format!(
+        "gc orphan: purged deletion vector files:{}, cost:{} sec",
+        purged_file_num,
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
     Ok(())
 }
 
@@ -334,6 +414,26 @@ pub async fn do_dry_run_orphan_files(
     ctx.set_status_info(&status);
 
     purge_files.extend(index_locations_to_be_purged);
+    if purge_files.len() >= dry_run_limit {
+        return Ok(());
+    }
+
+    // 5. Get purge orphan deletion vector files.
+    let deletion_vector_locations_to_be_purged = list_orphan_files(
+        fuse_table,
+        deletion_vector_prefix(fuse_table),
+        &referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "dry_run orphan: read deletion_vector_locations_to_be_purged:{}, cost:{} sec",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
+    purge_files.extend(deletion_vector_locations_to_be_purged);
 
     Ok(())
 }
@@ -33,8 +33,8 @@ use common_sql::plans::DropStreamPlan;
 use common_sql::plans::StreamNavigation;
 use common_storages_fuse::FuseTable;
 use common_storages_fuse::TableContext;
+use common_storages_stream::stream_table::StreamMode;
 use common_storages_stream::stream_table::StreamTable;
-use common_storages_stream::stream_table::MODE_APPEND_ONLY;
 use common_storages_stream::stream_table::OPT_KEY_DATABASE_NAME;
 use common_storages_stream::stream_table::OPT_KEY_MODE;
 use common_storages_stream::stream_table::OPT_KEY_TABLE_ID;
@@ -82,6 +82,12 @@ impl StreamHandler for RealStreamHandler {
                 .await?;
         }
 
+        let mode = if plan.append_only {
+            StreamMode::AppendOnly
+        } else {
+            StreamMode::Standard
+        };
+
         let mut options = BTreeMap::new();
         match &plan.navigation {
             Some(StreamNavigation::AtStream { database, name }) => {
@@ -108,6 +114,7 @@ impl StreamHandler for RealStreamHandler {
                     )));
                 }
                 options = stream.get_table_info().options().clone();
+                options.insert(OPT_KEY_MODE.to_string(), mode.to_string());
             }
             None => {
                 options.insert(OPT_KEY_TABLE_NAME.to_string(), plan.table_name.clone());
@@ -117,7 +124,7 @@ impl StreamHandler for RealStreamHandler {
                 );
                 options.insert(OPT_KEY_TABLE_ID.to_string(), table_id.to_string());
                 options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
-                options.insert(OPT_KEY_MODE.to_string(), MODE_APPEND_ONLY.to_string());
+                options.insert(OPT_KEY_MODE.to_string(), mode.to_string());
                 let fuse_table = FuseTable::try_from_table(table.as_ref())?;
                 if let Some(snapshot_loc) = fuse_table.snapshot_loc().await? {
                     options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
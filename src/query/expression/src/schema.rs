@@ -57,6 +57,15 @@ pub const CHANGE_ACTION_COLUMN_ID: u32 = u32::MAX - 7;
 pub const CHANGE_IS_UPDATE_COLUMN_ID: u32 = u32::MAX - 8;
 pub const CHANGE_ROW_ID_COLUMN_ID: u32 = u32::MAX - 9;
 
+/// Key of the [`TableSchema`] metadata which records the previous shapes of the fields
+/// whose type has been evolved without rewriting the data, keyed by the current column id
+/// of the field, newest first.
+pub const FIELD_EVOLUTION_KEY: &str = "field_evolution";
+
+/// Key of the [`TableSchema`] metadata which records the storage options of the fields,
+/// like `COMPRESSION` and `ENCODING`, keyed by the column id of the field.
+pub const FIELD_OPTIONS_KEY: &str = "field_options";
+
 // internal column name.
 pub const ROW_ID_COL_NAME: &str = "_row_id";
 pub const SNAPSHOT_NAME_COL_NAME: &str = "_snapshot_name";
@@ -432,7 +441,119 @@ impl TableSchema {
             }
         }
 
-        true
+        // columns of the previous shapes of evolved fields are still readable.
+        !self
+            .field_evolutions()
+            .iter()
+            .filter(|(id, _)| self.fields.iter().any(|f| f.column_id == **id))
+            .any(|(_, previous)| previous.iter().any(|f| f.contain_column_id(column_id)))
+    }
+
+    /// Change the type of the field at `i` without rewriting the data.
+    ///
+    /// The field is assigned new column ids, and its previous shape is recorded in the
+    /// schema metadata, so that blocks written before can still be found and cast at
+    /// read time.
+    pub fn evolve_field(&mut self, i: FieldIndex, data_type: TableDataType) -> Result<()> {
+        let previous_field = self.fields[i].clone();
+        let mut evolutions = self.field_evolutions();
+        let mut previous = evolutions
+            .remove(&previous_field.column_id)
+            .unwrap_or_default();
+
+        let mut field = previous_field.clone();
+        field.data_type = data_type;
+        let field = field.build_column_id(&mut self.next_column_id);
+
+        previous.insert(0, previous_field);
+        evolutions.insert(field.column_id, previous);
+
+        // the storage options go along with the field.
+        let mut field_options = self.all_field_options();
+        if let Some(options) = field_options.remove(&self.fields[i].column_id) {
+            field_options.insert(field.column_id, options);
+            self.set_all_field_options(&field_options)?;
+        }
+
+        self.fields[i] = field;
+        self.set_field_evolutions(&evolutions)
+    }
+
+    /// Returns the previous shapes of the field which contains `column_id`, newest first.
+    pub fn previous_fields_of(&self, column_id: ColumnId) -> Vec<TableField> {
+        let Some(field) = self.fields.iter().find(|f| f.contain_column_id(column_id)) else {
+            return vec![];
+        };
+        self.field_evolutions()
+            .remove(&field.column_id)
+            .unwrap_or_default()
+    }
+
+    pub fn has_evolved_fields(&self) -> bool {
+        self.metadata.contains_key(FIELD_EVOLUTION_KEY)
+    }
+
+    fn field_evolutions(&self) -> BTreeMap<ColumnId, Vec<TableField>> {
+        self.metadata
+            .get(FIELD_EVOLUTION_KEY)
+            .and_then(|v| serde_json::from_str(v).ok())
+            .unwrap_or_default()
+    }
+
+    fn set_field_evolutions(
+        &mut self,
+        evolutions: &BTreeMap<ColumnId, Vec<TableField>>,
+    ) -> Result<()> {
+        if evolutions.is_empty() {
+            self.metadata.remove(FIELD_EVOLUTION_KEY);
+        } else {
+            let value = serde_json::to_string(evolutions)?;
+            self.metadata.insert(FIELD_EVOLUTION_KEY.to_string(), value);
+        }
+        Ok(())
+    }
+
+    /// Returns the storage options of the field whose column id is `column_id`.
+    pub fn field_options(&self, column_id: ColumnId) -> BTreeMap<String, String> {
+        self.all_field_options()
+            .remove(&column_id)
+            .unwrap_or_default()
+    }
+
+    /// Sets the storage options of the field whose column id is `column_id`,
+    /// empty `options` removes them.
+    pub fn set_field_options(
+        &mut self,
+        column_id: ColumnId,
+        options: BTreeMap<String, String>,
+    ) -> Result<()> {
+        let mut field_options = self.all_field_options();
+        if options.is_empty() {
+            field_options.remove(&column_id);
+        } else {
+            field_options.insert(column_id, options);
+        }
+        self.set_all_field_options(&field_options)
+    }
+
+    fn all_field_options(&self) -> BTreeMap<ColumnId, BTreeMap<String, String>> {
+        self.metadata
+            .get(FIELD_OPTIONS_KEY)
+            .and_then(|v| serde_json::from_str(v).ok())
+            .unwrap_or_default()
+    }
+
+    fn set_all_field_options(
+        &mut self,
+        field_options: &BTreeMap<ColumnId, BTreeMap<String, String>>,
+    ) -> Result<()> {
+        if field_options.is_empty() {
+            self.metadata.remove(FIELD_OPTIONS_KEY);
+        } else {
+            let value = serde_json::to_string(field_options)?;
+            self.metadata.insert(FIELD_OPTIONS_KEY.to_string(), value);
+        }
+        Ok(())
     }
 
     pub fn add_columns(&mut self, fields: &[TableField]) -> Result<()> {
@@ -483,7 +604,15 @@ impl TableSchema {
             ));
         }
         let i = self.index_of(column)?;
-        self.fields.remove(i);
+        let field = self.fields.remove(i);
+
+        let mut evolutions = self.field_evolutions();
+        if evolutions.remove(&field.column_id).is_some() {
+            self.set_field_evolutions(&evolutions)?;
+        }
+        if !self.field_options(field.column_id).is_empty() {
+            self.set_field_options(field.column_id, BTreeMap::new())?;
+        }
 
         Ok(i)
     }
@@ -569,6 +569,105 @@ fn test_schema_modify_field() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_schema_evolve_field() -> Result<()> {
+    let tuple = TableDataType::Tuple {
+        fields_name: vec!["a".to_string(), "b".to_string()],
+        fields_type: vec![
+            TableDataType::Number(NumberDataType::Int32),
+            TableDataType::Number(NumberDataType::Int32),
+        ],
+    };
+    let mut schema = TableSchema::new(vec![
+        TableField::new("x", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("t", tuple.clone()),
+    ]);
+    assert_eq!(schema.to_leaf_column_ids(), vec![0, 1, 2]);
+    assert!(!schema.has_evolved_fields());
+
+    // widen x
+    schema.evolve_field(0, TableDataType::Number(NumberDataType::Int64))?;
+    assert_eq!(schema.column_id_of("x")?, 3);
+    assert_eq!(schema.next_column_id(), 4);
+    assert!(schema.has_evolved_fields());
+    let previous = schema.previous_fields_of(3);
+    assert_eq!(previous.len(), 1);
+    assert_eq!(previous[0].column_id(), 0);
+    assert_eq!(
+        previous[0].data_type(),
+        &TableDataType::Number(NumberDataType::Int32)
+    );
+    assert_eq!(schema.is_column_deleted(0), false);
+    assert_eq!(schema.is_column_deleted(3), false);
+
+    // widen x again, the whole history is kept
+    schema.evolve_field(
+        0,
+        TableDataType::Number(NumberDataType::Int64).wrap_nullable(),
+    )?;
+    assert_eq!(schema.column_id_of("x")?, 4);
+    let previous = schema.previous_fields_of(4);
+    assert_eq!(
+        previous.iter().map(|f| f.column_id()).collect::<Vec<_>>(),
+        vec![3, 0]
+    );
+    assert!(schema.previous_fields_of(3).is_empty());
+
+    // add a field to t
+    let new_tuple = TableDataType::Tuple {
+        fields_name: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        fields_type: vec![
+            TableDataType::Number(NumberDataType::Int32),
+            TableDataType::Number(NumberDataType::Int32),
+            TableDataType::String.wrap_nullable(),
+        ],
+    };
+    schema.evolve_field(1, new_tuple)?;
+    assert_eq!(schema.field_leaf_column_ids(), vec![vec![4], vec![5, 6, 7]]);
+    let previous = schema.previous_fields_of(6);
+    assert_eq!(previous.len(), 1);
+    assert_eq!(previous[0].data_type(), &tuple);
+    assert_eq!(previous[0].leaf_column_ids(), vec![1, 2]);
+    assert_eq!(schema.is_column_deleted(1), false);
+
+    // dropping the field drops its history
+    schema.drop_column("t")?;
+    assert_eq!(schema.is_column_deleted(1), true);
+    assert_eq!(schema.is_column_deleted(0), false);
+
+    Ok(())
+}
+
+#[test]
+fn test_schema_field_options() -> Result<()> {
+    let mut schema = TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::String),
+    ]);
+    assert!(schema.field_options(0).is_empty());
+
+    let options = BTreeMap::from([
+        ("compression".to_string(), "zstd(9)".to_string()),
+        ("encoding".to_string(), "delta".to_string()),
+    ]);
+    schema.set_field_options(0, options.clone())?;
+    assert_eq!(schema.field_options(0), options);
+    assert!(schema.field_options(1).is_empty());
+
+    // the options follow the field when its type is evolved
+    schema.evolve_field(0, TableDataType::Number(NumberDataType::Int64))?;
+    assert_eq!(schema.column_id_of("a")?, 2);
+    assert!(schema.field_options(0).is_empty());
+    assert_eq!(schema.field_options(2), options);
+
+    // and are dropped with the field
+    schema.drop_column("a")?;
+    assert!(schema.field_options(2).is_empty());
+    assert!(!schema.metadata.contains_key("field_options"));
+
+    Ok(())
+}
+
 #[test]
 fn test_leaf_columns_of() -> Result<()> {
     let fields = vec![
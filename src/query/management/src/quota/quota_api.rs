@@ -14,6 +14,7 @@
 
 use common_exception::Result;
 use common_meta_app::tenant::TenantQuota;
+use common_meta_app::tenant::TenantUsage;
 use common_meta_types::MatchSeq;
 use common_meta_types::SeqV;
 
@@ -22,4 +23,7 @@ pub trait QuotaApi: Sync + Send {
     async fn get_quota(&self, seq: MatchSeq) -> Result<SeqV<TenantQuota>>;
 
     async fn set_quota(&self, quota: &TenantQuota, seq: MatchSeq) -> Result<u64>;
+
+    /// Get the current usage of the resources limited by the quota.
+    async fn get_usage(&self) -> Result<TenantUsage>;
 }
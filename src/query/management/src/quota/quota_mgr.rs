@@ -12,13 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_base::base::escape_for_key;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_api::get_u64_value;
+use common_meta_api::list_u64_value;
+use common_meta_app::schema::DBIdTableName;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::tenant::TenantMetaBytesIdent;
 use common_meta_app::tenant::TenantQuota;
+use common_meta_app::tenant::TenantQuotaIdent;
+use common_meta_app::tenant::TenantUsage;
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::Key;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::IntoSeqV;
 use common_meta_types::MatchSeq;
@@ -28,11 +37,12 @@ use common_meta_types::Operation;
 use common_meta_types::SeqV;
 
 use super::quota_api::QuotaApi;
-
-static QUOTA_API_KEY_PREFIX: &str = "__fd_quotas";
+use crate::stage::USER_STAGE_API_KEY_PREFIX;
+use crate::udf::UDF_API_KEY_PREFIX;
 
 pub struct QuotaMgr {
     kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant: String,
     key: String,
 }
 
@@ -45,9 +55,15 @@ impl QuotaMgr {
         }
         Ok(QuotaMgr {
             kv_api,
-            key: format!("{}/{}", QUOTA_API_KEY_PREFIX, escape_for_key(tenant)?),
+            tenant: tenant.to_string(),
+            key: TenantQuotaIdent::new(tenant).to_string_key(),
         })
     }
+
+    async fn count_keys(&self, prefix: &str) -> Result<u64> {
+        let kvs = self.kv_api.prefix_list_kv(prefix).await?;
+        Ok(kvs.len() as u64)
+    }
 }
 
 #[async_trait::async_trait]
@@ -84,4 +100,44 @@ impl QuotaApi for QuotaMgr {
             )),
         }
     }
+
+    #[async_backtrace::framed]
+    async fn get_usage(&self) -> Result<TenantUsage> {
+        let kv_api = self.kv_api.as_ref();
+
+        let db_prefix = DatabaseNameIdent {
+            tenant: self.tenant.clone(),
+            db_name: "".to_string(),
+        };
+        let (db_names, db_ids) = list_u64_value(kv_api, &db_prefix).await?;
+
+        let mut tables = BTreeMap::new();
+        for (name_ident, db_id) in db_names.into_iter().zip(db_ids) {
+            let table_prefix = DBIdTableName {
+                db_id,
+                table_name: "".to_string(),
+            };
+            let count = self.count_keys(&table_prefix.to_string_key()).await?;
+            tables.insert(name_ident.db_name, count);
+        }
+
+        let tenant = escape_for_key(&self.tenant)?;
+        let stages = self
+            .count_keys(&format!("{}/{}/", USER_STAGE_API_KEY_PREFIX, tenant))
+            .await?;
+        let udfs = self
+            .count_keys(&format!("{}/{}/", UDF_API_KEY_PREFIX, tenant))
+            .await?;
+
+        let (_, meta_bytes) =
+            get_u64_value(kv_api, &TenantMetaBytesIdent::new(&self.tenant)).await?;
+
+        Ok(TenantUsage {
+            databases: tables.len() as u64,
+            tables,
+            stages,
+            udfs,
+            meta_bytes,
+        })
+    }
 }
@@ -17,3 +17,4 @@ mod stage_mgr;
 
 pub use stage_api::StageApi;
 pub use stage_mgr::StageMgr;
+pub(crate) use stage_mgr::USER_STAGE_API_KEY_PREFIX;
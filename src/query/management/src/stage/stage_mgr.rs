@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_base::base::escape_for_key;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_api::check_create_quota;
 use common_meta_api::reply::txn_reply_to_api_result;
 use common_meta_api::txn_cond_seq;
 use common_meta_api::txn_op_del;
@@ -25,13 +26,15 @@ use common_meta_app::app_error::TxnRetryMaxTimes;
 use common_meta_app::principal::StageFile;
 use common_meta_app::principal::StageInfo;
 use common_meta_kvapi::kvapi;
-use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::txn_op::Request;
+use common_meta_types::txn_op_response::Response;
 use common_meta_types::ConditionResult::Eq;
 use common_meta_types::MatchSeq;
 use common_meta_types::MatchSeqExt;
 use common_meta_types::MetaError;
-use common_meta_types::Operation;
 use common_meta_types::SeqV;
+use common_meta_types::TxnCondition;
+use common_meta_types::TxnGetRequest;
 use common_meta_types::TxnOp;
 use common_meta_types::TxnRequest;
 
@@ -39,12 +42,13 @@ use crate::serde::deserialize_struct;
 use crate::serde::serialize_struct;
 use crate::stage::StageApi;
 
-static USER_STAGE_API_KEY_PREFIX: &str = "__fd_stages";
+pub(crate) static USER_STAGE_API_KEY_PREFIX: &str = "__fd_stages";
 static STAGE_FILE_API_KEY_PREFIX: &str = "__fd_stage_files";
 const TXN_MAX_RETRY_TIMES: u32 = 10;
 
 pub struct StageMgr {
     kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant: String,
     stage_prefix: String,
     stage_file_prefix: String,
 }
@@ -59,6 +63,7 @@ impl StageMgr {
 
         Ok(StageMgr {
             kv_api,
+            tenant: tenant.to_string(),
             stage_prefix: format!("{}/{}", USER_STAGE_API_KEY_PREFIX, escape_for_key(tenant)?),
             stage_file_prefix: format!("{}/{}", STAGE_FILE_API_KEY_PREFIX, escape_for_key(tenant)?),
         })
@@ -70,26 +75,66 @@ impl StageApi for StageMgr {
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn add_stage(&self, info: StageInfo) -> Result<u64> {
-        let seq = MatchSeq::Exact(0);
-        let val = Operation::Update(serialize_struct(
-            &info,
-            ErrorCode::IllegalUserStageFormat,
-            || "",
-        )?);
+        let val = serialize_struct(&info, ErrorCode::IllegalUserStageFormat, || "")?;
         let key = format!(
             "{}/{}",
             self.stage_prefix,
             escape_for_key(&info.stage_name)?
         );
-        let upsert_info = self
-            .kv_api
-            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
 
-        let res_seq = upsert_info.await?.added_seq_or_else(|v| {
-            ErrorCode::StageAlreadyExists(format!("Stage already exists, seq [{}]", v.seq))
-        })?;
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (mut condition, mut if_then) = check_create_quota(
+                self.kv_api.as_ref(),
+                &self.tenant,
+                "stages",
+                |q| q.max_stages,
+                &format!("{}/", self.stage_prefix),
+                None,
+                "add_stage",
+            )
+            .await?;
+
+            // stage does not exist
+            condition.push(TxnCondition::exists(&key, false));
+            if_then.push(txn_op_put(&key, val.clone()));
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                // Tell whether the stage exists or the quota is changed, if the txn fails.
+                else_then: vec![TxnOp {
+                    request: Some(Request::Get(TxnGetRequest { key: key.clone() })),
+                }],
+            };
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, responses) = txn_reply_to_api_result(tx_reply)?;
 
-        Ok(res_seq)
+            if !succ {
+                let existing = match responses.first().and_then(|r| r.response.as_ref()) {
+                    Some(Response::Get(get_resp)) => get_resp.value.as_ref(),
+                    _ => None,
+                };
+                if let Some(seq_v) = existing {
+                    return Err(ErrorCode::StageAlreadyExists(format!(
+                        "Stage already exists, seq [{}]",
+                        seq_v.seq
+                    )));
+                }
+            }
+
+            if succ {
+                // The seq of the added stage, it is 0 if the stage is just removed by others.
+                let seq = self.kv_api.get_kv(&key).await?.map(|v| v.seq);
+                return Ok(seq.unwrap_or_default());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("add_stage", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
     }
 
     #[async_backtrace::framed]
@@ -17,3 +17,4 @@ mod udf_mgr;
 
 pub use udf_api::UdfApi;
 pub use udf_mgr::UdfMgr;
+pub(crate) use udf_mgr::UDF_API_KEY_PREFIX;
@@ -18,23 +18,35 @@ use common_base::base::escape_for_key;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::is_builtin_function;
+use common_meta_api::check_create_quota;
+use common_meta_api::reply::txn_reply_to_api_result;
+use common_meta_api::txn_op_put;
+use common_meta_app::app_error::TxnRetryMaxTimes;
 use common_meta_app::principal::UserDefinedFunction;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::txn_op::Request;
+use common_meta_types::txn_op_response::Response;
 use common_meta_types::MatchSeq;
 use common_meta_types::MatchSeqExt;
 use common_meta_types::MetaError;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
+use common_meta_types::TxnCondition;
+use common_meta_types::TxnGetRequest;
+use common_meta_types::TxnOp;
+use common_meta_types::TxnRequest;
 
 use crate::serde::deserialize_struct;
 use crate::serde::serialize_struct;
 use crate::udf::UdfApi;
 
-static UDF_API_KEY_PREFIX: &str = "__fd_udfs";
+pub(crate) static UDF_API_KEY_PREFIX: &str = "__fd_udfs";
+const TXN_MAX_RETRY_TIMES: u32 = 10;
 
 pub struct UdfMgr {
     kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant: String,
     udf_prefix: String,
 }
 
@@ -48,6 +60,7 @@ impl UdfMgr {
 
         Ok(UdfMgr {
             kv_api,
+            tenant: tenant.to_string(),
             udf_prefix: format!("{}/{}", UDF_API_KEY_PREFIX, escape_for_key(tenant)?),
         })
     }
@@ -65,18 +78,62 @@ impl UdfApi for UdfMgr {
             )));
         }
 
-        let seq = MatchSeq::Exact(0);
-        let val = Operation::Update(serialize_struct(&info, ErrorCode::IllegalUDFFormat, || "")?);
+        let val = serialize_struct(&info, ErrorCode::IllegalUDFFormat, || "")?;
         let key = format!("{}/{}", self.udf_prefix, escape_for_key(&info.name)?);
-        let upsert_info = self
-            .kv_api
-            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
 
-        let res_seq = upsert_info.await?.added_seq_or_else(|v| {
-            ErrorCode::UdfAlreadyExists(format!("UDF already exists, seq [{}]", v.seq))
-        })?;
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (mut condition, mut if_then) = check_create_quota(
+                self.kv_api.as_ref(),
+                &self.tenant,
+                "udfs",
+                |q| q.max_udfs,
+                &format!("{}/", self.udf_prefix),
+                None,
+                "add_udf",
+            )
+            .await?;
+
+            // udf does not exist
+            condition.push(TxnCondition::exists(&key, false));
+            if_then.push(txn_op_put(&key, val.clone()));
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                // Tell whether the udf exists or the quota is changed, if the txn fails.
+                else_then: vec![TxnOp {
+                    request: Some(Request::Get(TxnGetRequest { key: key.clone() })),
+                }],
+            };
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, responses) = txn_reply_to_api_result(tx_reply)?;
+
+            if !succ {
+                let existing = match responses.first().and_then(|r| r.response.as_ref()) {
+                    Some(Response::Get(get_resp)) => get_resp.value.as_ref(),
+                    _ => None,
+                };
+                if let Some(seq_v) = existing {
+                    return Err(ErrorCode::UdfAlreadyExists(format!(
+                        "UDF already exists, seq [{}]",
+                        seq_v.seq
+                    )));
+                }
+            }
+
+            if succ {
+                // The seq of the added udf, it is 0 if the udf is just removed by others.
+                let seq = self.kv_api.get_kv(&key).await?.map(|v| v.seq);
+                return Ok(seq.unwrap_or_default());
+            }
+        }
 
-        Ok(res_seq)
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("add_udf", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
     }
 
     #[async_backtrace::framed]
@@ -25,6 +25,8 @@ use common_meta_app::principal::UserIdentity;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::KVStream;
+use common_meta_kvapi::kvapi::LeaseReply;
+use common_meta_kvapi::kvapi::LeaseReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReply;
@@ -63,6 +65,8 @@ mock! {
 
         async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError>;
 
+        async fn lease(&self, req: LeaseReq) -> Result<LeaseReply, MetaError>;
+
         }
 }
 
@@ -0,0 +1,288 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::table::Table;
+use common_catalog::table::TableExt;
+use common_catalog::table_context::TableContext;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_meta_app::principal::UserInfo;
+use common_storages_fuse::operations::AutoCompactionPolicy;
+use common_storages_fuse::operations::SmallBlockStats;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::DEFAULT_AUTO_COMPACTION_INTERVAL;
+use common_storages_system::CompactionHistoryLogElement;
+use common_storages_system::CompactionHistoryQueue;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use log::info;
+use log::warn;
+
+use crate::interpreters::common::compact_table;
+use crate::interpreters::common::expire_table;
+use crate::interpreters::common::CompactTargetTableDescription;
+use crate::locks::LockManager;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// Compacts (and reclusters) the fuse tables with the `auto_compaction` option enabled,
+/// once the ratio of their small blocks exceeds the threshold of the table.
+///
+/// The expired rows of the fuse tables with a TTL are removed as well, every
+/// `auto_compaction_interval` seconds.
+///
+/// The service only runs on the query nodes with `enable_auto_compaction` set, which is
+/// expected to be one node of a cluster. The table lock still makes sure that a table is
+/// not compacted along with other mutations.
+pub struct AutoCompactionService {
+    config: InnerConfig,
+    // table id -> last time the table was checked by this node.
+    last_checks: HashMap<u64, Instant>,
+}
+
+impl AutoCompactionService {
+    pub fn start(config: &InnerConfig) {
+        let mut service = AutoCompactionService {
+            config: config.clone(),
+            last_checks: HashMap::new(),
+        };
+        let check_interval = Duration::from_secs(config.query.auto_compaction_check_interval_secs);
+        GlobalIORuntime::instance().spawn("auto-compaction", async move {
+            loop {
+                sleep(check_interval).await;
+                if let Err(e) = service.check_tables().await {
+                    warn!("auto compaction failed to check tables: {}", e);
+                }
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn check_tables(&mut self) -> Result<()> {
+        let tenant = self.config.query.tenant_id.clone();
+        let catalog = CatalogManager::instance().get_default_catalog()?;
+        let mut live_tables = HashMap::new();
+        for database in catalog.list_databases(&tenant).await? {
+            for table in database.list_tables().await? {
+                let (policy, has_ttl) = match FuseTable::try_from_table(table.as_ref()) {
+                    Ok(fuse_table) => (
+                        fuse_table.auto_compaction_policy(),
+                        fuse_table.ttl().is_some(),
+                    ),
+                    Err(_) => (None, false),
+                };
+                if policy.is_none() && !has_ttl {
+                    continue;
+                }
+                let interval = match &policy {
+                    Some(policy) => policy.interval,
+                    None => Duration::from_secs(DEFAULT_AUTO_COMPACTION_INTERVAL),
+                };
+
+                let table_id = table.get_id();
+                if let Some(last_check) = self.last_checks.get(&table_id).cloned() {
+                    if last_check.elapsed() < interval {
+                        live_tables.insert(table_id, last_check);
+                        continue;
+                    }
+                }
+                live_tables.insert(table_id, Instant::now());
+
+                let database_name = database.name().to_string();
+                let table_name = table.name().to_string();
+                // expire first, the removed rows may leave small blocks to compact.
+                if has_ttl {
+                    if let Err(e) = self.expire_rows(&database_name, &table_name).await {
+                        warn!(
+                            "auto removal of the expired rows of table {}.{} failed: {}",
+                            database_name, table_name, e
+                        );
+                    }
+                }
+                let policy = match policy {
+                    Some(policy) => policy,
+                    None => continue,
+                };
+                let table = if has_ttl {
+                    // the expired rows have been removed, reload the table.
+                    match database.get_table(&table_name).await {
+                        Ok(table) => table,
+                        Err(_) => continue,
+                    }
+                } else {
+                    table
+                };
+                if let Err(e) = self.check_table(&database_name, table, &policy).await {
+                    warn!(
+                        "auto compaction of table {}.{} failed: {}",
+                        database_name, table_name, e
+                    );
+                }
+            }
+        }
+        // forget the dropped tables.
+        self.last_checks = live_tables;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn check_table(
+        &self,
+        database: &str,
+        table: Arc<dyn Table>,
+        policy: &AutoCompactionPolicy,
+    ) -> Result<()> {
+        let ctx = self.create_query_context().await?;
+        let stats = FuseTable::try_from_table(table.as_ref())?
+            .small_block_stats(ctx.clone())
+            .await?;
+        if !Self::need_compact(&stats, policy) {
+            return Ok(());
+        }
+
+        // other nodes may be compacting the table, or a mutation is running on it.
+        let table_lock = LockManager::create_table_lock(table.get_table_info().clone())?;
+        let _guard = table_lock.try_lock(ctx.clone()).await?;
+
+        // the table may have been compacted while waiting for the lock.
+        let table = table.refresh(ctx.as_ref()).await?;
+        let stats = FuseTable::try_from_table(table.as_ref())?
+            .small_block_stats(ctx.clone())
+            .await?;
+        if !Self::need_compact(&stats, policy) {
+            return Ok(());
+        }
+
+        info!(
+            "auto compact table {}.{}, {} of {} blocks are small",
+            database,
+            table.name(),
+            stats.small_block_count,
+            stats.block_count
+        );
+        let start = SystemTime::now();
+        let table_name = table.name().to_string();
+        let compact_target = CompactTargetTableDescription {
+            catalog: ctx.get_current_catalog(),
+            database: database.to_string(),
+            table: table_name.clone(),
+        };
+        let result = compact_table(ctx.clone(), compact_target, false).await;
+
+        let block_count_after = match &result {
+            Ok(false) => {
+                // no blocks can be merged, e.g. the small blocks are in different clusters.
+                info!(
+                    "auto compaction of table {}.{} is skipped, nothing to compact",
+                    database, table_name
+                );
+                return Ok(());
+            }
+            Ok(true) => {
+                let table = table.refresh(ctx.as_ref()).await?;
+                FuseTable::try_from_table(table.as_ref())?
+                    .small_block_stats(ctx.clone())
+                    .await?
+                    .block_count
+            }
+            Err(_) => stats.block_count,
+        };
+        Self::write_log(
+            database,
+            &table_name,
+            start,
+            &stats,
+            block_count_after,
+            &result,
+        )?;
+        result.map(|_| ())
+    }
+
+    #[async_backtrace::framed]
+    async fn expire_rows(&self, database: &str, table: &str) -> Result<()> {
+        let ctx = self.create_query_context().await?;
+        let target = CompactTargetTableDescription {
+            catalog: ctx.get_current_catalog(),
+            database: database.to_string(),
+            table: table.to_string(),
+        };
+        expire_table(ctx, target).await
+    }
+
+    fn need_compact(stats: &SmallBlockStats, policy: &AutoCompactionPolicy) -> bool {
+        // a single small block can not be merged with anything.
+        stats.small_block_count > 1 && stats.ratio() > policy.max_small_block_ratio
+    }
+
+    async fn create_query_context(&self) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::AutoCompaction)
+            .await?;
+        let user = UserInfo::new_no_auth(
+            &format!(
+                "{}-{}-auto-compaction",
+                self.config.query.tenant_id, self.config.query.cluster_id
+            ),
+            "0.0.0.0",
+        );
+        session
+            .set_authed_user(user, Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()))
+            .await?;
+        session.create_query_context().await
+    }
+
+    fn write_log(
+        database: &str,
+        table: &str,
+        start: SystemTime,
+        stats: &SmallBlockStats,
+        block_count_after: u64,
+        result: &Result<bool>,
+    ) -> Result<()> {
+        let (status, error_message) = match result {
+            Ok(_) => ("Succeeded".to_string(), String::new()),
+            Err(e) => ("Failed".to_string(), e.to_string()),
+        };
+        CompactionHistoryQueue::instance()?.append_data(CompactionHistoryLogElement {
+            start_time: start
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_micros() as i64,
+            end_time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_micros() as i64,
+            database: database.to_string(),
+            table: table.to_string(),
+            operation: "compact".to_string(),
+            block_count_before: stats.block_count,
+            small_block_count_before: stats.small_block_count,
+            block_count_after,
+            removed_row_count: 0,
+            status,
+            error_message,
+        })
+    }
+}
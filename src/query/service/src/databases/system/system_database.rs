@@ -29,6 +29,7 @@ use common_storages_system::CatalogsTable;
 use common_storages_system::ClusteringHistoryTable;
 use common_storages_system::ClustersTable;
 use common_storages_system::ColumnsTable;
+use common_storages_system::CompactionHistoryTable;
 use common_storages_system::ConfigsTable;
 use common_storages_system::ContributorsTable;
 use common_storages_system::CreditsTable;
@@ -38,6 +39,7 @@ use common_storages_system::FunctionsTable;
 use common_storages_system::IndexesTable;
 use common_storages_system::MallocStatsTable;
 use common_storages_system::MallocStatsTotalsTable;
+use common_storages_system::MetaAuditLogTable;
 use common_storages_system::MetricsTable;
 use common_storages_system::OneTable;
 use common_storages_system::ProcessesTable;
@@ -56,6 +58,7 @@ use common_storages_system::TablesTableWithoutHistory;
 use common_storages_system::TaskHistoryTable;
 use common_storages_system::TasksTable;
 use common_storages_system::TempFilesTable;
+use common_storages_system::TenantUsageTable;
 use common_storages_system::TracingTable;
 use common_storages_system::UsersTable;
 
@@ -105,6 +108,10 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(CompactionHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -117,8 +124,10 @@ impl SystemDatabase {
             QueryProfileTable::create(sys_db_meta.next_table_id()),
             BackgroundTaskTable::create(sys_db_meta.next_table_id()),
             BackgroundJobTable::create(sys_db_meta.next_table_id()),
+            MetaAuditLogTable::create(sys_db_meta.next_table_id()),
             BacktraceTable::create(sys_db_meta.next_table_id()),
             TempFilesTable::create(sys_db_meta.next_table_id()),
+            TenantUsageTable::create(sys_db_meta.next_table_id()),
             QuerySummaryTable::create(sys_db_meta.next_table_id()),
             TasksTable::create(sys_db_meta.next_table_id()),
             TaskHistoryTable::create(sys_db_meta.next_table_id()),
@@ -28,6 +28,7 @@ use common_meta_app::schema::CatalogType;
 use common_profile::QueryProfileManager;
 use common_sharing::ShareEndpointManager;
 use common_storage::DataOperator;
+use common_storage::LocalSpillDisk;
 use common_storage::ShareTableConfig;
 use common_storages_hive::HiveCreator;
 use common_storages_iceberg::IcebergCreator;
@@ -121,6 +122,10 @@ impl GlobalServices {
         QueryProfileManager::init();
 
         DataOperator::init(&config.storage).await?;
+        LocalSpillDisk::init(
+            &config.query.spill_local_disk_path,
+            config.query.spill_local_disk_max_bytes,
+        )?;
         ShareTableConfig::init(
             &config.query.share_endpoint_address,
             &config.query.share_endpoint_auth_token_file,
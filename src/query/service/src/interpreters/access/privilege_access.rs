@@ -273,6 +273,14 @@ impl AccessChecker for PrivilegeAccess {
                     if table.is_source_of_view() {
                         continue;
                     }
+                    if table.database().eq_ignore_ascii_case("system")
+                        && table.name().eq_ignore_ascii_case("meta_audit_log")
+                    {
+                        // The audit log records the changes made by all the users of the tenant.
+                        self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
+                            .await?;
+                        continue;
+                    }
                     self.validate_access(
                         &GrantObject::Table(
                             table.catalog().to_string(),
@@ -883,6 +891,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::AlterUser(_)
             | Plan::RenameDatabase(_)
             | Plan::RevertTable(_)
+            | Plan::CreateTableTag(_)
+            | Plan::DropTableTag(_)
+            | Plan::DropTablePartition(_)
             | Plan::RefreshIndex(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Alter], false)
                     .await?;
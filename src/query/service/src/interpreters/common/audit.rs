@@ -0,0 +1,437 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::SendableDataBlockStream;
+use common_meta_api::AuditApi;
+use common_meta_app::audit::AppendAuditRecordReq;
+use common_meta_app::audit::AuditRecord;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::UserIdentity;
+use common_pipeline_core::SourcePipeBuilder;
+use common_sql::plans::Plan;
+use common_users::UserApiProvider;
+use log::warn;
+use serde_json::json;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// The object a schema or privilege change is applied to.
+enum AuditObject {
+    Database {
+        catalog: String,
+        database: String,
+    },
+    RenamedDatabase {
+        catalog: String,
+        database: String,
+        new_database: String,
+    },
+    Table {
+        catalog: String,
+        database: String,
+        table: String,
+    },
+    RenamedTable {
+        catalog: String,
+        database: String,
+        table: String,
+        new_database: String,
+        new_table: String,
+    },
+    User(UserIdentity),
+    Role(String),
+}
+
+impl AuditObject {
+    fn from_principal(principal: &PrincipalIdentity) -> Self {
+        match principal {
+            PrincipalIdentity::User(user) => AuditObject::User(user.clone()),
+            PrincipalIdentity::Role(role) => AuditObject::Role(role.clone()),
+        }
+    }
+
+    fn table(catalog: &str, database: &str, table: &str) -> Self {
+        AuditObject::Table {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+        }
+    }
+
+    fn database(catalog: &str, database: &str) -> Self {
+        AuditObject::Database {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+        }
+    }
+
+    /// Returns the operation name and the object, if the plan changes the schema or the privileges.
+    fn from_plan(plan: &Plan) -> Option<(&'static str, AuditObject)> {
+        let audit = match plan {
+            Plan::CreateDatabase(p) => ("CreateDatabase", Self::database(&p.catalog, &p.database)),
+            Plan::DropDatabase(p) => ("DropDatabase", Self::database(&p.catalog, &p.database)),
+            Plan::UndropDatabase(p) => ("UndropDatabase", Self::database(&p.catalog, &p.database)),
+            Plan::RenameDatabase(p) => {
+                let entity = p.entities.first()?;
+                ("RenameDatabase", AuditObject::RenamedDatabase {
+                    catalog: entity.catalog.clone(),
+                    database: entity.database.clone(),
+                    new_database: entity.new_database.clone(),
+                })
+            }
+
+            Plan::CreateTable(p) => (
+                "CreateTable",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTable(p) => ("DropTable", Self::table(&p.catalog, &p.database, &p.table)),
+            Plan::UndropTable(p) => (
+                "UndropTable",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::RenameTable(p) => ("RenameTable", AuditObject::RenamedTable {
+                catalog: p.catalog.clone(),
+                database: p.database.clone(),
+                table: p.table.clone(),
+                new_database: p.new_database.clone(),
+                new_table: p.new_table.clone(),
+            }),
+            Plan::AddTableColumn(p) => (
+                "AddTableColumn",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::RenameTableColumn(p) => (
+                "RenameTableColumn",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::ModifyTableColumn(p) => (
+                "ModifyTableColumn",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTableColumn(p) => (
+                "DropTableColumn",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::AlterTableClusterKey(p) => (
+                "AlterTableClusterKey",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTableClusterKey(p) => (
+                "DropTableClusterKey",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::SetOptions(p) => ("SetOptions", Self::table(&p.catalog, &p.database, &p.table)),
+            Plan::TruncateTable(p) => (
+                "TruncateTable",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::RevertTable(p) => (
+                "RevertTable",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::CreateTableTag(p) => (
+                "CreateTableTag",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTableTag(p) => (
+                "DropTableTag",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTablePartition(p) => (
+                "DropTablePartition",
+                Self::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::CreateView(p) => (
+                "CreateView",
+                Self::table(&p.catalog, &p.database, &p.view_name),
+            ),
+            Plan::AlterView(p) => (
+                "AlterView",
+                Self::table(&p.catalog, &p.database, &p.view_name),
+            ),
+            Plan::DropView(p) => (
+                "DropView",
+                Self::table(&p.catalog, &p.database, &p.view_name),
+            ),
+
+            Plan::CreateUser(p) => ("CreateUser", AuditObject::User(p.user.clone())),
+            Plan::AlterUser(p) => ("AlterUser", AuditObject::User(p.user.clone())),
+            Plan::DropUser(p) => ("DropUser", AuditObject::User(p.user.clone())),
+            Plan::CreateRole(p) => ("CreateRole", AuditObject::Role(p.role_name.clone())),
+            Plan::DropRole(p) => ("DropRole", AuditObject::Role(p.role_name.clone())),
+            Plan::GrantRole(p) => ("GrantRole", Self::from_principal(&p.principal)),
+            Plan::RevokeRole(p) => ("RevokeRole", Self::from_principal(&p.principal)),
+            Plan::GrantPriv(p) => ("GrantPrivilege", Self::from_principal(&p.principal)),
+            Plan::RevokePriv(p) => ("RevokePrivilege", Self::from_principal(&p.principal)),
+            _ => return None,
+        };
+        Some(audit)
+    }
+
+    fn name(&self) -> String {
+        match self {
+            AuditObject::Database { catalog, database } => format!("{}.{}", catalog, database),
+            AuditObject::RenamedDatabase {
+                catalog,
+                database,
+                new_database,
+            } => format!("{}.{} -> {}.{}", catalog, database, catalog, new_database),
+            AuditObject::Table {
+                catalog,
+                database,
+                table,
+            } => format!("{}.{}.{}", catalog, database, table),
+            AuditObject::RenamedTable {
+                catalog,
+                database,
+                table,
+                new_database,
+                new_table,
+            } => format!(
+                "{}.{}.{} -> {}.{}.{}",
+                catalog, database, table, catalog, new_database, new_table
+            ),
+            AuditObject::User(user) => user.to_string(),
+            AuditObject::Role(role) => format!("ROLE '{}'", role),
+        }
+    }
+
+    /// Capture the state of the object before the operation.
+    ///
+    /// `None` if the object does not exist, or it can not be read.
+    async fn before(&self, ctx: &QueryContext) -> Option<String> {
+        match self {
+            AuditObject::RenamedDatabase {
+                catalog, database, ..
+            } => database_snapshot(ctx, catalog, database).await.ok(),
+            AuditObject::RenamedTable {
+                catalog,
+                database,
+                table,
+                ..
+            } => table_snapshot(ctx, catalog, database, table).await.ok(),
+            _ => self.snapshot(ctx).await.ok(),
+        }
+    }
+
+    /// Capture the state of the object after the operation.
+    async fn after(&self, ctx: &QueryContext) -> Option<String> {
+        match self {
+            AuditObject::RenamedDatabase {
+                catalog,
+                new_database,
+                ..
+            } => database_snapshot(ctx, catalog, new_database).await.ok(),
+            AuditObject::RenamedTable {
+                catalog,
+                new_database,
+                new_table,
+                ..
+            } => table_snapshot(ctx, catalog, new_database, new_table)
+                .await
+                .ok(),
+            _ => self.snapshot(ctx).await.ok(),
+        }
+    }
+
+    async fn snapshot(&self, ctx: &QueryContext) -> Result<String> {
+        let tenant = ctx.get_tenant();
+        let snapshot = match self {
+            AuditObject::Database { catalog, database } => {
+                return database_snapshot(ctx, catalog, database).await;
+            }
+            AuditObject::Table {
+                catalog,
+                database,
+                table,
+            } => {
+                return table_snapshot(ctx, catalog, database, table).await;
+            }
+            AuditObject::RenamedDatabase { .. } | AuditObject::RenamedTable { .. } => {
+                unreachable!("renamed object has different before and after snapshot")
+            }
+            AuditObject::User(user) => {
+                let user = UserApiProvider::instance()
+                    .get_user(&tenant, user.clone())
+                    .await?;
+                json!({
+                    "grants": user.grants.to_string(),
+                    "option": format!("{:?}", user.option),
+                })
+            }
+            AuditObject::Role(role) => {
+                let role = UserApiProvider::instance()
+                    .get_role(&tenant, role.clone())
+                    .await?;
+                json!({
+                    "grants": role.grants.to_string(),
+                })
+            }
+        };
+        Ok(snapshot.to_string())
+    }
+}
+
+async fn database_snapshot(ctx: &QueryContext, catalog: &str, database: &str) -> Result<String> {
+    let catalog = ctx.get_catalog(catalog).await?;
+    let db = catalog.get_database(&ctx.get_tenant(), database).await?;
+    let info = db.get_db_info();
+    let snapshot = json!({
+        "id": info.ident.db_id,
+        "engine": info.meta.engine,
+        "comment": info.meta.comment,
+    });
+    Ok(snapshot.to_string())
+}
+
+async fn table_snapshot(
+    ctx: &QueryContext,
+    catalog: &str,
+    database: &str,
+    table: &str,
+) -> Result<String> {
+    // Read from the catalog directly, the table cached in the context may be stale.
+    let catalog = ctx.get_catalog(catalog).await?;
+    let table = catalog
+        .get_table(&ctx.get_tenant(), database, table)
+        .await?;
+    let info = table.get_table_info();
+    let columns = info
+        .meta
+        .schema
+        .fields()
+        .iter()
+        .map(|f| format!("{} {}", f.name(), f.data_type()))
+        .collect::<Vec<_>>();
+    let snapshot = json!({
+        "id": info.ident.table_id,
+        "engine": info.meta.engine,
+        "columns": columns,
+        "cluster_key": info.meta.default_cluster_key,
+        "options": info.meta.options,
+    });
+    Ok(snapshot.to_string())
+}
+
+/// Wraps the interpreter of a schema or privilege change,
+/// to append an audit record to the meta-service when it succeeds.
+///
+/// The plans that do not change the schema or the privileges are not wrapped,
+/// neither are they when the audit log is disabled.
+pub fn hook_audit_log(
+    ctx: Arc<QueryContext>,
+    plan: &Plan,
+    interpreter: InterpreterPtr,
+) -> Result<InterpreterPtr> {
+    let retention_days = ctx.get_settings().get_meta_audit_log_retention_days()?;
+    if retention_days == 0 {
+        return Ok(interpreter);
+    }
+
+    match AuditObject::from_plan(plan) {
+        Some((operation, object)) => Ok(Arc::new(AuditInterpreter {
+            ctx,
+            inner: interpreter,
+            operation,
+            object,
+            retention_days,
+        })),
+        None => Ok(interpreter),
+    }
+}
+
+struct AuditInterpreter {
+    ctx: Arc<QueryContext>,
+    inner: InterpreterPtr,
+    operation: &'static str,
+    object: AuditObject,
+    retention_days: u64,
+}
+
+impl AuditInterpreter {
+    async fn append(&self, before: Option<String>, after: Option<String>) -> Result<()> {
+        let user = self.ctx.get_current_user()?;
+        let client_address = match self.ctx.get_client_address() {
+            Some(addr) => addr.to_string(),
+            None => "".to_string(),
+        };
+        let created_on = Utc::now();
+        let expire_at = created_on.timestamp() as u64 + self.retention_days * 24 * 3600;
+
+        let record = AuditRecord {
+            user: user.identity().to_string(),
+            client_address,
+            query_id: self.ctx.get_id(),
+            operation: self.operation.to_string(),
+            object: self.object.name(),
+            before,
+            after,
+            query_text: self.ctx.get_query_str(),
+            created_on,
+        };
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        meta_api
+            .append_audit_record(AppendAuditRecordReq {
+                tenant: self.ctx.get_tenant(),
+                record,
+                expire_at,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AuditInterpreter {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let before = self.object.before(&self.ctx).await;
+
+        let build_res = self.inner.execute2().await?;
+
+        // The change has been made, a failure to audit it does not fail the query.
+        let after = self.object.after(&self.ctx).await;
+        if let Err(e) = self.append(before, after).await {
+            warn!(
+                "fail to append audit record of {} {} (ignored): {}",
+                self.operation,
+                self.object.name(),
+                e
+            );
+        }
+
+        Ok(build_res)
+    }
+
+    fn set_source_pipe_builder(&self, builder: Option<SourcePipeBuilder>) -> Result<()> {
+        self.inner.set_source_pipe_builder(builder)
+    }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        self.inner.inject_result()
+    }
+}
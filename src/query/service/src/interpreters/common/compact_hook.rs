@@ -96,11 +96,12 @@ async fn do_hook_compact(
     Ok(())
 }
 
-async fn compact_table(
+/// Compacts the table, returns false if there is nothing to compact.
+pub async fn compact_table(
     ctx: Arc<QueryContext>,
     compact_target: CompactTargetTableDescription,
     need_lock: bool,
-) -> Result<()> {
+) -> Result<bool> {
     // build the compact pipeline
     ctx.evict_table_from_cache(
         &compact_target.catalog,
@@ -120,7 +121,7 @@ async fn compact_table(
     let mut build_res = optimize_interpreter.execute2().await?;
 
     if build_res.main_pipeline.is_empty() {
-        return Ok(());
+        return Ok(false);
     }
 
     // execute the compact pipeline (for table with cluster keys, re-cluster will also be executed)
@@ -138,5 +139,5 @@ async fn compact_table(
         ctx.set_executor(complete_executor.get_inner())?;
         complete_executor.execute()?;
     }
-    Ok(())
+    Ok(true)
 }
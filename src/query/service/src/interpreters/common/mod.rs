@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit;
 mod compact_hook;
 mod grant;
 mod metrics;
@@ -20,18 +21,23 @@ mod refresh_aggregating_index;
 mod stream;
 mod table;
 mod task;
+mod ttl;
 mod util;
 
+pub use audit::hook_audit_log;
 pub use compact_hook::*;
 pub use grant::validate_grant_object_exists;
 pub use query_log::InterpreterQueryLog;
 pub use refresh_aggregating_index::hook_refresh_agg_index;
 pub use refresh_aggregating_index::RefreshAggIndexDesc;
 pub use stream::build_update_stream_meta_seq;
+pub use table::check_column_storage_options;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_ttl;
 pub use task::get_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
+pub use ttl::expire_table;
 pub use util::check_deduplicate_label;
 pub use util::create_push_down_filters;
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::Result;
@@ -71,9 +72,11 @@ fn get_stream_table(metadata: &MetadataRef) -> Result<Vec<Arc<dyn Table>>> {
     let r_lock = metadata.read();
     let tables = r_lock.tables();
     let mut streams = vec![];
+    let mut stream_ids = HashSet::new();
     for t in tables {
         let table = t.table();
-        if table.engine() == STREAM_ENGINE {
+        // A stream in standard mode is bound more than once, to read both sides of the changes.
+        if table.engine() == STREAM_ENGINE && stream_ids.insert(table.get_id()) {
             streams.push(table);
         }
     }
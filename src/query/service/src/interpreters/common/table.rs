@@ -12,14 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ComputedExpr;
 use common_expression::DataSchemaRef;
+use common_expression::TableSchema;
 use common_sql::parse_computed_expr;
+use common_sql::parse_exprs;
+use common_storages_fuse::FuseStorageFormat;
+use storages_common_table_meta::table::ColumnStorageOptions;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +64,52 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+pub fn check_referenced_ttl(
+    ctx: Arc<dyn TableContext>,
+    table: Arc<dyn Table>,
+    column: &str,
+) -> Result<()> {
+    let ttl = match table.options().get(OPT_KEY_TTL) {
+        Some(ttl) => ttl.clone(),
+        None => return Ok(()),
+    };
+    let index = table.schema().index_of(column)?;
+    let exprs = parse_exprs(ctx, table.clone(), &ttl)?;
+    if exprs
+        .iter()
+        .any(|expr| expr.column_refs().contains_key(&index))
+    {
+        return Err(ErrorCode::InvalidTableTtl(format!(
+            "column `{}` is referenced by the TTL `{}` of table {}",
+            column,
+            ttl,
+            table.name()
+        )));
+    }
+    Ok(())
+}
+
+/// Check the storage options of the columns are supported by the storage format of the
+/// fuse table with the table options.
+pub fn check_column_storage_options(
+    schema: &TableSchema,
+    table_options: &BTreeMap<String, String>,
+) -> Result<()> {
+    let storage_format = table_options
+        .get(OPT_KEY_STORAGE_FORMAT)
+        .map(|s| s.as_str())
+        .unwrap_or_default();
+    let is_native = matches!(
+        FuseStorageFormat::from_str(storage_format)?,
+        FuseStorageFormat::Native
+    );
+    for field in schema.fields() {
+        let options =
+            ColumnStorageOptions::try_from_field_options(&schema.field_options(field.column_id()))?;
+        options.check_storage_format(is_native).map_err(|e| {
+            e.add_message_back(format!(" (while checking column `{}`)", field.name()))
+        })?;
+    }
+    Ok(())
+}
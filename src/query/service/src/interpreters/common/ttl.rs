@@ -0,0 +1,165 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::Planner;
+use common_storages_fuse::operations::ExpiredBlockStats;
+use common_storages_fuse::FuseTable;
+use common_storages_system::CompactionHistoryLogElement;
+use common_storages_system::CompactionHistoryQueue;
+use log::info;
+
+use crate::interpreters::common::CompactTargetTableDescription;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::locks::LockManager;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::sessions::QueryContext;
+
+/// Removes the expired rows of a table with a TTL, and records it in `system.compaction_history`.
+///
+/// The blocks whose rows are all expired are dropped from the snapshot without
+/// touching the data, only the blocks which are partly expired are rewritten,
+/// by deleting their expired rows.
+pub async fn expire_table(
+    ctx: Arc<QueryContext>,
+    target: CompactTargetTableDescription,
+) -> Result<()> {
+    let start = SystemTime::now();
+    let table = get_table(&ctx, &target).await?;
+    let (block_count_before, row_count_before) = table_counts(table.as_ref()).await?;
+    let result = do_expire_table(ctx.clone(), &target).await;
+
+    let (block_count_after, removed_row_count) = match &result {
+        Ok(stats) => {
+            if stats.block_count == 0 && stats.partly_expired_block_count == 0 {
+                // nothing expired, not worth a record.
+                return Ok(());
+            }
+            let table = get_table(&ctx, &target).await?;
+            let (block_count_after, row_count_after) = table_counts(table.as_ref()).await?;
+            (
+                block_count_after,
+                row_count_before.saturating_sub(row_count_after),
+            )
+        }
+        Err(_) => (block_count_before, 0),
+    };
+    let (status, error_message) = match &result {
+        Ok(_) => ("Succeeded".to_string(), String::new()),
+        Err(e) => ("Failed".to_string(), e.to_string()),
+    };
+    CompactionHistoryQueue::instance()?.append_data(CompactionHistoryLogElement {
+        start_time: start
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64,
+        end_time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64,
+        database: target.database.clone(),
+        table: target.table.clone(),
+        operation: "ttl".to_string(),
+        block_count_before,
+        small_block_count_before: 0,
+        block_count_after,
+        removed_row_count,
+        status,
+        error_message,
+    })?;
+    result.map(|_| ())
+}
+
+async fn do_expire_table(
+    ctx: Arc<QueryContext>,
+    target: &CompactTargetTableDescription,
+) -> Result<ExpiredBlockStats> {
+    let table = get_table(&ctx, target).await?;
+    let table_lock = LockManager::create_table_lock(table.get_table_info().clone())?;
+    let table = FuseTable::try_from_table(table.as_ref())?;
+    let predicate = match table.ttl_predicate() {
+        Some(predicate) => predicate,
+        None => {
+            return Err(ErrorCode::InvalidTableTtl(format!(
+                "Table {}.{} has no TTL",
+                target.database, target.table
+            )));
+        }
+    };
+
+    // 1. drop the blocks that are entirely expired, it's a metadata only operation.
+    let stats = table.do_expire_blocks(ctx.clone(), table_lock).await?;
+    info!(
+        "expired {} blocks ({} rows) of table {}.{}, {} blocks are partly expired",
+        stats.block_count,
+        stats.row_count,
+        target.database,
+        target.table,
+        stats.partly_expired_block_count
+    );
+    if stats.partly_expired_block_count == 0 {
+        return Ok(stats);
+    }
+
+    // 2. delete the expired rows of the partly expired blocks.
+    ctx.evict_table_from_cache(&target.catalog, &target.database, &target.table)?;
+    let sql = format!(
+        "DELETE FROM `{}`.`{}`.`{}` WHERE {}",
+        target.catalog, target.database, target.table, predicate
+    );
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let mut build_res = interpreter.execute2().await?;
+    if build_res.main_pipeline.is_empty() || !build_res.main_pipeline.is_complete_pipeline()? {
+        return Ok(stats);
+    }
+
+    let settings = ctx.get_settings();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+    let mut pipelines = build_res.sources_pipelines;
+    pipelines.push(build_res.main_pipeline);
+    let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+    ctx.set_executor(executor.get_inner())?;
+    executor.execute()?;
+    Ok(stats)
+}
+
+async fn get_table(
+    ctx: &Arc<QueryContext>,
+    target: &CompactTargetTableDescription,
+) -> Result<Arc<dyn Table>> {
+    // the table is mutated in between, never use the cached one.
+    ctx.evict_table_from_cache(&target.catalog, &target.database, &target.table)?;
+    ctx.get_table(&target.catalog, &target.database, &target.table)
+        .await
+}
+
+// the number of blocks and rows of the current snapshot.
+async fn table_counts(table: &dyn Table) -> Result<(u64, u64)> {
+    let table = FuseTable::try_from_table(table)?;
+    Ok(match table.read_table_snapshot().await? {
+        Some(snapshot) => (snapshot.summary.block_count, snapshot.summary.row_count),
+        None => (0, 0),
+    })
+}
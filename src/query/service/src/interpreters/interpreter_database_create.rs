@@ -22,7 +22,6 @@ use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::Ownership;
 use common_meta_app::share::ShareGrantObjectPrivilege;
 use common_meta_app::share::ShareNameIdent;
-use common_meta_types::MatchSeq;
 use common_sharing::ShareEndpointManager;
 use common_sql::plans::CreateDatabasePlan;
 use common_users::UserApiProvider;
@@ -104,16 +103,7 @@ impl Interpreter for CreateDatabaseInterpreter {
         debug!("ctx.id" = self.ctx.get_id().as_str(); "create_database_execute");
 
         let tenant = self.plan.tenant.clone();
-        let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&tenant)?;
-        let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
-        let databases = catalog.list_databases(&tenant).await?;
-        if quota.max_databases != 0 && databases.len() >= quota.max_databases as usize {
-            return Err(ErrorCode::TenantQuotaExceeded(format!(
-                "Max databases quota exceeded {}",
-                quota.max_databases
-            )));
-        };
         // if create from other tenant, check from share endpoint
         if let Some(ref share_name) = self.plan.meta.from_share {
             self.check_create_database_from_share(&tenant, share_name)
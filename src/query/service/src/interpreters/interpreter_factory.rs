@@ -28,6 +28,7 @@ use super::interpreter_table_set_options::SetOptionsInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
 use crate::interpreters::access::Accessor;
+use crate::interpreters::common::hook_audit_log;
 use crate::interpreters::interpreter_catalog_drop::DropCatalogInterpreter;
 use crate::interpreters::interpreter_connection_create::CreateConnectionInterpreter;
 use crate::interpreters::interpreter_connection_desc::DescConnectionInterpreter;
@@ -41,6 +42,9 @@ use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterprete
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
+use crate::interpreters::interpreter_table_create_tag::CreateTableTagInterpreter;
+use crate::interpreters::interpreter_table_drop_partition::DropTablePartitionInterpreter;
+use crate::interpreters::interpreter_table_drop_tag::DropTableTagInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
 use crate::interpreters::interpreter_task_alter::AlterTaskInterpreter;
 use crate::interpreters::interpreter_task_create::CreateTaskInterpreter;
@@ -74,7 +78,8 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
-        Self::get_inner(ctx, plan)
+        let interpreter = Self::get_inner(ctx.clone(), plan)?;
+        hook_audit_log(ctx, plan, interpreter)
     }
 
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
@@ -440,6 +445,18 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateTableTag(p) => Ok(Arc::new(CreateTableTagInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropTableTag(p) => Ok(Arc::new(DropTableTagInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropTablePartition(p) => Ok(Arc::new(DropTablePartitionInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
             Plan::CreateDatamaskPolicy(p) => Ok(Arc::new(CreateDataMaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -29,6 +29,7 @@ use common_storages_share::save_share_table_info;
 use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_storages_view::view_table::VIEW_ENGINE;
 
+use crate::interpreters::common::check_column_storage_options;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -105,6 +106,13 @@ impl Interpreter for AddTableColumnInterpreter {
                 AddColumnOption::End => new_table_meta.schema.num_fields(),
             };
             new_table_meta.add_column(&field, &self.plan.comment, index)?;
+            if !self.plan.field_options.is_empty() {
+                let mut schema = new_table_meta.schema.as_ref().clone();
+                let column_id = schema.column_id_of(field.name())?;
+                schema.set_field_options(column_id, self.plan.field_options.clone())?;
+                check_column_storage_options(&schema, &new_table_meta.options)?;
+                new_table_meta.schema = Arc::new(schema);
+            }
 
             let table_id = table_info.ident.table_id;
             let table_version = table_info.ident.seq;
@@ -17,30 +17,39 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
+use common_ast::ast::Engine;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::is_internal_column;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
-use common_expression::TableSchemaRefExt;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_license::license::Feature::ComputedColumn;
 use common_license::license_manager::get_license_manager;
 use common_management::RoleApi;
 use common_meta_app::principal::GrantObjectByID;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::Ownership;
+use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
-use common_meta_types::MatchSeq;
 use common_sql::field_default_value;
+use common_sql::plans::CloneTableSource;
 use common_sql::plans::CreateTablePlan;
 use common_sql::BloomIndexColumns;
 use common_storage::DataOperator;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_OPT_KEY_AUTO_COMPACTION;
+use common_storages_fuse::FUSE_OPT_KEY_AUTO_COMPACTION_INTERVAL;
+use common_storages_fuse::FUSE_OPT_KEY_AUTO_COMPACTION_SMALL_BLOCK_RATIO;
+use common_storages_fuse::FUSE_OPT_KEY_AUTO_COMPACTION_TARGET_BLOCK_SIZE;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -58,12 +67,15 @@ use storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
+use crate::interpreters::common::check_column_storage_options;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -93,7 +105,6 @@ impl Interpreter for CreateTableInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let tenant = self.plan.tenant.clone();
         let has_computed_column = self
             .plan
             .schema
@@ -107,25 +118,8 @@ impl Interpreter for CreateTableInterpreter {
                 .check_enterprise_enabled(self.ctx.get_license_key(), ComputedColumn)?;
         }
 
-        let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&tenant)?;
-        let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
         let engine = self.plan.engine;
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
-        if quota.max_tables_per_database > 0 {
-            // Note:
-            // max_tables_per_database is a config quota. Default is 0.
-            // If a database has lot of tables, list_tables will be slow.
-            // So We check get it when max_tables_per_database != 0
-            let tables = catalog
-                .list_tables(&self.plan.tenant, &self.plan.database)
-                .await?;
-            if tables.len() >= quota.max_tables_per_database as usize {
-                return Err(ErrorCode::TenantQuotaExceeded(format!(
-                    "Max tables per database quota exceeded: {}",
-                    quota.max_tables_per_database
-                )));
-            }
-        }
 
         let engine_desc: Option<StorageDescription> = catalog
             .get_table_engines()
@@ -144,9 +138,12 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
-        match &self.plan.as_select {
-            Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
-            None => self.create_table().await,
+        match (&self.plan.as_select, &self.plan.clone_source) {
+            (Some(select_plan_node), _) => {
+                self.create_table_as_select(select_plan_node.clone()).await
+            }
+            (None, Some(clone_source)) => self.create_table_clone(clone_source).await,
+            (None, None) => self.create_table().await,
         }
     }
 }
@@ -280,6 +277,152 @@ impl CreateTableInterpreter {
         Ok(PipelineBuildResult::create())
     }
 
+    #[async_backtrace::framed]
+    async fn create_table_clone(
+        &self,
+        clone_source: &CloneTableSource,
+    ) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        if clone_source.catalog != self.plan.catalog {
+            return Err(ErrorCode::Unimplemented(
+                "Cloning a table into another catalog is not supported",
+            ));
+        }
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+        let source_table = catalog
+            .get_table(tenant.as_str(), &clone_source.database, &clone_source.table)
+            .await?;
+        let source_fuse_table = FuseTable::try_from_table(source_table.as_ref())?;
+        // the clone is kept in the default storage, so it can only share the files of
+        // tables kept there too.
+        if source_table.get_table_info().meta.storage_params.is_some() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Table {} with an external location can not be cloned",
+                source_table.name()
+            )));
+        }
+
+        let cloned_table = match &clone_source.point {
+            Some(point) => source_table.navigate_to(point).await?,
+            None => source_table.clone(),
+        };
+        let mut req = self.build_clone_request(cloned_table.get_table_info());
+        if let Some(current_role) = self.ctx.get_current_role() {
+            req.table_meta.owner = Some(Ownership::new(current_role.name));
+        }
+        let reply = catalog.create_table(req).await?;
+        if !reply.new_table {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let res: Result<()> = async {
+            if let Some(current_role) = self.ctx.get_current_role() {
+                let db = catalog
+                    .get_database(tenant.as_str(), &self.plan.database)
+                    .await?;
+                let db_id = db.get_db_info().ident.db_id;
+
+                let role_api = UserApiProvider::instance().get_role_api_client(&tenant)?;
+                role_api
+                    .grant_ownership(
+                        &GrantObjectByID::Table {
+                            catalog_name: self.plan.catalog.clone(),
+                            db_id,
+                            table_id: reply.table_id,
+                        },
+                        &current_role.name,
+                    )
+                    .await?;
+            }
+
+            let table = catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?;
+            source_fuse_table
+                .do_clone(
+                    self.ctx.as_ref(),
+                    FuseTable::try_from_table(cloned_table.as_ref())?,
+                    FuseTable::try_from_table(table.as_ref())?,
+                )
+                .await
+        }
+        .await;
+
+        // a clone without the data of the source must not be left behind.
+        if let Err(e) = res {
+            let drop_req = DropTableByIdReq {
+                if_exists: true,
+                tenant: tenant.to_string(),
+                tb_id: reply.table_id,
+            };
+            if let Err(drop_err) = catalog.drop_table_by_id(drop_req).await {
+                error!(
+                    "failed to drop the clone {} after cloning failed: {}",
+                    self.plan.table, drop_err
+                );
+            }
+            if let Err(unregister_err) = source_fuse_table
+                .unregister_clones(self.ctx.as_ref(), &[reply.table_id])
+                .await
+            {
+                error!(
+                    "failed to unregister the clone {} after cloning failed: {}",
+                    self.plan.table, unregister_err
+                );
+            }
+            return Err(e);
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+
+    /// Build CreateTableReq of a clone from the table info of the source, at the
+    /// point being cloned.
+    ///
+    /// The clone shares the blocks of the source, so the schema (with its column ids),
+    /// the storage format and the compression are the ones of the source.
+    fn build_clone_request(&self, source: &TableInfo) -> CreateTableReq {
+        let source_meta = &source.meta;
+        let mut options = self.plan.options.clone();
+        for key in [OPT_KEY_STORAGE_FORMAT, OPT_KEY_TABLE_COMPRESSION] {
+            match source_meta.options.get(key) {
+                Some(value) => options.insert(key.to_string(), value.clone()),
+                None => options.remove(key),
+            };
+        }
+        for (key, value) in source_meta.options.iter() {
+            if is_valid_create_opt(key) && key.as_str() != OPT_KEY_DATABASE_ID {
+                options.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+
+        let field_comments = if source_meta.field_comments.len() == source_meta.schema.num_fields()
+        {
+            source_meta.field_comments.clone()
+        } else {
+            vec!["".to_string(); source_meta.schema.num_fields()]
+        };
+        let table_meta = TableMeta {
+            schema: source_meta.schema.clone(),
+            engine: self.plan.engine.to_string(),
+            options,
+            default_cluster_key: source_meta.default_cluster_key.clone(),
+            cluster_keys: source_meta.cluster_keys.clone(),
+            default_cluster_key_id: source_meta.default_cluster_key_id,
+            field_comments,
+            ..Default::default()
+        };
+        CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.to_string(),
+                db_name: self.plan.database.to_string(),
+                table_name: self.plan.table.to_string(),
+            },
+            table_meta,
+        }
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -297,7 +440,21 @@ impl CreateTableInterpreter {
         } else {
             self.plan.field_comments.clone()
         };
-        let schema = TableSchemaRefExt::create(fields);
+        let mut schema = TableSchema::new(fields);
+        // keep the storage options of the fields, whose column ids may be reassigned.
+        let column_ids = schema
+            .fields()
+            .iter()
+            .map(|f| f.column_id())
+            .collect::<Vec<_>>();
+        for (source_field, column_id) in self.plan.schema.fields().iter().zip(column_ids) {
+            let options = self.plan.schema.field_options(source_field.column_id());
+            schema.set_field_options(column_id, options)?;
+        }
+        if self.plan.engine == Engine::Fuse {
+            check_column_storage_options(&schema, &self.plan.options)?;
+        }
+        let schema = Arc::new(schema);
 
         let mut table_meta = TableMeta {
             schema: schema.clone(),
@@ -321,6 +478,8 @@ impl CreateTableInterpreter {
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_auto_compaction(&table_meta.options)?;
+        is_valid_deletion_vector(&table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -424,6 +583,11 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_AUTO_COMPACTION);
+    r.insert(FUSE_OPT_KEY_AUTO_COMPACTION_TARGET_BLOCK_SIZE);
+    r.insert(FUSE_OPT_KEY_AUTO_COMPACTION_SMALL_BLOCK_RATIO);
+    r.insert(FUSE_OPT_KEY_AUTO_COMPACTION_INTERVAL);
+    r.insert(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
@@ -431,6 +595,8 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_TTL);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -501,3 +667,47 @@ pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()
     }
     Ok(())
 }
+
+pub fn is_valid_auto_compaction(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_AUTO_COMPACTION) {
+        value.to_lowercase().parse::<bool>()?;
+    }
+    if let Some(value) = options.get(FUSE_OPT_KEY_AUTO_COMPACTION_TARGET_BLOCK_SIZE) {
+        if value.parse::<u64>()? == 0 {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option, must be greater than 0",
+                FUSE_OPT_KEY_AUTO_COMPACTION_TARGET_BLOCK_SIZE
+            )));
+        }
+    }
+    if let Some(value) = options.get(FUSE_OPT_KEY_AUTO_COMPACTION_SMALL_BLOCK_RATIO) {
+        let ratio = value.parse::<f64>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option, must be a number between 0 and 1",
+                FUSE_OPT_KEY_AUTO_COMPACTION_SMALL_BLOCK_RATIO
+            ))
+        })?;
+        if !(0.0..=1.0).contains(&ratio) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option, must be a number between 0 and 1",
+                FUSE_OPT_KEY_AUTO_COMPACTION_SMALL_BLOCK_RATIO
+            )));
+        }
+    }
+    if let Some(value) = options.get(FUSE_OPT_KEY_AUTO_COMPACTION_INTERVAL) {
+        if value.parse::<u64>()? == 0 {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option, must be greater than 0",
+                FUSE_OPT_KEY_AUTO_COMPACTION_INTERVAL
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_deletion_vector(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR) {
+        value.to_lowercase().parse::<bool>()?;
+    }
+    Ok(())
+}
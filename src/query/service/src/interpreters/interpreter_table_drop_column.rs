@@ -29,6 +29,7 @@ use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -93,6 +94,8 @@ impl Interpreter for DropTableColumnInterpreter {
             )?;
         }
 
+        check_referenced_ttl(self.ctx.clone(), table.clone(), self.plan.column.as_str())?;
+
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
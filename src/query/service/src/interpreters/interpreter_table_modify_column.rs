@@ -41,6 +41,7 @@ use common_sql::plans::ModifyTableColumnPlan;
 use common_sql::plans::Plan;
 use common_sql::BloomIndexColumns;
 use common_sql::Planner;
+use common_storages_fuse::io::is_metadata_only_evolution;
 use common_storages_fuse::FuseTable;
 use common_storages_share::save_share_table_info;
 use common_storages_stream::stream_table::STREAM_ENGINE;
@@ -276,6 +277,54 @@ impl ModifyTableColumnInterpreter {
             return Ok(PipelineBuildResult::create());
         }
 
+        // Safe widenings are metadata-only: the columns are assigned new column ids,
+        // and blocks written before are cast when they are read.
+        let metadata_only =
+            schema
+                .fields()
+                .iter()
+                .zip(new_schema.fields())
+                .all(|(field, new_field)| {
+                    field.default_expr == new_field.default_expr
+                        && is_metadata_only_evolution(&field.data_type, &new_field.data_type)
+                });
+        // The native reader can not cast the blocks written before.
+        if metadata_only && fuse_table.is_native() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "cannot change the column types of table '{}' without rewriting the data, \
+                 the native storage format does not support it",
+                table.name()
+            )));
+        }
+        if metadata_only {
+            let mut evolved_schema = schema.clone();
+            for (i, field) in new_schema.fields().iter().enumerate() {
+                if field.data_type != schema.fields()[i].data_type {
+                    evolved_schema.evolve_field(i, field.data_type.clone())?;
+                }
+            }
+            table_info.meta.schema = Arc::new(evolved_schema);
+
+            let req = UpdateTableMetaReq {
+                table_id: table_info.ident.table_id,
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                new_table_meta: table_info.meta.clone(),
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            };
+            let res = catalog.update_table_meta(&table_info, req).await?;
+            if let Some(share_table_info) = res.share_table_info {
+                save_share_table_info(
+                    &self.ctx.get_tenant(),
+                    self.ctx.get_data_operator()?.operator(),
+                    share_table_info,
+                )
+                .await?;
+            }
+            return Ok(PipelineBuildResult::create());
+        }
+
         // 1. construct sql for selecting data from old table
         let mut sql = "select".to_string();
         schema
@@ -379,7 +428,11 @@ impl ModifyTableColumnInterpreter {
             new_field.computed_expr = None;
             let mut fields = schema.fields().clone();
             fields[i] = new_field;
-            TableSchema::new_from(fields, schema.metadata.clone())
+            TableSchema::new_from_column_ids(
+                fields,
+                schema.metadata.clone(),
+                schema.next_column_id(),
+            )
         } else {
             return Err(ErrorCode::UnknownColumn(format!(
                 "Cannot find column {}",
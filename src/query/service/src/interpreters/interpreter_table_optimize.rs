@@ -39,6 +39,8 @@ use common_storages_factory::NavigationPoint;
 use common_storages_fuse::FuseTable;
 use storages_common_table_meta::meta::TableSnapshot;
 
+use crate::interpreters::common::expire_table;
+use crate::interpreters::common::CompactTargetTableDescription;
 use crate::interpreters::interpreter_table_recluster::build_recluster_physical_plan;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterClusteringHistory;
@@ -97,6 +99,15 @@ impl Interpreter for OptimizeTableInterpreter {
                 self.build_pipeline(catalog, table, CompactTarget::Blocks, true)
                     .await
             }
+            OptimizeTableAction::Ttl => {
+                let target = CompactTargetTableDescription {
+                    catalog: plan.catalog,
+                    database: plan.database,
+                    table: plan.table,
+                };
+                expire_table(ctx, target).await?;
+                Ok(PipelineBuildResult::create())
+            }
         }
     }
 }
@@ -29,6 +29,7 @@ use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -84,6 +85,12 @@ impl Interpreter for RenameTableColumnInterpreter {
                 )));
             }
 
+            check_referenced_ttl(
+                self.ctx.clone(),
+                table.clone(),
+                self.plan.old_column.as_str(),
+            )?;
+
             let catalog = self.ctx.get_catalog(catalog_name).await?;
             let mut new_table_meta = table.get_table_info().meta.clone();
 
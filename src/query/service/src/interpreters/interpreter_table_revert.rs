@@ -18,6 +18,7 @@ use common_catalog::table::NavigationDescriptor;
 use common_catalog::table::TableExt;
 use common_exception::Result;
 use common_sql::plans::RevertTablePlan;
+use common_storages_fuse::FuseTable;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -53,6 +54,14 @@ impl Interpreter for RevertTableInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        if self.plan.restore {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            fuse_table
+                .do_restore_to(self.ctx.clone(), &self.plan.point)
+                .await?;
+            return Ok(PipelineBuildResult::create());
+        }
+
         let navigation_descriptor = NavigationDescriptor {
             database_name: self.plan.database.clone(),
             point: self.plan.point.clone(),
@@ -26,10 +26,12 @@ use log::error;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 
+use super::interpreter_table_create::is_valid_auto_compaction;
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_change_tracking;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_deletion_vector;
 use super::interpreter_table_create::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -61,6 +63,8 @@ impl Interpreter for SetOptionsInterpreter {
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
         is_valid_change_tracking(&self.plan.set_options)?;
+        is_valid_auto_compaction(&self.plan.set_options)?;
+        is_valid_deletion_vector(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
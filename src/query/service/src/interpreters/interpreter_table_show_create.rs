@@ -24,15 +24,18 @@ use common_expression::DataBlock;
 use common_expression::Scalar;
 use common_expression::Value;
 use common_sql::plans::ShowCreateTablePlan;
+use common_storages_stream::stream_table::StreamMode;
 use common_storages_stream::stream_table::StreamTable;
 use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use storages_common_table_meta::table::is_internal_opt_key;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -113,6 +116,11 @@ impl ShowCreateTableInterpreter {
                     }
                     _ => "".to_string(),
                 };
+                let storage_options = schema
+                    .field_options(field.column_id())
+                    .iter()
+                    .map(|(key, value)| format!(" {} '{}'", key.to_uppercase(), value))
+                    .collect::<String>();
                 // compatibility: creating table in the old planner will not have `fields_comments`
                 let comment = if field_comments.len() == n_fields && !field_comments[idx].is_empty()
                 {
@@ -125,12 +133,13 @@ impl ShowCreateTableInterpreter {
                     "".to_string()
                 };
                 let column = format!(
-                    "  `{}` {}{}{}{}{}",
+                    "  `{}` {}{}{}{}{}{}",
                     field.name(),
                     field.data_type().remove_recursive_nullable().sql_name(),
                     nullable,
                     default_expr,
                     computed_expr,
+                    storage_options,
                     comment
                 );
 
@@ -152,6 +161,12 @@ impl ShowCreateTableInterpreter {
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
+        if let Some(partition_key) = table_info.options().get(OPT_KEY_PARTITION_BY) {
+            table_create_sql.push_str(format!(" PARTITION BY ({})", partition_key).as_str());
+        }
+        if let Some(ttl) = table_info.options().get(OPT_KEY_TTL) {
+            table_create_sql.push_str(format!(" TTL {}", ttl).as_str());
+        }
 
         let settings = self.ctx.get_settings();
         let hide_options_in_show_create_table = settings
@@ -227,6 +242,9 @@ impl ShowCreateTableInterpreter {
             stream_table.source_table_database(),
             stream_table.source_table_name()
         );
+        if let StreamMode::Standard = stream_table.mode() {
+            create_sql.push_str(" APPEND_ONLY = false");
+        }
 
         let comment = stream_table.get_table_info().meta.comment.clone();
         if !comment.is_empty() {
@@ -17,7 +17,6 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::StageType;
-use common_meta_types::MatchSeq;
 use common_sql::plans::CreateStagePlan;
 use common_users::UserApiProvider;
 use log::debug;
@@ -61,16 +60,6 @@ impl Interpreter for CreateUserStageInterpreter {
             ));
         }
 
-        let quota_api = user_mgr.get_tenant_quota_api_client(&plan.tenant)?;
-        let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
-        let stages = user_mgr.get_stages(&plan.tenant).await?;
-        if quota.max_stages != 0 && stages.len() >= quota.max_stages as usize {
-            return Err(ErrorCode::TenantQuotaExceeded(format!(
-                "Max stages quota exceeded {}",
-                quota.max_stages
-            )));
-        };
-
         if user_stage.stage_type != StageType::External {
             let op = self.ctx.get_data_operator()?.operator();
             op.create_dir(&user_stage.stage_prefix()).await?
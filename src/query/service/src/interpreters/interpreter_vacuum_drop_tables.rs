@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::min;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::Result;
@@ -22,12 +23,15 @@ use common_expression::FromData;
 use common_license::license::Feature::Vacuum;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DroppedId;
 use common_meta_app::schema::GcDroppedTableReq;
 use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::TableInfoFilter;
 use common_sql::plans::VacuumDropTablePlan;
+use common_storages_fuse::FuseTable;
 use log::as_debug;
 use log::info;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use vacuum_handler::get_vacuum_handler;
 
 use crate::interpreters::Interpreter;
@@ -106,6 +110,36 @@ impl Interpreter for VacuumDropTablesInterpreter {
             .filter(|tbl| !tbl.as_ref().is_read_only())
             .collect::<Vec<_>>();
 
+        // the data of a dropped table can not be removed while its clones still
+        // reference it, keep the table (and its database) until they are gone.
+        let mut tables_to_vacuum = Vec::with_capacity(tables.len());
+        let mut kept_tables = HashSet::new();
+        let mut kept_dbs = HashSet::new();
+        for table in tables {
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                if fuse_table.has_clones(self.ctx.as_ref()).await? {
+                    info!(
+                        "vacuum drop table {:?} skipped, it is still referenced by its clones",
+                        table.name()
+                    );
+                    kept_tables.insert(table.get_id());
+                    if let Some(db_id) = table.get_table_info().options().get(OPT_KEY_DATABASE_ID) {
+                        kept_dbs.insert(db_id.parse::<u64>()?);
+                    }
+                    continue;
+                }
+            }
+            tables_to_vacuum.push(table);
+        }
+        let tables = tables_to_vacuum;
+        let drop_ids = drop_ids
+            .into_iter()
+            .filter(|id| match id {
+                DroppedId::Db(db_id, _) => !kept_dbs.contains(db_id),
+                DroppedId::Table(_, table_id, _) => !kept_tables.contains(table_id),
+            })
+            .collect::<Vec<_>>();
+
         let handler = get_vacuum_handler();
         let files_opt = handler
             .do_vacuum_drop_tables(
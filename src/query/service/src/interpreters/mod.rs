@@ -85,9 +85,12 @@ mod interpreter_stream_drop;
 mod interpreter_table_add_column;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
+mod interpreter_table_create_tag;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_partition;
+mod interpreter_table_drop_tag;
 mod interpreter_table_exists;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
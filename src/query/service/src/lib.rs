@@ -42,6 +42,7 @@ extern crate core;
 
 pub mod api;
 pub mod auth;
+pub mod auto_compaction;
 pub mod catalogs;
 pub mod clusters;
 pub mod databases;
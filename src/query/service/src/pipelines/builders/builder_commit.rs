@@ -51,12 +51,12 @@ impl PipelineBuilder {
                 } else {
                     plan.snapshot.segments.clone()
                 };
-                let mutation_aggregator = TableMutationAggregator::new(
+                let mutation_aggregator = TableMutationAggregator::try_create(
                     table,
                     self.ctx.clone(),
                     base_segments,
                     plan.mutation_kind,
-                );
+                )?;
                 Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
                     input,
                     output,
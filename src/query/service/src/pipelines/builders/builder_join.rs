@@ -14,16 +14,26 @@
 
 use std::sync::Arc;
 
+use async_channel::Receiver;
 use common_base::base::tokio::sync::Barrier;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::RemoteExpr;
+use common_expression::SortColumnDescription;
 use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sinks::AsyncSinker;
 use common_pipeline_sinks::Sinker;
+use common_pipeline_transforms::processors::try_add_multi_sort_merge;
 use common_pipeline_transforms::processors::ProcessorProfileWrapper;
 use common_pipeline_transforms::processors::ProfileStub;
 use common_pipeline_transforms::processors::Transformer;
 use common_sql::executor::physical_plans::HashJoin;
 use common_sql::executor::physical_plans::MaterializedCte;
 use common_sql::executor::physical_plans::RangeJoin;
+use common_sql::executor::physical_plans::SortMergeJoin;
 use common_sql::executor::PhysicalPlan;
 use common_sql::ColumnBinding;
 use common_sql::IndexType;
@@ -31,6 +41,9 @@ use common_sql::IndexType;
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinLeft;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinRight;
+use crate::pipelines::processors::transforms::sort_merge_join::SortMergeJoiner;
+use crate::pipelines::processors::transforms::sort_merge_join::TransformSortMergeJoinLeft;
+use crate::pipelines::processors::transforms::sort_merge_join::TransformSortMergeJoinRight;
 use crate::pipelines::processors::transforms::BuildSpillCoordinator;
 use crate::pipelines::processors::transforms::BuildSpillState;
 use crate::pipelines::processors::transforms::HashJoinBuildState;
@@ -123,6 +136,135 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub(crate) fn build_sort_merge_join(&mut self, join: &SortMergeJoin) -> Result<()> {
+        let max_block_size = self.settings.get_max_block_size()? as usize;
+        let right_receiver = self.expand_sort_merge_join_right(join, max_block_size)?;
+        self.build_pipeline(&join.left)?;
+        Self::merge_sorted_outputs(
+            &mut self.main_pipeline,
+            join.left.output_schema()?,
+            &join.left_keys,
+            join,
+            max_block_size,
+        )?;
+
+        self.main_pipeline.add_transform(|input, output| {
+            let joiner = SortMergeJoiner::try_create(self.func_ctx.clone(), join, max_block_size)?;
+            let transform =
+                TransformSortMergeJoinLeft::create(input, output, right_receiver.clone(), joiner);
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                    transform,
+                    join.plan_id,
+                    self.proc_profs.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+        if self.enable_profiling {
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(Transformer::create(
+                    input,
+                    output,
+                    ProfileStub::new(join.plan_id, self.proc_profs.clone())
+                        .accumulate_output_rows()
+                        .accumulate_output_bytes(),
+                )))
+            })?;
+        }
+
+        // the join itself merges the sorted inputs in a single stream, the operators
+        // above it run in parallel again.
+        let max_threads = self.settings.get_max_threads()? as usize;
+        self.main_pipeline.try_resize(max_threads)?;
+        Ok(())
+    }
+
+    fn expand_sort_merge_join_right(
+        &mut self,
+        join: &SortMergeJoin,
+        max_block_size: usize,
+    ) -> Result<Receiver<DataBlock>> {
+        let right_side_context = QueryContext::create_from(self.ctx.clone());
+        let mut right_side_builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            right_side_context,
+            self.enable_profiling,
+            self.proc_profs.clone(),
+            self.main_pipeline.plans_scope.clone(),
+        );
+        right_side_builder.cte_state = self.cte_state.clone();
+        let mut right_res = right_side_builder.finalize(&join.right)?;
+        Self::merge_sorted_outputs(
+            &mut right_res.main_pipeline,
+            join.right.output_schema()?,
+            &join.right_keys,
+            join,
+            max_block_size,
+        )?;
+
+        // The left side pulls the sorted blocks one by one, so a small buffer is enough.
+        let (tx, rx) = async_channel::bounded(2);
+        right_res.main_pipeline.add_sink(|input| {
+            let transform = AsyncSinker::create(
+                input,
+                self.ctx.clone(),
+                TransformSortMergeJoinRight::create(tx.clone()),
+            );
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                    transform,
+                    join.plan_id,
+                    self.proc_profs.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+        self.pipelines.push(right_res.main_pipeline.finalize());
+        self.pipelines.extend(right_res.sources_pipelines);
+        Ok(rx)
+    }
+
+    // Both inputs of sort-merge join must be a single sorted stream.
+    fn merge_sorted_outputs(
+        pipeline: &mut Pipeline,
+        schema: DataSchemaRef,
+        keys: &[RemoteExpr],
+        join: &SortMergeJoin,
+        block_size: usize,
+    ) -> Result<()> {
+        if pipeline.output_len() <= 1 {
+            return Ok(());
+        }
+        let sort_desc = keys
+            .iter()
+            .zip(join.sort_desc.iter())
+            .map(|(key, desc)| match key {
+                RemoteExpr::ColumnRef { id, data_type, .. } => Ok(SortColumnDescription {
+                    offset: *id,
+                    asc: desc.asc,
+                    nulls_first: desc.nulls_first,
+                    is_nullable: data_type.is_nullable(),
+                }),
+                _ => Err(ErrorCode::Internal(
+                    "Keys of sort-merge join must be columns",
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        try_add_multi_sort_merge(
+            pipeline,
+            schema,
+            block_size,
+            None,
+            Arc::new(sort_desc),
+            None,
+            false,
+        )
+    }
+
     pub(crate) fn build_join(&mut self, join: &HashJoin) -> Result<()> {
         let id = join.probe.get_table_index();
         let state = self.build_join_state(join, id)?;
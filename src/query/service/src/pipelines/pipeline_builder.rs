@@ -131,6 +131,7 @@ impl PipelineBuilder {
                 "Invalid physical plan with PhysicalPlan::Exchange",
             )),
             PhysicalPlan::RangeJoin(range_join) => self.build_range_join(range_join),
+            PhysicalPlan::SortMergeJoin(join) => self.build_sort_merge_join(join),
             PhysicalPlan::MaterializedCte(materialized_cte) => {
                 self.build_materialized_cte(materialized_cte)
             }
@@ -30,6 +30,8 @@ use common_pipeline_core::processors::Event;
 use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
+use common_storage::spill_target;
+use common_storage::SpillTier;
 use futures_util::future::BoxFuture;
 use log::info;
 use opendal::Operator;
@@ -187,9 +189,6 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
     params: &Arc<AggregatorParams>,
     mut payload: HashTablePayload<PartitionedHashMethod<Method>, usize>,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
-    let unique_name = GlobalUniqName::unique();
-    let location = format!("{}/{}", location_prefix, unique_name);
-
     let mut write_size = 0;
     let mut write_data = Vec::with_capacity(256);
     let mut spilled_buckets_payloads = Vec::with_capacity(256);
@@ -227,12 +226,25 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
         write_data.push(columns_data);
         spilled_buckets_payloads.push(BucketSpilledPayload {
             bucket: bucket as isize,
-            location: location.clone(),
+            location: String::new(),
             data_range: begin..write_size,
             columns_layout,
         });
     }
 
+    // Choose the storage tier after the size of spilled data is known.
+    let (operator, location) = spill_target(
+        &ctx.get_tenant(),
+        &ctx.get_id(),
+        &operator,
+        location_prefix,
+        &GlobalUniqName::unique(),
+        write_size,
+    );
+    for bucket_payload in spilled_buckets_payloads.iter_mut() {
+        bucket_payload.location = location.clone();
+    }
+
     Ok(Box::pin(async move {
         let instant = Instant::now();
 
@@ -251,6 +263,10 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            metrics_inc_spill_tier_write_bytes(
+                SpillTier::of(&location).to_string(),
+                write_bytes as u64,
+            );
         }
 
         // perf
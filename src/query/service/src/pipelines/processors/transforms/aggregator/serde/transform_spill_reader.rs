@@ -29,6 +29,7 @@ use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
+use common_storage::spill_source;
 use itertools::Itertools;
 use log::info;
 use opendal::Operator;
@@ -178,8 +179,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                 AggregateMeta::Serialized(_) => unreachable!(),
                 AggregateMeta::BucketSpilled(payload) => {
                     let instant = Instant::now();
-                    let data = self
-                        .operator
+                    let data = spill_source(&self.operator, &payload.location)
                         .read_with(&payload.location)
                         .range(payload.data_range.clone())
                         .await?;
@@ -197,7 +197,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                     for meta in data {
                         if let AggregateMeta::BucketSpilled(payload) = meta {
                             let location = payload.location.clone();
-                            let operator = self.operator.clone();
+                            let operator = spill_source(&self.operator, &location);
                             let data_range = payload.data_range.clone();
                             read_data.push(common_base::base::tokio::spawn(
                                 async_backtrace::frame!(async move {
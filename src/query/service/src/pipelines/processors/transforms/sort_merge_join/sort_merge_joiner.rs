@@ -0,0 +1,379 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::string::StringColumn;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::RowConverter;
+use common_expression::SortField;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::executor::physical_plans::SortMergeJoin;
+
+use crate::pipelines::processors::transforms::range_join::filter_block;
+
+/// What the joiner needs to make progress.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MergeStep {
+    NeedLeft,
+    NeedRight,
+    Merge,
+    Finished,
+}
+
+/// A non-empty block of pending rows, with its join keys in row format.
+struct SortedBlock {
+    block: DataBlock,
+    rows: StringColumn,
+    // If the join keys of the row contain null.
+    nulls: Vec<bool>,
+}
+
+impl SortedBlock {
+    fn last_row(&self) -> &[u8] {
+        unsafe { self.rows.index_unchecked(self.rows.len() - 1) }
+    }
+
+    // Number of rows whose keys are less than `bound`.
+    fn rows_before(&self, bound: &[u8]) -> usize {
+        let (mut lo, mut hi) = (0, self.rows.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if unsafe { self.rows.index_unchecked(mid) } < bound {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+/// Pending rows of one side, which are sorted by the join keys.
+///
+/// The pushed blocks are buffered as they are, and only concatenated once
+/// their rows are merged.
+struct SortedInput {
+    keys: Vec<Expr>,
+    blocks: VecDeque<SortedBlock>,
+    finished: bool,
+}
+
+impl SortedInput {
+    fn new(keys: &[RemoteExpr]) -> Self {
+        SortedInput {
+            keys: keys
+                .iter()
+                .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+                .collect(),
+            blocks: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    fn last_row(&self) -> Option<&[u8]> {
+        self.blocks.back().map(|block| block.last_row())
+    }
+
+    // Number of pending rows whose keys are less than `bound`.
+    fn rows_before(&self, bound: Option<&[u8]>) -> usize {
+        let mut num_rows = 0;
+        for block in self.blocks.iter() {
+            match bound {
+                Some(bound) if block.last_row() >= bound => {
+                    return num_rows + block.rows_before(bound);
+                }
+                _ => num_rows += block.block.num_rows(),
+            }
+        }
+        num_rows
+    }
+}
+
+/// Merge two inputs sorted by the join keys in the same order. Only rows whose keys
+/// are less than the last key of both unfinished inputs are joined, so the rows of
+/// the same key are always joined in one batch.
+pub struct SortMergeJoiner {
+    func_ctx: FunctionContext,
+    converter: RowConverter,
+    key_types: Vec<DataType>,
+    left: SortedInput,
+    right: SortedInput,
+    other_conditions: Vec<RemoteExpr>,
+    max_block_size: usize,
+}
+
+impl SortMergeJoiner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        join: &SortMergeJoin,
+        max_block_size: usize,
+    ) -> Result<Self> {
+        let left = SortedInput::new(&join.left_keys);
+        let right = SortedInput::new(&join.right_keys);
+
+        // The nullability of keys may be different between two sides.
+        let key_types = left
+            .keys
+            .iter()
+            .zip(right.keys.iter())
+            .map(|(l, r)| match l.data_type().is_nullable() {
+                true => l.data_type().clone(),
+                false => r.data_type().clone(),
+            })
+            .collect::<Vec<_>>();
+        let converter = RowConverter::new(
+            key_types
+                .iter()
+                .zip(join.sort_desc.iter())
+                .map(|(ty, desc)| {
+                    SortField::new_with_options(ty.clone(), desc.asc, desc.nulls_first)
+                })
+                .collect(),
+        )?;
+
+        Ok(SortMergeJoiner {
+            func_ctx,
+            converter,
+            key_types,
+            left,
+            right,
+            other_conditions: join.other_conditions.clone(),
+            max_block_size,
+        })
+    }
+
+    pub fn push_left(&mut self, block: DataBlock) -> Result<()> {
+        self.push(block, true)
+    }
+
+    pub fn push_right(&mut self, block: DataBlock) -> Result<()> {
+        self.push(block, false)
+    }
+
+    pub fn finish_left(&mut self) {
+        self.left.finished = true;
+    }
+
+    pub fn finish_right(&mut self) {
+        self.right.finished = true;
+    }
+
+    pub fn next_step(&self) -> MergeStep {
+        // Inner join can't produce more rows if any side is exhausted.
+        if (self.left.finished && self.left.is_empty())
+            || (self.right.finished && self.right.is_empty())
+        {
+            return MergeStep::Finished;
+        }
+        if self.left.is_empty() {
+            return MergeStep::NeedLeft;
+        }
+        if self.right.is_empty() {
+            return MergeStep::NeedRight;
+        }
+
+        let bound = self.bound();
+        if self.left.rows_before(bound) > 0 || self.right.rows_before(bound) > 0 {
+            return MergeStep::Merge;
+        }
+
+        // All the pending rows have the same key as the bound, keep pulling the side
+        // which may have more rows of the key.
+        match self.left.finished {
+            true => MergeStep::NeedRight,
+            false if self.left.last_row() == bound => MergeStep::NeedLeft,
+            false => MergeStep::NeedRight,
+        }
+    }
+
+    pub fn merge(&mut self) -> Result<Vec<DataBlock>> {
+        let bound = self.bound().map(|bound| bound.to_vec());
+        let left_len = self.left.rows_before(bound.as_deref());
+        let right_len = self.right.rows_before(bound.as_deref());
+
+        let (left_block, left_rows, left_nulls) = split_front(&mut self.left, left_len)?;
+        let (right_block, right_rows, right_nulls) = split_front(&mut self.right, right_len)?;
+
+        let mut left_indices = Vec::new();
+        let mut right_indices = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < left_len && j < right_len {
+            if left_nulls[i] {
+                i += 1;
+                continue;
+            }
+            if right_nulls[j] {
+                j += 1;
+                continue;
+            }
+            let left_key = unsafe { left_rows.index_unchecked(i) };
+            let right_key = unsafe { right_rows.index_unchecked(j) };
+            match left_key.cmp(right_key) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    let mut left_end = i + 1;
+                    while left_end < left_len
+                        && unsafe { left_rows.index_unchecked(left_end) } == left_key
+                    {
+                        left_end += 1;
+                    }
+                    let mut right_end = j + 1;
+                    while right_end < right_len
+                        && unsafe { right_rows.index_unchecked(right_end) } == right_key
+                    {
+                        right_end += 1;
+                    }
+                    for l in i..left_end {
+                        for r in j..right_end {
+                            left_indices.push(l as u32);
+                            right_indices.push(r as u32);
+                        }
+                    }
+                    i = left_end;
+                    j = right_end;
+                }
+            }
+        }
+
+        let mut result_blocks = Vec::with_capacity(left_indices.len() / self.max_block_size + 1);
+        for (left_indices, right_indices) in left_indices
+            .chunks(self.max_block_size)
+            .zip(right_indices.chunks(self.max_block_size))
+        {
+            let mut block = left_block.take(left_indices, &mut None)?;
+            let right = right_block.take(right_indices, &mut None)?;
+            for col in right.columns() {
+                block.add_column(col.clone());
+            }
+            for filter in self.other_conditions.iter() {
+                block = filter_block(block, filter)?;
+            }
+            if !block.is_empty() {
+                result_blocks.push(block);
+            }
+        }
+        Ok(result_blocks)
+    }
+
+    // The smallest last key of unfinished inputs, `None` if both inputs are finished.
+    fn bound(&self) -> Option<&[u8]> {
+        match (self.left.finished, self.right.finished) {
+            (true, true) => None,
+            (true, false) => self.right.last_row(),
+            (false, true) => self.left.last_row(),
+            (false, false) => std::cmp::min(self.left.last_row(), self.right.last_row()),
+        }
+    }
+
+    fn push(&mut self, block: DataBlock, is_left: bool) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+        let input = if is_left {
+            &mut self.left
+        } else {
+            &mut self.right
+        };
+
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut nulls = vec![false; num_rows];
+        let mut columns = Vec::with_capacity(input.keys.len());
+        for (key, key_type) in input.keys.iter().zip(self.key_types.iter()) {
+            let mut column = evaluator
+                .run(key)?
+                .convert_to_full_column(key.data_type(), num_rows);
+            if let (_, Some(validity)) = column.validity() {
+                for (idx, is_null) in nulls.iter_mut().enumerate() {
+                    *is_null |= !validity.get_bit(idx);
+                }
+            } else if matches!(column, Column::Null { .. }) {
+                nulls.iter_mut().for_each(|is_null| *is_null = true);
+            }
+            if column.data_type() != *key_type {
+                column = column.wrap_nullable(None);
+            }
+            columns.push(column);
+        }
+
+        input.blocks.push_back(SortedBlock {
+            rows: self.converter.convert_columns(&columns, num_rows),
+            nulls,
+            block,
+        });
+        Ok(())
+    }
+}
+
+// Split the first `len` rows from the pending rows, the blocks they span are concatenated.
+fn split_front(
+    input: &mut SortedInput,
+    len: usize,
+) -> Result<(DataBlock, StringColumn, Vec<bool>)> {
+    let mut blocks = vec![];
+    let mut rows = vec![];
+    let mut nulls = Vec::with_capacity(len);
+    let mut remain = len;
+    while remain > 0 {
+        let mut front = input.blocks.pop_front().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "sort merge join splits {len} rows, but only {} rows are pending",
+                len - remain
+            ))
+        })?;
+        let num_rows = front.block.num_rows();
+        if remain < num_rows {
+            blocks.push(front.block.slice(0..remain));
+            rows.push(front.rows.slice(0..remain));
+            nulls.extend(front.nulls.drain(..remain));
+            input.blocks.push_front(SortedBlock {
+                block: front.block.slice(remain..num_rows),
+                rows: front.rows.slice(remain..num_rows),
+                nulls: front.nulls,
+            });
+            remain = 0;
+        } else {
+            blocks.push(front.block);
+            rows.push(front.rows);
+            nulls.append(&mut front.nulls);
+            remain -= num_rows;
+        }
+    }
+
+    let block = match blocks.len() {
+        0 => DataBlock::empty(),
+        1 => blocks.pop().unwrap(),
+        _ => DataBlock::concat(&blocks)?,
+    };
+    let rows = match rows.len() {
+        1 => rows.pop().unwrap(),
+        _ => Column::concat_string_types(rows.into_iter(), len),
+    };
+    Ok((block, rows, nulls))
+}
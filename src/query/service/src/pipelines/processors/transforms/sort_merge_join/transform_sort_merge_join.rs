@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use async_channel::Receiver;
+use async_channel::Sender;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::Event;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_sinks::AsyncSink;
+
+use crate::pipelines::processors::transforms::sort_merge_join::MergeStep;
+use crate::pipelines::processors::transforms::sort_merge_join::SortMergeJoiner;
+
+/// Merge the sorted left input with the sorted right input received from the channel.
+pub struct TransformSortMergeJoinLeft {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    input_data: Option<DataBlock>,
+    output_data_blocks: VecDeque<DataBlock>,
+    right_receiver: Receiver<DataBlock>,
+    joiner: SortMergeJoiner,
+}
+
+impl TransformSortMergeJoinLeft {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        right_receiver: Receiver<DataBlock>,
+        joiner: SortMergeJoiner,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformSortMergeJoinLeft {
+            input,
+            output,
+            input_data: None,
+            output_data_blocks: VecDeque::new(),
+            right_receiver,
+            joiner,
+        })
+    }
+
+    fn finish(&mut self) -> Result<Event> {
+        // Stop the right side, it doesn't need to produce more data.
+        self.right_receiver.close();
+        self.input.finish();
+        self.output.finish();
+        Ok(Event::Finished)
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortMergeJoinLeft {
+    fn name(&self) -> String {
+        "TransformSortMergeJoinLeft".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            return self.finish();
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_data_blocks.pop_front() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        loop {
+            match self.joiner.next_step() {
+                MergeStep::Merge => return Ok(Event::Sync),
+                MergeStep::NeedRight => return Ok(Event::Async),
+                MergeStep::Finished => return self.finish(),
+                MergeStep::NeedLeft => {
+                    if self.input.has_data() {
+                        self.input_data = Some(self.input.pull_data().unwrap()?);
+                        return Ok(Event::Sync);
+                    }
+                    if !self.input.is_finished() {
+                        self.input.set_need_data();
+                        return Ok(Event::NeedData);
+                    }
+                    self.joiner.finish_left();
+                }
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            return self.joiner.push_left(data_block);
+        }
+
+        let blocks = self.joiner.merge()?;
+        self.output_data_blocks.extend(blocks);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.right_receiver.recv().await {
+            Ok(data_block) => self.joiner.push_right(data_block),
+            Err(_) => {
+                self.joiner.finish_right();
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Send the sorted right input to the left side in order.
+pub struct TransformSortMergeJoinRight {
+    sender: Option<Sender<DataBlock>>,
+}
+
+impl TransformSortMergeJoinRight {
+    pub fn create(sender: Sender<DataBlock>) -> Self {
+        TransformSortMergeJoinRight {
+            sender: Some(sender),
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncSink for TransformSortMergeJoinRight {
+    const NAME: &'static str = "TransformSortMergeJoinRight";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        drop(self.sender.take());
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        match self.sender.as_ref() {
+            // If the left side has finished the join, the channel is closed.
+            Some(sender) => Ok(sender.send(data_block).await.is_err()),
+            None => Ok(true),
+        }
+    }
+}
@@ -39,6 +39,7 @@ use common_settings::Settings;
 use common_sql::IndexType;
 use common_storage::CopyStatus;
 use common_storage::DataOperator;
+use common_storage::LocalSpillDisk;
 use common_storage::MergeStatus;
 use common_storage::StorageMetrics;
 use common_users::UserApiProvider;
@@ -442,7 +443,12 @@ impl Drop for QueryContextShared {
         // to avoid returning the query_id of the current statement.
         self.session
             .session_ctx
-            .update_query_ids_results(self.init_query_id.read().clone(), None)
+            .update_query_ids_results(self.init_query_id.read().clone(), None);
+
+        // Spilled files are useless after the query ends.
+        if let Some(disk) = LocalSpillDisk::instance() {
+            disk.cleanup_query(&self.init_query_id.read());
+        }
     }
 }
 
@@ -27,13 +27,18 @@ pub enum SessionType {
     Dummy,
     Fuzz,
     Local,
+    // The internal session of the auto compaction service.
+    AutoCompaction,
 }
 
 impl SessionType {
     pub fn is_user_session(&self) -> bool {
         !matches!(
             self,
-            SessionType::HTTPAPI(_) | SessionType::Dummy | SessionType::Fuzz
+            SessionType::HTTPAPI(_)
+                | SessionType::Dummy
+                | SessionType::Fuzz
+                | SessionType::AutoCompaction
         )
     }
 }
@@ -52,6 +57,7 @@ impl fmt::Display for SessionType {
             SessionType::HTTPAPI(usage) => format!("HTTPAPI({})", usage),
             SessionType::Fuzz => "Fuzz".to_string(),
             SessionType::Local => "Local".to_string(),
+            SessionType::AutoCompaction => "AutoCompaction".to_string(),
         };
         write!(f, "{}", name)
     }
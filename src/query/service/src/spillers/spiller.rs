@@ -26,6 +26,10 @@ use common_expression::arrow::deserialize_column;
 use common_expression::arrow::serialize_column;
 use common_expression::DataBlock;
 use common_hashtable::hash2bucket;
+use common_metrics::transform::metrics_inc_spill_tier_write_bytes;
+use common_storage::spill_source;
+use common_storage::spill_target;
+use common_storage::SpillTier;
 use log::info;
 use opendal::Operator;
 
@@ -110,7 +114,7 @@ impl Spiller {
     /// We should guarantee that the file is managed by this spiller.
     pub async fn read_spilled(&self, file: &str) -> Result<(DataBlock, u64)> {
         debug_assert!(self.columns_layout.contains_key(file));
-        let data = self.operator.read(file).await?;
+        let data = spill_source(&self.operator, file).read(file).await?;
         let bytes = data.len() as u64;
 
         let mut begin = 0;
@@ -124,32 +128,35 @@ impl Spiller {
         Ok((block, bytes))
     }
 
-    /// Write a [`DataBlock`] to storage.
+    /// Write a [`DataBlock`] to storage, the local disk is preferred if it has enough room.
     pub async fn spill_block(&mut self, data: DataBlock) -> Result<(String, u64)> {
-        let unique_name = GlobalUniqName::unique();
-        let location = format!("{}/{}", self.config.location_prefix, unique_name);
-        let mut write_bytes = 0;
-
-        let mut writer = self.operator.writer(&location).await?;
         let columns = data.columns().to_vec();
         let mut columns_data = Vec::with_capacity(columns.len());
+        let mut columns_layout = Vec::with_capacity(columns.len());
         for column in columns.into_iter() {
             let column = column.value.as_column().unwrap();
             let column_data = serialize_column(column);
-            self.columns_layout
-                .entry(location.clone())
-                .and_modify(|layouts| {
-                    layouts.push(column_data.len());
-                })
-                .or_insert(vec![column_data.len()]);
-            write_bytes += column_data.len() as u64;
+            columns_layout.push(column_data.len());
             columns_data.push(column_data);
         }
+        let write_bytes = columns_layout.iter().sum::<usize>() as u64;
+
+        let (operator, location) = spill_target(
+            &self.ctx.get_tenant(),
+            &self.ctx.get_id(),
+            &self.operator,
+            &self.config.location_prefix,
+            &GlobalUniqName::unique(),
+            write_bytes,
+        );
+        let mut writer = operator.writer(&location).await?;
         for data in columns_data.into_iter() {
             writer.write(data).await?;
         }
         writer.close().await?;
 
+        metrics_inc_spill_tier_write_bytes(SpillTier::of(&location).to_string(), write_bytes);
+        self.columns_layout.insert(location.clone(), columns_layout);
         Ok((location, write_bytes))
     }
 
@@ -210,6 +210,8 @@ impl TenantQuotaSource {
 /// max_tables_per_database: u32
 /// max_stages: u32
 /// max_files_per_stage: u32
+/// max_udfs: u32
+/// max_meta_bytes: u64
 #[async_trait::async_trait]
 impl AsyncSource for TenantQuotaSource {
     const NAME: &'static str = "tenant_quota";
@@ -253,6 +255,12 @@ impl AsyncSource for TenantQuotaSource {
         if let Some(max_files_per_stage) = args.get(4) {
             quota.max_files_per_stage = max_files_per_stage.parse::<u32>()?
         };
+        if let Some(max_udfs) = args.get(5) {
+            quota.max_udfs = max_udfs.parse::<u32>()?;
+        };
+        if let Some(max_meta_bytes) = args.get(6) {
+            quota.max_meta_bytes = max_meta_bytes.parse::<u64>()?;
+        };
 
         quota_api
             .set_quota(&quota, MatchSeq::Exact(res.seq))
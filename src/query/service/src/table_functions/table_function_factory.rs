@@ -36,6 +36,7 @@ use crate::storages::fuse::table_functions::FuseBlockTable;
 use crate::storages::fuse::table_functions::FuseSegmentTable;
 use crate::storages::fuse::table_functions::FuseSnapshotTable;
 use crate::storages::fuse::table_functions::FuseStatisticTable;
+use crate::storages::fuse::table_functions::FuseTagTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
 use crate::table_functions::infer_schema::InferSchemaTable;
 use crate::table_functions::inspect_parquet::InspectParquetTable;
@@ -131,6 +132,10 @@ impl TableFunctionFactory {
             "fuse_statistic".to_string(),
             (next_id(), Arc::new(FuseStatisticTable::create)),
         );
+        creators.insert(
+            "fuse_tags".to_string(),
+            (next_id(), Arc::new(FuseTagTable::create)),
+        );
 
         creators.insert(
             "clustering_information".to_string(),
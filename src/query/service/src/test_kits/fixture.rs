@@ -317,6 +317,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_source: None,
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -341,6 +342,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -376,6 +378,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -420,6 +423,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
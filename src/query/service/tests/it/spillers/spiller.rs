@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_base::base::tokio;
+use common_base::base::GlobalUniqName;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::types::DataType;
@@ -23,7 +24,10 @@ use common_expression::DataBlock;
 use common_expression::FromData;
 use common_expression::ScalarRef;
 use common_pipeline_core::query_spill_prefix;
+use common_storage::local_spill_prefix;
 use common_storage::DataOperator;
+use common_storage::LocalSpillDisk;
+use common_storage::SpillTier;
 use databend_query::spillers::Spiller;
 use databend_query::spillers::SpillerConfig;
 use databend_query::spillers::SpillerType;
@@ -75,3 +79,30 @@ async fn test_spill_with_partition() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_local_spill_disk_quota() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let path = std::env::temp_dir().join(format!("databend_spill_{}", GlobalUniqName::unique()));
+    let disk = LocalSpillDisk::try_create(path.to_str().unwrap(), 100)?;
+
+    assert!(disk.try_reserve("test", "query_1", 60));
+    assert!(!disk.try_reserve("test", "query_2", 60));
+    assert!(disk.try_reserve("test", "query_2", 40));
+    assert_eq!(disk.used_bytes(), 100);
+
+    let location = format!("{}/query_1/file", local_spill_prefix("test"));
+    assert_eq!(SpillTier::of(&location), SpillTier::Local);
+    assert_eq!(SpillTier::of("_query_spill/test/file"), SpillTier::Remote);
+    disk.operator().write(&location, vec![0; 60]).await?;
+
+    // The quota and files of the query are released after cleanup.
+    disk.cleanup_query("query_1");
+    assert_eq!(disk.used_bytes(), 40);
+    assert!(!disk.operator().is_exist(&location).await?);
+    assert!(disk.try_reserve("test", "query_3", 60));
+
+    std::fs::remove_dir_all(path)?;
+    Ok(())
+}
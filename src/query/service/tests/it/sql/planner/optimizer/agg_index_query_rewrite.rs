@@ -81,6 +81,7 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         .into(),
         field_comments: vec![],
         as_select: None,
+        clone_source: None,
         cluster_key: None,
     }
 }
@@ -322,7 +323,41 @@ fn get_test_suites() -> Vec<TestSuite> {
             index_selection: vec!["index_col_0 (#0)", "index_col_1 (#1)"],
             rewritten_predicates: vec![],
         },
+        // rollup from finer groups of the index
+        TestSuite {
+            query: "select sum(a) from t group by b",
+            index: "select b, c, sum(a) from t group by b, c",
+            is_matched: true,
+            index_selection: vec!["index_col_0 (#0)", "index_col_2 (#2)"],
+            rewritten_predicates: vec![],
+        },
+        TestSuite {
+            query: "select sum(a) from t",
+            index: "select b, sum(a) from t group by b",
+            is_matched: true,
+            index_selection: vec!["index_col_1 (#1)"],
+            rewritten_predicates: vec![],
+        },
+        TestSuite {
+            query: "select sum(a) from t group by b, c",
+            index: "select b, sum(a) from t group by b",
+            is_matched: false,
+            ..Default::default()
+        },
         // query: eval-agg-eval-filter-scan, index: eval-agg-eval-scan
+        TestSuite {
+            query: "select sum(a) from t where c = 1 group by b",
+            index: "select b, c, sum(a) from t group by b, c",
+            is_matched: true,
+            index_selection: vec!["index_col_0 (#0)", "index_col_2 (#2)"],
+            rewritten_predicates: vec!["eq(index_col_1 (#1), 1)"],
+        },
+        TestSuite {
+            query: "select sum(a) from t where c = 1 group by b",
+            index: "select b, sum(a) from t group by b",
+            is_matched: false,
+            ..Default::default()
+        },
         TestSuite {
             query: "select sum(a) + 1 from t where b > 1 group by b",
             index: "select b, sum(a) from t group by b",
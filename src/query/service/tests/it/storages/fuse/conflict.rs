@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::vec;
@@ -51,6 +52,8 @@ fn test_unresolvable_delete_conflict() {
         removed_segment_indexes: vec![1],
         removed_statistics: Statistics::default(),
         merged_statistics: Statistics::default(),
+        appended_partitions: BTreeMap::new(),
+        detach_partitions: false,
     });
 
     let mut generator = MutationGenerator::new(Arc::new(base_snapshot));
@@ -139,6 +142,8 @@ fn test_resolvable_delete_conflict() {
         removed_segment_indexes: vec![1],
         removed_statistics,
         merged_statistics,
+        appended_partitions: BTreeMap::new(),
+        detach_partitions: false,
     });
 
     let mut generator = MutationGenerator::new(Arc::new(base_snapshot));
@@ -242,6 +247,8 @@ fn test_resolvable_replace_conflict() {
         removed_segment_indexes: vec![1],
         removed_statistics,
         merged_statistics,
+        appended_partitions: BTreeMap::new(),
+        detach_partitions: false,
     });
 
     let mut generator = MutationGenerator::new(Arc::new(base_snapshot));
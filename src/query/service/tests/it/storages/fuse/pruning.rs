@@ -105,6 +105,7 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        clone_source: None,
         cluster_key: None,
     };
 
@@ -24,6 +24,9 @@ use once_cell::sync::OnceCell;
 
 static DEFAULT_SETTINGS: OnceCell<Arc<DefaultSettings>> = OnceCell::new();
 
+// The settings that affect all the sessions of a tenant, they can only be set with `SET GLOBAL`.
+const GLOBAL_ONLY_SETTINGS: &[&str] = &["meta_audit_log_retention_days"];
+
 // Settings for readability and writability of tags.
 // we will not be able to safely get its value when set to only write.
 // we will not be able to safely set its value when set to only read.
@@ -97,6 +100,13 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("meta_audit_log_retention_days", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(30),
+                    desc: "Sets the number of days to keep the audit records of schema and privilege changes, 0 disables the audit log. It can only be set globally.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("max_storage_io_requests", DefaultSettingValue {
                     value: UserSettingValue::UInt64(default_max_storage_io_requests),
                     desc: "Sets the maximum number of concurrent I/O requests.",
@@ -221,6 +231,27 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("enable_sort_merge_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables sort-merge join for equi-joins whose inputs are already sorted by the join keys.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("sort_merge_join_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Estimated rows of the join build side above which sort-merge join is preferred over hash join, 0 is disabled.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("enable_shared_subexpression", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables executing the identical subqueries of a query once and sharing the results, if it is cheaper than executing them repeatedly.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("enable_runtime_filter", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables runtime filter optimization for JOIN.",
@@ -779,6 +810,16 @@ impl DefaultSettings {
         }
     }
 
+    pub fn check_session_settable(key: &str) -> Result<()> {
+        if GLOBAL_ONLY_SETTINGS.contains(&key) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Variable {:?} can only be set globally, use SET GLOBAL instead",
+                key
+            )));
+        }
+        Ok(())
+    }
+
     pub fn check_setting_mode(key: &str, expect: SettingMode) -> Result<()> {
         let default_settings = DefaultSettings::instance()?;
         let setting_mode = default_settings
@@ -99,6 +99,7 @@ impl Settings {
 
     pub fn set_setting(&self, k: String, v: String) -> Result<()> {
         DefaultSettings::check_setting_mode(&k, SettingMode::Write)?;
+        DefaultSettings::check_session_settable(&k)?;
 
         unsafe { self.unchecked_set_setting(k, v) }
     }
@@ -171,6 +172,10 @@ impl Settings {
         self.try_get_u64("retention_period")
     }
 
+    pub fn get_meta_audit_log_retention_days(&self) -> Result<u64> {
+        self.try_get_u64("meta_audit_log_retention_days")
+    }
+
     pub fn get_max_storage_io_requests(&self) -> Result<u64> {
         self.try_get_u64("max_storage_io_requests")
     }
@@ -258,6 +263,18 @@ impl Settings {
         Ok(self.try_get_u64("join_spilling_threshold")? as usize)
     }
 
+    pub fn get_enable_sort_merge_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_sort_merge_join")? != 0)
+    }
+
+    pub fn get_sort_merge_join_threshold(&self) -> Result<u64> {
+        self.try_get_u64("sort_merge_join_threshold")
+    }
+
+    pub fn get_enable_shared_subexpression(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_shared_subexpression")? != 0)
+    }
+
     pub fn get_runtime_filter(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_runtime_filter")? != 0)
     }
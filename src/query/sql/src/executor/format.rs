@@ -46,6 +46,7 @@ use crate::executor::physical_plans::RangeJoinType;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortMergeJoin;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
@@ -127,6 +128,20 @@ impl PhysicalPlan {
                     children,
                 ))
             }
+            PhysicalPlan::SortMergeJoin(plan) => {
+                let left_child = plan.left.format_join(metadata)?;
+                let right_child = plan.right.format_join(metadata)?;
+
+                let children = vec![
+                    FormatTreeNode::with_children("Left".to_string(), vec![left_child]),
+                    FormatTreeNode::with_children("Right".to_string(), vec![right_child]),
+                ];
+
+                Ok(FormatTreeNode::with_children(
+                    format!("SortMergeJoin: {}", plan.join_type),
+                    children,
+                ))
+            }
             PhysicalPlan::CteScan(cte_scan) => Ok(FormatTreeNode::with_children(
                 format!(
                     "CteScan: {}, sub index: {}",
@@ -203,6 +218,7 @@ fn to_format_tree(
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Udf(plan) => udf_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RangeJoin(plan) => range_join_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SortMergeJoin(plan) => sort_merge_join_to_format_tree(plan, metadata, profs),
         PhysicalPlan::CopyIntoTable(plan) => copy_into_table(plan),
         PhysicalPlan::ReplaceAsyncSourcer(_) => {
             Ok(FormatTreeNode::new("ReplaceAsyncSourcer".to_string()))
@@ -866,6 +882,63 @@ fn range_join_to_format_tree(
     ))
 }
 
+fn sort_merge_join_to_format_tree(
+    plan: &SortMergeJoin,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let left_keys = plan
+        .left_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let right_keys = plan
+        .right_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let other_conditions = plan
+        .other_conditions
+        .iter()
+        .map(|filter| filter.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut left_child = to_format_tree(&plan.left, metadata, prof_span_set)?;
+    let mut right_child = to_format_tree(&plan.right, metadata, prof_span_set)?;
+
+    left_child.payload = format!("{}(Left)", left_child.payload);
+    right_child.payload = format!("{}(Right)", right_child.payload);
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("join type: {}", plan.join_type)),
+        FormatTreeNode::new(format!("left keys: [{left_keys}]")),
+        FormatTreeNode::new(format!("right keys: [{right_keys}]")),
+        FormatTreeNode::new(format!("other conditions: [{other_conditions}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(left_child);
+    children.push(right_child);
+
+    Ok(FormatTreeNode::with_children(
+        "SortMergeJoin".to_string(),
+        children,
+    ))
+}
+
 fn hash_join_to_format_tree(
     plan: &HashJoin,
     metadata: &Metadata,
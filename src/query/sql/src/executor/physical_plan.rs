@@ -49,6 +49,7 @@ use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortMergeJoin;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
@@ -73,6 +74,7 @@ pub enum PhysicalPlan {
     RowFetch(RowFetch),
     HashJoin(HashJoin),
     RangeJoin(RangeJoin),
+    SortMergeJoin(SortMergeJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
     CteScan(CteScan),
@@ -136,6 +138,7 @@ impl PhysicalPlan {
             PhysicalPlan::RowFetch(v) => v.plan_id,
             PhysicalPlan::HashJoin(v) => v.plan_id,
             PhysicalPlan::RangeJoin(v) => v.plan_id,
+            PhysicalPlan::SortMergeJoin(v) => v.plan_id,
             PhysicalPlan::Exchange(v) => v.plan_id,
             PhysicalPlan::UnionAll(v) => v.plan_id,
             PhysicalPlan::DistributedInsertSelect(v) => v.plan_id,
@@ -182,6 +185,7 @@ impl PhysicalPlan {
             PhysicalPlan::UnionAll(plan) => plan.output_schema(),
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::RangeJoin(plan) => plan.output_schema(),
+            PhysicalPlan::SortMergeJoin(plan) => plan.output_schema(),
             PhysicalPlan::CopyIntoTable(plan) => plan.output_schema(),
             PhysicalPlan::CteScan(plan) => plan.output_schema(),
             PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
@@ -228,6 +232,7 @@ impl PhysicalPlan {
             PhysicalPlan::DeleteSource(_) => "DeleteSource".to_string(),
             PhysicalPlan::CommitSink(_) => "CommitSink".to_string(),
             PhysicalPlan::RangeJoin(_) => "RangeJoin".to_string(),
+            PhysicalPlan::SortMergeJoin(_) => "SortMergeJoin".to_string(),
             PhysicalPlan::CopyIntoTable(_) => "CopyIntoTable".to_string(),
             PhysicalPlan::ReplaceAsyncSourcer(_) => "ReplaceAsyncSourcer".to_string(),
             PhysicalPlan::ReplaceDeduplicate(_) => "ReplaceDeduplicate".to_string(),
@@ -284,6 +289,9 @@ impl PhysicalPlan {
             PhysicalPlan::RangeJoin(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::SortMergeJoin(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
             PhysicalPlan::ReplaceDeduplicate(plan) => {
                 Box::new(std::iter::once(plan.input.as_ref()))
             }
@@ -324,6 +332,7 @@ impl PhysicalPlan {
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::HashJoin(_)
             | PhysicalPlan::RangeJoin(_)
+            | PhysicalPlan::SortMergeJoin(_)
             | PhysicalPlan::MaterializedCte(_)
             | PhysicalPlan::AggregateExpand(_)
             | PhysicalPlan::AggregateFinal(_)
@@ -378,6 +387,7 @@ impl PhysicalPlan {
             // Todo: support union and range join return valid table index by join probe keys
             PhysicalPlan::UnionAll(_) |
             PhysicalPlan::RangeJoin(_)|
+            PhysicalPlan::SortMergeJoin(_)|
             PhysicalPlan::ConstantTableScan(_)
             |PhysicalPlan::CteScan(_)
             | PhysicalPlan::Udf(_)
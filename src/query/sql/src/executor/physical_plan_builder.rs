@@ -38,6 +38,8 @@ pub struct PhysicalPlanBuilder {
     pub(crate) dry_run: bool,
     // Record cte_idx and the cte's output columns
     pub(crate) cte_output_columns: HashMap<IndexType, Vec<ColumnBinding>>,
+    // Record cte_idx and all the columns of the cte, `CteScan` refers to them by offsets
+    pub(crate) cte_columns: HashMap<IndexType, Vec<ColumnBinding>>,
 }
 
 impl PhysicalPlanBuilder {
@@ -50,6 +52,7 @@ impl PhysicalPlanBuilder {
             func_ctx,
             dry_run,
             cte_output_columns: Default::default(),
+            cte_columns: Default::default(),
         }
     }
 
@@ -51,6 +51,7 @@ use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortMergeJoin;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
@@ -96,6 +97,7 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::CommitSink(commit) => write!(f, "{}", commit)?,
             PhysicalPlan::ProjectSet(unnest) => write!(f, "{}", unnest)?,
             PhysicalPlan::RangeJoin(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::SortMergeJoin(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::CopyIntoTable(copy_into_table) => write!(f, "{}", copy_into_table)?,
             PhysicalPlan::ReplaceAsyncSourcer(async_sourcer) => write!(f, "{}", async_sourcer)?,
             PhysicalPlan::ReplaceDeduplicate(deduplicate) => write!(f, "{}", deduplicate)?,
@@ -367,6 +369,26 @@ impl Display for RangeJoin {
     }
 }
 
+impl Display for SortMergeJoin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let left_keys = self
+            .left_keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .join(", ");
+        let right_keys = self
+            .right_keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .join(", ");
+        write!(
+            f,
+            "SortMergeJoin: {}, left keys: [{}], right keys: [{}]",
+            &self.join_type, left_keys, right_keys,
+        )
+    }
+}
+
 impl Display for Exchange {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let keys = self
@@ -49,6 +49,7 @@ use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortMergeJoin;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
@@ -81,6 +82,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::DeleteSource(plan) => self.replace_delete_source(plan),
             PhysicalPlan::CommitSink(plan) => self.replace_commit_sink(plan),
             PhysicalPlan::RangeJoin(plan) => self.replace_range_join(plan),
+            PhysicalPlan::SortMergeJoin(plan) => self.replace_sort_merge_join(plan),
             PhysicalPlan::CopyIntoTable(plan) => self.replace_copy_into_table(plan),
             PhysicalPlan::ReplaceAsyncSourcer(plan) => self.replace_async_sourcer(plan),
             PhysicalPlan::ReplaceDeduplicate(plan) => self.replace_deduplicate(plan),
@@ -267,6 +269,17 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_sort_merge_join(&mut self, plan: &SortMergeJoin) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+
+        Ok(PhysicalPlan::SortMergeJoin(SortMergeJoin {
+            left: Box::new(left),
+            right: Box::new(right),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_sort(&mut self, plan: &Sort) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -573,6 +586,10 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::SortMergeJoin(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::ReclusterSink(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -80,6 +80,8 @@ mod physical_row_fetch;
 pub use physical_row_fetch::RowFetch;
 mod physical_sort;
 pub use physical_sort::Sort;
+mod physical_sort_merge_join;
+pub use physical_sort_merge_join::*;
 mod physical_table_scan;
 pub use physical_table_scan::TableScan;
 
@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_settings::Settings;
 
 use crate::binder::JoinPredicate;
 use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::try_sort_merge_join;
+use crate::executor::physical_plans::SortMergeJoinKeys;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::RelExpr;
@@ -30,12 +33,18 @@ pub enum PhysicalJoinType {
     Hash,
     // The first arg is range conditions, the second arg is other conditions
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
+    // Equi-join keys ordered by the sort order of inputs
+    SortMerge(SortMergeJoinKeys),
 }
 
 // Choose physical join type by join conditions
-pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
+pub fn physical_join(join: &Join, s_expr: &SExpr, settings: &Settings) -> Result<PhysicalJoinType> {
     if !join.left_conditions.is_empty() {
-        // Contain equi condition, use hash join
+        // Contain equi condition, use sort-merge join if inputs are sorted by join keys,
+        // otherwise use hash join
+        if let Some(keys) = try_sort_merge_join(join, s_expr, settings)? {
+            return Ok(PhysicalJoinType::SortMerge(keys));
+        }
         return Ok(PhysicalJoinType::Hash);
     }
 
@@ -137,7 +146,7 @@ impl PhysicalPlanBuilder {
 
         // 2. Build physical plan.
         // Choose physical join type by join conditions
-        let physical_join = physical_join(join, s_expr)?;
+        let physical_join = physical_join(join, s_expr, &self.ctx.get_settings())?;
         match physical_join {
             PhysicalJoinType::Hash => {
                 self.build_hash_join(
@@ -154,6 +163,10 @@ impl PhysicalPlanBuilder {
                 self.build_range_join(s_expr, left_required, right_required, range, other)
                     .await
             }
+            PhysicalJoinType::SortMerge(keys) => {
+                self.build_sort_merge_join(s_expr, join, left_required, right_required, keys)
+                    .await
+            }
         }
     }
 }
@@ -20,6 +20,7 @@ use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
 use crate::ColumnBinding;
 use crate::ColumnSet;
 use crate::IndexType;
@@ -53,10 +54,12 @@ impl PhysicalPlanBuilder {
             .derive_relational_prop_child(0)?
             .output_columns
             .clone();
-        let right_used_column = RelExpr::with_s_expr(s_expr)
+        let mut right_used_column = RelExpr::with_s_expr(s_expr)
             .derive_relational_prop_child(1)?
             .used_columns
             .clone();
+        // The consumers may rename the columns of the cte, which are resolved by offsets.
+        collect_cte_scan_columns(s_expr.child(1)?, cte, &mut right_used_column);
         // Get the intersection of `left_used_column` and `right_used_column`
         let left_required = left_output_column
             .intersection(&right_used_column)
@@ -71,6 +74,8 @@ impl PhysicalPlanBuilder {
         }
         self.cte_output_columns
             .insert(cte.cte_idx, required_output_columns.clone());
+        self.cte_columns
+            .insert(cte.cte_idx, cte.left_output_columns.clone());
 
         // 2. Build physical plan.
         Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
@@ -82,3 +87,27 @@ impl PhysicalPlanBuilder {
         }))
     }
 }
+
+// Collect the columns of the cte read by its `CteScan`s.
+fn collect_cte_scan_columns(
+    s_expr: &SExpr,
+    cte: &crate::plans::MaterializedCte,
+    columns: &mut ColumnSet,
+) {
+    if let RelOperator::CteScan(scan) = s_expr.plan() {
+        if scan.cte_idx.0 == cte.cte_idx {
+            for (field, offset) in scan.fields.iter().zip(scan.offsets.iter()) {
+                if field
+                    .name()
+                    .parse::<IndexType>()
+                    .map_or(false, |index| columns.contains(&index))
+                {
+                    columns.insert(cte.left_output_columns[*offset].index);
+                }
+            }
+        }
+    }
+    for child in s_expr.children() {
+        collect_cte_scan_columns(child, cte, columns);
+    }
+}
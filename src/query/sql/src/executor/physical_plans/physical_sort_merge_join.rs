@@ -0,0 +1,334 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_expression::RemoteExpr;
+use common_expression::RowConverter;
+use common_expression::SortField;
+use common_settings::Settings;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::common::SortDesc;
+use crate::executor::physical_plans::Sort;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::hash_join_cost;
+use crate::optimizer::sort_merge_join_cost;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::SortItem;
+use crate::IndexType;
+use crate::ScalarExpr;
+use crate::TypeCheck;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SortMergeJoin {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub left: Box<PhysicalPlan>,
+    pub right: Box<PhysicalPlan>,
+    // Both sides are sorted by the join keys in the same order, with the same
+    // direction for each key.
+    pub left_keys: Vec<RemoteExpr>,
+    pub right_keys: Vec<RemoteExpr>,
+    pub sort_desc: Vec<SortMergeJoinKeyDesc>,
+    // Conditions which are evaluated on the merged rows.
+    pub other_conditions: Vec<RemoteExpr>,
+    // Now only support inner join
+    pub join_type: JoinType,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SortMergeJoin {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let mut fields = self.left.output_schema()?.fields().clone();
+        fields.extend(self.right.output_schema()?.fields().clone());
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SortMergeJoinKeyDesc {
+    pub asc: bool,
+    pub nulls_first: bool,
+}
+
+/// Equi-join keys of a sort-merge join, in the order both sides are sorted by.
+pub struct SortMergeJoinKeys {
+    pub left_keys: Vec<BoundColumnRef>,
+    pub right_keys: Vec<BoundColumnRef>,
+    pub sort_items: Vec<SortItem>,
+    // If the side is already sorted by the keys, otherwise a sort is added before merging.
+    pub left_sorted: bool,
+    pub right_sorted: bool,
+}
+
+/// Check if the equi-join can be executed by sort-merge join, it's chosen if
+/// 1. both sides are already ordered by the join keys, or
+/// 2. the build side is large enough and sorting is cheaper than building the hash table.
+pub fn try_sort_merge_join(
+    join: &Join,
+    s_expr: &SExpr,
+    settings: &Settings,
+) -> Result<Option<SortMergeJoinKeys>> {
+    if !settings.get_enable_sort_merge_join()?
+        || join.join_type != JoinType::Inner
+        || join.need_hold_hash_table
+        || join.from_correlated_subquery
+    {
+        return Ok(None);
+    }
+
+    // The join keys must be columns with the same comparable data type,
+    // so rows of both sides can be compared in row format directly.
+    let mut keys = Vec::with_capacity(join.left_conditions.len());
+    for (left, right) in join
+        .left_conditions
+        .iter()
+        .zip(join.right_conditions.iter())
+    {
+        match (left, right) {
+            (ScalarExpr::BoundColumnRef(left), ScalarExpr::BoundColumnRef(right))
+                if left.column.data_type == right.column.data_type
+                    && RowConverter::new(vec![SortField::new(
+                        left.column.data_type.as_ref().clone(),
+                    )])
+                    .is_ok() =>
+            {
+                if keys
+                    .iter()
+                    .any(|(l, _): &(BoundColumnRef, BoundColumnRef)| {
+                        l.column.index == left.column.index
+                    })
+                {
+                    return Ok(None);
+                }
+                keys.push((left.clone(), right.clone()));
+            }
+            _ => return Ok(None),
+        }
+    }
+
+    let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_physical_prop()?;
+    let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_physical_prop()?;
+
+    // Reorder the keys by the ordering of left side, fallback to the ordering of right side.
+    let (keys, sort_items) = if let Some(keys) = order_keys(&keys, &left_prop.ordering, true) {
+        let sort_items = left_prop.ordering[..keys.len()].to_vec();
+        (keys, sort_items)
+    } else if let Some(keys) = order_keys(&keys, &right_prop.ordering, false) {
+        let sort_items = right_prop.ordering[..keys.len()]
+            .iter()
+            .zip(keys.iter())
+            .map(|(item, (left, _))| SortItem {
+                index: left.column.index,
+                asc: item.asc,
+                nulls_first: item.nulls_first,
+            })
+            .collect::<Vec<_>>();
+        (keys, sort_items)
+    } else {
+        let sort_items = keys
+            .iter()
+            .map(|(left, _)| SortItem {
+                index: left.column.index,
+                asc: true,
+                nulls_first: false,
+            })
+            .collect::<Vec<_>>();
+        (keys, sort_items)
+    };
+
+    let left_columns = keys.iter().map(|(l, _)| l.column.index).collect::<Vec<_>>();
+    let right_columns = keys.iter().map(|(_, r)| r.column.index).collect::<Vec<_>>();
+    let left_sorted = satisfy_ordering(&left_prop.ordered_by(&left_columns), &sort_items);
+    let right_sorted = satisfy_ordering(&right_prop.ordered_by(&right_columns), &sort_items);
+
+    let left_card = RelExpr::with_s_expr(s_expr.child(0)?)
+        .derive_cardinality()?
+        .cardinality;
+    let right_card = RelExpr::with_s_expr(s_expr.child(1)?)
+        .derive_cardinality()?
+        .cardinality;
+    if !(left_sorted && right_sorted) {
+        // Sorting unordered inputs only pays off if the hash table is too large.
+        let threshold = settings.get_sort_merge_join_threshold()?;
+        if threshold == 0 || right_card < threshold as f64 {
+            return Ok(None);
+        }
+    }
+
+    let merge_cost = sort_merge_join_cost(left_card, right_card, left_sorted, right_sorted);
+    if merge_cost >= hash_join_cost(right_card, left_card) {
+        return Ok(None);
+    }
+
+    let (left_keys, right_keys) = keys.into_iter().unzip();
+    Ok(Some(SortMergeJoinKeys {
+        left_keys,
+        right_keys,
+        sort_items,
+        left_sorted,
+        right_sorted,
+    }))
+}
+
+// Reorder the key pairs to follow the given ordering of one side.
+fn order_keys(
+    keys: &[(BoundColumnRef, BoundColumnRef)],
+    ordering: &[SortItem],
+    is_left: bool,
+) -> Option<Vec<(BoundColumnRef, BoundColumnRef)>> {
+    if ordering.len() < keys.len() {
+        return None;
+    }
+    ordering[..keys.len()]
+        .iter()
+        .map(|item| {
+            keys.iter()
+                .find(|(left, right)| {
+                    let column = if is_left { left } else { right };
+                    column.column.index == item.index
+                })
+                .cloned()
+        })
+        .collect()
+}
+
+fn satisfy_ordering(delivered: &Option<&[SortItem]>, required: &[SortItem]) -> bool {
+    match delivered {
+        Some(delivered) => delivered
+            .iter()
+            .zip(required.iter())
+            .all(|(d, r)| d.asc == r.asc && d.nulls_first == r.nulls_first),
+        None => false,
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub async fn build_sort_merge_join(
+        &mut self,
+        s_expr: &SExpr,
+        join: &Join,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+        keys: SortMergeJoinKeys,
+    ) -> Result<PhysicalPlan> {
+        let mut left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let mut right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_columns = keys
+            .left_keys
+            .iter()
+            .map(|key| key.column.index)
+            .collect::<Vec<_>>();
+        let right_columns = keys
+            .right_keys
+            .iter()
+            .map(|key| key.column.index)
+            .collect::<Vec<_>>();
+        if !keys.left_sorted {
+            left_side = self.build_join_input_sort(left_side, &left_columns, &keys.sort_items);
+        }
+        if !keys.right_sorted {
+            right_side = self.build_join_input_sort(right_side, &right_columns, &keys.sort_items);
+        }
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+        let merged_schema = DataSchemaRefExt::create(
+            left_schema
+                .fields()
+                .iter()
+                .chain(right_schema.fields())
+                .cloned()
+                .collect::<Vec<_>>(),
+        );
+
+        let left_keys = keys
+            .left_keys
+            .into_iter()
+            .map(|key| resolve_scalar(&ScalarExpr::BoundColumnRef(key), &left_schema))
+            .collect::<Result<_>>()?;
+        let right_keys = keys
+            .right_keys
+            .into_iter()
+            .map(|key| resolve_scalar(&ScalarExpr::BoundColumnRef(key), &right_schema))
+            .collect::<Result<_>>()?;
+
+        Ok(PhysicalPlan::SortMergeJoin(SortMergeJoin {
+            plan_id: self.next_plan_id(),
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            left_keys,
+            right_keys,
+            sort_desc: keys
+                .sort_items
+                .iter()
+                .map(|item| SortMergeJoinKeyDesc {
+                    asc: item.asc,
+                    nulls_first: item.nulls_first,
+                })
+                .collect(),
+            other_conditions: join
+                .non_equi_conditions
+                .iter()
+                .map(|scalar| resolve_scalar(scalar, &merged_schema))
+                .collect::<Result<_>>()?,
+            join_type: join.join_type.clone(),
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+
+    // Sort the input of sort-merge join by join keys, the sort can be spilled.
+    fn build_join_input_sort(
+        &mut self,
+        input: PhysicalPlan,
+        columns: &[IndexType],
+        sort_items: &[SortItem],
+    ) -> PhysicalPlan {
+        PhysicalPlan::Sort(Sort {
+            plan_id: self.next_plan_id(),
+            input: Box::new(input),
+            order_by: columns
+                .iter()
+                .zip(sort_items.iter())
+                .map(|(column, item)| SortDesc {
+                    asc: item.asc,
+                    nulls_first: item.nulls_first,
+                    order_by: *column,
+                })
+                .collect(),
+            limit: None,
+            after_exchange: None,
+            pre_projection: None,
+            stat_info: None,
+        })
+    }
+}
+
+fn resolve_scalar(scalar: &ScalarExpr, schema: &DataSchemaRef) -> Result<RemoteExpr> {
+    let expr = scalar
+        .type_check(schema.as_ref())?
+        .project_column_ref(|index| schema.index_of(&index.to_string()).unwrap());
+    Ok(expr.as_remote_expr())
+}
@@ -399,6 +399,38 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::SortMergeJoin(join) => {
+            flatten_plan_node_profile(metadata, &join.left, profs, plan_node_profs)?;
+            flatten_plan_node_profile(metadata, &join.right, profs, plan_node_profs)?;
+            let proc_prof = profs.get(&join.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: join.plan_id,
+                operator_type: OperatorType::Join,
+                children: vec![join.left.get_id(), join.right.get_id()],
+                execution_info: proc_prof.into(),
+                attribute: OperatorAttribute::Join(JoinAttribute {
+                    join_type: join.join_type.to_string(),
+                    equi_conditions: join
+                        .left_keys
+                        .iter()
+                        .zip(join.right_keys.iter())
+                        .map(|(l, r)| {
+                            format!(
+                                "{} = {}",
+                                l.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                                r.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                            )
+                        })
+                        .join(" AND "),
+                    non_equi_conditions: join
+                        .other_conditions
+                        .iter()
+                        .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                        .join(" AND "),
+                }),
+            };
+            plan_node_profs.push(prof);
+        }
         PhysicalPlan::Exchange(exchange) => {
             flatten_plan_node_profile(metadata, &exchange.input, profs, plan_node_profs)?;
             let proc_prof = profs.get(&exchange.plan_id).copied().unwrap_or_default();
@@ -21,6 +21,7 @@ use common_ast::ast::AlterTableAction;
 use common_ast::ast::AlterTableStmt;
 use common_ast::ast::AnalyzeTableStmt;
 use common_ast::ast::AttachTableStmt;
+use common_ast::ast::BinaryOperator;
 use common_ast::ast::ColumnDefinition;
 use common_ast::ast::ColumnExpr;
 use common_ast::ast::CompactTarget;
@@ -53,6 +54,7 @@ use common_ast::ast::VacuumTableStmt;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
+use common_catalog::table::Table;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -60,6 +62,7 @@ use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
 use common_expression::types::DataType;
 use common_expression::ComputedExpr;
+use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
 use common_expression::TableField;
@@ -76,15 +79,21 @@ use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use log::error;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::ColumnStorageOptions;
+use storages_common_table_meta::table::FIELD_OPT_KEY_COMPRESSION;
+use storages_common_table_meta::table::FIELD_OPT_KEY_ENCODING;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::binder::get_storage_params_from_options;
 use crate::binder::parse_uri_location;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
@@ -93,6 +102,7 @@ use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
+use crate::parse_exprs;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
@@ -100,11 +110,15 @@ use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableTagPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableTagPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -401,6 +415,8 @@ impl Binder {
             source,
             table_options,
             cluster_by,
+            partition_by,
+            ttl,
             as_query,
             transient,
             engine,
@@ -451,6 +467,51 @@ impl Binder {
             _ => (None, "".to_string()),
         };
 
+        let clone_source = match source {
+            Some(CreateTableSource::Clone {
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+                travel_point,
+            }) => {
+                if as_query.is_some()
+                    || !cluster_by.is_empty()
+                    || partition_by.is_some()
+                    || ttl.is_some()
+                    || uri_location.is_some()
+                {
+                    return Err(ErrorCode::SyntaxException(
+                        "CREATE TABLE ... CLONE can not be used together with AS SELECT, CLUSTER BY, PARTITION BY, TTL or an external location",
+                    ));
+                }
+                if engine != Engine::Fuse {
+                    return Err(ErrorCode::TableEngineNotSupported(
+                        "CREATE TABLE ... CLONE is only supported for FUSE engine",
+                    ));
+                }
+                let (source_catalog, source_database, source_table) = self
+                    .normalize_object_identifier_triple(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                    );
+                let point = match travel_point {
+                    Some(travel_point) => Some(
+                        self.resolve_data_travel_point(&mut BindContext::new(), travel_point)
+                            .await?,
+                    ),
+                    None => None,
+                };
+                Some(CloneTableSource {
+                    catalog: source_catalog,
+                    database: source_database,
+                    table: source_table,
+                    point,
+                })
+            }
+            _ => None,
+        };
+
         // If table is TRANSIENT, set a flag in table option
         if *transient {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
@@ -613,6 +674,35 @@ impl Binder {
             }
         };
 
+        if let Some(partition_by) = partition_by {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::TableEngineNotSupported(
+                    "PARTITION BY is only supported for FUSE engine",
+                ));
+            }
+            // Blocks of a partitioned table are never reclustered across
+            // partitions, so the two can not be combined.
+            if cluster_key.is_some() {
+                return Err(ErrorCode::InvalidPartitionKey(
+                    "PARTITION BY can not be used together with CLUSTER BY",
+                ));
+            }
+            let partition_key = self
+                .analyze_partition_key(partition_by, schema.clone())
+                .await?;
+            options.insert(OPT_KEY_PARTITION_BY.to_owned(), partition_key);
+        }
+
+        if let Some(ttl) = ttl {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::TableEngineNotSupported(
+                    "TTL is only supported for FUSE engine",
+                ));
+            }
+            let ttl = self.analyze_ttl(ttl, schema.clone()).await?;
+            options.insert(OPT_KEY_TTL.to_owned(), ttl);
+        }
+
         let plan = CreateTablePlan {
             if_not_exists: *if_not_exists,
             tenant: self.ctx.get_tenant(),
@@ -638,6 +728,7 @@ impl Binder {
             } else {
                 None
             },
+            clone_source,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -707,6 +798,7 @@ impl Binder {
             field_comments: vec![],
             cluster_key: None,
             as_select: None,
+            clone_source: None,
         })))
     }
 
@@ -832,6 +924,7 @@ impl Binder {
                     .await?
                     .schema();
                 let (field, comment) = self.analyze_add_column(column, schema).await?;
+                let field_options = Self::analyze_column_storage_options(column)?;
                 let option = match ast_option {
                     AstAddColumnOption::First => AddColumnOption::First,
                     AstAddColumnOption::After(ident) => AddColumnOption::After(
@@ -846,6 +939,7 @@ impl Binder {
                     table,
                     field,
                     comment,
+                    field_options,
                     option,
                 })))
             }
@@ -894,11 +988,13 @@ impl Binder {
                 })))
             }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
-                let schema = self
-                    .ctx
-                    .get_table(&catalog, &database, &table)
-                    .await?
-                    .schema();
+                let table_meta = self.ctx.get_table(&catalog, &database, &table).await?;
+                if table_meta.options().contains_key(OPT_KEY_PARTITION_BY) {
+                    return Err(ErrorCode::InvalidPartitionKey(format!(
+                        "Can not add cluster keys to the partitioned table {database}.{table}"
+                    )));
+                }
+                let schema = table_meta.schema();
                 let cluster_keys = self.analyze_cluster_keys(cluster_by, schema).await?;
 
                 Ok(Plan::AlterTableClusterKey(Box::new(
@@ -964,6 +1060,48 @@ impl Binder {
                     database,
                     table,
                     point,
+                    restore: false,
+                })))
+            }
+            AlterTableAction::RestoreTo { point } => {
+                let point = self.resolve_data_travel_point(bind_context, point).await?;
+                Ok(Plan::RevertTable(Box::new(RevertTablePlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    point,
+                    restore: true,
+                })))
+            }
+            AlterTableAction::CreateTag { tag, retain_days } => {
+                Ok(Plan::CreateTableTag(Box::new(CreateTableTagPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    tag: tag.clone(),
+                    retain_days: *retain_days,
+                })))
+            }
+            AlterTableAction::DropTag { tag } => {
+                Ok(Plan::DropTableTag(Box::new(DropTableTagPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    tag: tag.clone(),
+                })))
+            }
+            AlterTableAction::DropPartition { partition } => {
+                let table_meta = self.ctx.get_table(&catalog, &database, &table).await?;
+                let partition = self.analyze_partition_value(table_meta, partition).await?;
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    partition,
                 })))
             }
             AlterTableAction::SetOptions { set_options } => {
@@ -1068,6 +1206,7 @@ impl Binder {
                 CompactTarget::Block => OptimizeTableAction::CompactBlocks,
                 CompactTarget::Segment => OptimizeTableAction::CompactSegments,
             },
+            AstOptimizeTableAction::Ttl => OptimizeTableAction::Ttl,
         };
 
         Ok(Plan::OptimizeTable(Box::new(OptimizeTablePlan {
@@ -1359,11 +1498,34 @@ impl Binder {
             fields
         };
 
-        let schema = TableSchemaRefExt::create(fields);
+        let mut schema = TableSchema::new(fields);
+        let column_ids = schema
+            .fields()
+            .iter()
+            .map(|f| f.column_id())
+            .collect::<Vec<_>>();
+        for (column, column_id) in columns.iter().zip(column_ids) {
+            schema.set_field_options(column_id, Self::analyze_column_storage_options(column)?)?;
+        }
+        let schema = Arc::new(schema);
         Self::validate_create_table_schema(&schema)?;
         Ok((schema, fields_comments))
     }
 
+    /// Validate and normalize the storage options of the column, like `COMPRESSION` and `ENCODING`.
+    fn analyze_column_storage_options(
+        column: &ColumnDefinition,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut options = BTreeMap::new();
+        if let Some(compression) = &column.compression {
+            options.insert(FIELD_OPT_KEY_COMPRESSION.to_string(), compression.clone());
+        }
+        if let Some(encoding) = &column.encoding {
+            options.insert(FIELD_OPT_KEY_ENCODING.to_string(), encoding.clone());
+        }
+        Ok(ColumnStorageOptions::try_from_field_options(&options)?.to_field_options())
+    }
+
     #[async_backtrace::framed]
     async fn analyze_create_table_schema(
         &self,
@@ -1396,6 +1558,24 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                ..
+            } => {
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                let table = self.ctx.get_table(&catalog, &database, &table).await?;
+                if table.engine() != "FUSE" {
+                    return Err(ErrorCode::TableEngineNotSupported(format!(
+                        "CLONE is only supported for FUSE tables, but table {} is of engine {}",
+                        table.name(),
+                        table.engine()
+                    )));
+                }
+                Ok((table.schema(), table.field_comments().clone()))
+            }
         }
     }
 
@@ -1504,6 +1684,232 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn analyze_partition_key(
+        &mut self,
+        partition_by: &Expr,
+        schema: TableSchemaRef,
+    ) -> Result<String> {
+        // Build a temporary BindContext to resolve the expr
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.add_column_binding(column);
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        // partition key cannot be a udf expression.
+        scalar_binder.forbid_udf();
+
+        let (partition_key, _) = scalar_binder.bind(partition_by).await?;
+        if partition_key.used_columns().is_empty() || !partition_key.evaluable() {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition by expression `{:#}` is invalid",
+                partition_by
+            )));
+        }
+
+        let expr = partition_key.as_expr()?;
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition by expression `{:#}` is not deterministic",
+                partition_by
+            )));
+        }
+
+        let data_type = expr.data_type();
+        if !Self::valid_cluster_key_type(data_type) {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Unsupported data type '{}' for partition by expression `{:#}`",
+                data_type, partition_by
+            )));
+        }
+
+        let mut partition_by = partition_by.clone();
+        walk_expr_mut(
+            &mut IdentifierNormalizer {
+                ctx: &self.name_resolution_ctx,
+            },
+            &mut partition_by,
+        );
+        Ok(format!("{:#}", &partition_by))
+    }
+
+    /// Validates the `TTL` expression of a table, and formats it to be stored
+    /// in the table options.
+    ///
+    /// The expression must be a date or timestamp column, optionally shifted by
+    /// a constant interval, so that the expiration time of the rows of a block
+    /// is bounded by the min/max statistics of the column.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn analyze_ttl(
+        &mut self,
+        ttl: &Expr,
+        schema: TableSchemaRef,
+    ) -> Result<String> {
+        if !Self::is_shifted_column(ttl) {
+            return Err(ErrorCode::InvalidTableTtl(format!(
+                "TTL expression `{:#}` must be a column optionally shifted by a constant interval, e.g. `ts + INTERVAL 90 DAY`",
+                ttl
+            )));
+        }
+
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.add_column_binding(column);
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        scalar_binder.forbid_udf();
+
+        let (scalar, _) = scalar_binder.bind(ttl).await?;
+        let expr = scalar.as_expr()?;
+        let column_refs = expr.column_refs();
+        let column = match column_refs.keys().next() {
+            Some(column) if column_refs.len() == 1 => schema.field(column.index),
+            _ => {
+                return Err(ErrorCode::InvalidTableTtl(format!(
+                    "TTL expression `{:#}` must reference exactly one column",
+                    ttl
+                )));
+            }
+        };
+        if matches!(column.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+            return Err(ErrorCode::InvalidTableTtl(format!(
+                "TTL can not be defined on the virtual computed column `{}`",
+                column.name()
+            )));
+        }
+
+        let column_type = DataType::from(column.data_type());
+        for data_type in [&column_type, expr.data_type()] {
+            if !matches!(
+                data_type.remove_nullable(),
+                DataType::Timestamp | DataType::Date
+            ) {
+                return Err(ErrorCode::InvalidTableTtl(format!(
+                    "TTL expression `{:#}` must be of DATE or TIMESTAMP type, but got {}",
+                    ttl, data_type
+                )));
+            }
+        }
+
+        let mut ttl = ttl.clone();
+        walk_expr_mut(
+            &mut IdentifierNormalizer {
+                ctx: &self.name_resolution_ctx,
+            },
+            &mut ttl,
+        );
+        Ok(format!("{:#}", &ttl))
+    }
+
+    // `col`, `col +/- INTERVAL n unit`, `DATE_ADD(unit, n, col)` or `DATE_SUB(unit, n, col)`.
+    fn is_shifted_column(expr: &Expr) -> bool {
+        fn is_constant_interval(expr: &Expr) -> bool {
+            match expr {
+                Expr::Interval { expr, .. } => matches!(**expr, Expr::Literal { .. }),
+                _ => false,
+            }
+        }
+
+        match expr {
+            Expr::ColumnRef { .. } => true,
+            Expr::BinaryOp {
+                op: BinaryOperator::Plus,
+                left,
+                right,
+                ..
+            } => {
+                (matches!(**left, Expr::ColumnRef { .. }) && is_constant_interval(right))
+                    || (is_constant_interval(left) && matches!(**right, Expr::ColumnRef { .. }))
+            }
+            Expr::BinaryOp {
+                op: BinaryOperator::Minus,
+                left,
+                right,
+                ..
+            } => matches!(**left, Expr::ColumnRef { .. }) && is_constant_interval(right),
+            Expr::DateAdd { interval, date, .. } | Expr::DateSub { interval, date, .. } => {
+                matches!(**interval, Expr::Literal { .. })
+                    && matches!(**date, Expr::ColumnRef { .. })
+            }
+            _ => false,
+        }
+    }
+
+    /// Evaluates the value given to `DROP PARTITION` as the partition key
+    /// type of the table, and formats it as a partition name.
+    #[async_backtrace::framed]
+    async fn analyze_partition_value(
+        &mut self,
+        table_meta: Arc<dyn Table>,
+        partition: &Expr,
+    ) -> Result<String> {
+        let partition_key = match table_meta.options().get(OPT_KEY_PARTITION_BY) {
+            Some(partition_key) => partition_key.clone(),
+            None => {
+                return Err(ErrorCode::InvalidPartitionKey(format!(
+                    "Table {} is not partitioned",
+                    table_meta.name()
+                )));
+            }
+        };
+        let partition_exprs = parse_exprs(self.ctx.clone(), table_meta, &partition_key)?;
+        let data_type = partition_exprs[0].data_type().clone();
+
+        let mut bind_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (scalar, _) = scalar_binder.bind(partition).await?;
+        let expr = wrap_cast(&scalar, &data_type).as_expr()?;
+        let (expr, _) =
+            ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+        match expr {
+            common_expression::Expr::Constant { scalar, .. } => Ok(scalar.as_ref().to_string()),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Partition value `{:#}` must be a constant",
+                partition
+            ))),
+        }
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
@@ -212,11 +212,13 @@ impl Binder {
         if !right_prop.outer_columns.is_empty() {
             // If there are outer columns in right child, then the join is a correlated lateral join
             let mut decorrelator = SubqueryRewriter::new(self.metadata.clone());
+            decorrelator.outer_input = Some(left_child.clone());
             right_child = decorrelator.flatten_plan(
                 &right_child,
                 &right_prop.outer_columns,
                 &mut FlattenInfo {
                     from_count_func: false,
+                    from_lateral_join: true,
                 },
                 false,
             )?;
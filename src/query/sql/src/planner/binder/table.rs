@@ -39,6 +39,7 @@ use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
 use common_ast::ast::UriLocation;
 use common_ast::parser::parse_sql;
+use common_ast::parser::quote::quote_ident;
 use common_ast::parser::tokenize_sql;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
@@ -117,6 +118,11 @@ use crate::BindContext;
 use crate::ColumnEntry;
 use crate::IndexType;
 use crate::ScalarExpr;
+use crate::CHANGE_DELETE_ALIAS;
+use crate::CHANGE_INSERT_ALIAS;
+use crate::CHANGE_TYPE_DELETE;
+use crate::CHANGE_TYPE_INSERT;
+use crate::CHANGE_TYPE_OPTION;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -267,6 +273,37 @@ impl Binder {
             }
         };
 
+        let mut table_meta = table_meta;
+        if is_standard_stream(table_meta.as_ref()) {
+            let change_type = match table_alias_name.as_deref() {
+                Some(CHANGE_INSERT_ALIAS) => CHANGE_TYPE_INSERT,
+                Some(CHANGE_DELETE_ALIAS) => CHANGE_TYPE_DELETE,
+                _ => {
+                    return self
+                        .bind_stream_changes(
+                            bind_context,
+                            span,
+                            &catalog,
+                            &database,
+                            table_meta,
+                            alias,
+                        )
+                        .await;
+                }
+            };
+            // Read one side of the changes, see `bind_stream_changes`.
+            let mut table_info = table_meta.get_table_info().clone();
+            table_info
+                .meta
+                .options
+                .insert(CHANGE_TYPE_OPTION.to_string(), change_type.to_string());
+            table_meta = self
+                .ctx
+                .get_catalog(&catalog)
+                .await?
+                .get_table_by_info(&table_info)?;
+        }
+
         match table_meta.engine() {
             "VIEW" => {
                 // TODO(leiysky): this check is error-prone,
@@ -335,6 +372,70 @@ impl Binder {
         }
     }
 
+    /// Bind a stream in standard mode as the changes of its table since the stream offset.
+    ///
+    /// The rows in the blocks added since the offset are the insert side,
+    /// and the rows in the blocks removed since the offset are the delete side.
+    /// A row that is on both sides with the same `change$row_id` is either updated,
+    /// or unchanged but rewritten by a mutation or a compaction, which is not a change.
+    #[async_backtrace::framed]
+    async fn bind_stream_changes(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        catalog: &str,
+        database: &str,
+        table_meta: Arc<dyn Table>,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let columns = table_meta
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| quote_ident(f.name(), '`', true))
+            .collect::<Vec<_>>();
+        let stream = format!(
+            "{}.{}.{}",
+            quote_ident(catalog, '`', true),
+            quote_ident(database, '`', true),
+            quote_ident(table_meta.name(), '`', true)
+        );
+        let query = gen_stream_changes_query(&stream, &columns);
+        info!("stream changes query: {}", query);
+
+        let tokens = tokenize_sql(query.as_str())?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let query = match &stmt {
+            Statement::Query(query) => query,
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid changes query of stream: {}",
+                    table_meta.name()
+                ))
+                .set_span(*span));
+            }
+        };
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let (s_expr, mut new_bind_context) = self.bind_query(&mut new_bind_context, query).await?;
+        if let Some(alias) = alias {
+            new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        } else {
+            for column in new_bind_context.columns.iter_mut() {
+                column.database_name = None;
+                column.table_name = Some(table_meta.name().to_string());
+            }
+        }
+        // Like in append-only mode, the change columns are not expanded by `*`.
+        for column in new_bind_context.columns.iter_mut() {
+            if column.column_name.starts_with("change$") {
+                column.visibility = Visibility::InVisible;
+            }
+        }
+        new_bind_context.parent = Some(Box::new(bind_context.clone()));
+        Ok((s_expr, new_bind_context))
+    }
+
     /// Extract the srf inner tuple fields as columns.
     #[async_backtrace::framed]
     async fn extract_srf_table_function_columns(
@@ -1202,9 +1303,16 @@ impl Binder {
 
         let table = self.metadata.read().table(table_index).clone();
         let table_name = table.name();
+        let is_change_side = matches!(
+            table.alias_name().as_deref(),
+            Some(CHANGE_INSERT_ALIAS) | Some(CHANGE_DELETE_ALIAS)
+        );
         let table = table.table();
         let statistics_provider = table.column_statistics_provider().await?;
-        let table_version = if table.engine() == "STREAM" {
+        // Either side of the changes of a standard stream reads all the rows of the changed blocks.
+        let table_version = if table.engine() == "STREAM"
+            && !(is_change_side && is_standard_stream(table.as_ref()))
+        {
             let options = table.options();
             let table_version = options
                 .get("table_version")
@@ -1420,6 +1528,7 @@ impl Binder {
     ) -> Result<NavigationPoint> {
         match travel_point {
             TimeTravelPoint::Snapshot(s) => Ok(NavigationPoint::SnapshotID(s.to_owned())),
+            TimeTravelPoint::Tag(t) => Ok(NavigationPoint::Tag(t.to_owned())),
             TimeTravelPoint::Timestamp(expr) => {
                 let mut type_checker = TypeChecker::try_create(
                     bind_context,
@@ -1474,6 +1583,55 @@ impl Binder {
     }
 }
 
+fn is_standard_stream(table: &dyn Table) -> bool {
+    table.engine() == "STREAM"
+        && table.options().get("mode").map(|s| s.as_str()) == Some("standard")
+}
+
+/// Generate the query that joins the insert side and the delete side of a standard stream.
+///
+/// A row is reported as an update, i.e. `change$is_update` is true,
+/// if there is a row of the same `change$row_id` on the other side.
+fn gen_stream_changes_query(stream: &str, columns: &[String]) -> String {
+    let side = |alias: &str| {
+        format!("(SELECT *, change$row_id AS `change$row_id` FROM {stream} AS {alias})")
+    };
+    let select_list = |t: &str| {
+        columns
+            .iter()
+            .map(|c| format!("{t}.{c}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let changed = |l: &str, r: &str| {
+        let mut conds = vec![format!("{r}.`change$row_id` IS NULL")];
+        conds.extend(
+            columns
+                .iter()
+                .map(|c| format!("{l}.{c} IS DISTINCT FROM {r}.{c}")),
+        );
+        conds.join(" OR ")
+    };
+
+    let insert_side = side(CHANGE_INSERT_ALIAS);
+    let delete_side = side(CHANGE_DELETE_ALIAS);
+    format!(
+        "SELECT {ins_cols}, 'INSERT' AS `change$action`, \
+            d.`change$row_id` IS NOT NULL AS `change$is_update`, i.`change$row_id` AS `change$row_id` \
+            FROM {insert_side} AS i LEFT OUTER JOIN {delete_side} AS d \
+            ON i.`change$row_id` = d.`change$row_id` WHERE {ins_changed} \
+            UNION ALL \
+            SELECT {del_cols}, 'DELETE' AS `change$action`, \
+            i.`change$row_id` IS NOT NULL AS `change$is_update`, d.`change$row_id` AS `change$row_id` \
+            FROM {delete_side} AS d LEFT OUTER JOIN {insert_side} AS i \
+            ON d.`change$row_id` = i.`change$row_id` WHERE {del_changed}",
+        ins_cols = select_list("i"),
+        del_cols = select_list("d"),
+        ins_changed = changed("i", "d"),
+        del_changed = changed("d", "i"),
+    )
+}
+
 // copy from common-storages-fuse to avoid cyclic dependency.
 fn string_value(value: &Scalar) -> Result<String> {
     match value {
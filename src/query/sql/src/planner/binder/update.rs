@@ -13,15 +13,18 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use common_ast::ast::TableReference;
 use common_ast::ast::UpdateStmt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 
 use crate::binder::Binder;
 use crate::binder::ScalarBinder;
 use crate::normalize_identifier;
+use crate::parse_exprs;
 use crate::plans::Plan;
 use crate::plans::UpdatePlan;
 use crate::BindContext;
@@ -81,6 +84,14 @@ impl Binder {
             self.ctes_map.clone(),
         );
         let schema = table.schema();
+        // rows can not be moved to another partition by update.
+        let partition_columns = match table.options().get(OPT_KEY_PARTITION_BY) {
+            Some(partition_key) => parse_exprs(self.ctx.clone(), table.clone(), partition_key)?
+                .iter()
+                .flat_map(|expr| expr.column_refs().into_keys())
+                .collect::<HashSet<_>>(),
+            None => HashSet::new(),
+        };
         let mut update_columns = HashMap::with_capacity(update_list.len());
         for update_expr in update_list {
             let col_name = normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
@@ -98,6 +109,12 @@ impl Binder {
                     field.name()
                 )));
             }
+            if partition_columns.contains(&index) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The partition column '{}' can not be updated",
+                    field.name()
+                )));
+            }
 
             // TODO(zhyass): update_list support subquery.
             let (scalar, _) = scalar_binder.bind(&update_expr.expr).await?;
@@ -161,6 +161,9 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(_) => Ok("ShowObjectGrantPrivileges".to_string()),
             Plan::ShowGrantTenantsOfShare(_) => Ok("ShowGrantTenantsOfShare".to_string()),
             Plan::RevertTable(_) => Ok("RevertTable".to_string()),
+            Plan::CreateTableTag(_) => Ok("CreateTableTag".to_string()),
+            Plan::DropTableTag(_) => Ok("DropTableTag".to_string()),
+            Plan::DropTablePartition(_) => Ok("DropTablePartition".to_string()),
 
             // data mask
             Plan::CreateDatamaskPolicy(_) => Ok("CreateDatamaskPolicy".to_string()),
@@ -0,0 +1,572 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataField;
+use log::info;
+use parking_lot::RwLock;
+
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateFunction;
+use crate::plans::BoundColumnRef;
+use crate::plans::CteScan;
+use crate::plans::MaterializedCte;
+use crate::plans::Operator;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::UDFServerCall;
+use crate::plans::VisitorMut;
+use crate::ColumnBinding;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Column mapping from an occurrence of a common subexpression to the first occurrence.
+type ColumnMap = HashMap<IndexType, IndexType>;
+
+/// A subtree which may be shared with other identical subtrees.
+struct Candidate<'a> {
+    path: Vec<usize>,
+    s_expr: &'a SExpr,
+    // The structure of the subtree, identical subtrees have the same signature.
+    signature: String,
+    size: usize,
+}
+
+/// Share the identical subtrees of a query plan.
+///
+/// A query may reference the same table subquery several times, such as self-joins of a
+/// non-materialized CTE. The subtrees are identical except the column indices, which are
+/// assigned separately for each reference. If recomputing the subtree costs more than
+/// materializing its result, the subtree is executed once as a materialized CTE, and each
+/// reference is replaced by a `CteScan` reading the materialized result.
+///
+/// `required` is the output columns of the query.
+pub fn share_common_subexpressions(
+    ctx: Arc<dyn TableContext>,
+    metadata: MetadataRef,
+    s_expr: SExpr,
+    required: &ColumnSet,
+) -> Result<SExpr> {
+    if !ctx.get_settings().get_enable_shared_subexpression()? {
+        return Ok(s_expr);
+    }
+
+    let mut candidates = vec![];
+    collect_candidates(&metadata, &s_expr, vec![], &mut candidates)?;
+    if candidates.len() < 2 {
+        return Ok(s_expr);
+    }
+    // Larger subtrees are shared first.
+    candidates.sort_by(|a, b| b.size.cmp(&a.size));
+
+    let mut referenced = required.clone();
+    collect_referenced_columns(&s_expr, &mut referenced)?;
+
+    let mut next_cte_idx = max_cte_idx(&s_expr).map_or(0, |idx| idx + 1);
+    let mut shared_paths: Vec<Vec<usize>> = vec![];
+    let mut replacements = HashMap::new();
+    let mut ctes = vec![];
+    for (i, first) in candidates.iter().enumerate() {
+        if overlaps(&shared_paths, &first.path) {
+            continue;
+        }
+        let mut occurrences = vec![(first, None)];
+        for other in candidates[i + 1..].iter() {
+            if other.signature != first.signature || overlaps(&shared_paths, &other.path) {
+                continue;
+            }
+            let mut column_map = ColumnMap::new();
+            if match_subtree(&metadata, first.s_expr, other.s_expr, &mut column_map)? {
+                occurrences.push((other, Some(column_map)));
+            }
+        }
+        if occurrences.len() < 2 || !worth_sharing(first.s_expr, occurrences.len())? {
+            continue;
+        }
+
+        // Only the columns referenced by the query are materialized.
+        let mut columns = ColumnSet::new();
+        for (occurrence, column_map) in occurrences.iter() {
+            let output_columns = RelExpr::with_s_expr(occurrence.s_expr)
+                .derive_relational_prop()?
+                .output_columns
+                .clone();
+            for column in output_columns.iter().filter(|c| referenced.contains(c)) {
+                match column_map {
+                    Some(column_map) => columns.extend(column_map.get(column)),
+                    None => {
+                        columns.insert(*column);
+                    }
+                }
+            }
+        }
+        if columns.is_empty() {
+            continue;
+        }
+        let mut columns = columns.into_iter().collect::<Vec<_>>();
+        columns.sort();
+        let left_output_columns = {
+            let metadata = metadata.read();
+            columns
+                .iter()
+                .map(|index| {
+                    let column = metadata.column(*index);
+                    ColumnBindingBuilder::new(
+                        column.name(),
+                        *index,
+                        Box::new(column.data_type()),
+                        Visibility::Visible,
+                    )
+                    .build()
+                })
+                .collect::<Vec<ColumnBinding>>()
+        };
+
+        let cte_idx = next_cte_idx;
+        let stat = RelExpr::with_s_expr(first.s_expr).derive_cardinality()?;
+        let mut scans = Vec::with_capacity(occurrences.len());
+        for (occurrence, column_map) in occurrences.iter() {
+            // Map the columns of the first occurrence back to the columns of this occurrence.
+            let reversed = column_map.as_ref().map(|column_map| {
+                column_map
+                    .iter()
+                    .map(|(k, v)| (*v, *k))
+                    .collect::<ColumnMap>()
+            });
+            let mut fields = Vec::with_capacity(left_output_columns.len());
+            for column in left_output_columns.iter() {
+                let index = match &reversed {
+                    Some(reversed) => match reversed.get(&column.index) {
+                        Some(index) => *index,
+                        None => break,
+                    },
+                    None => column.index,
+                };
+                fields.push(DataField::new(
+                    &index.to_string(),
+                    *column.data_type.clone(),
+                ));
+            }
+            if fields.len() != left_output_columns.len() {
+                break;
+            }
+            scans.push((occurrence.path.clone(), fields));
+        }
+        if scans.len() != occurrences.len() {
+            continue;
+        }
+
+        for (consumer, (path, fields)) in scans.into_iter().enumerate() {
+            // Consumers are numbered from 1, the materialized blocks are written to the first one.
+            let cte_scan_idx = (cte_idx, consumer + 1);
+            ctx.set_materialized_cte(cte_scan_idx, Arc::new(RwLock::new(vec![])))?;
+            let cte_scan = CteScan {
+                cte_idx: cte_scan_idx,
+                offsets: (0..fields.len()).collect(),
+                fields,
+                stat: stat.clone(),
+            };
+            replacements.insert(path.clone(), SExpr::create_leaf(Arc::new(cte_scan.into())));
+            shared_paths.push(path);
+        }
+        info!(
+            "Share {} identical subtrees as materialized cte {}",
+            occurrences.len(),
+            cte_idx
+        );
+        ctes.push((
+            MaterializedCte {
+                left_output_columns,
+                cte_idx,
+            },
+            first.s_expr.clone(),
+        ));
+        next_cte_idx += 1;
+    }
+
+    if ctes.is_empty() {
+        return Ok(s_expr);
+    }
+    let mut result = replace_subtrees(&s_expr, &mut vec![], &replacements);
+    for (cte, producer) in ctes {
+        result = SExpr::create_binary(
+            Arc::new(RelOperator::MaterializedCte(cte)),
+            Arc::new(producer),
+            Arc::new(result),
+        );
+    }
+    Ok(result)
+}
+
+// Collect the subtrees which can be shared, returns if the subtree of `s_expr` can be shared.
+fn collect_candidates<'a>(
+    metadata: &MetadataRef,
+    s_expr: &'a SExpr,
+    path: Vec<usize>,
+    candidates: &mut Vec<Candidate<'a>>,
+) -> Result<bool> {
+    let mut shareable = true;
+    let mut size = 1;
+    let mut children_signature = Vec::with_capacity(s_expr.arity());
+    let num_candidates = candidates.len();
+    for (idx, child) in s_expr.children().iter().enumerate() {
+        let mut child_path = path.clone();
+        child_path.push(idx);
+        shareable &= collect_candidates(metadata, child, child_path, candidates)?;
+    }
+    for candidate in candidates[num_candidates..].iter() {
+        if candidate.path.len() == path.len() + 1 {
+            size += candidate.size;
+            children_signature.push(candidate.signature.clone());
+        }
+    }
+
+    let label = match s_expr.plan() {
+        RelOperator::Scan(scan) => {
+            let metadata = metadata.read();
+            let table = metadata.table(scan.table_index);
+            format!(
+                "Scan({}.{}.{})",
+                table.catalog(),
+                table.database(),
+                table.name()
+            )
+        }
+        RelOperator::Filter(_)
+        | RelOperator::EvalScalar(_)
+        | RelOperator::Aggregate(_)
+        | RelOperator::Sort(_)
+        | RelOperator::Limit(_)
+        | RelOperator::Join(_)
+        | RelOperator::UnionAll(_) => format!("{:?}", s_expr.plan().rel_op()),
+        _ => return Ok(false),
+    };
+    if !shareable || children_signature.len() != s_expr.arity() || s_expr.contain_subquery() {
+        return Ok(false);
+    }
+    let prop = RelExpr::with_s_expr(s_expr).derive_relational_prop()?;
+    if !prop.outer_columns.is_empty() {
+        // Correlated subtree can't be shared.
+        return Ok(false);
+    }
+
+    candidates.push(Candidate {
+        path,
+        s_expr,
+        signature: format!("{}[{}]", label, children_signature.join(", ")),
+        size,
+    });
+    Ok(true)
+}
+
+// Check if the subtree `other` is identical to `first` except the column indices,
+// the columns of `other` are mapped to the columns of `first` in `column_map`.
+fn match_subtree(
+    metadata: &MetadataRef,
+    first: &SExpr,
+    other: &SExpr,
+    column_map: &mut ColumnMap,
+) -> Result<bool> {
+    if first.arity() != other.arity() {
+        return Ok(false);
+    }
+    for (first_child, other_child) in first.children().iter().zip(other.children().iter()) {
+        if !match_subtree(metadata, first_child, other_child, column_map)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(match (first.plan(), other.plan()) {
+        (RelOperator::Scan(first), RelOperator::Scan(other)) => {
+            if first.push_down_predicates.is_some()
+                || other.push_down_predicates.is_some()
+                || first.prewhere.is_some()
+                || other.prewhere.is_some()
+                || first.limit != other.limit
+                || first.order_by.is_some()
+                || other.order_by.is_some()
+                || first.agg_index.is_some()
+                || other.agg_index.is_some()
+            {
+                return Ok(false);
+            }
+            let metadata = metadata.read();
+            let first_table = metadata.table(first.table_index);
+            let other_table = metadata.table(other.table_index);
+            if first_table.catalog() != other_table.catalog()
+                || first_table.table().get_table_info() != other_table.table().get_table_info()
+            {
+                return Ok(false);
+            }
+            let first_columns = metadata.columns_by_table_index(first.table_index);
+            let other_columns = metadata.columns_by_table_index(other.table_index);
+            if first_columns.len() != other_columns.len() {
+                return Ok(false);
+            }
+            for (first_column, other_column) in first_columns.iter().zip(other_columns.iter()) {
+                if first_column.name() != other_column.name() {
+                    return Ok(false);
+                }
+                column_map.insert(other_column.index(), first_column.index());
+            }
+            other
+                .columns
+                .iter()
+                .map(|column| column_map.get(column).cloned())
+                .collect::<Option<ColumnSet>>()
+                == Some(first.columns.clone())
+        }
+        (RelOperator::Filter(first), RelOperator::Filter(other)) => {
+            scalars_match(&first.predicates, &other.predicates, column_map)
+        }
+        (RelOperator::EvalScalar(first), RelOperator::EvalScalar(other)) => {
+            items_match(&first.items, &other.items, column_map)
+        }
+        (RelOperator::Aggregate(first), RelOperator::Aggregate(other)) => {
+            first.mode == other.mode
+                && first.from_distinct == other.from_distinct
+                && first.limit == other.limit
+                && first.grouping_sets.is_none()
+                && other.grouping_sets.is_none()
+                && items_match(&first.group_items, &other.group_items, column_map)
+                && items_match(
+                    &first.aggregate_functions,
+                    &other.aggregate_functions,
+                    column_map,
+                )
+        }
+        (RelOperator::Sort(first), RelOperator::Sort(other)) => {
+            first.limit == other.limit
+                && first.items.len() == other.items.len()
+                && first.items.iter().zip(other.items.iter()).all(|(f, o)| {
+                    column_map.get(&o.index) == Some(&f.index)
+                        && f.asc == o.asc
+                        && f.nulls_first == o.nulls_first
+                })
+        }
+        (RelOperator::Limit(first), RelOperator::Limit(other)) => {
+            first.limit == other.limit && first.offset == other.offset
+        }
+        (RelOperator::Join(first), RelOperator::Join(other)) => {
+            first.join_type == other.join_type
+                && first.marker_index.is_none()
+                && other.marker_index.is_none()
+                && !first.from_correlated_subquery
+                && !other.from_correlated_subquery
+                && scalars_match(&first.left_conditions, &other.left_conditions, column_map)
+                && scalars_match(&first.right_conditions, &other.right_conditions, column_map)
+                && scalars_match(
+                    &first.non_equi_conditions,
+                    &other.non_equi_conditions,
+                    column_map,
+                )
+        }
+        (RelOperator::UnionAll(first), RelOperator::UnionAll(other)) => {
+            first.pairs.len() == other.pairs.len()
+                && first.pairs.iter().zip(other.pairs.iter()).all(|(f, o)| {
+                    column_map.get(&o.0) == Some(&f.0) && column_map.get(&o.1) == Some(&f.1)
+                })
+        }
+        _ => false,
+    })
+}
+
+fn scalars_match(first: &[ScalarExpr], other: &[ScalarExpr], column_map: &ColumnMap) -> bool {
+    first.len() == other.len()
+        && first.iter().zip(other.iter()).all(|(f, o)| {
+            match (
+                normalize_scalar(f, None),
+                normalize_scalar(o, Some(column_map)),
+            ) {
+                (Some(f), Some(o)) => f == o,
+                _ => false,
+            }
+        })
+}
+
+// The derived columns of `other` are mapped to the ones of `first` if the items match.
+fn items_match(first: &[ScalarItem], other: &[ScalarItem], column_map: &mut ColumnMap) -> bool {
+    if first.len() != other.len() {
+        return false;
+    }
+    for (f, o) in first.iter().zip(other.iter()) {
+        match (
+            normalize_scalar(&f.scalar, None),
+            normalize_scalar(&o.scalar, Some(column_map)),
+        ) {
+            (Some(f_scalar), Some(o_scalar)) if f_scalar == o_scalar => {}
+            _ => return false,
+        }
+    }
+    for (f, o) in first.iter().zip(other.iter()) {
+        column_map.insert(o.index, f.index);
+    }
+    true
+}
+
+// Remove the names in the scalar which depend on the query text, such as table alias,
+// and map the column indices by `column_map`. Returns `None` if a column is not mapped.
+fn normalize_scalar(scalar: &ScalarExpr, column_map: Option<&ColumnMap>) -> Option<ScalarExpr> {
+    struct Normalizer<'b> {
+        column_map: Option<&'b ColumnMap>,
+        mapped: bool,
+    }
+
+    impl<'a, 'b> VisitorMut<'a> for Normalizer<'b> {
+        fn visit_bound_column_ref(&mut self, col: &'a mut BoundColumnRef) -> Result<()> {
+            let index = match self.column_map {
+                Some(column_map) => match column_map.get(&col.column.index) {
+                    Some(index) => *index,
+                    None => {
+                        self.mapped = false;
+                        col.column.index
+                    }
+                },
+                None => col.column.index,
+            };
+            col.column = ColumnBindingBuilder::new(
+                String::new(),
+                index,
+                col.column.data_type.clone(),
+                Visibility::Visible,
+            )
+            .build();
+            Ok(())
+        }
+
+        fn visit_aggregate_function(&mut self, aggregate: &'a mut AggregateFunction) -> Result<()> {
+            aggregate.display_name = String::new();
+            for expr in &mut aggregate.args {
+                self.visit(expr)?;
+            }
+            Ok(())
+        }
+
+        fn visit_udf_server_call(&mut self, udf: &'a mut UDFServerCall) -> Result<()> {
+            udf.display_name = String::new();
+            for expr in &mut udf.arguments {
+                self.visit(expr)?;
+            }
+            Ok(())
+        }
+    }
+
+    let mut scalar = scalar.clone();
+    let mut normalizer = Normalizer {
+        column_map,
+        mapped: true,
+    };
+    normalizer.visit(&mut scalar).ok()?;
+    normalizer.mapped.then_some(scalar)
+}
+
+// Sharing the subtree saves the cost of recomputing it, but costs to materialize its
+// result and read it by each occurrence.
+fn worth_sharing(s_expr: &SExpr, occurrences: usize) -> Result<bool> {
+    let output_rows = RelExpr::with_s_expr(s_expr)
+        .derive_cardinality()?
+        .cardinality;
+    let scan_rows = scanned_rows(s_expr)?;
+    Ok(scan_rows * (occurrences - 1) as f64 > output_rows * occurrences as f64)
+}
+
+fn scanned_rows(s_expr: &SExpr) -> Result<f64> {
+    if let RelOperator::Scan(_) = s_expr.plan() {
+        return Ok(RelExpr::with_s_expr(s_expr)
+            .derive_cardinality()?
+            .cardinality);
+    }
+    let mut rows = 0.0;
+    for child in s_expr.children() {
+        rows += scanned_rows(child)?;
+    }
+    Ok(rows)
+}
+
+// Collect the columns referenced by the operators of the plan.
+fn collect_referenced_columns(s_expr: &SExpr, columns: &mut ColumnSet) -> Result<()> {
+    match s_expr.plan() {
+        RelOperator::EvalScalar(eval_scalar) => columns.extend(eval_scalar.used_columns()?),
+        RelOperator::Filter(filter) => columns.extend(filter.used_columns()?),
+        RelOperator::Aggregate(aggregate) => columns.extend(aggregate.used_columns()?),
+        RelOperator::Join(join) => columns.extend(join.used_columns()?),
+        RelOperator::Window(window) => columns.extend(window.used_columns()?),
+        RelOperator::UnionAll(union_all) => columns.extend(union_all.used_columns()?),
+        RelOperator::Udf(udf) => columns.extend(udf.used_columns()?),
+        RelOperator::Sort(sort) => columns.extend(sort.items.iter().map(|item| item.index)),
+        RelOperator::ProjectSet(project_set) => {
+            for srf in project_set.srfs.iter() {
+                columns.extend(srf.scalar.used_columns());
+            }
+        }
+        RelOperator::MaterializedCte(cte) => {
+            columns.extend(cte.left_output_columns.iter().map(|column| column.index))
+        }
+        _ => {}
+    }
+    for child in s_expr.children() {
+        collect_referenced_columns(child, columns)?;
+    }
+    Ok(())
+}
+
+fn max_cte_idx(s_expr: &SExpr) -> Option<IndexType> {
+    let cte_idx = match s_expr.plan() {
+        RelOperator::MaterializedCte(cte) => Some(cte.cte_idx),
+        RelOperator::CteScan(scan) => Some(scan.cte_idx.0),
+        _ => None,
+    };
+    s_expr
+        .children()
+        .iter()
+        .filter_map(|child| max_cte_idx(child))
+        .chain(cte_idx)
+        .max()
+}
+
+// If the path is in or contains the shared subtrees.
+fn overlaps(shared_paths: &[Vec<usize>], path: &[usize]) -> bool {
+    shared_paths
+        .iter()
+        .any(|shared| shared.starts_with(path) || path.starts_with(shared))
+}
+
+fn replace_subtrees(
+    s_expr: &SExpr,
+    path: &mut Vec<usize>,
+    replacements: &HashMap<Vec<usize>, SExpr>,
+) -> SExpr {
+    if let Some(replacement) = replacements.get(path) {
+        return replacement.clone();
+    }
+    if !replacements.keys().any(|p| p.starts_with(path)) {
+        return s_expr.clone();
+    }
+    let mut children = Vec::with_capacity(s_expr.arity());
+    for (idx, child) in s_expr.children().iter().enumerate() {
+        path.push(idx);
+        children.push(Arc::new(replace_subtrees(child, path, replacements)));
+        path.pop();
+    }
+    s_expr.replace_children(children)
+}
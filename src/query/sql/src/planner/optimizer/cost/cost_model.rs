@@ -27,6 +27,7 @@ use crate::plans::Scan;
 static COST_FACTOR_COMPUTE_PER_ROW: f64 = 1.0;
 static COST_FACTOR_HASH_TABLE_PER_ROW: f64 = 10.0;
 static COST_FACTOR_AGGREGATE_PER_ROW: f64 = 5.0;
+static COST_FACTOR_SORT_PER_ROW: f64 = 2.0;
 
 #[derive(Default)]
 pub struct DefaultCostModel;
@@ -74,8 +75,7 @@ fn compute_cost_join(memo: &Memo, m_expr: &MExpr, plan: &Join) -> Result<Cost> {
     let build_card = build_group.stat_info.cardinality;
     let probe_card = probe_group.stat_info.cardinality;
 
-    let mut cost =
-        build_card * COST_FACTOR_HASH_TABLE_PER_ROW + probe_card * COST_FACTOR_COMPUTE_PER_ROW;
+    let mut cost = hash_join_cost(build_card, probe_card).0;
 
     if matches!(plan.join_type, JoinType::RightAnti | JoinType::RightSemi) {
         // Due to implementation reasons, right semi join is more expensive than left semi join
@@ -85,6 +85,30 @@ fn compute_cost_join(memo: &Memo, m_expr: &MExpr, plan: &Join) -> Result<Cost> {
     Ok(Cost(cost))
 }
 
+/// Compute cost for a hash join which builds the hash table with the rows of build side.
+pub fn hash_join_cost(build_card: f64, probe_card: f64) -> Cost {
+    Cost(build_card * COST_FACTOR_HASH_TABLE_PER_ROW + probe_card * COST_FACTOR_COMPUTE_PER_ROW)
+}
+
+/// Compute cost for a sort-merge join, the side which is not ordered by join keys
+/// has to be sorted before merging.
+pub fn sort_merge_join_cost(
+    left_card: f64,
+    right_card: f64,
+    left_sorted: bool,
+    right_sorted: bool,
+) -> Cost {
+    let sort_cost = |card: f64, sorted: bool| {
+        if sorted || card <= 1.0 {
+            0.0
+        } else {
+            card * card.log2() * COST_FACTOR_SORT_PER_ROW
+        }
+    };
+    let merge_cost = (left_card + right_card) * COST_FACTOR_COMPUTE_PER_ROW;
+    Cost(sort_cost(left_card, left_sorted) + sort_cost(right_card, right_sorted) + merge_cost)
+}
+
 fn compute_materialized_cte(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
     let left_group = m_expr.child_group(memo, 0)?;
     let cost = left_group.stat_info.cardinality * COST_FACTOR_COMPUTE_PER_ROW;
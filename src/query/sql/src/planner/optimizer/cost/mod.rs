@@ -19,4 +19,6 @@ mod cost_model;
 pub use cost::Cost;
 pub use cost::CostContext;
 pub use cost::CostModel;
+pub use cost_model::hash_join_cost;
+pub use cost_model::sort_merge_join_cost;
 pub use cost_model::DefaultCostModel;
@@ -269,6 +269,24 @@ impl SubqueryRewriter {
         subquery: &SubqueryExpr,
         flatten_info: &mut FlattenInfo,
         is_conjunctive_predicate: bool,
+    ) -> Result<(SExpr, UnnestResult)> {
+        let outer_input = self.outer_input.replace(left.clone());
+        let result = self.decorrelate_subquery_to_join(
+            left,
+            subquery,
+            flatten_info,
+            is_conjunctive_predicate,
+        );
+        self.outer_input = outer_input;
+        result
+    }
+
+    fn decorrelate_subquery_to_join(
+        &mut self,
+        left: &SExpr,
+        subquery: &SubqueryExpr,
+        flatten_info: &mut FlattenInfo,
+        is_conjunctive_predicate: bool,
     ) -> Result<(SExpr, UnnestResult)> {
         match subquery.typ {
             SubqueryType::Scalar => {
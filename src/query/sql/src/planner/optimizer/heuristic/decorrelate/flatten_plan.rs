@@ -19,9 +19,13 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
 
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
+use crate::binder::WindowOrderByInfo;
 use crate::optimizer::heuristic::subquery_rewriter::FlattenInfo;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
@@ -31,18 +35,27 @@ use crate::plans::Aggregate;
 use crate::plans::AggregateFunction;
 use crate::plans::AggregateMode;
 use crate::plans::BoundColumnRef;
+use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::Limit;
 use crate::plans::ProjectSet;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::plans::SortItem;
 use crate::plans::SrfItem;
 use crate::plans::UnionAll;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
@@ -64,65 +77,9 @@ impl SubqueryRewriter {
             if !need_cross_join {
                 return Ok(plan.clone());
             }
-            // Construct a LogicalGet plan by correlated columns.
+            // Construct the domain of correlated columns.
             // Finally generate a cross join, so we finish flattening the subquery.
-            let mut metadata = self.metadata.write();
-            // Currently, we don't support left plan's from clause contains subquery.
-            // Such as: select t2.a from (select a + 1 as a from t) as t2 where (select sum(a) from t as t1 where t1.a < t2.a) = 1;
-            let table_index = metadata
-                .table_index_by_column_indexes(correlated_columns)
-                .unwrap();
-            let mut data_types = Vec::with_capacity(correlated_columns.len());
-            for correlated_column in correlated_columns.iter() {
-                let column_entry = metadata.column(*correlated_column).clone();
-                let name = column_entry.name();
-                let data_type = column_entry.data_type();
-                data_types.push(data_type.clone());
-                self.derived_columns.insert(
-                    *correlated_column,
-                    metadata.add_derived_column(name.to_string(), data_type),
-                );
-            }
-            let logical_get = SExpr::create_leaf(Arc::new(
-                Scan {
-                    table_index,
-                    columns: self.derived_columns.values().cloned().collect(),
-                    ..Default::default()
-                }
-                .into(),
-            ));
-            // Wrap logical get with distinct to eliminate duplicates rows.
-            let mut group_items = Vec::with_capacity(self.derived_columns.len());
-            for (index, column_index) in self.derived_columns.values().cloned().enumerate() {
-                group_items.push(ScalarItem {
-                    scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
-                        span: None,
-                        column: ColumnBindingBuilder::new(
-                            "".to_string(),
-                            column_index,
-                            Box::new(data_types[index].clone()),
-                            Visibility::Visible,
-                        )
-                        .table_index(Some(table_index))
-                        .build(),
-                    }),
-                    index: column_index,
-                });
-            }
-            let duplicate_delete_get = SExpr::create_unary(
-                Arc::new(
-                    Aggregate {
-                        mode: AggregateMode::Initial,
-                        group_items,
-                        aggregate_functions: vec![],
-                        from_distinct: false,
-                        limit: None,
-                        grouping_sets: None,
-                    }
-                    .into(),
-                ),
-                Arc::new(logical_get),
-            );
+            let domain = self.build_domain(correlated_columns)?;
 
             let cross_join = Join {
                 left_conditions: vec![],
@@ -138,7 +95,7 @@ impl SubqueryRewriter {
 
             return Ok(SExpr::create_binary(
                 Arc::new(cross_join),
-                Arc::new(duplicate_delete_get),
+                Arc::new(domain),
                 Arc::new(plan.clone()),
             ));
         }
@@ -183,14 +140,26 @@ impl SubqueryRewriter {
                 need_cross_join,
             ),
 
-            RelOperator::Limit(_) => {
-                self.flatten_limit(plan, correlated_columns, flatten_info, need_cross_join)
-            }
+            RelOperator::Limit(limit) => self.flatten_limit(
+                plan,
+                limit,
+                correlated_columns,
+                flatten_info,
+                need_cross_join,
+            ),
 
             RelOperator::UnionAll(op) => {
-                self.flatten_union_all(op, plan, correlated_columns, flatten_info, need_cross_join)
+                self.flatten_union_all(op, plan, correlated_columns, flatten_info)
             }
 
+            RelOperator::Window(window) => self.flatten_window(
+                plan,
+                window,
+                correlated_columns,
+                flatten_info,
+                need_cross_join,
+            ),
+
             _ => Err(ErrorCode::Internal(
                 "Invalid plan type for flattening subquery",
             )),
@@ -405,44 +374,95 @@ impl SubqueryRewriter {
         let join_rel_expr = RelExpr::with_s_expr(plan);
         let left_prop = join_rel_expr.derive_relational_prop_child(0)?;
         let right_prop = join_rel_expr.derive_relational_prop_child(1)?;
+        let mut left_correlated = left_need_cross_join || !left_prop.outer_columns.is_empty();
+        let mut right_correlated = right_need_cross_join || !right_prop.outer_columns.is_empty();
 
-        for condition in join.non_equi_conditions.iter() {
-            for col in condition.used_columns() {
-                if correlated_columns.contains(&col) {
-                    if left_prop.output_columns.contains(&col) {
-                        left_need_cross_join = true;
-                    } else if right_prop.output_columns.contains(&col) {
-                        right_need_cross_join = true;
-                    }
-                }
-            }
+        // Non-equi conditions need the derived columns from one side at least.
+        if needs_cross_join(&join.non_equi_conditions, correlated_columns)
+            && !left_correlated
+            && !right_correlated
+        {
+            left_need_cross_join = true;
+            left_correlated = true;
         }
 
+        // If the rows of one side are kept even if they are not matched, whether they are matched
+        // depends on the values of correlated columns. So the side should be joined with the domain
+        // too, and the derived columns of both sides should be equal.
+        let preserve_right = matches!(
+            join.join_type,
+            JoinType::Right
+                | JoinType::RightSemi
+                | JoinType::RightAnti
+                | JoinType::LeftMark
+                | JoinType::RightSingle
+        );
+        let preserve_left =
+            !preserve_right && !matches!(join.join_type, JoinType::Inner | JoinType::Cross);
+        if (preserve_left || join.join_type == JoinType::Full)
+            && right_correlated
+            && !left_correlated
+        {
+            left_need_cross_join = true;
+            left_correlated = true;
+        }
+        if (preserve_right || join.join_type == JoinType::Full)
+            && left_correlated
+            && !right_correlated
+        {
+            right_need_cross_join = true;
+            right_correlated = true;
+        }
+
+        let derived_columns = self.derived_columns.clone();
         let left_flatten_plan = self.flatten_plan(
             plan.child(0)?,
             correlated_columns,
             flatten_info,
             left_need_cross_join,
         )?;
+        let left_derived_columns = std::mem::replace(&mut self.derived_columns, derived_columns);
         let right_flatten_plan = self.flatten_plan(
             plan.child(1)?,
             correlated_columns,
             flatten_info,
             right_need_cross_join,
         )?;
+        let right_derived_columns = self.derived_columns.clone();
 
-        let left_conditions = process_conditions(
+        let mut left_conditions = process_conditions(
             &join.left_conditions,
             correlated_columns,
-            &self.derived_columns,
+            &left_derived_columns,
             left_need_cross_join,
         )?;
-        let right_conditions = process_conditions(
+        let mut right_conditions = process_conditions(
             &join.right_conditions,
             correlated_columns,
-            &self.derived_columns,
+            &right_derived_columns,
             right_need_cross_join,
         )?;
+
+        // Both sides are flattened, the rows of them should be from the same value of correlated columns.
+        if left_correlated && right_correlated {
+            for correlated_column in correlated_columns.iter() {
+                if let (Some(left), Some(right)) = (
+                    left_derived_columns.get(correlated_column),
+                    right_derived_columns.get(correlated_column),
+                ) {
+                    if left != right {
+                        left_conditions.push(self.column_ref(*left));
+                        right_conditions.push(self.column_ref(*right));
+                    }
+                }
+            }
+        }
+
+        self.derived_columns = if (preserve_right && right_correlated) || !left_correlated {
+            right_derived_columns.clone()
+        } else {
+            left_derived_columns.clone()
+        };
         let non_equi_conditions = process_conditions(
             &join.non_equi_conditions,
             correlated_columns,
@@ -450,13 +470,17 @@ impl SubqueryRewriter {
             true,
         )?;
 
-        Ok(SExpr::create_binary(
+        let join_type = match join.join_type {
+            JoinType::Cross if !left_conditions.is_empty() => JoinType::Inner,
+            _ => join.join_type.clone(),
+        };
+        let join_plan = SExpr::create_binary(
             Arc::new(
                 Join {
                     left_conditions,
                     right_conditions,
                     non_equi_conditions,
-                    join_type: join.join_type.clone(),
+                    join_type,
                     marker_index: join.marker_index,
                     from_correlated_subquery: false,
                     need_hold_hash_table: false,
@@ -466,6 +490,66 @@ impl SubqueryRewriter {
             ),
             Arc::new(left_flatten_plan),
             Arc::new(right_flatten_plan),
+        );
+
+        if join.join_type == JoinType::Full && left_correlated && right_correlated {
+            return self.coalesce_derived_columns(
+                join_plan,
+                correlated_columns,
+                &left_derived_columns,
+                &right_derived_columns,
+            );
+        }
+        Ok(join_plan)
+    }
+
+    // The unmatched rows of full join only have the derived columns of one side,
+    // so the derived columns are replaced by the not null one of both sides.
+    fn coalesce_derived_columns(
+        &mut self,
+        join_plan: SExpr,
+        correlated_columns: &ColumnSet,
+        left_derived_columns: &HashMap<IndexType, IndexType>,
+        right_derived_columns: &HashMap<IndexType, IndexType>,
+    ) -> Result<SExpr> {
+        let mut items = Vec::with_capacity(correlated_columns.len());
+        for correlated_column in correlated_columns.iter() {
+            let (left, right) = match (
+                left_derived_columns.get(correlated_column),
+                right_derived_columns.get(correlated_column),
+            ) {
+                (Some(left), Some(right)) => (left, right),
+                _ => continue,
+            };
+            let (left_column, right_column) = (self.column_ref(*left), self.column_ref(*right));
+            let index = {
+                let mut metadata = self.metadata.write();
+                let column_entry = metadata.column(*left).clone();
+                metadata.add_derived_column(
+                    column_entry.name(),
+                    column_entry.data_type().wrap_nullable(),
+                )
+            };
+            let is_not_null = ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "is_not_null".to_string(),
+                params: vec![],
+                arguments: vec![left_column.clone()],
+            });
+            items.push(ScalarItem {
+                scalar: ScalarExpr::FunctionCall(FunctionCall {
+                    span: None,
+                    func_name: "if".to_string(),
+                    params: vec![],
+                    arguments: vec![is_not_null, left_column, right_column],
+                }),
+                index,
+            });
+            self.derived_columns.insert(*correlated_column, index);
+        }
+        Ok(SExpr::create_unary(
+            Arc::new(EvalScalar { items }.into()),
+            Arc::new(join_plan),
         ))
     }
 
@@ -477,6 +561,17 @@ impl SubqueryRewriter {
         flatten_info: &mut FlattenInfo,
         mut need_cross_join: bool,
     ) -> Result<SExpr> {
+        if flatten_info.from_lateral_join
+            && aggregate.group_items.is_empty()
+            && aggregate.grouping_sets.is_none()
+        {
+            return self.flatten_scalar_aggregate(
+                aggregate,
+                plan,
+                correlated_columns,
+                flatten_info,
+            );
+        }
         if aggregate
             .used_columns()?
             .iter()
@@ -566,6 +661,92 @@ impl SubqueryRewriter {
         ))
     }
 
+    // Scalar aggregation returns one row even if the input is empty. To keep a row for each value
+    // of correlated columns, the flattened input is left joined to the domain of correlated columns
+    // and grouped by the domain, `count` only counts the matched rows.
+    fn flatten_scalar_aggregate(
+        &mut self,
+        aggregate: &Aggregate,
+        plan: &SExpr,
+        correlated_columns: &ColumnSet,
+        flatten_info: &mut FlattenInfo,
+    ) -> Result<SExpr> {
+        let flatten_plan =
+            self.flatten_plan(plan.child(0)?, correlated_columns, flatten_info, true)?;
+        let input_derived_columns = self.derived_columns.clone();
+        let domain = self.build_domain(correlated_columns)?;
+
+        let mut left_conditions = Vec::with_capacity(correlated_columns.len());
+        let mut right_conditions = Vec::with_capacity(correlated_columns.len());
+        let mut group_items = Vec::with_capacity(correlated_columns.len());
+        for correlated_column in correlated_columns.iter() {
+            let (domain_column, input_column) = match (
+                self.derived_columns.get(correlated_column),
+                input_derived_columns.get(correlated_column),
+            ) {
+                (Some(domain_column), Some(input_column)) => (*domain_column, *input_column),
+                _ => return Err(ErrorCode::Internal("Missing derived columns")),
+            };
+            left_conditions.push(self.column_ref(domain_column));
+            right_conditions.push(self.column_ref(input_column));
+            group_items.push(ScalarItem {
+                scalar: self.column_ref(domain_column),
+                index: domain_column,
+            });
+        }
+        // The derived column of input is not null for the matched rows.
+        let matched_column = right_conditions[0].clone();
+
+        let mut agg_items = Vec::with_capacity(aggregate.aggregate_functions.len());
+        for item in aggregate.aggregate_functions.iter() {
+            let mut scalar = self.flatten_scalar(&item.scalar, correlated_columns)?;
+            if let ScalarExpr::AggregateFunction(agg) = &mut scalar {
+                if (agg.func_name.eq_ignore_ascii_case("count")
+                    || agg.func_name.eq("count_distinct"))
+                    && agg
+                        .args
+                        .iter()
+                        .all(|arg| matches!(arg, ScalarExpr::ConstantExpr(_)))
+                {
+                    agg.args = vec![matched_column.clone()];
+                }
+            }
+            agg_items.push(ScalarItem {
+                scalar,
+                index: item.index,
+            });
+        }
+
+        let left_join = Join {
+            left_conditions,
+            right_conditions,
+            non_equi_conditions: vec![],
+            join_type: JoinType::Left,
+            marker_index: None,
+            from_correlated_subquery: false,
+            need_hold_hash_table: false,
+            broadcast: false,
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    mode: AggregateMode::Initial,
+                    group_items,
+                    aggregate_functions: agg_items,
+                    from_distinct: aggregate.from_distinct,
+                    limit: aggregate.limit,
+                    grouping_sets: None,
+                }
+                .into(),
+            ),
+            Arc::new(SExpr::create_binary(
+                Arc::new(left_join.into()),
+                Arc::new(domain),
+                Arc::new(flatten_plan),
+            )),
+        ))
+    }
+
     fn flatten_sort(
         &mut self,
         plan: &SExpr,
@@ -603,6 +784,7 @@ impl SubqueryRewriter {
     fn flatten_limit(
         &mut self,
         plan: &SExpr,
+        limit: &Limit,
         correlated_columns: &ColumnSet,
         flatten_info: &mut FlattenInfo,
         need_cross_join: bool,
@@ -614,43 +796,295 @@ impl SubqueryRewriter {
             flatten_info,
             need_cross_join,
         )?;
+
+        // The limit should be applied to the rows of each value of correlated columns, so it's
+        // rewritten to a filter of the row number partitioned by the derived columns.
+        let (order_by, input) = split_sort(&flatten_plan)?;
+        let row_number = self.metadata.write().add_derived_column(
+            "row_number".to_string(),
+            DataType::Number(NumberDataType::UInt64),
+        );
+        let partition_by = correlated_columns
+            .iter()
+            .filter_map(|column| self.derived_columns.get(column))
+            .map(|index| ScalarItem {
+                scalar: self.column_ref(*index),
+                index: *index,
+            })
+            .collect();
+        let order_by = order_by
+            .iter()
+            .map(|item| WindowOrderByInfo {
+                order_by_item: ScalarItem {
+                    scalar: self.column_ref(item.index),
+                    index: item.index,
+                },
+                asc: Some(item.asc),
+                nulls_first: Some(item.nulls_first),
+            })
+            .collect();
+        let window = Window {
+            span: None,
+            index: row_number,
+            function: WindowFuncType::RowNumber,
+            arguments: vec![],
+            partition_by,
+            order_by,
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Rows,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::CurrentRow,
+            },
+        };
+
+        let row_number_column = self.column_ref(row_number);
+        let bound = |func_name: &str, value: usize| {
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: func_name.to_string(),
+                params: vec![],
+                arguments: vec![
+                    row_number_column.clone(),
+                    ScalarExpr::ConstantExpr(ConstantExpr {
+                        span: None,
+                        value: Scalar::Number(NumberScalar::UInt64(value as u64)),
+                    }),
+                ],
+            })
+        };
+        let mut predicates = vec![];
+        if limit.offset > 0 {
+            predicates.push(bound("gt", limit.offset));
+        }
+        if let Some(limit_count) = limit.limit {
+            predicates.push(bound("lte", limit.offset + limit_count));
+        }
+        let window_plan = SExpr::create_unary(Arc::new(window.into()), Arc::new(input));
+        if predicates.is_empty() {
+            return Ok(window_plan);
+        }
         Ok(SExpr::create_unary(
-            Arc::new(plan.plan().clone()),
-            Arc::new(flatten_plan),
+            Arc::new(Filter { predicates }.into()),
+            Arc::new(window_plan),
         ))
     }
 
-    fn flatten_union_all(
+    fn flatten_window(
         &mut self,
-        op: &UnionAll,
         plan: &SExpr,
+        window: &Window,
         correlated_columns: &ColumnSet,
         flatten_info: &mut FlattenInfo,
         mut need_cross_join: bool,
     ) -> Result<SExpr> {
-        if op
+        if window
             .used_columns()?
             .iter()
             .any(|index| correlated_columns.contains(index))
         {
             need_cross_join = true;
         }
-        let left_flatten_plan = self.flatten_plan(
+        let flatten_plan = self.flatten_plan(
             plan.child(0)?,
             correlated_columns,
             flatten_info,
             need_cross_join,
         )?;
-        let right_flatten_plan = self.flatten_plan(
-            plan.child(1)?,
-            correlated_columns,
-            flatten_info,
-            need_cross_join,
-        )?;
+
+        let mut window = window.clone();
+        if let WindowFuncType::Aggregate(agg) = &mut window.function {
+            for arg in agg.args.iter_mut() {
+                *arg = self.flatten_scalar(arg, correlated_columns)?;
+            }
+        }
+        for item in window
+            .arguments
+            .iter_mut()
+            .chain(window.partition_by.iter_mut())
+        {
+            item.scalar = self.flatten_scalar(&item.scalar, correlated_columns)?;
+        }
+        for item in window.order_by.iter_mut() {
+            item.order_by_item.scalar =
+                self.flatten_scalar(&item.order_by_item.scalar, correlated_columns)?;
+        }
+        // The window is computed for each value of correlated columns.
+        for derived_column in correlated_columns
+            .iter()
+            .filter_map(|column| self.derived_columns.get(column))
+        {
+            window.partition_by.push(ScalarItem {
+                scalar: self.column_ref(*derived_column),
+                index: *derived_column,
+            });
+        }
+        Ok(SExpr::create_unary(
+            Arc::new(window.into()),
+            Arc::new(flatten_plan),
+        ))
+    }
+
+    fn flatten_union_all(
+        &mut self,
+        op: &UnionAll,
+        plan: &SExpr,
+        correlated_columns: &ColumnSet,
+        flatten_info: &mut FlattenInfo,
+    ) -> Result<SExpr> {
+        // Both sides should output the derived columns, so they are always joined with the domain.
+        let left_flatten_plan =
+            self.flatten_plan(plan.child(0)?, correlated_columns, flatten_info, true)?;
+        let left_derived_columns = self.derived_columns.clone();
+        let right_flatten_plan =
+            self.flatten_plan(plan.child(1)?, correlated_columns, flatten_info, true)?;
+
+        let mut pairs = op.pairs.clone();
+        for correlated_column in correlated_columns.iter() {
+            match (
+                left_derived_columns.get(correlated_column),
+                self.derived_columns.get(correlated_column),
+            ) {
+                (Some(left), Some(right)) => pairs.push((*left, *right)),
+                _ => return Err(ErrorCode::Internal("Missing derived columns")),
+            }
+        }
+        self.derived_columns = left_derived_columns;
+
         Ok(SExpr::create_binary(
-            Arc::new(op.clone().into()),
+            Arc::new(UnionAll { pairs }.into()),
             Arc::new(left_flatten_plan),
             Arc::new(right_flatten_plan),
         ))
     }
+
+    // Build the domain of correlated columns, which contains the distinct values of them.
+    // The correlated columns are mapped to the output columns of the domain.
+    fn build_domain(&mut self, correlated_columns: &ColumnSet) -> Result<SExpr> {
+        // If the correlated columns are from the same table, the domain is built by scanning the table.
+        // Otherwise, it's built from the outer query, such as the outer query is a join or contains subquery:
+        // select t2.a from (select a + 1 as a from t) as t2 where (select sum(a) from t as t1 where t1.a < t2.a) = 1;
+        let table_index = {
+            let metadata = self.metadata.read();
+            let table_indexes = correlated_columns
+                .iter()
+                .map(|index| match metadata.column(*index) {
+                    ColumnEntry::BaseTableColumn(BaseTableColumn { table_index, .. }) => {
+                        Some(*table_index)
+                    }
+                    _ => None,
+                })
+                .collect::<HashSet<_>>();
+            match table_indexes.len() {
+                1 => table_indexes.into_iter().next().unwrap(),
+                _ => None,
+            }
+        };
+
+        let mut derived_columns = Vec::with_capacity(correlated_columns.len());
+        for correlated_column in correlated_columns.iter() {
+            let mut metadata = self.metadata.write();
+            let column_entry = metadata.column(*correlated_column).clone();
+            let derived_column =
+                metadata.add_derived_column(column_entry.name(), column_entry.data_type());
+            self.derived_columns
+                .insert(*correlated_column, derived_column);
+            derived_columns.push(derived_column);
+        }
+
+        let input = match table_index {
+            Some(table_index) => SExpr::create_leaf(Arc::new(
+                Scan {
+                    table_index,
+                    columns: derived_columns.iter().cloned().collect(),
+                    ..Default::default()
+                }
+                .into(),
+            )),
+            None => {
+                let outer_input = self.outer_input.clone().ok_or_else(|| {
+                    ErrorCode::Internal("Missing outer query to build the domain of subquery")
+                })?;
+                let outer_prop = RelExpr::with_s_expr(&outer_input).derive_relational_prop()?;
+                if !correlated_columns.is_subset(&outer_prop.output_columns) {
+                    return Err(ErrorCode::Internal(
+                        "Correlated columns of subquery are not from the outer query",
+                    ));
+                }
+                let items = correlated_columns
+                    .iter()
+                    .zip(derived_columns.iter())
+                    .map(|(correlated_column, derived_column)| ScalarItem {
+                        scalar: self.column_ref(*correlated_column),
+                        index: *derived_column,
+                    })
+                    .collect();
+                SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(outer_input))
+            }
+        };
+
+        // Wrap the input with distinct to eliminate duplicates rows.
+        let mut group_items = Vec::with_capacity(derived_columns.len());
+        for column_index in derived_columns.iter().cloned() {
+            let data_type = self.metadata.read().column(column_index).data_type();
+            group_items.push(ScalarItem {
+                scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBindingBuilder::new(
+                        "".to_string(),
+                        column_index,
+                        Box::new(data_type),
+                        Visibility::Visible,
+                    )
+                    .table_index(table_index)
+                    .build(),
+                }),
+                index: column_index,
+            });
+        }
+        Ok(SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    mode: AggregateMode::Initial,
+                    group_items,
+                    aggregate_functions: vec![],
+                    from_distinct: false,
+                    limit: None,
+                    grouping_sets: None,
+                }
+                .into(),
+            ),
+            Arc::new(input),
+        ))
+    }
+
+    fn column_ref(&self, index: IndexType) -> ScalarExpr {
+        let metadata = self.metadata.read();
+        let column_entry = metadata.column(index);
+        ScalarExpr::BoundColumnRef(BoundColumnRef {
+            span: None,
+            column: ColumnBindingBuilder::new(
+                column_entry.name(),
+                index,
+                Box::new(column_entry.data_type()),
+                Visibility::Visible,
+            )
+            .build(),
+        })
+    }
+}
+
+// Take the sort below a limit out of the plan, the sort may be under the projection of the query.
+// The row number of the rows is computed in the order of the sort items.
+fn split_sort(plan: &SExpr) -> Result<(Vec<SortItem>, SExpr)> {
+    match plan.plan() {
+        RelOperator::Sort(sort) => Ok((sort.items.clone(), plan.child(0)?.clone())),
+        RelOperator::EvalScalar(_) => {
+            let (order_by, child) = split_sort(plan.child(0)?)?;
+            if order_by.is_empty() {
+                return Ok((order_by, plan.clone()));
+            }
+            Ok((order_by, plan.replace_children(vec![Arc::new(child)])))
+        }
+        _ => Ok((vec![], plan.clone())),
+    }
 }
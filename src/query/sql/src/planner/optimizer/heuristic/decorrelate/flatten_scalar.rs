@@ -22,7 +22,9 @@ use crate::plans::AggregateFunction;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::ScalarExpr;
+use crate::plans::UDFLambdaCall;
 use crate::plans::UDFServerCall;
 
 impl SubqueryRewriter {
@@ -104,6 +106,29 @@ impl SubqueryRewriter {
                     arguments,
                 }))
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.flatten_scalar(arg, correlated_columns))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }))
+            }
+            ScalarExpr::UDFLambdaCall(udf) => {
+                let scalar = self.flatten_scalar(&udf.scalar, correlated_columns)?;
+                Ok(ScalarExpr::UDFLambdaCall(UDFLambdaCall {
+                    span: udf.span,
+                    func_name: udf.func_name.clone(),
+                    scalar: Box::new(scalar),
+                }))
+            }
             _ => Err(ErrorCode::Internal(
                 "Invalid scalar for flattening subquery",
             )),
@@ -63,12 +63,16 @@ pub enum UnnestResult {
 
 pub struct FlattenInfo {
     pub from_count_func: bool,
+    // Lateral join keeps the rows of scalar aggregation even if the input is empty.
+    pub from_lateral_join: bool,
 }
 
 /// Rewrite subquery into `Apply` operator
 pub struct SubqueryRewriter {
     pub(crate) metadata: MetadataRef,
     pub(crate) derived_columns: HashMap<IndexType, IndexType>,
+    // The outer query of the subquery being flattened, used to build the domain of correlated columns.
+    pub(crate) outer_input: Option<SExpr>,
 }
 
 impl SubqueryRewriter {
@@ -76,6 +80,7 @@ impl SubqueryRewriter {
         Self {
             metadata,
             derived_columns: Default::default(),
+            outer_input: None,
         }
     }
 
@@ -237,6 +242,7 @@ impl SubqueryRewriter {
                 let prop = rel_expr.derive_relational_prop()?;
                 let mut flatten_info = FlattenInfo {
                     from_count_func: false,
+                    from_lateral_join: false,
                 };
                 let (s_expr, result) = if prop.outer_columns.is_empty() {
                     self.try_rewrite_uncorrelated_subquery(s_expr, &subquery)?
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod cascades;
+mod common_subexpression;
 mod cost;
 mod distributed;
 mod format;
@@ -30,6 +31,8 @@ pub mod s_expr;
 mod util;
 
 pub use cascades::CascadesOptimizer;
+pub use cost::hash_join_cost;
+pub use cost::sort_merge_join_cost;
 pub use heuristic::FlattenInfo;
 pub use heuristic::HeuristicOptimizer;
 pub use heuristic::SubqueryRewriter;
@@ -26,6 +26,7 @@ use super::distributed::MergeSourceOptimizer;
 use super::format::display_memo;
 use super::Memo;
 use crate::optimizer::cascades::CascadesOptimizer;
+use crate::optimizer::common_subexpression::share_common_subexpressions;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::util::contains_local_table_scan;
@@ -38,6 +39,7 @@ use crate::plans::CopyIntoLocationPlan;
 use crate::plans::MergeInto;
 use crate::plans::Plan;
 use crate::plans::RelOperator;
+use crate::ColumnSet;
 use crate::IndexType;
 use crate::MetadataRef;
 
@@ -71,14 +73,23 @@ pub fn optimize(
             rewrite_kind,
             formatted_ast,
             ignore_result,
-        } => Ok(Plan::Query {
-            s_expr: Box::new(optimize_query(ctx, opt_ctx, metadata.clone(), *s_expr)?),
-            bind_context,
-            metadata,
-            rewrite_kind,
-            formatted_ast,
-            ignore_result,
-        }),
+        } => {
+            let required = bind_context
+                .columns
+                .iter()
+                .map(|column| column.index)
+                .collect::<ColumnSet>();
+            let s_expr =
+                share_common_subexpressions(ctx.clone(), metadata.clone(), *s_expr, &required)?;
+            Ok(Plan::Query {
+                s_expr: Box::new(optimize_query(ctx, opt_ctx, metadata.clone(), s_expr)?),
+                bind_context,
+                metadata,
+                rewrite_kind,
+                formatted_ast,
+                ignore_result,
+            })
+        }
         Plan::Explain { kind, plan } => match kind {
             ExplainKind::Raw | ExplainKind::Ast(_) | ExplainKind::Syntax(_) => {
                 Ok(Plan::Explain { kind, plan })
@@ -16,6 +16,7 @@ use std::collections::HashSet;
 
 use super::column_stat::ColumnStatSet;
 use crate::plans::ScalarExpr;
+use crate::plans::SortItem;
 use crate::IndexType;
 
 pub type ColumnSet = HashSet<IndexType>;
@@ -64,6 +65,25 @@ pub struct RelationalProperty {
 #[derive(Default, Clone)]
 pub struct PhysicalProperty {
     pub distribution: Distribution,
+    /// The order of rows delivered by the plan, empty if the rows are not
+    /// guaranteed to be ordered.
+    pub ordering: Vec<SortItem>,
+}
+
+impl PhysicalProperty {
+    /// Check if the delivered rows are ordered by the given columns, in the
+    /// given order of keys. Returns the sort items of the matched prefix.
+    pub fn ordered_by(&self, columns: &[IndexType]) -> Option<&[SortItem]> {
+        if columns.is_empty() || self.ordering.len() < columns.len() {
+            return None;
+        }
+        let prefix = &self.ordering[..columns.len()];
+        prefix
+            .iter()
+            .zip(columns.iter())
+            .all(|(item, column)| item.index == *column)
+            .then_some(prefix)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,426 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use log::info;
+
+use super::try_rewrite;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::rule::transform::modify_final_aggregate_function;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Try to rewrite an aggregation over inner joins with the aggregating index of a joined table.
+///
+/// The aggregation is pushed down to the table as an eager aggregation, which is grouped by
+/// the group items only using columns of the table, and the columns of the table used above
+/// the aggregation (such as join keys). If the eager aggregation can be served by an
+/// aggregating index of the table (maybe rolled up from finer groups), the table scan is
+/// replaced by the index scan, and the original aggregation merges the eager results.
+///
+/// ```text
+///       Expression                         Expression
+///           |                                  |
+///    Aggregate(Final)                   Aggregate(Final)
+///           |                                  |
+///   Aggregate(Partial)                 Aggregate(Partial)
+///           |                                  |
+///       Expression           =>            Expression
+///           |                                  |
+///          Join                               Join
+///         /    \                             /    \
+///      Scan(t)  ...               Aggregate(Final)  ...
+///                                         |
+///                                Aggregate(Partial)
+///                                         |
+///                                    Expression
+///                                         |
+///                                Scan(t, agg_index)
+/// ```
+pub fn try_rewrite_join(metadata: &MetadataRef, s_expr: &SExpr) -> Result<Option<SExpr>> {
+    let final_expr = s_expr.child(0)?;
+    let partial_expr = final_expr.child(0)?;
+    let input_expr = partial_expr.child(0)?;
+    let join_expr = input_expr.child(0)?;
+    let (agg, input) = match (final_expr.plan(), input_expr.plan()) {
+        (RelOperator::Aggregate(agg), RelOperator::EvalScalar(input))
+            if agg.mode == AggregateMode::Final && agg.grouping_sets.is_none() =>
+        {
+            (agg, input)
+        }
+        _ => return Ok(None),
+    };
+
+    // The eager results are merged by applying the same function to them (`count` is merged
+    // by `sum`), which is only correct for these functions, e.g. `avg` of `avg` is wrong.
+    for item in agg.aggregate_functions.iter() {
+        match &item.scalar {
+            ScalarExpr::AggregateFunction(func)
+                if !func.distinct
+                    && func.args.len() <= 1
+                    && matches!(func.func_name.as_str(), "sum" | "min" | "max" | "count") => {}
+            _ => return Ok(None),
+        }
+    }
+
+    let input_items = input
+        .items
+        .iter()
+        .map(|item| (item.index, &item.scalar))
+        .collect::<HashMap<_, _>>();
+    let agg_args = agg
+        .aggregate_functions
+        .iter()
+        .flat_map(|item| match &item.scalar {
+            ScalarExpr::AggregateFunction(func) => func.args.clone(),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    let mut agg_arg_columns = ColumnSet::new();
+    for arg in agg_args.iter() {
+        agg_arg_columns.extend(input_scalar(&input_items, arg).used_columns());
+    }
+
+    let mut join_columns = ColumnSet::new();
+    let mut leaves = Vec::new();
+    if !collect_join_leaves(join_expr, vec![], &mut join_columns, &mut leaves) {
+        return Ok(None);
+    }
+
+    for (path, scan) in leaves {
+        let (table_name, base_columns, index_plans) = {
+            let metadata = metadata.read();
+            let table = metadata.table(scan.table_index);
+            let table_name = format!("{}.{}.{}", table.catalog(), table.database(), table.name());
+            let index_plans = match metadata.get_agg_indexes(&table_name) {
+                Some(plans) if !plans.is_empty() => plans.to_vec(),
+                // No enterprise license or no index.
+                _ => continue,
+            };
+            let base_columns = metadata.columns_by_table_index(scan.table_index);
+            (table_name, base_columns, index_plans)
+        };
+        let table_columns = base_columns
+            .iter()
+            .map(|col| col.index())
+            .collect::<ColumnSet>();
+        if !agg_arg_columns.is_subset(&table_columns) {
+            continue;
+        }
+
+        // Group items only using the columns of the table can be pushed down,
+        // the columns of the table used by other group items should be kept.
+        let mut pushed_items = Vec::new();
+        let mut key_columns = join_columns
+            .intersection(&table_columns)
+            .cloned()
+            .collect::<ColumnSet>();
+        for item in agg.group_items.iter() {
+            let scalar = input_scalar(&input_items, &item.scalar);
+            let used_columns = scalar.used_columns();
+            if !used_columns.is_empty() && used_columns.is_subset(&table_columns) {
+                pushed_items.push((item, scalar));
+            } else {
+                key_columns.extend(used_columns.intersection(&table_columns));
+            }
+        }
+        for item in input.items.iter() {
+            if agg.group_items.iter().all(|g| g.index != item.index)
+                && agg_args
+                    .iter()
+                    .all(|arg| !arg.used_columns().contains(&item.index))
+            {
+                key_columns.extend(item.scalar.used_columns().intersection(&table_columns));
+            }
+        }
+        key_columns.retain(|col| pushed_items.iter().all(|(item, _)| item.index != *col));
+
+        let eager = build_eager_aggregation(
+            metadata,
+            scan.table_index,
+            &base_columns,
+            agg,
+            &input_items,
+            &pushed_items,
+            &key_columns,
+            leaf_of(join_expr, &path),
+        )?;
+        let rewritten =
+            match try_rewrite(scan.table_index, &base_columns, &eager.s_expr, &index_plans)? {
+                Some(rewritten) => rewritten,
+                None => continue,
+            };
+        info!("Push down aggregation over join to the aggregating index of {table_name}");
+
+        // Merge the eager results by the original aggregation.
+        let mut new_agg = agg.clone();
+        for (item, eager_index) in new_agg
+            .aggregate_functions
+            .iter_mut()
+            .zip(eager.func_indices.iter())
+        {
+            if let ScalarExpr::AggregateFunction(func) = &mut item.scalar {
+                modify_final_aggregate_function(func, *eager_index);
+            }
+        }
+        let new_input = EvalScalar {
+            items: input
+                .items
+                .iter()
+                .filter(|item| {
+                    pushed_items.iter().all(|(g, _)| g.index != item.index)
+                        && agg_args
+                            .iter()
+                            .all(|arg| !arg.used_columns().contains(&item.index))
+                })
+                .cloned()
+                .collect(),
+        };
+        let new_join = replace_leaf(join_expr, &path, rewritten.child(0)?.clone())?;
+        let mut partial_agg = new_agg.clone();
+        partial_agg.mode = AggregateMode::Partial;
+
+        let result = s_expr.replace_children(vec![Arc::new(
+            final_expr
+                .replace_plan(Arc::new(new_agg.into()))
+                .replace_children(vec![Arc::new(
+                    partial_expr
+                        .replace_plan(Arc::new(partial_agg.into()))
+                        .replace_children(vec![Arc::new(
+                            input_expr
+                                .replace_plan(Arc::new(new_input.into()))
+                                .replace_children(vec![Arc::new(new_join)]),
+                        )]),
+                )]),
+        )]);
+        return Ok(Some(result));
+    }
+
+    Ok(None)
+}
+
+struct EagerAggregation {
+    s_expr: SExpr,
+    // The output column of each eager aggregate function.
+    func_indices: Vec<IndexType>,
+}
+
+// Build the eager aggregation over the table, it has the same shape as the query plan
+// of an aggregating index, so it can be matched by `try_rewrite`.
+#[allow(clippy::too_many_arguments)]
+fn build_eager_aggregation(
+    metadata: &MetadataRef,
+    table_index: IndexType,
+    base_columns: &[ColumnEntry],
+    agg: &Aggregate,
+    input_items: &HashMap<IndexType, &ScalarExpr>,
+    pushed_items: &[(&ScalarItem, ScalarExpr)],
+    key_columns: &ColumnSet,
+    leaf: &SExpr,
+) -> Result<EagerAggregation> {
+    let mut eager_input = Vec::new();
+    let mut group_items = Vec::new();
+    for (item, scalar) in pushed_items {
+        eager_input.push(ScalarItem {
+            index: item.index,
+            scalar: scalar.clone(),
+        });
+        group_items.push((*item).clone());
+    }
+    for col in base_columns
+        .iter()
+        .filter(|col| key_columns.contains(&col.index()))
+    {
+        let column: ScalarExpr = BoundColumnRef {
+            span: None,
+            column: ColumnBindingBuilder::new(
+                col.name(),
+                col.index(),
+                Box::new(col.data_type()),
+                Visibility::Visible,
+            )
+            .table_index(Some(table_index))
+            .build(),
+        }
+        .into();
+        eager_input.push(ScalarItem {
+            index: col.index(),
+            scalar: column.clone(),
+        });
+        group_items.push(ScalarItem {
+            index: col.index(),
+            scalar: column,
+        });
+    }
+
+    let mut aggregate_functions = Vec::with_capacity(agg.aggregate_functions.len());
+    let mut func_indices = Vec::with_capacity(agg.aggregate_functions.len());
+    for item in agg.aggregate_functions.iter() {
+        if let ScalarExpr::AggregateFunction(func) = &item.scalar {
+            for arg in func.args.iter() {
+                if let ScalarExpr::BoundColumnRef(col) = arg {
+                    if eager_input.iter().all(|i| i.index != col.column.index) {
+                        eager_input.push(ScalarItem {
+                            index: col.column.index,
+                            scalar: input_scalar(input_items, arg),
+                        });
+                    }
+                }
+            }
+            let index = metadata.write().add_derived_column(
+                format!("_eager_{}", func.display_name),
+                *func.return_type.clone(),
+            );
+            aggregate_functions.push(ScalarItem {
+                index,
+                scalar: item.scalar.clone(),
+            });
+            func_indices.push(index);
+        }
+    }
+
+    let selection = group_items
+        .iter()
+        .chain(aggregate_functions.iter())
+        .map(|item| {
+            Ok(ScalarItem {
+                index: item.index,
+                scalar: BoundColumnRef {
+                    span: None,
+                    column: ColumnBindingBuilder::new(
+                        format!("_eager_{}", item.index),
+                        item.index,
+                        Box::new(item.scalar.data_type()?),
+                        Visibility::Visible,
+                    )
+                    .build(),
+                }
+                .into(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let partial = Aggregate {
+        mode: AggregateMode::Partial,
+        group_items,
+        aggregate_functions,
+        from_distinct: false,
+        limit: None,
+        grouping_sets: None,
+    };
+    let mut final_agg = partial.clone();
+    final_agg.mode = AggregateMode::Final;
+
+    let s_expr = SExpr::create_unary(
+        Arc::new(EvalScalar { items: selection }.into()),
+        Arc::new(SExpr::create_unary(
+            Arc::new(final_agg.into()),
+            Arc::new(SExpr::create_unary(
+                Arc::new(partial.into()),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(EvalScalar { items: eager_input }.into()),
+                    Arc::new(leaf.clone()),
+                )),
+            )),
+        )),
+    );
+    Ok(EagerAggregation {
+        s_expr,
+        func_indices,
+    })
+}
+
+// The input of the aggregation referenced by the column.
+fn input_scalar(input_items: &HashMap<IndexType, &ScalarExpr>, scalar: &ScalarExpr) -> ScalarExpr {
+    if let ScalarExpr::BoundColumnRef(col) = scalar {
+        if let Some(input) = input_items.get(&col.column.index) {
+            return (*input).clone();
+        }
+    }
+    scalar.clone()
+}
+
+// Collect table scans under inner joins, returns false if there are other kinds of joins.
+fn collect_join_leaves<'a>(
+    s_expr: &'a SExpr,
+    path: Vec<usize>,
+    join_columns: &mut ColumnSet,
+    leaves: &mut Vec<(Vec<usize>, &'a Scan)>,
+) -> bool {
+    match s_expr.plan() {
+        RelOperator::Join(join) => {
+            if join.join_type != JoinType::Inner || join.from_correlated_subquery {
+                return false;
+            }
+            for condition in join
+                .left_conditions
+                .iter()
+                .chain(join.right_conditions.iter())
+                .chain(join.non_equi_conditions.iter())
+            {
+                join_columns.extend(condition.used_columns());
+            }
+            for (idx, child) in s_expr.children().iter().enumerate() {
+                let mut child_path = path.clone();
+                child_path.push(idx);
+                if !collect_join_leaves(child, child_path, join_columns, leaves) {
+                    return false;
+                }
+            }
+            true
+        }
+        RelOperator::Scan(scan) => {
+            leaves.push((path, scan));
+            true
+        }
+        RelOperator::Filter(_) => {
+            if let RelOperator::Scan(scan) = s_expr.children()[0].plan() {
+                leaves.push((path, scan));
+            }
+            true
+        }
+        _ => true,
+    }
+}
+
+fn leaf_of<'a>(s_expr: &'a SExpr, path: &[usize]) -> &'a SExpr {
+    path.iter()
+        .fold(s_expr, |s_expr, idx| &s_expr.children()[*idx])
+}
+
+fn replace_leaf(s_expr: &SExpr, path: &[usize], leaf: SExpr) -> Result<SExpr> {
+    match path.split_first() {
+        None => Ok(leaf),
+        Some((idx, rest)) => {
+            let mut children = s_expr.children().to_vec();
+            children[*idx] = Arc::new(replace_leaf(s_expr.child(*idx)?, rest, leaf)?);
+            Ok(s_expr.replace_children(children))
+        }
+    }
+}
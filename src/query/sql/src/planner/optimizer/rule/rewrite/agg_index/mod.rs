@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod join_rewrite;
 mod query_rewrite;
 
+pub use join_rewrite::try_rewrite_join;
 pub use query_rewrite::*;
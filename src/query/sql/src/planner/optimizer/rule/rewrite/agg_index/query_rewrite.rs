@@ -94,9 +94,13 @@ pub fn try_rewrite(
         match (&query_info.aggregation, &index_info.aggregation) {
             (Some((query_agg, _)), Some(_)) => {
                 is_agg = true;
-                // Check if group items are the same.
+                // The query can be rolled up from the finer-grained groups of the index,
+                // the aggregate states of the index will be merged by the query's `Aggregate`.
                 let index_group_items = index_info.formatted_group_items();
-                if query_group_items != index_group_items {
+                if !query_group_items
+                    .iter()
+                    .all(|item| index_group_items.contains(item))
+                {
                     continue;
                 }
                 // If the query is an aggregation query, the index selection is to rewrite the input `EvalScalar` operator of `Aggregate` operators.
@@ -198,14 +202,15 @@ pub fn try_rewrite(
                 } else {
                     continue;
                 }
-            }
-            (Some((qe, qr, qo)), None) => {
-                if !qe.is_empty() {
-                    continue;
-                }
-                if !qo.is_empty() {
+                if let Some(preds) =
+                    check_predicates_residual(&query_info, qe, qo, ie, io, &index_selection)
+                {
+                    new_predicates.extend(preds);
+                } else {
                     continue;
                 }
+            }
+            (Some((qe, qr, qo)), None) => {
                 if let Some(preds) = check_predicates_range(
                     qr,
                     &HashMap::new(),
@@ -216,6 +221,13 @@ pub fn try_rewrite(
                 } else {
                     continue;
                 }
+                if let Some(preds) =
+                    check_predicates_residual(&query_info, qe, qo, &[], &[], &index_selection)
+                {
+                    new_predicates.extend(preds);
+                } else {
+                    continue;
+                }
             }
             (None, Some(_)) => {
                 // Not matched.
@@ -924,6 +936,51 @@ fn check_predicates_other(query: &OtherPredicates, index: &OtherPredicates) -> b
     true
 }
 
+/// Check if equal and other predicates of the query, which are not in the index,
+/// can be evaluated on the index outputs.
+///
+/// Returns an [`Option`]:
+///
+/// - If any of them can not be evaluated, returns [None].
+/// - Otherwise, returns the rewritten predicates to filter the index data.
+fn check_predicates_residual(
+    query_info: &RewriteInfomartion<'_>,
+    query_equal: &EqualPredicates,
+    query_other: &OtherPredicates,
+    index_equal: &[(&ScalarExpr, &ScalarExpr)],
+    index_other: &[&ScalarExpr],
+    index_selection: &SelectionMap<'_>,
+) -> Option<Vec<ScalarExpr>> {
+    let mut out = Vec::new();
+    for (left, right) in query_equal {
+        if index_equal
+            .iter()
+            .any(|(l, r)| (l == left && r == right) || (l == right && r == left))
+        {
+            continue;
+        }
+        out.push(
+            FunctionCall {
+                span: None,
+                func_name: "eq".to_string(),
+                params: vec![],
+                arguments: vec![
+                    rewrite_by_selection(query_info, left, index_selection)?,
+                    rewrite_by_selection(query_info, right, index_selection)?,
+                ],
+            }
+            .into(),
+        );
+    }
+    for pred in query_other {
+        if index_other.iter().any(|p| p == pred) {
+            continue;
+        }
+        out.push(rewrite_by_selection(query_info, pred, index_selection)?);
+    }
+    Some(out)
+}
+
 fn try_create_column_binding(
     index_selection: &SelectionMap<'_>,
     formatted_scalar: &str,
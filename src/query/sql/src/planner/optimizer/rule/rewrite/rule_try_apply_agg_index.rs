@@ -183,6 +183,57 @@ impl RuleTryApplyAggIndex {
                         )),
                     )),
                 ),
+                // Expression
+                //     |
+                // Aggregation
+                //     |
+                // Expression
+                //     |
+                //   Join
+                //   /  \
+                // ...  ...
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Aggregate,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_unary(
+                            Arc::new(
+                                PatternPlan {
+                                    plan_type: RelOp::Aggregate,
+                                }
+                                .into(),
+                            ),
+                            Arc::new(SExpr::create_unary(
+                                Arc::new(
+                                    PatternPlan {
+                                        plan_type: RelOp::EvalScalar,
+                                    }
+                                    .into(),
+                                ),
+                                Arc::new(SExpr::create_binary(
+                                    Arc::new(
+                                        PatternPlan {
+                                            plan_type: RelOp::Join,
+                                        }
+                                        .into(),
+                                    ),
+                                    Arc::new(SExpr::create_pattern_leaf()),
+                                    Arc::new(SExpr::create_pattern_leaf()),
+                                )),
+                            )),
+                        )),
+                    )),
+                ),
             ],
         }
     }
@@ -202,7 +253,21 @@ impl Rule for RuleTryApplyAggIndex {
         s_expr: &SExpr,
         state: &mut crate::optimizer::rule::TransformResult,
     ) -> Result<()> {
-        let (table_index, table_name) = self.get_table(s_expr);
+        if self.is_rewritten(s_expr) {
+            // The scan is already rewritten by an aggregating index.
+            return Ok(());
+        }
+        let (table_index, table_name) = match self.get_table(s_expr) {
+            Some(table) => table,
+            None => {
+                // The aggregation is over joins, try to push it down to a joined table.
+                if let Some(mut result) = agg_index::try_rewrite_join(&self.metadata, s_expr)? {
+                    result.set_applied_rule(&self.id);
+                    state.add_result(result);
+                }
+                return Ok(());
+            }
+        };
         let metadata = self.metadata.read();
         let index_plans = metadata.get_agg_indexes(&table_name);
         if index_plans.is_none() {
@@ -229,16 +294,25 @@ impl Rule for RuleTryApplyAggIndex {
 }
 
 impl RuleTryApplyAggIndex {
-    fn get_table(&self, s_expr: &SExpr) -> (IndexType, String) {
+    fn is_rewritten(&self, s_expr: &SExpr) -> bool {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => scan.agg_index.is_some(),
+            RelOperator::Join(_) => false,
+            _ => self.is_rewritten(s_expr.child(0).unwrap()),
+        }
+    }
+
+    fn get_table(&self, s_expr: &SExpr) -> Option<(IndexType, String)> {
         match s_expr.plan() {
             RelOperator::Scan(scan) => {
                 let metadata = self.metadata.read();
                 let table = metadata.table(scan.table_index);
-                (
+                Some((
                     scan.table_index,
                     format!("{}.{}.{}", table.catalog(), table.database(), table.name()),
-                )
+                ))
             }
+            RelOperator::Join(_) => None,
             _ => self.get_table(s_expr.child(0).unwrap()),
         }
     }
@@ -18,5 +18,6 @@ mod rule_left_exchange_join;
 mod util;
 
 pub use rule_commute_join_base_table::RuleCommuteJoinBaseTable;
+pub(crate) use rule_eager_aggregation::modify_final_aggregate_function;
 pub use rule_eager_aggregation::RuleEagerAggregation;
 pub use rule_left_exchange_join::RuleLeftExchangeJoin;
@@ -1317,7 +1317,7 @@ fn get_eager_aggregation_functions(
 
 // Final aggregate functions's data type = eager aggregate functions's return_type
 // For COUNT, func_name: count => sum, return_type: Nullable(UInt64)
-fn modify_final_aggregate_function(agg: &mut AggregateFunction, args_index: usize) {
+pub(crate) fn modify_final_aggregate_function(agg: &mut AggregateFunction, args_index: usize) {
     if agg.func_name.as_str() == "count" {
         agg.func_name = "sum".to_string();
         agg.return_type = Box::new(DataType::Nullable(Box::new(DataType::Number(
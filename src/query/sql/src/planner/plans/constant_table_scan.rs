@@ -116,6 +116,7 @@ impl Operator for ConstantTableScan {
     fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
         Ok(PhysicalProperty {
             distribution: Distribution::Serial,
+            ordering: vec![],
         })
     }
 
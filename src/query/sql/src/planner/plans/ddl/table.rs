@@ -51,6 +51,7 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    pub clone_source: Option<CloneTableSource>,
 }
 
 impl CreateTablePlan {
@@ -59,6 +60,16 @@ impl CreateTablePlan {
     }
 }
 
+/// The table, and optionally the point of its history, that
+/// `CREATE TABLE ... CLONE` shares the data of.
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub point: Option<NavigationPoint>,
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -173,6 +184,8 @@ pub enum OptimizeTableAction {
     Purge(Option<NavigationPoint>),
     CompactBlocks,
     CompactSegments,
+    /// Remove the expired rows of a table with a TTL.
+    Ttl,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -230,6 +243,8 @@ pub struct AddTableColumnPlan {
     pub table: String,
     pub field: TableField,
     pub comment: String,
+    /// Storage options of the column, like `compression` and `encoding`.
+    pub field_options: BTreeMap<String, String>,
     pub option: AddColumnOption,
 }
 
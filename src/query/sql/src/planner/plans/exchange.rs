@@ -54,6 +54,7 @@ impl Operator for Exchange {
                 Exchange::Merge => Distribution::Serial,
                 Exchange::MergeSort => Distribution::Serial,
             },
+            ordering: vec![],
         })
     }
 
@@ -406,15 +406,18 @@ impl Operator for Join {
             // the distribution of build side.
             (Distribution::Random, _) => Ok(PhysicalProperty {
                 distribution: build_prop.distribution.clone(),
+                ordering: vec![],
             }),
             // If both sides are broadcast, which means broadcast join is enabled, to make sure the current join is broadcast, should return Random.
             // Then required proper is broadcast, and the join will be broadcast.
             (Distribution::Broadcast, Distribution::Broadcast) => Ok(PhysicalProperty {
                 distribution: Distribution::Random,
+                ordering: vec![],
             }),
             // Otherwise pass through probe side.
             _ => Ok(PhysicalProperty {
                 distribution: probe_prop.distribution.clone(),
+                ordering: vec![],
             }),
         }
     }
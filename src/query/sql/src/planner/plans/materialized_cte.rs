@@ -56,6 +56,7 @@ impl Operator for MaterializedCte {
     fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
         Ok(PhysicalProperty {
             distribution: Distribution::Serial,
+            ordering: vec![],
         })
     }
 
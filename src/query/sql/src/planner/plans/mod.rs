@@ -47,6 +47,8 @@ mod scan;
 mod setting;
 pub mod share;
 mod sort;
+mod table_partition;
+mod table_tag;
 mod union_all;
 mod update;
 mod window;
@@ -91,6 +93,9 @@ pub use scan::*;
 pub use setting::*;
 pub use share::*;
 pub use sort::*;
+pub use table_partition::DropTablePartitionPlan;
+pub use table_tag::CreateTableTagPlan;
+pub use table_tag::DropTableTagPlan;
 pub use udf::*;
 pub use union_all::UnionAll;
 pub use update::*;
@@ -52,6 +52,7 @@ use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableTagPlan;
 use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
@@ -78,7 +79,9 @@ use crate::plans::DropStagePlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableTagPlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
@@ -195,6 +198,9 @@ pub enum Plan {
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
+    CreateTableTag(Box<CreateTableTagPlan>),
+    DropTableTag(Box<DropTableTagPlan>),
+    DropTablePartition(Box<DropTablePartitionPlan>),
     TruncateTable(Box<TruncateTablePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
     VacuumTable(Box<VacuumTablePlan>),
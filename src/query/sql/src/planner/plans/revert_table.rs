@@ -21,4 +21,7 @@ pub struct RevertTablePlan {
     pub database: String,
     pub table: String,
     pub point: NavigationPoint,
+    /// `RESTORE TO`, which also restores the schema dropped or changed after the snapshot
+    /// of the point, and checks the files of the snapshot are not purged.
+    pub restore: bool,
 }
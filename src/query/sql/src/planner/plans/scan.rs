@@ -175,6 +175,7 @@ impl Operator for Scan {
     fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
         Ok(PhysicalProperty {
             distribution: Distribution::Random,
+            ordering: vec![],
         })
     }
 
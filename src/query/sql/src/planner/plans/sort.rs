@@ -54,7 +54,13 @@ impl Operator for Sort {
     }
 
     fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
-        rel_expr.derive_physical_prop_child(0)
+        let mut prop = rel_expr.derive_physical_prop_child(0)?;
+        // A sort before exchange only sorts the rows of each node partially.
+        prop.ordering = match self.after_exchange {
+            Some(false) => vec![],
+            _ => self.items.clone(),
+        };
+        Ok(prop)
     }
 
     fn compute_required_prop_child(
@@ -85,6 +85,7 @@ impl Operator for UnionAll {
         let left_child = rel_expr.derive_physical_prop_child(0)?;
         Ok(PhysicalProperty {
             distribution: left_child.distribution,
+            ordering: vec![],
         })
     }
 
@@ -98,7 +98,10 @@ impl Operator for Window {
     }
 
     fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
-        rel_expr.derive_physical_prop_child(0)
+        let mut prop = rel_expr.derive_physical_prop_child(0)?;
+        // Window functions re-sort rows by the partition and order keys.
+        prop.ordering.clear();
+        Ok(prop)
     }
 
     fn compute_required_prop_child(
@@ -33,6 +33,16 @@ use crate::Visibility;
 pub const CURRENT_BLOCK_ID_COL_NAME: &str = "_current_block_id";
 pub const CURRENT_BLOCK_ROW_NUM_COL_NAME: &str = "_current_block_row_num";
 
+/// A stream in standard mode is read by joining the rows inserted into and deleted from its table.
+/// Binding a standard stream with one of these aliases reads only that side of the changes.
+pub const CHANGE_INSERT_ALIAS: &str = "_change_insert";
+pub const CHANGE_DELETE_ALIAS: &str = "_change_delete";
+
+/// The stream table option that tells which side of the changes to read.
+pub const CHANGE_TYPE_OPTION: &str = "change_type";
+pub const CHANGE_TYPE_INSERT: &str = "insert";
+pub const CHANGE_TYPE_DELETE: &str = "delete";
+
 /// Generate stream columns operator '_origin_block_id' and
 /// '_origin_block_row_num' for mutation.
 pub fn gen_mutation_stream_operator(
@@ -14,6 +14,7 @@
 
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::io::parquet::write::can_encode;
 use common_arrow::arrow::io::parquet::write::transverse;
 use common_arrow::arrow::io::parquet::write::RowGroupIterator;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
@@ -26,16 +27,27 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::TableSchema;
+use storages_common_table_meta::table::ColumnEncoding;
+use storages_common_table_meta::table::ColumnStorageOptions;
 use storages_common_table_meta::table::TableCompression;
 
 /// Serialize data blocks to parquet format.
+///
+/// The columns are compressed with `compression`, unless the field options of the
+/// schema say otherwise.
 pub fn blocks_to_parquet(
     schema: impl AsRef<TableSchema>,
     blocks: Vec<DataBlock>,
     write_buffer: &mut Vec<u8>,
     compression: TableCompression,
 ) -> Result<(u64, ThriftFileMetaData)> {
-    let arrow_schema = schema.as_ref().to_arrow();
+    let schema = schema.as_ref();
+    let arrow_schema = schema.to_arrow();
+    let column_options = schema
+        .fields()
+        .iter()
+        .map(|f| ColumnStorageOptions::try_from_field_options(&schema.field_options(f.column_id())))
+        .collect::<Result<Vec<_>>>()?;
 
     let row_group_write_options = WriteOptions {
         write_statistics: false,
@@ -56,7 +68,25 @@ pub fn blocks_to_parquet(
     let encodings: Vec<Vec<_>> = arrow_schema
         .fields
         .iter()
-        .map(|f| transverse(&f.data_type, encoding_map))
+        .zip(column_options.iter())
+        .map(|(f, options)| match options.encoding {
+            ColumnEncoding::Plain => transverse(&f.data_type, |_| Encoding::Plain),
+            ColumnEncoding::Delta => transverse(&f.data_type, |data_type| {
+                if can_encode(data_type, Encoding::DeltaBinaryPacked) {
+                    Encoding::DeltaBinaryPacked
+                } else {
+                    Encoding::Plain
+                }
+            }),
+            _ => transverse(&f.data_type, encoding_map),
+        })
+        .collect::<Vec<_>>();
+    let compressions = column_options
+        .iter()
+        .map(|options| match options.compression {
+            Some(column_compression) => column_compression.into(),
+            None => compression.into(),
+        })
         .collect::<Vec<_>>();
 
     let row_groups = RowGroupIterator::try_new(
@@ -64,7 +94,8 @@ pub fn blocks_to_parquet(
         &arrow_schema,
         row_group_write_options,
         encodings,
-    )?;
+    )?
+    .with_compressions(compressions)?;
 
     use common_arrow::parquet::write::WriteOptions as FileWriteOption;
     let options = FileWriteOption {
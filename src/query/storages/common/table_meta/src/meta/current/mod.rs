@@ -18,6 +18,7 @@ pub use v2::BlockMeta;
 pub use v2::ClusterStatistics;
 pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
+pub use v2::DeletionVectorMeta;
 pub use v2::Statistics;
 pub use v4::CompactSegmentInfo;
 pub use v4::SegmentInfo;
@@ -26,6 +26,19 @@ pub type Location = (String, FormatVersion);
 pub type ClusterKey = (u32, String);
 pub type StatisticsOfColumns = HashMap<ColumnId, ColumnStatistics>;
 
+/// Summary of one partition of a partitioned table.
+///
+/// Every block of a partitioned table belongs to exactly one partition, and
+/// segments never mix blocks of different partitions, so a partition can be
+/// pruned, or dropped, as a whole without reading its segments.
+///
+/// `col_stats` only covers the columns of the partition key.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct PartitionSummary {
+    pub segments: Vec<Location>,
+    pub col_stats: StatisticsOfColumns,
+}
+
 // Assigned to executors, describes that which blocks of given segment, an executor should take care of
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
 pub struct BlockSlotDescription {
@@ -80,6 +80,23 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// the rows of the block that are deleted, but not yet removed from the block data
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorMeta>,
+}
+
+/// Meta information of a deletion vector, a bitmap of the deleted rows of a block.
+///
+/// The bitmap is stored in a separate file, the block data is left untouched.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVectorMeta {
+    /// location of the bitmap file
+    pub location: Location,
+    /// size of the bitmap file, in bytes
+    pub size: u64,
+    /// number of the deleted rows
+    pub deleted_rows: u64,
 }
 
 impl BlockMeta {
@@ -109,6 +126,7 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            deletion_vector: None,
         }
     }
 
@@ -116,6 +134,14 @@ impl BlockMeta {
         self.compression
     }
 
+    /// The number of rows that are not deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        match &self.deletion_vector {
+            Some(deletion_vector) => self.row_count - deletion_vector.deleted_rows,
+            None => self.row_count,
+        }
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -254,6 +280,7 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            deletion_vector: None,
         }
     }
 
@@ -285,6 +312,7 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            deletion_vector: None,
         }
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::io::Cursor;
 
 use chrono::DateTime;
@@ -35,6 +36,7 @@ use crate::meta::ClusterKey;
 use crate::meta::FormatVersion;
 use crate::meta::Location;
 use crate::meta::MetaEncoding;
+use crate::meta::PartitionSummary;
 use crate::meta::SnapshotId;
 use crate::meta::Statistics;
 use crate::meta::Versioned;
@@ -83,6 +85,13 @@ pub struct TableSnapshot {
     // The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
     pub table_statistics_location: Option<String>,
+
+    /// Partitions of a partitioned table, keyed by the partition value.
+    ///
+    /// Segments that are not listed in any partition (e.g. written by
+    /// MERGE INTO) are never pruned by partition.
+    #[serde(default)]
+    pub partitions: BTreeMap<String, PartitionSummary>,
 }
 
 impl TableSnapshot {
@@ -114,6 +123,7 @@ impl TableSnapshot {
             segments,
             cluster_key_meta,
             table_statistics_location,
+            partitions: BTreeMap::new(),
         }
     }
 
@@ -134,7 +144,7 @@ impl TableSnapshot {
         let id = Uuid::new_v4();
         let clone = previous.clone();
         // the timestamp of the new snapshot will be adjusted by the `new` method
-        Self::new(
+        let mut snapshot = Self::new(
             id,
             &clone.timestamp,
             Some((clone.snapshot_id, clone.format_version)),
@@ -143,7 +153,9 @@ impl TableSnapshot {
             clone.segments,
             clone.cluster_key_meta,
             clone.table_statistics_location,
-        )
+        );
+        snapshot.partitions = clone.partitions;
+        snapshot
     }
 
     /// Serializes the struct to a byte vector.
@@ -221,6 +233,7 @@ impl From<v2::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            partitions: BTreeMap::new(),
         }
     }
 }
@@ -242,6 +255,7 @@ where T: Into<v3::TableSnapshot>
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            partitions: BTreeMap::new(),
         }
     }
 }
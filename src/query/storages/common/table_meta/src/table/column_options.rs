@@ -0,0 +1,215 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_arrow::parquet;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::table::TableCompression;
+
+pub const FIELD_OPT_KEY_COMPRESSION: &str = "compression";
+pub const FIELD_OPT_KEY_ENCODING: &str = "encoding";
+
+/// The compression of a column, set by `COMPRESSION '<codec>[(<level>)]'`, e.g. `zstd(9)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnCompression {
+    pub codec: TableCompression,
+    /// Compression level, only zstd has levels.
+    ///
+    /// Levels are only supported by the parquet storage format.
+    pub level: Option<i32>,
+}
+
+impl TryFrom<&str> for ColumnCompression {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let value = value.trim().to_lowercase();
+        let (codec, level) = match value.strip_suffix(')').and_then(|v| v.split_once('(')) {
+            Some((codec, level)) => {
+                let level = level.trim().parse::<i32>().map_err(|_| {
+                    ErrorCode::TableOptionInvalid(format!("invalid compression level of '{value}'"))
+                })?;
+                (codec.trim(), Some(level))
+            }
+            None => (value.as_str(), None),
+        };
+        let codec = TableCompression::try_from(codec)
+            .map_err(|e| ErrorCode::TableOptionInvalid(e.message()))?;
+        if let Some(level) = level {
+            if !matches!(codec, TableCompression::Zstd) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "compression level is only supported by zstd, but got '{value}'"
+                )));
+            }
+            if !(1..=22).contains(&level) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "zstd compression level must be between 1 and 22, but got {level}"
+                )));
+            }
+        }
+        Ok(ColumnCompression { codec, level })
+    }
+}
+
+impl Display for ColumnCompression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let codec = match self.codec {
+            TableCompression::None => "none",
+            TableCompression::LZ4 => "lz4",
+            TableCompression::Snappy => "snappy",
+            TableCompression::Zstd => "zstd",
+        };
+        match self.level {
+            Some(level) => write!(f, "{codec}({level})"),
+            None => write!(f, "{codec}"),
+        }
+    }
+}
+
+/// Convert to parquet CompressionOptions.
+impl From<ColumnCompression> for parquet::compression::CompressionOptions {
+    fn from(value: ColumnCompression) -> Self {
+        match (value.codec, value.level) {
+            (TableCompression::Zstd, Some(level)) => {
+                parquet::compression::CompressionOptions::Zstd(
+                    parquet::compression::ZstdLevel::try_new(level).ok(),
+                )
+            }
+            (codec, _) => codec.into(),
+        }
+    }
+}
+
+/// The encoding of a column, set by `ENCODING '<encoding>'`.
+///
+/// The native storage format encodes all the pages of the column with it, if it applies
+/// to the type of the column. The parquet storage format only supports `plain`, and
+/// `delta` for integers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColumnEncoding {
+    /// Choose the encoding of each page by its data.
+    #[default]
+    Auto,
+    Plain,
+    Dict,
+    Rle,
+    Delta,
+    Bitpacking,
+}
+
+impl TryFrom<&str> for ColumnEncoding {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "auto" => Ok(ColumnEncoding::Auto),
+            "plain" => Ok(ColumnEncoding::Plain),
+            "dict" => Ok(ColumnEncoding::Dict),
+            "rle" => Ok(ColumnEncoding::Rle),
+            "delta" => Ok(ColumnEncoding::Delta),
+            "bitpacking" => Ok(ColumnEncoding::Bitpacking),
+            other => Err(ErrorCode::TableOptionInvalid(format!(
+                "unsupported column encoding: {other}, expect one of auto, plain, dict, rle, delta and bitpacking"
+            ))),
+        }
+    }
+}
+
+impl Display for ColumnEncoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let encoding = match self {
+            ColumnEncoding::Auto => "auto",
+            ColumnEncoding::Plain => "plain",
+            ColumnEncoding::Dict => "dict",
+            ColumnEncoding::Rle => "rle",
+            ColumnEncoding::Delta => "delta",
+            ColumnEncoding::Bitpacking => "bitpacking",
+        };
+        write!(f, "{encoding}")
+    }
+}
+
+/// The storage options of a column, kept as the field options of the table schema.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ColumnStorageOptions {
+    /// Overrides the compression of the table.
+    pub compression: Option<ColumnCompression>,
+    pub encoding: ColumnEncoding,
+}
+
+impl ColumnStorageOptions {
+    pub fn try_from_field_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let mut column_options = ColumnStorageOptions::default();
+        for (key, value) in options {
+            match key.as_str() {
+                FIELD_OPT_KEY_COMPRESSION => {
+                    column_options.compression = Some(ColumnCompression::try_from(value.as_str())?)
+                }
+                FIELD_OPT_KEY_ENCODING => {
+                    column_options.encoding = ColumnEncoding::try_from(value.as_str())?
+                }
+                other => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "unknown column option: {other}"
+                    )));
+                }
+            }
+        }
+        Ok(column_options)
+    }
+
+    /// Check the options are supported by the storage format of the table.
+    pub fn check_storage_format(&self, is_native: bool) -> Result<()> {
+        if is_native {
+            if let Some(compression @ ColumnCompression { level: Some(_), .. }) = &self.compression
+            {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "compression level is not supported by the native storage format, but got '{compression}'"
+                )));
+            }
+        } else if matches!(
+            self.encoding,
+            ColumnEncoding::Dict | ColumnEncoding::Rle | ColumnEncoding::Bitpacking
+        ) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "column encoding '{}' is not supported by the parquet storage format, expect one of auto, plain and delta",
+                self.encoding
+            )));
+        }
+        Ok(())
+    }
+
+    /// The normalized field options, the default ones are omitted.
+    pub fn to_field_options(&self) -> BTreeMap<String, String> {
+        let mut options = BTreeMap::new();
+        if let Some(compression) = &self.compression {
+            options.insert(
+                FIELD_OPT_KEY_COMPRESSION.to_string(),
+                compression.to_string(),
+            );
+        }
+        if self.encoding != ColumnEncoding::Auto {
+            options.insert(
+                FIELD_OPT_KEY_ENCODING.to_string(),
+                self.encoding.to_string(),
+            );
+        }
+        options
+    }
+}
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod column_options;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
 
+pub use column_options::*;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
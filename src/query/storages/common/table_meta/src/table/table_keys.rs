@@ -23,6 +23,16 @@ pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
+/// Comma separated ids of the tables cloned from this table, which may
+/// still reference its segments and blocks. An id may be followed by `:` and
+/// the location of the snapshot it is cloned from.
+pub const OPT_KEY_CLONES: &str = "clones";
+/// The snapshot tags of a table, a json map from the name of a tag to the tag.
+pub const OPT_KEY_SNAPSHOT_TAGS: &str = "snapshot_tags";
+/// The partition expression of a partitioned table, set by `PARTITION BY`.
+pub const OPT_KEY_PARTITION_BY: &str = "partition_by";
+/// The expiration expression of the rows of a table, set by `TTL`.
+pub const OPT_KEY_TTL: &str = "ttl";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -49,6 +59,10 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_CLONES);
+    r.insert(OPT_KEY_SNAPSHOT_TAGS);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_TTL);
     r
 });
 
@@ -57,6 +71,10 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_CLONES);
+    r.insert(OPT_KEY_SNAPSHOT_TAGS);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_TTL);
     r
 });
 
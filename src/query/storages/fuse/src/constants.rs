@@ -17,6 +17,13 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+pub const FUSE_OPT_KEY_AUTO_COMPACTION: &str = "auto_compaction";
+pub const FUSE_OPT_KEY_AUTO_COMPACTION_TARGET_BLOCK_SIZE: &str =
+    "auto_compaction_target_block_size";
+pub const FUSE_OPT_KEY_AUTO_COMPACTION_SMALL_BLOCK_RATIO: &str =
+    "auto_compaction_small_block_ratio";
+pub const FUSE_OPT_KEY_AUTO_COMPACTION_INTERVAL: &str = "auto_compaction_interval";
+pub const FUSE_OPT_KEY_ENABLE_DELETION_VECTOR: &str = "enable_deletion_vector";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
@@ -27,9 +34,14 @@ pub const FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX: &str = "_ts";
 pub const FUSE_TBL_LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
 pub const FUSE_TBL_VIRTUAL_BLOCK_PREFIX: &str = "_vb";
 pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
+pub const FUSE_TBL_DELETION_VECTOR_PREFIX: &str = "_dv";
 
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_PAGE: usize = 131072;
 pub const DEFAULT_ROW_PER_PAGE_FOR_BLOCKING: usize = 2048;
 
 pub const DEFAULT_AVG_DEPTH_THRESHOLD: f64 = 0.001;
+
+pub const DEFAULT_AUTO_COMPACTION_SMALL_BLOCK_RATIO: f64 = 0.2;
+// in seconds.
+pub const DEFAULT_AUTO_COMPACTION_INTERVAL: u64 = 3600;
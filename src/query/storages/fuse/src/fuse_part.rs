@@ -32,6 +32,7 @@ use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 use storages_common_table_meta::meta::Location;
 
 /// Fuse table partition information.
@@ -47,6 +48,8 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// The deletion vector of the block, rows in it must be skipped when reading.
+    pub deletion_vector: Option<DeletionVectorMeta>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -79,6 +82,7 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        deletion_vector: Option<DeletionVectorMeta>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -89,6 +93,7 @@ impl FusePartInfo {
             sort_min_max,
             block_meta_index,
             columns_stat,
+            deletion_vector,
         }))
     }
 
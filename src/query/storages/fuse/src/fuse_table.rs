@@ -91,6 +91,7 @@ use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -403,6 +404,12 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    /// Whether DELETE and UPDATE mark the rows with deletion vectors instead of
+    /// rewriting the blocks.
+    pub fn deletion_vector_enabled(&self) -> bool {
+        self.get_option(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR, false)
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -759,6 +766,7 @@ impl Table for FuseTable {
             NavigationPoint::TimePoint(time_point) => Ok(self
                 .navigate_to_time_point(snapshot_location, *time_point)
                 .await?),
+            NavigationPoint::Tag(tag) => Ok(self.navigate_to_tag(tag.as_str()).await?),
         }
     }
 
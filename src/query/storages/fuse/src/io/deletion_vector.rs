@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deletion vectors record the offsets of the rows deleted from a block,
+//! so that DELETE and UPDATE do not have to rewrite the whole block.
+//!
+//! A deletion vector is persisted as a serialized roaring bitmap of the
+//! deleted row offsets, and is referenced by the `deletion_vector` of the
+//! [`BlockMeta`].
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVectorMeta;
+
+use crate::io::BlockReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::FusePartInfo;
+
+pub const DELETION_VECTOR_VERSION: u64 = 0;
+
+pub async fn read_deletion_vector(
+    dal: &Operator,
+    meta: &DeletionVectorMeta,
+) -> Result<RoaringBitmap> {
+    let data = dal.read(&meta.location.0).await?;
+    deserialize_deletion_vector(&data)
+}
+
+pub fn read_deletion_vector_sync(
+    dal: &Operator,
+    meta: &DeletionVectorMeta,
+) -> Result<RoaringBitmap> {
+    let data = dal.blocking().read(&meta.location.0)?;
+    deserialize_deletion_vector(&data)
+}
+
+/// Reads the deletion vector of the block, if the block has one.
+pub async fn read_block_deletion_vector(
+    dal: &Operator,
+    block_meta: &BlockMeta,
+) -> Result<Option<RoaringBitmap>> {
+    match &block_meta.deletion_vector {
+        Some(meta) => Ok(Some(read_deletion_vector(dal, meta).await?)),
+        None => Ok(None),
+    }
+}
+
+/// Persists the deleted row offsets of a block, returns the meta to be
+/// attached to the block.
+pub async fn write_deletion_vector(
+    dal: &Operator,
+    location_gen: &TableMetaLocationGenerator,
+    deleted: &RoaringBitmap,
+) -> Result<DeletionVectorMeta> {
+    let location = location_gen.gen_deletion_vector_location();
+    let mut data = Vec::with_capacity(deleted.serialized_size());
+    deleted.serialize_into(&mut data)?;
+    let size = data.len() as u64;
+    dal.write(&location.0, data).await?;
+    Ok(DeletionVectorMeta {
+        location,
+        size,
+        deleted_rows: deleted.len(),
+    })
+}
+
+impl BlockReader {
+    /// Reads the deletion vector of the partition, if the block has one.
+    pub async fn read_deletion_vector(&self, part: &FusePartInfo) -> Result<Option<RoaringBitmap>> {
+        match &part.deletion_vector {
+            Some(meta) => Ok(Some(read_deletion_vector(&self.operator, meta).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn sync_read_deletion_vector(&self, part: &FusePartInfo) -> Result<Option<RoaringBitmap>> {
+        match &part.deletion_vector {
+            Some(meta) => Ok(Some(read_deletion_vector_sync(&self.operator, meta)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+fn deserialize_deletion_vector(data: &[u8]) -> Result<RoaringBitmap> {
+    RoaringBitmap::deserialize_from(data)
+        .map_err(|e| ErrorCode::StorageOther(format!("failed to deserialize deletion vector: {e}")))
+}
+
+/// Removes the deleted rows from the whole block data.
+pub fn filter_deleted_rows(block: DataBlock, deleted: Option<&RoaringBitmap>) -> Result<DataBlock> {
+    match deleted {
+        Some(deleted) if !deleted.is_empty() => {
+            let filter = live_rows_filter(deleted, 0, block.num_rows());
+            block.filter_with_bitmap(&filter)
+        }
+        _ => Ok(block),
+    }
+}
+
+/// Builds the filter of the live rows in `[offset, offset + num_rows)` of a block.
+pub fn live_rows_filter(deleted: &RoaringBitmap, offset: usize, num_rows: usize) -> Bitmap {
+    let mut bitmap = MutableBitmap::from_len_set(num_rows);
+    let start = offset as u32;
+    let end = (offset + num_rows) as u32;
+    let rows = deleted
+        .iter()
+        .skip_while(|row| *row < start)
+        .take_while(|row| *row < end);
+    for row in rows {
+        bitmap.set(row as usize - offset, false);
+    }
+    bitmap.into()
+}
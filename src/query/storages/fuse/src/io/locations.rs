@@ -29,7 +29,9 @@ use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
+use crate::io::DELETION_VECTOR_VERSION;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
@@ -96,6 +98,17 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let dv_uuid = Uuid::new_v4().simple().to_string();
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix, FUSE_TBL_DELETION_VECTOR_PREFIX, dv_uuid, DELETION_VECTOR_VERSION,
+            ),
+            DELETION_VECTOR_VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
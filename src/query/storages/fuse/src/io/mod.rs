@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod deletion_vector;
 mod files;
 mod locations;
 pub mod read;
@@ -19,8 +20,17 @@ mod segments;
 mod snapshots;
 mod write;
 
+pub use deletion_vector::filter_deleted_rows;
+pub use deletion_vector::live_rows_filter;
+pub use deletion_vector::read_block_deletion_vector;
+pub use deletion_vector::read_deletion_vector;
+pub use deletion_vector::read_deletion_vector_sync;
+pub use deletion_vector::write_deletion_vector;
+pub use deletion_vector::DELETION_VECTOR_VERSION;
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
+pub use read::evolve_column;
+pub use read::is_metadata_only_evolution;
 pub use read::AggIndexReader;
 pub use read::BlockReader;
 pub use read::BloomBlockFilterReader;
@@ -35,6 +35,8 @@ use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
 use opendal::Operator;
 
+use super::EvolvedColumnNode;
+
 // TODO: make BlockReader as a trait.
 #[derive(Clone)]
 pub struct BlockReader {
@@ -46,6 +48,9 @@ pub struct BlockReader {
     pub(crate) project_column_nodes: Vec<ColumnNode>,
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
+    // previous shapes of the projected columns, used to read blocks written before the
+    // columns evolved.
+    pub(crate) evolved_column_nodes: Vec<Vec<EvolvedColumnNode>>,
     pub query_internal_columns: bool,
     // used for mutation to update stream columns.
     pub update_stream_columns: bool,
@@ -134,6 +139,7 @@ impl BlockReader {
             .map(|c| (*c).clone())
             .collect();
         let project_indices = Self::build_projection_indices(&project_column_nodes);
+        let evolved_column_nodes = Self::build_evolved_column_nodes(&schema, &projection)?;
 
         Ok(Arc::new(BlockReader {
             ctx,
@@ -144,6 +150,7 @@ impl BlockReader {
             project_column_nodes,
             parquet_schema_descriptor,
             default_vals,
+            evolved_column_nodes,
             query_internal_columns,
             update_stream_columns,
             put_cache,
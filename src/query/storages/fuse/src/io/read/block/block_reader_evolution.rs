@@ -0,0 +1,284 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading blocks written before a metadata-only type change of their columns.
+//!
+//! `ALTER TABLE ... MODIFY COLUMN` with a safe widening assigns the column new column ids
+//! and records its previous shapes in the table schema (see [`TableSchema::evolve_field`]).
+//! Blocks which do not contain the new column ids are read with the previous shape and
+//! cast to the current type here.
+
+use std::collections::BTreeMap;
+
+use common_arrow::arrow::io::parquet::write::to_parquet_schema;
+use common_arrow::parquet::metadata::SchemaDescriptor;
+use common_catalog::plan::Projection;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_storage::ColumnNode;
+use common_storage::ColumnNodes;
+
+use super::block_reader_deserialize::DeserializedArray;
+use super::block_reader_deserialize::FieldDeserializationContext;
+use crate::io::BlockReader;
+
+/// A previous shape of a projected column, which older blocks were written with.
+#[derive(Clone)]
+pub struct EvolvedColumnNode {
+    pub column_node: ColumnNode,
+    pub parquet_schema_descriptor: SchemaDescriptor,
+    /// The previous type of the whole field.
+    pub source_type: TableDataType,
+    /// The current type of the whole field.
+    pub target_type: TableDataType,
+    /// Path of the projected column inside the field, empty if the whole field is projected.
+    pub inner_path: Vec<FieldIndex>,
+}
+
+/// Returns true if the values of type `from` can be read as `to` without rewriting them:
+/// lossless number widening, decimal precision increase, making a column nullable,
+/// and adding nullable fields to or dropping fields from a tuple.
+pub fn is_metadata_only_evolution(from: &TableDataType, to: &TableDataType) -> bool {
+    if from == to {
+        return true;
+    }
+    match (from, to) {
+        (TableDataType::Nullable(from), TableDataType::Nullable(to)) => {
+            is_metadata_only_evolution(from, to)
+        }
+        (from, TableDataType::Nullable(to)) => is_metadata_only_evolution(from, to),
+        (TableDataType::Number(from), TableDataType::Number(to)) => from.can_lossless_cast_to(*to),
+        (TableDataType::Decimal(from), TableDataType::Decimal(to)) => {
+            from.scale() == to.scale() && from.precision() <= to.precision()
+        }
+        (TableDataType::Array(from), TableDataType::Array(to)) => {
+            is_metadata_only_evolution(from, to)
+        }
+        (
+            TableDataType::Tuple {
+                fields_name: from_names,
+                fields_type: from_types,
+            },
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            },
+        ) => fields_name.iter().zip(fields_type).all(|(name, ty)| {
+            match from_names.iter().position(|n| n == name) {
+                Some(i) => is_metadata_only_evolution(&from_types[i], ty),
+                None => ty.is_nullable(),
+            }
+        }),
+        _ => false,
+    }
+}
+
+/// Cast a column of type `from` to `to`, where `from` can be evolved to `to`
+/// (see [`is_metadata_only_evolution`]). Tuple fields are matched by name.
+pub fn evolve_column(
+    column: Column,
+    from: &TableDataType,
+    to: &TableDataType,
+    func_ctx: &FunctionContext,
+) -> Result<Column> {
+    if from == to {
+        return Ok(column);
+    }
+    match (from, to, column) {
+        (TableDataType::Nullable(from), TableDataType::Nullable(to), Column::Nullable(c)) => {
+            let column = evolve_column(c.column, from, to, func_ctx)?;
+            Ok(column.wrap_nullable(Some(c.validity)))
+        }
+        (from, TableDataType::Nullable(to), column) => {
+            let column = evolve_column(column, from, to, func_ctx)?;
+            Ok(column.wrap_nullable(None))
+        }
+        (TableDataType::Array(from), TableDataType::Array(to), Column::Array(c)) => {
+            let mut c = *c;
+            c.values = evolve_column(c.values, from, to, func_ctx)?;
+            Ok(Column::Array(Box::new(c)))
+        }
+        (
+            TableDataType::Tuple {
+                fields_name: from_names,
+                fields_type: from_types,
+            },
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            },
+            Column::Tuple(columns),
+        ) => {
+            let num_rows = columns.first().map_or(0, |c| c.len());
+            let columns = fields_name
+                .iter()
+                .zip(fields_type)
+                .map(
+                    |(name, ty)| match from_names.iter().position(|n| n == name) {
+                        Some(i) => evolve_column(columns[i].clone(), &from_types[i], ty, func_ctx),
+                        None => Ok(ColumnBuilder::repeat(
+                            &Scalar::Null.as_ref(),
+                            num_rows,
+                            &DataType::from(ty),
+                        )
+                        .build()),
+                    },
+                )
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Column::Tuple(columns))
+        }
+        (from, to, column) => {
+            let num_rows = column.len();
+            let data_type = DataType::from(from);
+            let dest_type = DataType::from(to);
+            let block = DataBlock::new(
+                vec![BlockEntry::new(data_type.clone(), Value::Column(column))],
+                num_rows,
+            );
+            let expr = Expr::Cast {
+                span: None,
+                is_try: false,
+                expr: Box::new(Expr::ColumnRef {
+                    span: None,
+                    id: 0,
+                    data_type,
+                    display_name: String::new(),
+                }),
+                dest_type: dest_type.clone(),
+            };
+            let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+            let value = evaluator.run(&expr)?;
+            Ok(value.convert_to_full_column(&dest_type, num_rows))
+        }
+    }
+}
+
+// Extract the projected inner column from the column of the whole tuple field.
+fn inner_column(mut column: Column, path: &[FieldIndex]) -> Result<Column> {
+    for index in path {
+        if let Column::Nullable(c) = column {
+            column = c.column;
+        }
+        column = match column {
+            Column::Tuple(mut columns) => columns.swap_remove(*index),
+            _ => {
+                return Err(ErrorCode::StorageOther(
+                    "unexpected evolved column, inner column of non-tuple column",
+                ));
+            }
+        };
+    }
+    Ok(column)
+}
+
+impl BlockReader {
+    pub(crate) fn build_evolved_column_nodes(
+        schema: &TableSchema,
+        projection: &Projection,
+    ) -> Result<Vec<Vec<EvolvedColumnNode>>> {
+        let paths: Vec<Vec<FieldIndex>> = match projection {
+            Projection::Columns(indices) => indices.iter().map(|i| vec![*i]).collect(),
+            Projection::InnerColumns(path_indices) => path_indices.values().cloned().collect(),
+        };
+        if !schema.has_evolved_fields() {
+            return Ok(vec![vec![]; paths.len()]);
+        }
+
+        let mut evolved_column_nodes = Vec::with_capacity(paths.len());
+        for path in paths {
+            let field = &schema.fields()[path[0]];
+            let mut nodes = vec![];
+            for previous_field in schema.previous_fields_of(field.column_id()) {
+                let next_column_id = previous_field.column_ids().into_iter().max().unwrap_or(0) + 1;
+                let previous_schema = TableSchema::new_from_column_ids(
+                    vec![previous_field.clone()],
+                    BTreeMap::new(),
+                    next_column_id,
+                );
+                let arrow_schema = previous_schema.to_arrow();
+                let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
+                let mut column_nodes =
+                    ColumnNodes::new_from_schema(&arrow_schema, Some(&previous_schema));
+                nodes.push(EvolvedColumnNode {
+                    column_node: column_nodes.column_nodes.remove(0),
+                    parquet_schema_descriptor,
+                    source_type: previous_field.data_type().clone(),
+                    target_type: field.data_type().clone(),
+                    inner_path: path[1..].to_vec(),
+                });
+            }
+            evolved_column_nodes.push(nodes);
+        }
+        Ok(evolved_column_nodes)
+    }
+
+    /// Leaf column ids of the previous shapes of the projected columns.
+    pub(crate) fn evolved_column_ids(&self) -> impl Iterator<Item = &ColumnId> {
+        self.evolved_column_nodes
+            .iter()
+            .flatten()
+            .flat_map(|node| node.column_node.leaf_column_ids.iter())
+    }
+
+    /// Deserialize the projected column at `index` from a previous shape of its field,
+    /// and cast it to the current type.
+    pub(super) fn deserialize_evolved_field(
+        &self,
+        deserialization_context: &FieldDeserializationContext,
+        index: usize,
+    ) -> Result<Option<Column>> {
+        for evolved in &self.evolved_column_nodes[index] {
+            let parquet_schema_descriptor = Some(evolved.parquet_schema_descriptor.clone());
+            let context = FieldDeserializationContext {
+                column_metas: deserialization_context.column_metas,
+                column_chunks: deserialization_context.column_chunks,
+                num_rows: deserialization_context.num_rows,
+                compression: deserialization_context.compression,
+                uncompressed_buffer: deserialization_context.uncompressed_buffer,
+                parquet_schema_descriptor: &parquet_schema_descriptor,
+            };
+            let array = match self.deserialize_field(&context, &evolved.column_node)? {
+                None => continue,
+                Some(DeserializedArray::Cached(sized_column)) => sized_column.0.clone(),
+                Some(DeserializedArray::Deserialized((_, array, _))) => array,
+                Some(DeserializedArray::NoNeedToCache(array)) => array,
+            };
+
+            let column = Column::from_arrow(array.as_ref(), &DataType::from(&evolved.source_type));
+            let column = evolve_column(
+                column,
+                &evolved.source_type,
+                &evolved.target_type,
+                &self.ctx.get_function_context()?,
+            )?;
+            return inner_column(column, &evolved.inner_path).map(Some);
+        }
+        Ok(None)
+    }
+}
@@ -105,7 +105,11 @@ impl BlockReader {
 
         let mut ranges = vec![];
         let mut cached_column_array = vec![];
-        for (_index, (column_id, ..)) in self.project_indices.iter() {
+        let column_ids = self
+            .project_indices
+            .values()
+            .map(|(column_id, ..)| column_id);
+        for column_id in column_ids.chain(self.evolved_column_ids()) {
             if let Some(ignore_column_ids) = ignore_column_ids {
                 if ignore_column_ids.contains(column_id) {
                     continue;
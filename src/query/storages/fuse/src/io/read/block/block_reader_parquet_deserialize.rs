@@ -29,8 +29,10 @@ use common_arrow::parquet::read::PageMetaData;
 use common_arrow::parquet::read::PageReader;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::BlockEntry;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
+use common_expression::Value;
 use common_metrics::storage::*;
 use common_storage::ColumnNode;
 use storages_common_cache::CacheAccessor;
@@ -110,9 +112,17 @@ impl BlockReader {
             uncompressed_buffer: &uncompressed_buffer,
             parquet_schema_descriptor: &None::<SchemaDescriptor>,
         };
-        for column_node in &self.project_column_nodes {
+        let mut evolved_columns = vec![];
+        for (index, column_node) in self.project_column_nodes.iter().enumerate() {
             match self.deserialize_field(&field_deserialization_ctx, column_node)? {
                 None => {
+                    // the block may be written before the column evolved,
+                    // fill the default value first and replace it later.
+                    if let Some(column) =
+                        self.deserialize_evolved_field(&field_deserialization_ctx, index)?
+                    {
+                        evolved_columns.push((index, column));
+                    }
                     need_to_fill_default_val = true;
                     need_default_vals.push(true);
                 }
@@ -141,7 +151,7 @@ impl BlockReader {
 
         // build data block
         let chunk = Chunk::try_new(chunk_arrays)?;
-        let data_block = if !need_to_fill_default_val {
+        let mut data_block = if !need_to_fill_default_val {
             DataBlock::from_arrow_chunk(&chunk, &self.data_schema())?
         } else {
             let data_schema = self.data_schema();
@@ -161,6 +171,15 @@ impl BlockReader {
             )?
         };
 
+        if !evolved_columns.is_empty() {
+            let mut columns = data_block.columns().to_vec();
+            for (index, column) in evolved_columns {
+                let data_type = columns[index].data_type.clone();
+                columns[index] = BlockEntry::new(data_type, Value::Column(column));
+            }
+            data_block = DataBlock::new(columns, num_rows);
+        }
+
         // populate cache if necessary
         if self.put_cache {
             if let Some(cache) = CacheManager::instance().get_table_data_array_cache() {
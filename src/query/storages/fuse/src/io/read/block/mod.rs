@@ -14,6 +14,7 @@
 
 mod block_reader;
 mod block_reader_deserialize;
+mod block_reader_evolution;
 mod block_reader_merge_io;
 mod block_reader_merge_io_async;
 mod block_reader_merge_io_sync;
@@ -25,6 +26,9 @@ mod decompressor;
 pub use block_reader::BlockReader;
 pub use block_reader_deserialize::DeserializedArray;
 pub use block_reader_deserialize::FieldDeserializationContext;
+pub use block_reader_evolution::evolve_column;
+pub use block_reader_evolution::is_metadata_only_evolution;
+pub use block_reader_evolution::EvolvedColumnNode;
 pub use block_reader_merge_io::MergeIOReadResult;
 pub use block_reader_native::NativeReaderExt;
 pub use block_reader_native::NativeSourceData;
@@ -22,6 +22,8 @@ mod utils;
 mod virtual_column;
 
 pub use agg_index::AggIndexReader;
+pub use block::evolve_column;
+pub use block::is_metadata_only_evolution;
 pub use block::BlockReader;
 pub use block::MergeIOReadResult;
 pub use block::NativeReaderExt;
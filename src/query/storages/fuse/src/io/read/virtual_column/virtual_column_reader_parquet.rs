@@ -96,6 +96,7 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
             let merge_io_result =
@@ -138,6 +139,7 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
             let merge_io_result = BlockReader::merge_io_read(
@@ -19,6 +19,8 @@ use std::sync::Arc;
 use chrono::Utc;
 use common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use common_arrow::native::write::NativeWriter;
+use common_arrow::native::write::WriteOptions as NativeWriteOptions;
+use common_arrow::native::Compression as NativeCompression;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::ColumnId;
@@ -35,6 +37,8 @@ use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::table::ColumnEncoding;
+use storages_common_table_meta::table::ColumnStorageOptions;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::io::write::WriteSettings;
@@ -63,21 +67,32 @@ pub fn serialize_block(
             let arrow_schema = schema.to_arrow();
             let leaf_column_ids = schema.to_leaf_column_ids();
 
-            let mut default_compress_ratio = Some(2.10f64);
-            if matches!(write_settings.table_compression, TableCompression::Zstd) {
-                default_compress_ratio = Some(3.72f64);
+            let write_options = NativeWriteOptions {
+                default_compression: write_settings.table_compression.into(),
+                max_page_size: Some(write_settings.max_page_size),
+                default_compress_ratio: default_compress_ratio(write_settings.table_compression),
+                forbidden_compressions: vec![],
+            };
+
+            // the leaf columns of the fields with storage options are written with their own options.
+            let mut column_options = HashMap::new();
+            let mut leaf_index = 0;
+            for field in schema.fields() {
+                let num_leaves = field.leaf_column_ids().len();
+                let options = ColumnStorageOptions::try_from_field_options(
+                    &schema.field_options(field.column_id()),
+                )?;
+                if options != ColumnStorageOptions::default() {
+                    let options = native_column_write_options(&write_options, &options);
+                    for i in leaf_index..leaf_index + num_leaves {
+                        column_options.insert(i, options.clone());
+                    }
+                }
+                leaf_index += num_leaves;
             }
 
-            let mut writer = NativeWriter::new(
-                buf,
-                arrow_schema,
-                common_arrow::native::write::WriteOptions {
-                    default_compression: write_settings.table_compression.into(),
-                    max_page_size: Some(write_settings.max_page_size),
-                    default_compress_ratio,
-                    forbidden_compressions: vec![],
-                },
-            );
+            let mut writer = NativeWriter::new(buf, arrow_schema, write_options)
+                .with_column_options(column_options);
 
             let batch = ArrowChunk::try_from(block)?;
 
@@ -97,6 +112,58 @@ pub fn serialize_block(
     }
 }
 
+fn default_compress_ratio(compression: TableCompression) -> Option<f64> {
+    match compression {
+        TableCompression::Zstd => Some(3.72f64),
+        _ => Some(2.10f64),
+    }
+}
+
+/// The native write options of a column with storage options.
+///
+/// An encoding other than `auto` is forced by forbidding all the others, so a column whose
+/// type the encoding does not apply to is written plain. Compression levels are rejected
+/// when the column is defined, see [`ColumnStorageOptions::check_storage_format`].
+fn native_column_write_options(
+    table_options: &NativeWriteOptions,
+    column_options: &ColumnStorageOptions,
+) -> NativeWriteOptions {
+    let mut write_options = table_options.clone();
+    if let Some(compression) = column_options.compression {
+        write_options.default_compression = compression.codec.into();
+        write_options.default_compress_ratio = default_compress_ratio(compression.codec);
+    }
+
+    let encoding = match column_options.encoding {
+        ColumnEncoding::Auto => return write_options,
+        ColumnEncoding::Plain => None,
+        ColumnEncoding::Dict => Some(NativeCompression::Dict),
+        ColumnEncoding::Rle => Some(NativeCompression::Rle),
+        ColumnEncoding::Delta => Some(NativeCompression::DeltaBitpacking),
+        ColumnEncoding::Bitpacking => Some(NativeCompression::Bitpacking),
+    };
+    match encoding {
+        None => write_options.default_compress_ratio = None,
+        Some(encoding) => {
+            // any ratio beats the basic compression.
+            write_options.default_compress_ratio = Some(0.0);
+            write_options.forbidden_compressions = [
+                NativeCompression::Rle,
+                NativeCompression::Dict,
+                NativeCompression::OneValue,
+                NativeCompression::Freq,
+                NativeCompression::Bitpacking,
+                NativeCompression::DeltaBitpacking,
+                NativeCompression::Patas,
+            ]
+            .into_iter()
+            .filter(|c| *c != encoding)
+            .collect();
+        }
+    }
+    write_options
+}
+
 /// Take ownership here to avoid extra copy.
 #[async_backtrace::framed]
 pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str) -> Result<()> {
@@ -213,6 +280,7 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
             create_on: Some(Utc::now()),
+            deletion_vector: None,
         };
 
         let serialized = BlockSerialization {
@@ -37,6 +37,7 @@ use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::executor::physical_plans::MutationKind;
 
 use crate::operations::common::TransformSerializeBlock;
+use crate::operations::TransformPartitionBlock;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
@@ -74,6 +75,17 @@ impl FuseTable {
 
         let cluster_stats_gen =
             self.cluster_gen_for_append(ctx.clone(), pipeline, block_thresholds, None)?;
+        if let Some(partition_expr) = self.partition_expr(ctx.clone())? {
+            let func_ctx = ctx.get_function_context()?;
+            pipeline.add_transform(|input, output| {
+                Ok(TransformPartitionBlock::create(
+                    input,
+                    output,
+                    func_ctx.clone(),
+                    partition_expr.clone(),
+                ))
+            })?;
+        }
         pipeline.add_transform(|input, output| {
             let proc = TransformSerializeBlock::try_create(
                 ctx.clone(),
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::BlockThresholds;
+use storages_common_table_meta::meta::CompactSegmentInfo;
+
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+use crate::DEFAULT_AUTO_COMPACTION_INTERVAL;
+use crate::DEFAULT_AUTO_COMPACTION_SMALL_BLOCK_RATIO;
+use crate::FUSE_OPT_KEY_AUTO_COMPACTION;
+use crate::FUSE_OPT_KEY_AUTO_COMPACTION_INTERVAL;
+use crate::FUSE_OPT_KEY_AUTO_COMPACTION_SMALL_BLOCK_RATIO;
+use crate::FUSE_OPT_KEY_AUTO_COMPACTION_TARGET_BLOCK_SIZE;
+
+/// The auto compaction policy of a table, set by the `auto_compaction*` table options.
+#[derive(Clone, Debug)]
+pub struct AutoCompactionPolicy {
+    /// Blocks smaller than this (uncompressed bytes) are small blocks, and are merged
+    /// up to this size by the compaction. If not set, the block thresholds of the table are used.
+    pub target_block_size: Option<u64>,
+    /// The table is compacted once the ratio of small blocks exceeds this.
+    pub max_small_block_ratio: f64,
+    /// The minimal interval between two checks of the table.
+    pub interval: Duration,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SmallBlockStats {
+    pub block_count: u64,
+    pub small_block_count: u64,
+}
+
+impl SmallBlockStats {
+    pub fn ratio(&self) -> f64 {
+        if self.block_count == 0 {
+            0.0
+        } else {
+            self.small_block_count as f64 / self.block_count as f64
+        }
+    }
+}
+
+impl FuseTable {
+    /// Returns the auto compaction policy, or None if auto compaction is not enabled.
+    pub fn auto_compaction_policy(&self) -> Option<AutoCompactionPolicy> {
+        if !self.get_option(FUSE_OPT_KEY_AUTO_COMPACTION, false) {
+            return None;
+        }
+
+        let target_block_size = self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_AUTO_COMPACTION_TARGET_BLOCK_SIZE)
+            .and_then(|v| v.parse::<u64>().ok());
+        Some(AutoCompactionPolicy {
+            target_block_size,
+            max_small_block_ratio: self.get_option(
+                FUSE_OPT_KEY_AUTO_COMPACTION_SMALL_BLOCK_RATIO,
+                DEFAULT_AUTO_COMPACTION_SMALL_BLOCK_RATIO,
+            ),
+            interval: Duration::from_secs(self.get_option(
+                FUSE_OPT_KEY_AUTO_COMPACTION_INTERVAL,
+                DEFAULT_AUTO_COMPACTION_INTERVAL,
+            )),
+        })
+    }
+
+    /// The block thresholds used to select and merge the blocks to compact.
+    ///
+    /// The `auto_compaction_target_block_size` option, if set, replaces the bytes
+    /// threshold of the table.
+    pub fn compact_block_thresholds(&self) -> BlockThresholds {
+        let mut thresholds = self.get_block_thresholds();
+        if let Some(target) = self
+            .auto_compaction_policy()
+            .and_then(|policy| policy.target_block_size)
+        {
+            thresholds.max_bytes_per_block = target as usize;
+        }
+        thresholds
+    }
+
+    /// Counts the blocks of the current snapshot, and how many of them are small.
+    #[async_backtrace::framed]
+    pub async fn small_block_stats(&self, ctx: Arc<dyn TableContext>) -> Result<SmallBlockStats> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(SmallBlockStats::default()),
+        };
+
+        let thresholds = self.compact_block_thresholds();
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let segments_io = SegmentsIO::create(ctx, self.get_operator(), self.schema());
+        let mut stats = SmallBlockStats::default();
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            for segment in segments_io
+                .read_segments::<Arc<CompactSegmentInfo>>(chunk, false)
+                .await?
+            {
+                for block in segment?.block_metas()? {
+                    let small = !thresholds
+                        .check_large_enough(block.row_count as usize, block.block_size as usize);
+                    stats.block_count += 1;
+                    stats.small_block_count += small as u64;
+                }
+            }
+        }
+        Ok(stats)
+    }
+}
@@ -0,0 +1,406 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::Duration;
+use chrono::Utc;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use log::info;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_CLONES;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::gc::LocationTuple;
+use crate::FuseTable;
+use crate::FUSE_TBL_SNAPSHOT_PREFIX;
+
+/// Files that must survive a purge of a table, although none of the retained
+/// snapshots of the table references them.
+pub struct SharedLocations {
+    /// Storage prefix of the table being purged. Files outside of it were
+    /// inherited from a clone source, and are owned by that table.
+    prefix: String,
+    /// Snapshots (and their statistics) kept by tags.
+    snapshots: HashSet<String>,
+    segments: HashSet<String>,
+    locations: LocationTuple,
+}
+
+impl SharedLocations {
+    pub fn is_shared_snapshot(&self, location: &str) -> bool {
+        self.snapshots.contains(location)
+    }
+
+    pub fn is_shared_segment(&self, location: &str) -> bool {
+        !location.starts_with(&self.prefix) || self.segments.contains(location)
+    }
+
+    pub fn is_shared_block(&self, location: &str) -> bool {
+        !location.starts_with(&self.prefix) || self.locations.block_location.contains(location)
+    }
+
+    pub fn is_shared_bloom(&self, location: &str) -> bool {
+        !location.starts_with(&self.prefix) || self.locations.bloom_location.contains(location)
+    }
+
+    pub fn segments(&self) -> &HashSet<String> {
+        &self.segments
+    }
+
+    pub fn blocks(&self) -> &HashSet<String> {
+        &self.locations.block_location
+    }
+
+    pub fn blooms(&self) -> &HashSet<String> {
+        &self.locations.bloom_location
+    }
+}
+
+impl FuseTable {
+    /// Ids of the tables cloned from this table.
+    pub fn clone_ids(&self) -> Vec<u64> {
+        parse_clones(self.table_info.options().get(OPT_KEY_CLONES))
+            .into_iter()
+            .map(|entry| entry.table_id)
+            .collect()
+    }
+
+    /// Make `clone`, a freshly created table, share the data of `point`, which is
+    /// this table at the point being cloned.
+    ///
+    /// No block is copied: the first snapshot of the clone references the segments
+    /// of the snapshot of `point`. The clone is registered in the options of this
+    /// table before that, along with the snapshot being cloned, so that the gc of
+    /// this table keeps the shared files from then on.
+    #[async_backtrace::framed]
+    pub async fn do_clone(
+        &self,
+        ctx: &dyn TableContext,
+        point: &FuseTable,
+        clone: &FuseTable,
+    ) -> Result<()> {
+        let snapshot_location = point.snapshot_loc().await?;
+        self.update_clones(ctx, |clones| {
+            clones.push(CloneEntry {
+                table_id: clone.get_id(),
+                pinned_snapshot: snapshot_location.clone(),
+            })
+        })
+        .await?;
+
+        let snapshot = match point.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+        let mut new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            snapshot.schema.clone(),
+            snapshot.summary.clone(),
+            snapshot.segments.clone(),
+            snapshot.cluster_key_meta.clone(),
+            None,
+        );
+        new_snapshot.partitions = snapshot.partitions.clone();
+        FuseTable::commit_to_meta_server(
+            ctx,
+            &clone.table_info,
+            &clone.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &clone.operator,
+        )
+        .await
+    }
+
+    /// Remove the clones in `table_ids` from the options of this table.
+    #[async_backtrace::framed]
+    pub async fn unregister_clones(&self, ctx: &dyn TableContext, table_ids: &[u64]) -> Result<()> {
+        self.update_clones(ctx, |clones| {
+            clones.retain(|entry| !table_ids.contains(&entry.table_id))
+        })
+        .await
+    }
+
+    /// Update the clones kept in the options of the latest version of this table.
+    #[async_backtrace::framed]
+    async fn update_clones<F>(&self, ctx: &dyn TableContext, f: F) -> Result<()>
+    where F: Fn(&mut Vec<CloneEntry>) {
+        self.update_table_options(ctx, |options| {
+            let mut clones = parse_clones(options.get(OPT_KEY_CLONES));
+            f(&mut clones);
+            if clones.is_empty() {
+                options.remove(OPT_KEY_CLONES);
+            } else {
+                options.insert(OPT_KEY_CLONES.to_owned(), format_clones(&clones));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Whether any clone of this table is still kept in the meta, and so may
+    /// reference the files of this table.
+    ///
+    /// The clones whose meta has been removed are unregistered from this table.
+    #[async_backtrace::framed]
+    pub async fn has_clones(&self, ctx: &dyn TableContext) -> Result<bool> {
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let mut removed = vec![];
+        let mut has_clones = false;
+        for table_id in self.clone_ids() {
+            match catalog.get_table_meta_by_id(table_id).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => removed.push(table_id),
+                Err(e) => return Err(e),
+                Ok(_) => has_clones = true,
+            }
+        }
+        if !removed.is_empty() {
+            self.unregister_clones(ctx, &removed).await?;
+        }
+        Ok(has_clones)
+    }
+
+    /// Collect the files of this table that are still referenced by the snapshots
+    /// of its clones, and of the clones of them, or by the snapshots of its tags
+    /// that have not expired.
+    ///
+    /// The snapshots of a clone are the ones its own gc keeps: the current one and
+    /// the ones within the retention period before it. A clone that has not committed
+    /// its first snapshot yet keeps the snapshot of this table it is cloned from.
+    ///
+    /// A clone counts as long as its meta is kept, even if it has been dropped, so that
+    /// an undropped clone is still readable. The clones whose meta has been removed are
+    /// unregistered from this table.
+    #[async_backtrace::framed]
+    pub async fn read_shared_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<SharedLocations> {
+        let mut shared = SharedLocations {
+            prefix: format!("{}/", self.meta_location_generator.prefix()),
+            snapshots: HashSet::new(),
+            segments: HashSet::new(),
+            locations: LocationTuple::default(),
+        };
+
+        let retention = Duration::hours(ctx.get_settings().get_retention_period()? as i64);
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let mut removed = vec![];
+        let mut visited = HashSet::new();
+        let mut pending =
+            VecDeque::from(parse_clones(self.table_info.options().get(OPT_KEY_CLONES)));
+        while let Some(entry) = pending.pop_front() {
+            if !visited.insert(entry.table_id) {
+                continue;
+            }
+            let meta = match catalog.get_table_meta_by_id(entry.table_id).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => {
+                    info!(
+                        "clone {} of table {} no longer exists",
+                        entry.table_id, self.table_info.desc
+                    );
+                    if self.clone_ids().contains(&entry.table_id) {
+                        removed.push(entry.table_id);
+                    }
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok((_, meta)) => meta,
+            };
+            pending.extend(parse_clones(meta.options.get(OPT_KEY_CLONES)));
+
+            let location = match meta.options.get(OPT_KEY_SNAPSHOT_LOCATION) {
+                Some(location) => location.clone(),
+                None => {
+                    if let Some(pinned) = &entry.pinned_snapshot {
+                        if let Some(snapshot) = self.read_snapshot_if_exists(pinned).await? {
+                            shared.snapshots.insert(pinned.clone());
+                            if let Some(ts) = &snapshot.table_statistics_location {
+                                shared.snapshots.insert(ts.clone());
+                            }
+                            self.extend_shared_locations(ctx, &mut shared, &snapshot)
+                                .await?;
+                        }
+                    }
+                    continue;
+                }
+            };
+
+            // segments of the clone may be compacted into its own prefix, while
+            // still referencing the blocks of this table, hence read all of them.
+            for snapshot in self.read_retained_snapshots(location, retention).await? {
+                self.extend_shared_locations(ctx, &mut shared, &snapshot)
+                    .await?;
+            }
+        }
+        if !removed.is_empty() {
+            self.unregister_clones(ctx.as_ref(), &removed).await?;
+        }
+
+        let now = Utc::now();
+        for tag in self.list_tags().await? {
+            if tag.is_expired(now) {
+                continue;
+            }
+            let snapshot = match self.read_tagged_snapshot(&tag).await? {
+                Some(snapshot) => snapshot,
+                None => continue,
+            };
+            shared.snapshots.insert(tag.snapshot_location.clone());
+            if let Some(ts) = &snapshot.table_statistics_location {
+                shared.snapshots.insert(ts.clone());
+            }
+            self.extend_shared_locations(ctx, &mut shared, &snapshot)
+                .await?;
+        }
+        Ok(shared)
+    }
+
+    #[async_backtrace::framed]
+    async fn read_snapshot_if_exists(&self, location: &str) -> Result<Option<Arc<TableSnapshot>>> {
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            ver: TableMetaLocationGenerator::snapshot_version(location),
+            location: location.to_string(),
+            len_hint: None,
+            put_cache: false,
+        };
+        match reader.read(&params).await {
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => Ok(None),
+            Err(e) => Err(e),
+            Ok(snapshot) => Ok(Some(snapshot)),
+        }
+    }
+
+    /// Read the snapshot at `location` of a clone, and the previous ones that
+    /// are within `retention` before it.
+    #[async_backtrace::framed]
+    async fn read_retained_snapshots(
+        &self,
+        location: String,
+        retention: Duration,
+    ) -> Result<Vec<Arc<TableSnapshot>>> {
+        // the snapshots of a clone are kept under the prefix of the clone.
+        let prefix = match location.rsplit_once(&format!("/{}/", FUSE_TBL_SNAPSHOT_PREFIX)) {
+            Some((prefix, _)) => prefix.to_string(),
+            None => return Ok(vec![]),
+        };
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let version = TableMetaLocationGenerator::snapshot_version(&location);
+        let mut snapshot_stream = reader.snapshot_history(
+            location,
+            version,
+            TableMetaLocationGenerator::with_prefix(prefix),
+        );
+
+        let mut snapshots = vec![];
+        let mut retention_point = None;
+        while let Some((snapshot, _)) = snapshot_stream.try_next().await? {
+            let timestamp = snapshot.timestamp;
+            if let (Some(point), Some(timestamp)) = (retention_point, timestamp) {
+                if timestamp < point {
+                    break;
+                }
+            }
+            if retention_point.is_none() {
+                retention_point = timestamp.map(|t| t - retention);
+            }
+            snapshots.push(snapshot);
+        }
+        Ok(snapshots)
+    }
+
+    #[async_backtrace::framed]
+    async fn extend_shared_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        shared: &mut SharedLocations,
+        snapshot: &TableSnapshot,
+    ) -> Result<()> {
+        let locations = self
+            .get_block_locations(ctx.clone(), &snapshot.segments, false, true)
+            .await?;
+        shared.segments.extend(
+            snapshot
+                .segments
+                .iter()
+                .map(|(location, _)| location.clone()),
+        );
+        shared
+            .locations
+            .block_location
+            .extend(locations.block_location);
+        shared
+            .locations
+            .bloom_location
+            .extend(locations.bloom_location);
+        Ok(())
+    }
+}
+
+/// A clone kept in [`OPT_KEY_CLONES`] of the table it is cloned from.
+///
+/// It is formatted as `<table_id>` or, if it is cloned from a snapshot,
+/// `<table_id>:<snapshot_location>`.
+struct CloneEntry {
+    table_id: u64,
+    /// The snapshot being cloned, kept until the clone commits its first snapshot.
+    pinned_snapshot: Option<String>,
+}
+
+fn parse_clones(value: Option<&String>) -> Vec<CloneEntry> {
+    match value {
+        Some(value) => value
+            .split(',')
+            .filter_map(|entry| {
+                let (id, pinned_snapshot) = match entry.trim().split_once(':') {
+                    Some((id, location)) => (id, Some(location.to_string())),
+                    None => (entry.trim(), None),
+                };
+                let table_id = id.parse::<u64>().ok()?;
+                Some(CloneEntry {
+                    table_id,
+                    pinned_snapshot,
+                })
+            })
+            .collect(),
+        None => vec![],
+    }
+}
+
+fn format_clones(clones: &[CloneEntry]) -> String {
+    clones
+        .iter()
+        .map(|entry| match &entry.pinned_snapshot {
+            Some(location) => format!("{}:{}", entry.table_id, location),
+            None => entry.table_id.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -42,6 +43,7 @@ use opendal::Operator;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::PartitionSummary;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::SnapshotId;
 use storages_common_table_meta::meta::Statistics;
@@ -60,6 +62,7 @@ use crate::operations::common::CommitSink;
 use crate::operations::common::ConflictResolveContext;
 use crate::operations::common::TableMutationAggregator;
 use crate::operations::common::TransformSerializeSegment;
+use crate::operations::merge_partitions;
 use crate::operations::set_backoff;
 use crate::statistics::merge_statistics;
 use crate::FuseTable;
@@ -86,8 +89,12 @@ impl FuseTable {
         })?;
 
         pipeline.add_transform(|input, output| {
-            let aggregator =
-                TableMutationAggregator::new(self, ctx.clone(), vec![], MutationKind::Insert);
+            let aggregator = TableMutationAggregator::try_create(
+                self,
+                ctx.clone(),
+                vec![],
+                MutationKind::Insert,
+            )?;
             Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
                 input, output, aggregator,
             )))
@@ -246,6 +253,45 @@ impl FuseTable {
         }
     }
 
+    /// Update the options of the latest version of this table with `f`.
+    ///
+    /// The update is committed with the seq of the version it is applied to, and
+    /// is applied again to the newer version if the table is changed concurrently,
+    /// thus the checks made by `f` hold for the committed options.
+    #[async_backtrace::framed]
+    pub async fn update_table_options<F>(&self, ctx: &dyn TableContext, f: F) -> Result<()>
+    where F: Fn(&mut BTreeMap<String, String>) -> Result<()> {
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let mut backoff = set_backoff(None, None, None);
+        let mut latest = self.refresh(ctx).await?;
+        loop {
+            let table_info = latest.get_table_info();
+            let mut new_table_meta = table_info.meta.clone();
+            f(&mut new_table_meta.options)?;
+            let req = UpdateTableMetaReq {
+                table_id: table_info.ident.table_id,
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            };
+            match catalog.update_table_meta(table_info, req).await {
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                    match backoff.next_backoff() {
+                        Some(d) => {
+                            common_base::base::tokio::time::sleep(d).await;
+                            latest = latest.refresh(ctx).await?;
+                        }
+                        None => return Err(e),
+                    }
+                }
+                Err(e) => return Err(e),
+                Ok(_) => return Ok(()),
+            }
+        }
+    }
+
     // Left a hint file which indicates the location of the latest snapshot
     #[async_backtrace::framed]
     pub async fn write_last_snapshot_hint(
@@ -285,6 +331,7 @@ impl FuseTable {
         base_snapshot: Arc<TableSnapshot>,
         base_segments: &[Location],
         base_summary: Statistics,
+        base_partitions: BTreeMap<String, PartitionSummary>,
         abort_operation: AbortOperation,
         max_retry_elapsed: Option<Duration>,
     ) -> Result<()> {
@@ -321,6 +368,11 @@ impl FuseTable {
             .await?;
             snapshot_tobe_committed.segments = segments_tobe_committed;
             snapshot_tobe_committed.summary = statistics_tobe_committed;
+            snapshot_tobe_committed.partitions = Self::merge_partitions_with_base(
+                &base_partitions,
+                &latest_snapshot.partitions,
+                concurrently_appended_segment_locations,
+            );
 
             match Self::commit_to_meta_server(
                 ctx.as_ref(),
@@ -414,6 +466,37 @@ impl FuseTable {
         }
     }
 
+    // Adds the partitions of the concurrently appended segments to the base partitions.
+    fn merge_partitions_with_base(
+        base_partitions: &BTreeMap<String, PartitionSummary>,
+        latest_partitions: &BTreeMap<String, PartitionSummary>,
+        concurrently_appended_segment_locations: &[Location],
+    ) -> BTreeMap<String, PartitionSummary> {
+        let appended = concurrently_appended_segment_locations
+            .iter()
+            .collect::<HashSet<_>>();
+        let mut partitions = base_partitions.clone();
+        for (partition, summary) in latest_partitions {
+            let segments = summary
+                .segments
+                .iter()
+                .filter(|location| appended.contains(location))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !segments.is_empty() {
+                let appended = PartitionSummary {
+                    segments,
+                    col_stats: summary.col_stats.clone(),
+                };
+                merge_partitions(
+                    &mut partitions,
+                    &BTreeMap::from([(partition.clone(), appended)]),
+                );
+            }
+        }
+        partitions
+    }
+
     #[async_backtrace::framed]
     async fn merge_with_base(
         ctx: Arc<dyn TableContext>,
@@ -20,6 +20,7 @@ use opendal::Operator;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CacheManager;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 
 use crate::io::Files;
 
@@ -45,6 +46,10 @@ impl AbortOperation {
         }
     }
 
+    pub fn add_deletion_vector(&mut self, deletion_vector: &DeletionVectorMeta) {
+        self.blocks.push(deletion_vector.location.0.clone());
+    }
+
     pub fn add_segment(&mut self, segment: String) {
         self.segments.push(segment);
     }
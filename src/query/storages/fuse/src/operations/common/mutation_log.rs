@@ -21,12 +21,14 @@ use common_expression::BlockMetaInfoDowncast;
 use common_expression::DataBlock;
 use common_pipeline_transforms::processors::AccumulatingTransform;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 use storages_common_table_meta::meta::FormatVersion;
 use storages_common_table_meta::meta::Statistics;
 
 use crate::operations::common::AbortOperation;
 use crate::operations::common::ConflictResolveContext;
 use crate::operations::common::SnapshotChanges;
+use crate::operations::merge_partitions;
 use crate::operations::mutation::BlockIndex;
 use crate::operations::mutation::CompactExtraInfo;
 use crate::operations::mutation::DeletedSegmentInfo;
@@ -45,6 +47,7 @@ pub enum MutationLogEntry {
         format_version: FormatVersion,
         abort_operation: AbortOperation,
         summary: Statistics,
+        partition: Option<String>,
     },
     DeletedBlock {
         index: BlockMetaIndex,
@@ -59,6 +62,13 @@ pub enum MutationLogEntry {
     CompactExtras {
         extras: CompactExtraInfo,
     },
+    /// The rows of the block are marked as deleted by the deletion vector instead of
+    /// rewriting the block, the rows updated by UPDATE are kept in `appended_block`.
+    DeletionVector {
+        index: BlockMetaIndex,
+        deletion_vector: DeletionVectorMeta,
+        appended_block: Option<Arc<BlockMeta>>,
+    },
     DoNothing,
 }
 
@@ -157,6 +167,12 @@ fn merge_conflict_resolve_context(
                     &r.merged_statistics,
                     default_cluster_key_id,
                 ),
+                appended_partitions: {
+                    let mut partitions = l.appended_partitions;
+                    merge_partitions(&mut partitions, &r.appended_partitions);
+                    partitions
+                },
+                detach_partitions: l.detach_partitions || r.detach_partitions,
             })
         }
         _ => unreachable!(
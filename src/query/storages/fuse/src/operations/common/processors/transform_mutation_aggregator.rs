@@ -15,6 +15,7 @@
 use std::collections::hash_map::Entry;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -24,6 +25,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::BlockMetaInfoPtr;
 use common_expression::BlockThresholds;
+use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_expression::TableSchemaRef;
 use common_pipeline_transforms::processors::AsyncAccumulatingTransform;
@@ -34,7 +36,9 @@ use log::info;
 use log::warn;
 use opendal::Operator;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::PartitionSummary;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::Versioned;
@@ -42,6 +46,7 @@ use storages_common_table_meta::meta::Versioned;
 use crate::io::SegmentsIO;
 use crate::io::SerializedSegment;
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::add_partition_segment;
 use crate::operations::common::AbortOperation;
 use crate::operations::common::CommitMeta;
 use crate::operations::common::ConflictResolveContext;
@@ -67,6 +72,8 @@ pub struct TableMutationAggregator {
     mutations: HashMap<SegmentIndex, BlockMutations>,
     appended_segments: Vec<Location>,
     appended_statistics: Statistics,
+    appended_partitions: BTreeMap<String, PartitionSummary>,
+    partition_columns: HashSet<ColumnId>,
     removed_segment_indexes: Vec<SegmentIndex>,
     removed_statistics: Statistics,
     abort_operation: AbortOperation,
@@ -102,13 +109,14 @@ impl AsyncAccumulatingTransform for TableMutationAggregator {
 }
 
 impl TableMutationAggregator {
-    pub fn new(
+    pub fn try_create(
         table: &FuseTable,
         ctx: Arc<dyn TableContext>,
         base_segments: Vec<Location>,
         kind: MutationKind,
-    ) -> Self {
-        TableMutationAggregator {
+    ) -> Result<Self> {
+        Ok(TableMutationAggregator {
+            partition_columns: table.partition_column_ids(ctx.clone())?,
             ctx,
             schema: table.schema(),
             dal: table.get_operator(),
@@ -120,12 +128,13 @@ impl TableMutationAggregator {
             base_segments,
             abort_operation: AbortOperation::default(),
             appended_statistics: Statistics::default(),
+            appended_partitions: BTreeMap::new(),
             removed_segment_indexes: vec![],
             removed_statistics: Statistics::default(),
             kind,
             finished_tasks: 0,
             start_time: Instant::now(),
-        }
+        })
     }
 
     pub fn refresh_status(&mut self, task_num: usize) {
@@ -170,14 +179,39 @@ impl TableMutationAggregator {
                     self.default_cluster_key_id,
                 );
             }
+            MutationLogEntry::DeletionVector {
+                index,
+                deletion_vector,
+                appended_block,
+            } => {
+                self.abort_operation.add_deletion_vector(&deletion_vector);
+                if let Some(block_meta) = &appended_block {
+                    self.abort_operation.add_block(block_meta);
+                }
+                let mutations = self.mutations.entry(index.segment_idx).or_default();
+                mutations
+                    .deletion_vectors
+                    .push((index.block_idx, deletion_vector));
+                mutations.appended_blocks.extend(appended_block);
+            }
             MutationLogEntry::DoNothing => (),
             MutationLogEntry::AppendSegment {
                 segment_location,
                 format_version,
                 abort_operation,
                 summary,
+                partition,
             } => {
                 self.abort_operation.merge(abort_operation);
+                if let Some(partition) = partition {
+                    add_partition_segment(
+                        &mut self.appended_partitions,
+                        partition,
+                        (segment_location.clone(), format_version),
+                        &summary.col_stats,
+                        &self.partition_columns,
+                    );
+                }
                 merge_statistics_mut(
                     &mut self.appended_statistics,
                     &summary,
@@ -195,7 +229,7 @@ impl TableMutationAggregator {
                     Entry::Vacant(v) => {
                         v.insert(BlockMutations {
                             replaced_blocks: extras.unchanged_blocks,
-                            deleted_blocks: vec![],
+                            ..Default::default()
                         });
                     }
                 }
@@ -214,11 +248,13 @@ impl TableMutationAggregator {
     pub async fn apply(&mut self) -> Result<CommitMeta> {
         let appended_segments = std::mem::take(&mut self.appended_segments);
         let appended_statistics = std::mem::take(&mut self.appended_statistics);
+        let appended_partitions = std::mem::take(&mut self.appended_partitions);
         let conflict_resolve_context = match self.kind {
             MutationKind::Insert => ConflictResolveContext::AppendOnly((
                 SnapshotMerged {
                     merged_segments: appended_segments,
                     merged_statistics: appended_statistics,
+                    merged_partitions: appended_partitions,
                 },
                 self.schema.clone(),
             )),
@@ -284,6 +320,9 @@ impl TableMutationAggregator {
                     removed_segment_indexes: std::mem::take(&mut self.removed_segment_indexes),
                     merged_statistics,
                     removed_statistics: std::mem::take(&mut self.removed_statistics),
+                    appended_partitions,
+                    // the segments rewritten by merge into may take rows of any partition
+                    detach_partitions: matches!(self.kind, MutationKind::MergeInto),
                 })
             }
         };
@@ -326,6 +365,13 @@ impl TableMutationAggregator {
                     for idx in segment_mutation.deleted_blocks {
                         block_editor.remove(&idx);
                     }
+                    for (idx, deletion_vector) in segment_mutation.deletion_vectors {
+                        if let Some(meta) = block_editor.get_mut(&idx) {
+                            let mut new_meta = meta.as_ref().clone();
+                            new_meta.deletion_vector = Some(deletion_vector);
+                            *meta = Arc::new(new_meta);
+                        }
+                    }
 
                     if block_editor.is_empty() {
                         return Ok(SegmentLite {
@@ -336,7 +382,8 @@ impl TableMutationAggregator {
                     }
 
                     // assign back the mutated blocks to segment
-                    let new_blocks = block_editor.into_values().collect::<Vec<_>>();
+                    let mut new_blocks = block_editor.into_values().collect::<Vec<_>>();
+                    new_blocks.extend(segment_mutation.appended_blocks);
                     (new_blocks, Some(segment_info.summary))
                 } else {
                     // use by compact.
@@ -403,20 +450,22 @@ impl TableMutationAggregator {
 struct BlockMutations {
     replaced_blocks: Vec<(BlockIndex, Arc<BlockMeta>)>,
     deleted_blocks: Vec<BlockIndex>,
+    deletion_vectors: Vec<(BlockIndex, DeletionVectorMeta)>,
+    appended_blocks: Vec<Arc<BlockMeta>>,
 }
 
 impl BlockMutations {
     fn new_replacement(block_idx: BlockIndex, block_meta: Arc<BlockMeta>) -> Self {
         BlockMutations {
             replaced_blocks: vec![(block_idx, block_meta)],
-            deleted_blocks: vec![],
+            ..Default::default()
         }
     }
 
     fn new_deletion(block_idx: BlockIndex) -> Self {
         BlockMutations {
-            replaced_blocks: vec![],
             deleted_blocks: vec![block_idx],
+            ..Default::default()
         }
     }
 
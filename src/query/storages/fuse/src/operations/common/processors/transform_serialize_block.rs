@@ -34,9 +34,12 @@ use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::PipeItem;
 use common_sql::executor::physical_plans::MutationKind;
 use opendal::Operator;
+use roaring::RoaringBitmap;
 use storages_common_index::BloomIndex;
+use storages_common_table_meta::meta::BlockMeta;
 
 use crate::io::write_data;
+use crate::io::write_deletion_vector;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::operations::common::BlockMetaIndex;
@@ -44,6 +47,8 @@ use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::PartitionMeta;
+use crate::operations::PartitionedBlockMeta;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
@@ -53,10 +58,18 @@ enum State {
         block: DataBlock,
         stats_type: ClusterStatsGenType,
         index: Option<BlockMetaIndex>,
+        partition: Option<String>,
+        deleted: Option<RoaringBitmap>,
     },
     Serialized {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
+        partition: Option<String>,
+    },
+    WriteDeletionVector {
+        index: BlockMetaIndex,
+        deleted: RoaringBitmap,
+        appended: Option<BlockSerialization>,
     },
 }
 
@@ -140,6 +153,41 @@ impl TransformSerializeBlock {
         };
         DataBlock::empty_with_meta(Box::new(meta))
     }
+
+    async fn write_block(&self, serialized: BlockSerialization) -> Result<BlockMeta> {
+        let start = Instant::now();
+        // write block data.
+        let raw_block_data = serialized.block_raw_data;
+        let data_size = raw_block_data.len();
+        let path = serialized.block_meta.location.0.as_str();
+        write_data(raw_block_data, &self.dal, path).await?;
+
+        // Perf.
+        {
+            metrics_inc_block_write_nums(1);
+            metrics_inc_block_write_bytes(data_size as u64);
+            metrics_inc_block_write_milliseconds(start.elapsed().as_millis() as u64);
+        }
+
+        // write index data.
+        let bloom_index_state = serialized.bloom_index_state;
+        if let Some(bloom_index_state) = bloom_index_state {
+            let index_size = bloom_index_state.data.len();
+            write_data(
+                bloom_index_state.data,
+                &self.dal,
+                &bloom_index_state.location.0,
+            )
+            .await?;
+            // Perf.
+            {
+                metrics_inc_block_index_write_nums(1);
+                metrics_inc_block_index_write_bytes(index_size as u64);
+                metrics_inc_block_index_write_milliseconds(start.elapsed().as_millis() as u64);
+            }
+        }
+        Ok(serialized.block_meta)
+    }
 }
 
 #[async_trait::async_trait]
@@ -157,7 +205,10 @@ impl Processor for TransformSerializeBlock {
             return Ok(Event::Sync);
         }
 
-        if matches!(self.state, State::Serialized { .. }) {
+        if matches!(
+            self.state,
+            State::Serialized { .. } | State::WriteDeletionVector { .. }
+        ) {
             return Ok(Event::Async);
         }
 
@@ -187,6 +238,17 @@ impl Processor for TransformSerializeBlock {
         let mut input_data = self.input.pull_data().unwrap()?;
         let meta = input_data.take_meta();
         if let Some(meta) = meta {
+            if let Some(partition_meta) = PartitionMeta::downcast_ref_from(&meta) {
+                // append block of a partition
+                self.state = State::NeedSerialize {
+                    block: input_data,
+                    stats_type: ClusterStatsGenType::Generally,
+                    index: None,
+                    partition: Some(partition_meta.partition.clone()),
+                    deleted: None,
+                };
+                return Ok(Event::Sync);
+            }
             let meta = SerializeDataMeta::downcast_from(meta)
                 .ok_or_else(|| ErrorCode::Internal("It's a bug"))?;
             match meta {
@@ -211,6 +273,29 @@ impl Processor for TransformSerializeBlock {
                             block: input_data,
                             stats_type: serialize_block.stats_type,
                             index: Some(serialize_block.index),
+                            partition: None,
+                            deleted: None,
+                        };
+                        Ok(Event::Sync)
+                    }
+                }
+                SerializeDataMeta::DeletionVector(deletion_vector) => {
+                    if input_data.is_empty() {
+                        // only mark the rows as deleted
+                        self.state = State::WriteDeletionVector {
+                            index: deletion_vector.index,
+                            deleted: deletion_vector.deleted,
+                            appended: None,
+                        };
+                        Ok(Event::Async)
+                    } else {
+                        // the updated rows are appended as a new block
+                        self.state = State::NeedSerialize {
+                            block: input_data,
+                            stats_type: ClusterStatsGenType::Generally,
+                            index: Some(deletion_vector.index),
+                            partition: None,
+                            deleted: Some(deletion_vector.deleted),
                         };
                         Ok(Event::Sync)
                     }
@@ -235,6 +320,8 @@ impl Processor for TransformSerializeBlock {
                 block: input_data,
                 stats_type: ClusterStatsGenType::Generally,
                 index: None,
+                partition: None,
+                deleted: None,
             };
             Ok(Event::Sync)
         }
@@ -246,6 +333,8 @@ impl Processor for TransformSerializeBlock {
                 block,
                 stats_type,
                 index,
+                partition,
+                deleted,
             } => {
                 // Check if the datablock is valid, this is needed to ensure data is correct
                 block.check_valid()?;
@@ -261,7 +350,18 @@ impl Processor for TransformSerializeBlock {
                             }
                         })?;
 
-                self.state = State::Serialized { serialized, index };
+                self.state = match (index, deleted) {
+                    (Some(index), Some(deleted)) => State::WriteDeletionVector {
+                        index,
+                        deleted,
+                        appended: Some(serialized),
+                    },
+                    (index, _) => State::Serialized {
+                        serialized,
+                        index,
+                        partition,
+                    },
+                };
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
@@ -271,60 +371,58 @@ impl Processor for TransformSerializeBlock {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
-            State::Serialized { serialized, index } => {
-                let start = Instant::now();
-                // write block data.
-                let raw_block_data = serialized.block_raw_data;
-                let data_size = raw_block_data.len();
-                let path = serialized.block_meta.location.0.as_str();
-                write_data(raw_block_data, &self.dal, path).await?;
-
-                // Perf.
-                {
-                    metrics_inc_block_write_nums(1);
-                    metrics_inc_block_write_bytes(data_size as u64);
-                    metrics_inc_block_write_milliseconds(start.elapsed().as_millis() as u64);
-                }
-
-                // write index data.
-                let bloom_index_state = serialized.bloom_index_state;
-                if let Some(bloom_index_state) = bloom_index_state {
-                    let index_size = bloom_index_state.data.len();
-                    write_data(
-                        bloom_index_state.data,
-                        &self.dal,
-                        &bloom_index_state.location.0,
-                    )
-                    .await?;
-                    // Perf.
-                    {
-                        metrics_inc_block_index_write_nums(1);
-                        metrics_inc_block_index_write_bytes(index_size as u64);
-                        metrics_inc_block_index_write_milliseconds(
-                            start.elapsed().as_millis() as u64
-                        );
-                    }
-                }
+            State::Serialized {
+                serialized,
+                index,
+                partition,
+            } => {
+                let block_meta = self.write_block(serialized).await?;
 
                 let data_block = if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::ReplacedBlock {
                         index,
-                        block_meta: Arc::new(serialized.block_meta),
+                        block_meta: Arc::new(block_meta),
                     })
                 } else {
                     let progress_values = ProgressValues {
-                        rows: serialized.block_meta.row_count as usize,
-                        bytes: serialized.block_meta.block_size as usize,
+                        rows: block_meta.row_count as usize,
+                        bytes: block_meta.block_size as usize,
                     };
                     self.block_builder
                         .ctx
                         .get_write_progress()
                         .incr(&progress_values);
 
-                    DataBlock::empty_with_meta(Box::new(serialized.block_meta))
+                    match partition {
+                        Some(partition) => {
+                            DataBlock::empty_with_meta(Box::new(PartitionedBlockMeta {
+                                partition,
+                                block_meta,
+                            }))
+                        }
+                        None => DataBlock::empty_with_meta(Box::new(block_meta)),
+                    }
                 };
                 self.output_data = Some(data_block);
             }
+            State::WriteDeletionVector {
+                index,
+                deleted,
+                appended,
+            } => {
+                let appended_block = match appended {
+                    Some(serialized) => Some(Arc::new(self.write_block(serialized).await?)),
+                    None => None,
+                };
+                let deletion_vector =
+                    write_deletion_vector(&self.dal, &self.block_builder.meta_locations, &deleted)
+                        .await?;
+                self.output_data = Some(Self::mutation_logs(MutationLogEntry::DeletionVector {
+                    index,
+                    deletion_vector,
+                    appended_block,
+                }));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -40,6 +41,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
+use crate::operations::PartitionedBlockMeta;
 use crate::statistics::StatisticsAccumulator;
 use crate::FuseTable;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
@@ -47,15 +49,19 @@ use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 
 enum State {
     None,
-    GenerateSegment,
+    GenerateSegment {
+        partition: Option<String>,
+    },
     SerializedSegment {
         data: Vec<u8>,
         location: String,
         segment: Arc<SegmentInfo>,
+        partition: Option<String>,
     },
     PreCommitSegment {
         location: String,
         segment: Arc<SegmentInfo>,
+        partition: Option<String>,
     },
     Finished,
 }
@@ -64,7 +70,8 @@ pub struct TransformSerializeSegment {
     ctx: Arc<dyn TableContext>,
     data_accessor: Operator,
     meta_locations: TableMetaLocationGenerator,
-    accumulator: StatisticsAccumulator,
+    // blocks of different partitions never share a segment
+    accumulators: BTreeMap<Option<String>, StatisticsAccumulator>,
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
@@ -92,7 +99,7 @@ impl TransformSerializeSegment {
             data_accessor: table.get_operator(),
             meta_locations: table.meta_location_generator().clone(),
             state: State::None,
-            accumulator: Default::default(),
+            accumulators: BTreeMap::new(),
             block_per_seg: table
                 .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
                 as u64,
@@ -126,7 +133,7 @@ impl Processor for TransformSerializeSegment {
     fn event(&mut self) -> Result<Event> {
         if matches!(
             &self.state,
-            State::GenerateSegment | State::PreCommitSegment { .. }
+            State::GenerateSegment { .. } | State::PreCommitSegment { .. }
         ) {
             return Ok(Event::Sync);
         }
@@ -149,8 +156,13 @@ impl Processor for TransformSerializeSegment {
         }
 
         if self.input.is_finished() {
-            if self.accumulator.summary_row_count != 0 {
-                self.state = State::GenerateSegment;
+            let pending = self
+                .accumulators
+                .iter()
+                .find(|(_, acc)| acc.summary_row_count != 0)
+                .map(|(partition, _)| partition.clone());
+            if let Some(partition) = pending {
+                self.state = State::GenerateSegment { partition };
                 return Ok(Event::Sync);
             }
             self.output.finish();
@@ -166,13 +178,21 @@ impl Processor for TransformSerializeSegment {
                 .get_meta()
                 .cloned()
                 .ok_or_else(|| ErrorCode::Internal("No block meta. It's a bug"))?;
-            let block_meta = BlockMeta::downcast_ref_from(&input_meta)
-                .ok_or_else(|| ErrorCode::Internal("No commit meta. It's a bug"))?
-                .clone();
+            let (partition, block_meta) = match PartitionedBlockMeta::downcast_ref_from(&input_meta)
+            {
+                Some(meta) => (Some(meta.partition.clone()), meta.block_meta.clone()),
+                None => {
+                    let block_meta = BlockMeta::downcast_ref_from(&input_meta)
+                        .ok_or_else(|| ErrorCode::Internal("No commit meta. It's a bug"))?
+                        .clone();
+                    (None, block_meta)
+                }
+            };
 
-            self.accumulator.add_with_block_meta(block_meta);
-            if self.accumulator.summary_block_count >= self.block_per_seg {
-                self.state = State::GenerateSegment;
+            let accumulator = self.accumulators.entry(partition.clone()).or_default();
+            accumulator.add_with_block_meta(block_meta);
+            if accumulator.summary_block_count >= self.block_per_seg {
+                self.state = State::GenerateSegment { partition };
                 return Ok(Event::Sync);
             }
         }
@@ -183,8 +203,8 @@ impl Processor for TransformSerializeSegment {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
-            State::GenerateSegment => {
-                let acc = std::mem::take(&mut self.accumulator);
+            State::GenerateSegment { partition } => {
+                let acc = self.accumulators.remove(&partition).unwrap_or_default();
                 let summary = acc.summary(self.thresholds, self.default_cluster_key_id);
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, summary);
@@ -193,9 +213,14 @@ impl Processor for TransformSerializeSegment {
                     data: segment_info.to_bytes()?,
                     location: self.meta_locations.gen_segment_info_location(),
                     segment: Arc::new(segment_info),
+                    partition,
                 }
             }
-            State::PreCommitSegment { location, segment } => {
+            State::PreCommitSegment {
+                location,
+                segment,
+                partition,
+            } => {
                 if let Some(segment_cache) = SegmentInfo::cache() {
                     segment_cache.put(location.clone(), Arc::new(segment.as_ref().try_into()?));
                 }
@@ -216,6 +241,7 @@ impl Processor for TransformSerializeSegment {
                         format_version,
                         abort_operation,
                         summary: segment.summary.clone(),
+                        partition,
                     }],
                 };
 
@@ -238,11 +264,16 @@ impl Processor for TransformSerializeSegment {
                 data,
                 location,
                 segment,
+                partition,
             } => {
                 self.data_accessor.write(&location, data).await?;
                 info!("fuse append wrote down segment {} ", location);
 
-                self.state = State::PreCommitSegment { location, segment };
+                self.state = State::PreCommitSegment {
+                    location,
+                    segment,
+                    partition,
+                };
             }
             _state => {
                 return Err(ErrorCode::Internal("Unknown state for fuse table sink."));
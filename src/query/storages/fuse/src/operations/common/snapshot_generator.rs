@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ops::Range;
@@ -30,10 +31,13 @@ use log::info;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::PartitionSummary;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use uuid::Uuid;
 
+use crate::operations::merge_partitions;
+use crate::operations::rebase_partitions;
 use crate::statistics::merge_statistics;
 use crate::statistics::reducers::deduct_statistics_mut;
 use crate::statistics::reducers::merge_statistics_mut;
@@ -66,6 +70,13 @@ pub struct SnapshotChanges {
 
     pub merged_statistics: Statistics,
     pub removed_statistics: Statistics,
+
+    #[serde(default)]
+    pub appended_partitions: BTreeMap<String, PartitionSummary>,
+    /// Whether the replaced segments may mix rows of different partitions, and
+    /// so should no longer be mapped to the partition of the segments they replace.
+    #[serde(default)]
+    pub detach_partitions: bool,
 }
 
 impl SnapshotChanges {
@@ -103,6 +114,8 @@ impl SnapshotChanges {
 pub struct SnapshotMerged {
     pub merged_segments: Vec<Location>,
     pub merged_statistics: Statistics,
+    #[serde(default)]
+    pub merged_partitions: BTreeMap<String, PartitionSummary>,
 }
 
 #[derive(Clone, serde::Serialize, serde::Deserialize, Debug, PartialEq)]
@@ -245,19 +258,39 @@ impl SnapshotGenerator for MutationGenerator {
                 {
                     info!("resolvable conflicts detected");
                     metrics_inc_commit_mutation_modified_segment_exists_in_latest();
-                    let new_segments = ConflictResolveContext::merge_segments(
-                        previous.segments.clone(),
-                        ctx.appended_segments.clone(),
-                        replaced,
-                        removed,
-                    );
                     let mut new_summary = merge_statistics(
                         &ctx.merged_statistics,
                         &previous.summary,
                         default_cluster_key_id,
                     );
                     deduct_statistics_mut(&mut new_summary, &ctx.removed_statistics);
-                    let new_snapshot = TableSnapshot::new(
+                    let mut new_partitions = previous.partitions.clone();
+                    if !new_partitions.is_empty() {
+                        let replaced_locations = replaced
+                            .iter()
+                            .map(|(position, location)| {
+                                (previous.segments[*position].clone(), location.clone())
+                            })
+                            .collect::<HashMap<_, _>>();
+                        let removed_locations = removed
+                            .iter()
+                            .map(|position| previous.segments[*position].clone())
+                            .collect::<HashSet<_>>();
+                        rebase_partitions(
+                            &mut new_partitions,
+                            &replaced_locations,
+                            &removed_locations,
+                            !ctx.detach_partitions,
+                        );
+                    }
+                    merge_partitions(&mut new_partitions, &ctx.appended_partitions);
+                    let new_segments = ConflictResolveContext::merge_segments(
+                        previous.segments.clone(),
+                        ctx.appended_segments.clone(),
+                        replaced,
+                        removed,
+                    );
+                    let mut new_snapshot = TableSnapshot::new(
                         Uuid::new_v4(),
                         &previous.timestamp,
                         Some((previous.snapshot_id, previous.format_version)),
@@ -267,6 +300,7 @@ impl SnapshotGenerator for MutationGenerator {
                         cluster_key_meta,
                         previous.table_statistics_location.clone(),
                     );
+                    new_snapshot.partitions = new_partitions;
                     return Ok(new_snapshot);
                 }
             }
@@ -376,6 +410,7 @@ impl SnapshotGenerator for AppendGenerator {
         let mut table_statistics_location = None;
         let mut new_segments = snapshot_merged.merged_segments.clone();
         let mut new_summary = snapshot_merged.merged_statistics.clone();
+        let mut new_partitions = snapshot_merged.merged_partitions.clone();
 
         if let Some(snapshot) = &previous {
             prev_timestamp = snapshot.timestamp;
@@ -418,10 +453,13 @@ impl SnapshotGenerator for AppendGenerator {
                     &summary,
                     cluster_key_meta.clone().map(|v| v.0),
                 );
+
+                new_partitions = snapshot.partitions.clone();
+                merge_partitions(&mut new_partitions, &snapshot_merged.merged_partitions);
             }
         }
 
-        Ok(TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -430,7 +468,9 @@ impl SnapshotGenerator for AppendGenerator {
             new_segments,
             cluster_key_meta,
             table_statistics_location,
-        ))
+        );
+        new_snapshot.partitions = new_partitions;
+        Ok(new_snapshot)
     }
 }
 
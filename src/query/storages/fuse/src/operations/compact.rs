@@ -92,7 +92,7 @@ impl FuseTable {
             return Ok(None);
         };
 
-        let thresholds = self.get_block_thresholds();
+        let thresholds = self.compact_block_thresholds();
         let mut mutator = BlockCompactMutator::new(
             ctx.clone(),
             thresholds,
@@ -120,7 +120,7 @@ impl FuseTable {
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let is_lazy = parts.is_lazy;
-        let thresholds = self.get_block_thresholds();
+        let thresholds = self.compact_block_thresholds();
         let cluster_key_id = self.cluster_key_id();
         let mut max_threads = ctx.get_settings().get_max_threads()? as usize;
         if is_lazy {
@@ -214,8 +214,12 @@ impl FuseTable {
         if is_lazy {
             pipeline.try_resize(1)?;
             pipeline.add_transform(|input, output| {
-                let mutation_aggregator =
-                    TableMutationAggregator::new(self, ctx.clone(), vec![], MutationKind::Compact);
+                let mutation_aggregator = TableMutationAggregator::try_create(
+                    self,
+                    ctx.clone(),
+                    vec![],
+                    MutationKind::Compact,
+                )?;
                 Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
                     input,
                     output,
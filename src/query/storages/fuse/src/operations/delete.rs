@@ -236,6 +236,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    self.deletion_vector_enabled(),
                 )
             },
             max_threads,
@@ -40,6 +40,7 @@ use crate::io::SegmentsIO;
 use crate::io::SnapshotLiteExtended;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::SharedLocations;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
@@ -70,6 +71,14 @@ impl FuseTable {
             )));
         }
 
+        if !dry_run {
+            self.purge_expired_tags(ctx.as_ref()).await?;
+        }
+
+        // Files shared with the clones of this table, or inherited from the table
+        // this one was cloned from, and the snapshots kept by tags, must be kept.
+        let shared_locations = self.read_shared_locations(ctx).await?;
+
         let snapshots_io = SnapshotsIO::create(ctx.clone(), self.operator.clone());
         let location_gen = self.meta_location_generator();
         let purged_snapshot_limit = limit.unwrap_or(snapshot_files.len());
@@ -133,17 +142,21 @@ impl FuseTable {
                     if purged_snapshot_count >= purged_snapshot_limit {
                         break;
                     }
-                    snapshots_to_be_purged.insert(loc);
-                    purged_snapshot_count += 1;
+                    if !shared_locations.is_shared_snapshot(&loc) {
+                        snapshots_to_be_purged.insert(loc);
+                        purged_snapshot_count += 1;
+                    }
                 }
 
                 let diff: HashSet<_> = s.segments.difference(&base_segments).cloned().collect();
                 segments_to_be_purged.extend(diff);
 
-                if s.table_statistics_location.is_some()
-                    && s.table_statistics_location != base_ts_location_opt
-                {
-                    ts_to_be_purged.insert(s.table_statistics_location.unwrap());
+                if let Some(ts) = s.table_statistics_location {
+                    if Some(&ts) != base_ts_location_opt.as_ref()
+                        && !shared_locations.is_shared_snapshot(&ts)
+                    {
+                        ts_to_be_purged.insert(ts);
+                    }
                 }
             }
 
@@ -166,6 +179,7 @@ impl FuseTable {
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        &shared_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -181,6 +195,7 @@ impl FuseTable {
                         ctx,
                         &mut counter,
                         &root_snapshot_info.referenced_locations,
+                        &shared_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -206,14 +221,18 @@ impl FuseTable {
                     if purged_snapshot_count >= purged_snapshot_limit {
                         break;
                     }
-                    snapshots_to_be_purged.insert(loc);
-                    purged_snapshot_count += 1;
+                    if !shared_locations.is_shared_snapshot(&loc) {
+                        snapshots_to_be_purged.insert(loc);
+                        purged_snapshot_count += 1;
+                    }
                 }
 
                 segments_to_be_purged.extend(s.segments);
 
-                if s.table_statistics_location.is_some() {
-                    ts_to_be_purged.insert(s.table_statistics_location.unwrap());
+                if let Some(ts) = s.table_statistics_location {
+                    if !shared_locations.is_shared_snapshot(&ts) {
+                        ts_to_be_purged.insert(ts);
+                    }
                 }
             }
             if dry_run {
@@ -221,6 +240,7 @@ impl FuseTable {
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    &shared_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -232,6 +252,7 @@ impl FuseTable {
                     ctx,
                     &mut counter,
                     &root_snapshot_info.referenced_locations,
+                    &shared_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -253,6 +274,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &shared_locations,
                 &table_agg_index_ids,
             )
             .await?;
@@ -316,6 +338,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
+        shared_locations: &SharedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -331,7 +354,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || shared_locations.is_shared_block(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -345,13 +370,20 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || shared_locations.is_shared_bloom(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
             }
 
-            purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(
+                chunk
+                    .iter()
+                    .filter(|loc| !shared_locations.is_shared_segment(&loc.0))
+                    .map(|loc| loc.0.clone()),
+            );
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
@@ -365,6 +397,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        shared_locations: &SharedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -383,7 +416,9 @@ impl FuseTable {
             let mut blocks_to_be_purged = HashSet::new();
             let mut agg_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || shared_locations.is_shared_block(loc)
+                {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
@@ -398,7 +433,9 @@ impl FuseTable {
 
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || shared_locations.is_shared_bloom(loc)
+                {
                     continue;
                 }
                 blooms_to_be_purged.insert(loc.to_string());
@@ -407,6 +444,7 @@ impl FuseTable {
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
+                    .filter(|loc| !shared_locations.is_shared_segment(&loc.0))
                     .map(|loc| loc.0.clone())
                     .collect::<Vec<String>>(),
             );
@@ -438,6 +476,7 @@ impl FuseTable {
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -445,15 +484,29 @@ impl FuseTable {
         root_snapshot: Arc<SnapshotLiteExtended>,
         root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        shared_locations: &SharedLocations,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
+                .filter(|loc| !shared_locations.is_shared_segment(&loc.0))
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
         );
+        let root_location_tuple = LocationTuple {
+            block_location: root_location_tuple
+                .block_location
+                .into_iter()
+                .filter(|loc| !shared_locations.is_shared_block(loc))
+                .collect(),
+            bloom_location: root_location_tuple
+                .bloom_location
+                .into_iter()
+                .filter(|loc| !shared_locations.is_shared_bloom(loc))
+                .collect(),
+        };
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         for index_id in table_agg_index_ids {
@@ -476,15 +529,16 @@ impl FuseTable {
 
         let mut ts_to_be_purged = HashSet::new();
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
-            ts_to_be_purged.insert(ts);
+            if !shared_locations.is_shared_snapshot(&ts) {
+                ts_to_be_purged.insert(ts);
+            }
         }
-        self.purge_ts_snapshots(
-            ctx,
-            counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
-        )
-        .await
+        let mut snapshots_to_be_purged = HashSet::new();
+        if !shared_locations.is_shared_snapshot(&root_snapshot_location) {
+            snapshots_to_be_purged.insert(root_snapshot_location);
+        }
+        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
+            .await
     }
 
     async fn purge_block_segments(
@@ -688,6 +742,11 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            // deletion vectors are purged along with the bloom indexes,
+            // they are not the data files of aggregating indexes.
+            if let Some(deletion_vector) = &block_meta.deletion_vector {
+                bloom_location.insert(deletion_vector.location.0.clone());
+            }
         }
         Ok(Self {
             block_location,
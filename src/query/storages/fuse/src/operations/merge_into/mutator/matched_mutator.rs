@@ -46,6 +46,7 @@ use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 
+use crate::io::read_block_deletion_vector;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
@@ -316,10 +317,18 @@ impl AggregationContext {
         )
         .await?;
         let origin_num_rows = origin_data_block.num_rows();
+        // rows deleted by the deletion vector are dropped together with the modified rows,
+        // the offsets are the physical row offsets in the block.
+        let deletion_vector =
+            read_block_deletion_vector(&self.block_reader.operator, block_meta).await?;
         // apply delete
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            if modified_offsets.contains(&row)
+                || deletion_vector
+                    .as_ref()
+                    .is_some_and(|deleted| deleted.contains(row as u32))
+            {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
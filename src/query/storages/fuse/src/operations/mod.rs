@@ -15,6 +15,8 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod auto_compaction;
+mod clone;
 mod commit;
 pub mod common;
 mod compact;
@@ -24,6 +26,7 @@ mod merge;
 mod merge_into;
 mod mutation;
 mod navigate;
+mod partition;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -31,17 +34,30 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod tag;
 mod truncate;
+mod ttl;
 mod update;
 pub mod util;
 pub use agg_index_sink::AggIndexSink;
+pub use auto_compaction::AutoCompactionPolicy;
+pub use auto_compaction::SmallBlockStats;
+pub use clone::SharedLocations;
 pub use common::*;
 pub use compact::CompactOptions;
 pub use delete::MutationBlockPruningContext;
 pub use merge_into::*;
 pub use mutation::*;
+pub use partition::add_partition_segment;
+pub use partition::merge_partitions;
+pub use partition::rebase_partitions;
+pub use partition::PartitionMeta;
+pub use partition::PartitionedBlockMeta;
+pub use partition::TransformPartitionBlock;
 pub use read::build_row_fetcher_pipeline;
 pub use replace_into::*;
+pub use tag::SnapshotTag;
+pub use ttl::ExpiredBlockStats;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
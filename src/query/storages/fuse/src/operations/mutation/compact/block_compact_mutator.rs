@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::sync::Arc;
@@ -93,10 +95,18 @@ impl BlockCompactMutator {
             self.operator.clone(),
             Arc::new(self.compact_params.base_snapshot.schema.clone()),
         );
-        let mut checker = SegmentCompactChecker::new(
-            self.compact_params.block_per_seg as u64,
-            self.cluster_key_id,
-        );
+        // segments of different partitions are never compacted together.
+        let segment_partitions = snapshot
+            .partitions
+            .iter()
+            .flat_map(|(partition, summary)| {
+                summary
+                    .segments
+                    .iter()
+                    .map(move |location| (location, partition))
+            })
+            .collect::<HashMap<_, _>>();
+        let mut checkers: BTreeMap<Option<&String>, SegmentCompactChecker> = BTreeMap::new();
 
         let mut segment_idx = 0;
         let mut is_end = false;
@@ -131,14 +141,27 @@ impl BlockCompactMutator {
             // Check the segment to be compacted.
             // Size of compacted segment should be in range R == [threshold, 2 * threshold)
             for (segment_idx, compact_segment) in segment_infos.into_iter() {
+                let partition = segment_partitions
+                    .get(&segment_locations[segment_idx])
+                    .copied();
+                let checker = checkers.entry(partition).or_insert_with(|| {
+                    SegmentCompactChecker::new(
+                        self.compact_params.block_per_seg as u64,
+                        self.cluster_key_id,
+                    )
+                });
                 let segments_vec = checker.add(segment_idx, compact_segment);
                 for segments in segments_vec {
-                    self.generate_part(segments, &mut parts, &mut checker);
+                    Self::generate_part(segments, &mut parts, checker);
                 }
 
-                if checker.compacted_segment_cnt + checker.segments.len() >= limit
-                    || checker.compacted_block_cnt >= MAX_BLOCK_COUNT as u64
-                {
+                let (segment_cnt, block_cnt) = checkers.values().fold((0, 0), |acc, c| {
+                    (
+                        acc.0 + c.compacted_segment_cnt + c.segments.len(),
+                        acc.1 + c.compacted_block_cnt,
+                    )
+                });
+                if segment_cnt >= limit || block_cnt >= MAX_BLOCK_COUNT as u64 {
                     is_end = true;
                     break;
                 }
@@ -161,17 +184,17 @@ impl BlockCompactMutator {
         }
 
         // finalize the compaction.
-        self.generate_part(
-            std::mem::take(&mut checker.segments),
-            &mut parts,
-            &mut checker,
-        );
+        let mut compacted_segment_cnt = 0;
+        for checker in checkers.values_mut() {
+            Self::generate_part(std::mem::take(&mut checker.segments), &mut parts, checker);
+            compacted_segment_cnt += checker.compacted_segment_cnt;
+        }
 
         // Status.
         self.ctx.set_status_info(&format!(
             "compact: end to build lazy compact parts:{}, segments to be compacted:{}, cost:{} sec",
             parts.len(),
-            checker.compacted_segment_cnt,
+            compacted_segment_cnt,
             start.elapsed().as_secs()
         ));
 
@@ -280,7 +303,6 @@ impl BlockCompactMutator {
     }
 
     fn generate_part(
-        &mut self,
         segments: Vec<(SegmentIndex, Arc<CompactSegmentInfo>)>,
         parts: &mut Vec<PartInfoPtr>,
         checker: &mut SegmentCompactChecker,
@@ -421,7 +443,7 @@ impl CompactTaskBuilder {
             }
         }
 
-        let total_rows = self.total_rows + block.row_count as usize;
+        let total_rows = self.total_rows + block.live_row_count() as usize;
         let total_size = self.total_size + block.block_size as usize;
         if !thresholds.check_large_enough(total_rows, total_size) {
             // blocks < N
@@ -457,6 +479,10 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        // Purge the rows marked by the deletion vector.
+        if block.deletion_vector.is_some() {
+            return true;
+        }
         let column_ids: HashSet<ColumnId> = block.col_metas.keys().cloned().collect();
         if self.column_ids == column_ids {
             // Check if the block needs to be resort.
@@ -549,7 +575,7 @@ impl CompactTaskBuilder {
 
                 let (total_rows, total_size) =
                     blocks.iter().chain(tail.iter()).fold((0, 0), |mut acc, x| {
-                        acc.0 += x.row_count as usize;
+                        acc.0 += x.live_row_count() as usize;
                         acc.1 += x.block_size as usize;
                         acc
                     });
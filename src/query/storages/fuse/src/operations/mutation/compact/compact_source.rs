@@ -31,8 +31,11 @@ use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_sql::evaluator::BlockOperator;
+use roaring::RoaringBitmap;
 use storages_common_table_meta::meta::BlockMeta;
 
+use crate::io::filter_deleted_rows;
+use crate::io::read_block_deletion_vector;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::mutation::compact::compact_part::CompactPartInfo;
@@ -46,7 +49,7 @@ use crate::MergeIOReadResult;
 enum State {
     ReadData(Option<PartInfoPtr>),
     Concat {
-        read_res: Vec<MergeIOReadResult>,
+        read_res: Vec<(MergeIOReadResult, Option<RoaringBitmap>)>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
@@ -146,7 +149,7 @@ impl Processor for CompactSource {
                 let blocks = read_res
                     .into_iter()
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
+                    .map(|((data, deletion_vector), meta)| {
                         let mut block = self.block_reader.deserialize_chunks_with_meta(
                             &meta,
                             &self.storage_format,
@@ -155,7 +158,8 @@ impl Processor for CompactSource {
 
                         if self.block_reader.update_stream_columns() {
                             let num_rows = block.num_rows();
-                            let stream_meta = gen_mutation_stream_meta(None, &meta.location.0)?;
+                            let stream_meta =
+                                gen_mutation_stream_meta(None, &meta.location.0, None)?;
                             for stream_column in self.stream_columns.iter() {
                                 let entry =
                                     stream_column.generate_column_values(&stream_meta, num_rows);
@@ -166,7 +170,8 @@ impl Processor for CompactSource {
                                 .iter()
                                 .try_fold(block, |input, op| op.execute(&self.func_ctx, input))?;
                         }
-                        Ok(block)
+                        // Compaction drops the rows marked by the deletion vector.
+                        filter_deleted_rows(block, deletion_vector.as_ref())
                     })
                     .collect::<Result<Vec<_>>>()?;
 
@@ -222,14 +227,18 @@ impl Processor for CompactSource {
                                     metrics_inc_compact_block_read_bytes(block.block_size);
                                 }
 
-                                block_reader
+                                let data = block_reader
                                     .read_columns_data_by_merge_io(
                                         &settings,
                                         &block.location.0,
                                         &block.col_metas,
                                         &None,
                                     )
-                                    .await
+                                    .await?;
+                                let deletion_vector =
+                                    read_block_deletion_vector(&block_reader.operator, block)
+                                        .await?;
+                                Ok::<_, ErrorCode>((data, deletion_vector))
                             });
                         }
 
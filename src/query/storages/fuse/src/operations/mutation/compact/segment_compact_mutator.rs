@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -22,8 +24,10 @@ use log::info;
 use metrics::gauge;
 use opendal::Operator;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::PartitionSummary;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::io::SegmentWriter;
 use crate::io::SegmentsIO;
@@ -43,6 +47,8 @@ pub struct SegmentCompactionState {
     pub new_segment_paths: Vec<String>,
     // number of fragmented segments compacted
     pub num_fragments_compacted: usize,
+    // locations of the segments of each partition, if the table is partitioned
+    pub partitions: BTreeMap<String, Vec<Location>>,
 }
 
 pub struct SegmentCompactMutator {
@@ -83,39 +89,60 @@ impl SegmentCompactMutator {
     pub async fn target_select(&mut self) -> Result<bool> {
         let select_begin = Instant::now();
 
-        let mut base_segment_locations = self.compact_params.base_snapshot.segments.clone();
-        if base_segment_locations.len() <= 1 {
+        let base_snapshot = self.compact_params.base_snapshot.clone();
+        if base_snapshot.segments.len() <= 1 {
             // no need to compact
             return Ok(false);
         }
-        // traverse the segment in reversed order, so that newly created unmergeable fragmented segment
-        // will be left at the "top", and likely to be merged in the next compaction; instead of leaving
-        // an unmergeable fragmented segment in the middle.
-        base_segment_locations.reverse();
 
         // need at lease 2 segments to make sense
-        let num_segments = base_segment_locations.len();
+        let num_segments = base_snapshot.segments.len();
         let limit = std::cmp::max(2, self.compact_params.limit.unwrap_or(num_segments));
 
         // prepare compactor
-        let schema = Arc::new(self.compact_params.base_snapshot.schema.clone());
+        let schema = Arc::new(base_snapshot.schema.clone());
         let fuse_segment_io =
             SegmentsIO::create(self.ctx.clone(), self.data_accessor.clone(), schema);
-        let segment_writer = SegmentWriter::new(&self.data_accessor, &self.location_generator);
         let chunk_size = self.ctx.get_settings().get_max_threads()? as usize * 4;
-        let compactor = SegmentCompactor::new(
-            self.compact_params.block_per_seg as u64,
-            self.default_cluster_key_id,
-            chunk_size,
-            &fuse_segment_io,
-            segment_writer,
-        );
 
-        self.compaction = compactor
-            .compact(base_segment_locations, limit, |status| {
-                self.ctx.set_status_info(&status);
-            })
-            .await?;
+        // segments of different partitions are never compacted together.
+        let mut compaction = SegmentCompactionState::default();
+        for (partition, segments) in Self::group_by_partition(&base_snapshot) {
+            let remain = limit.saturating_sub(compaction.num_fragments_compacted);
+            if segments.len() <= 1 || remain < 2 {
+                compaction.add_group(partition, segments);
+                continue;
+            }
+
+            // traverse the segment in reversed order, so that newly created unmergeable fragmented segment
+            // will be left at the "top", and likely to be merged in the next compaction; instead of leaving
+            // an unmergeable fragmented segment in the middle.
+            let mut base_segment_locations = segments.clone();
+            base_segment_locations.reverse();
+
+            let segment_writer = SegmentWriter::new(&self.data_accessor, &self.location_generator);
+            let compactor = SegmentCompactor::new(
+                self.compact_params.block_per_seg as u64,
+                self.default_cluster_key_id,
+                chunk_size,
+                &fuse_segment_io,
+                segment_writer,
+            );
+            let group = compactor
+                .compact(base_segment_locations, remain, |status| {
+                    self.ctx.set_status_info(&status);
+                })
+                .await?;
+
+            if group.new_segment_paths.is_empty() {
+                compaction.add_group(partition, segments);
+            } else {
+                compaction.num_fragments_compacted += group.num_fragments_compacted;
+                compaction.new_segment_paths.extend(group.new_segment_paths);
+                compaction.add_group(partition, group.segments_locations);
+            }
+        }
+        self.compaction = compaction;
 
         gauge!(
             "fuse_compact_segments_select_duration_second",
@@ -139,6 +166,21 @@ impl SegmentCompactMutator {
 
         // summary of snapshot is unchanged for compact segments.
         let statistics = self.compact_params.base_snapshot.summary.clone();
+        // so are the column statistics of the partitions.
+        let partitions = self
+            .compaction
+            .partitions
+            .iter()
+            .map(|(partition, segments)| {
+                let col_stats = self.compact_params.base_snapshot.partitions[partition]
+                    .col_stats
+                    .clone();
+                (partition.clone(), PartitionSummary {
+                    segments: segments.clone(),
+                    col_stats,
+                })
+            })
+            .collect();
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
 
         let _guard = self.lock.try_lock(self.ctx.clone()).await?;
@@ -149,11 +191,48 @@ impl SegmentCompactMutator {
                 self.compact_params.base_snapshot.clone(),
                 &self.compaction.segments_locations,
                 statistics,
+                partitions,
                 abort_action,
                 None,
             )
             .await
     }
+
+    // Groups the segments of the snapshot by the partition they belong to, the
+    // segments not mapped to any partition come first.
+    fn group_by_partition(snapshot: &TableSnapshot) -> Vec<(Option<String>, Vec<Location>)> {
+        if snapshot.partitions.is_empty() {
+            return vec![(None, snapshot.segments.clone())];
+        }
+
+        let partitioned = snapshot
+            .partitions
+            .values()
+            .flat_map(|summary| summary.segments.iter())
+            .collect::<HashSet<_>>();
+        let unpartitioned = snapshot
+            .segments
+            .iter()
+            .filter(|location| !partitioned.contains(location))
+            .cloned()
+            .collect();
+        std::iter::once((None, unpartitioned))
+            .chain(
+                snapshot.partitions.iter().map(|(partition, summary)| {
+                    (Some(partition.clone()), summary.segments.clone())
+                }),
+            )
+            .collect()
+    }
+}
+
+impl SegmentCompactionState {
+    fn add_group(&mut self, partition: Option<String>, segments: Vec<Location>) {
+        if let Some(partition) = partition {
+            self.partitions.insert(partition, segments.clone());
+        }
+        self.segments_locations.extend(segments);
+    }
 }
 
 // Segments compactor that preserver the order of ingestion.
@@ -14,6 +14,7 @@
 
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
+use roaring::RoaringBitmap;
 use storages_common_table_meta::meta::ClusterStatistics;
 
 use crate::operations::common::BlockMetaIndex;
@@ -25,6 +26,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    DeletionVector(SerializeDeletionVector),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -55,3 +57,17 @@ impl SerializeBlock {
         SerializeBlock { index, stats_type }
     }
 }
+
+/// Rows deleted by a deletion vector, the data block carries the updated rows of UPDATE if any.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SerializeDeletionVector {
+    pub index: BlockMetaIndex,
+    /// The offsets of all the deleted rows in the block, including the ones deleted before.
+    pub deleted: RoaringBitmap,
+}
+
+impl SerializeDeletionVector {
+    pub fn create(index: BlockMetaIndex, deleted: RoaringBitmap) -> Self {
+        SerializeDeletionVector { index, deleted }
+    }
+}
@@ -16,6 +16,7 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::ProgressValues;
 use common_catalog::plan::gen_mutation_stream_meta;
 use common_catalog::plan::InternalColumn;
@@ -32,6 +33,7 @@ use common_expression::BlockMetaInfoPtr;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
 use common_expression::Expr;
+use common_expression::Scalar;
 use common_expression::Value;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
@@ -40,9 +42,13 @@ use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_sql::evaluator::BlockOperator;
+use roaring::RoaringBitmap;
 
 use super::mutation_meta::SerializeBlock;
+use super::mutation_meta::SerializeDeletionVector;
 use crate::fuse_part::FusePartInfo;
+use crate::io::filter_deleted_rows;
+use crate::io::live_rows_filter;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::common::BlockMetaIndex;
@@ -88,9 +94,16 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    enable_deletion_vector: bool,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    // The rows deleted before of current block.
+    deletion_vector: Option<RoaringBitmap>,
+    // The deleted rows after the mutation, if the mutation is done by deletion vector.
+    new_deletion_vector: Option<RoaringBitmap>,
+    // The offsets in the block of the rows to write, if some rows of the block are skipped.
+    row_offsets: Option<Vec<usize>>,
 }
 
 impl MutationSource {
@@ -105,6 +118,7 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        enable_deletion_vector: bool,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -117,10 +131,27 @@ impl MutationSource {
             storage_format,
             action,
             query_row_id_col,
+            enable_deletion_vector,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            deletion_vector: None,
+            new_deletion_vector: None,
+            row_offsets: None,
         })))
     }
+
+    /// Merges the rows selected by the predicate into the deletion vector of the block,
+    /// `live_offsets` maps the rows of the data block to the offsets in the block.
+    fn merge_deletion_vector(
+        &self,
+        predicate: &Bitmap,
+        live_offsets: &Option<Vec<usize>>,
+    ) -> RoaringBitmap {
+        let mut deleted = self.deletion_vector.clone().unwrap_or_default();
+        let rows = selected_offsets(predicate, live_offsets);
+        deleted.extend(rows.into_iter().map(|i| i as u32));
+        deleted
+    }
 }
 
 #[async_trait::async_trait]
@@ -181,7 +212,23 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+                // Skip the rows deleted before, keep the offsets of the live rows.
+                let live_offsets = match &self.deletion_vector {
+                    Some(deleted) => {
+                        let live = live_rows_filter(deleted, 0, data_block.num_rows());
+                        data_block = data_block.filter_with_bitmap(&live)?;
+                        Some(
+                            live.iter()
+                                .enumerate()
+                                .filter(|(_, live)| *live)
+                                .map(|(i, _)| i)
+                                .collect::<Vec<_>>(),
+                        )
+                    }
+                    None => None,
+                };
                 let num_rows = data_block.num_rows();
+                self.row_offsets = live_offsets.clone();
 
                 let fuse_part = FusePartInfo::from_part(&part)?;
                 if let Some(filter) = self.filter.as_ref() {
@@ -194,7 +241,7 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: None,
-                            offsets: None,
+                            offsets: live_offsets.clone(),
                             base_block_ids: None,
                         };
                         let internal_col = InternalColumn {
@@ -253,9 +300,28 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if self.enable_deletion_vector {
+                                    // mark the rows as deleted, the block is kept as it is.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    let deleted =
+                                        self.merge_deletion_vector(&predicate_col, &live_offsets);
+                                    let meta = Box::new(SerializeDataMeta::DeletionVector(
+                                        SerializeDeletionVector::create(
+                                            self.index.clone(),
+                                            deleted,
+                                        ),
+                                    ));
+                                    self.state = State::Output(
+                                        self.ctx.get_partition(),
+                                        DataBlock::empty_with_meta(meta),
+                                    );
                                 } else {
                                     let predicate_col = predicates.into_column().unwrap();
                                     let filter = predicate_col.not();
+                                    if live_offsets.is_some() {
+                                        self.row_offsets =
+                                            Some(selected_offsets(&filter, &live_offsets));
+                                    }
                                     data_block = data_block.filter_with_bitmap(&filter)?;
                                     if self.remain_reader.is_none() {
                                         self.state = State::PerformOperator(
@@ -272,6 +338,35 @@ impl Processor for MutationSource {
                                 }
                             }
 
+                            MutationAction::Update
+                                if self.enable_deletion_vector && affect_rows != num_rows =>
+                            {
+                                // mark the updated rows as deleted, and only write the
+                                // updated rows to a new block.
+                                let predicate_col = predicates.into_column().unwrap();
+                                self.new_deletion_vector =
+                                    Some(self.merge_deletion_vector(&predicate_col, &live_offsets));
+                                self.row_offsets =
+                                    Some(selected_offsets(&predicate_col, &live_offsets));
+                                data_block = data_block.filter_with_bitmap(&predicate_col)?;
+                                data_block.add_column(BlockEntry::new(
+                                    DataType::Boolean,
+                                    Value::Scalar(Scalar::Boolean(true)),
+                                ));
+                                if self.remain_reader.is_none() {
+                                    self.state = State::PerformOperator(
+                                        data_block,
+                                        fuse_part.location.clone(),
+                                    );
+                                } else {
+                                    self.state = State::ReadRemain {
+                                        part,
+                                        data_block,
+                                        filter: Some(Value::Column(predicate_col)),
+                                    };
+                                }
+                            }
+
                             MutationAction::Update => {
                                 data_block.add_column(BlockEntry::new(
                                     DataType::Boolean,
@@ -319,9 +414,11 @@ impl Processor for MutationSource {
                         chunks,
                         &self.storage_format,
                     )?;
+                    let remain_block =
+                        filter_deleted_rows(remain_block, self.deletion_vector.as_ref())?;
 
                     let remain_block = if let Some(filter) = filter {
-                        // for deletion.
+                        // for deletion, or the updated rows of deletion vector.
                         remain_block.filter_boolean_value(&filter)?
                     } else {
                         remain_block
@@ -342,11 +439,21 @@ impl Processor for MutationSource {
                     .operators
                     .iter()
                     .try_fold(data_block, |input, op| op.execute(&func_ctx, input))?;
-                let inner_meta = Box::new(SerializeDataMeta::SerializeBlock(
-                    SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
-                ));
+                let inner_meta = match self.new_deletion_vector.take() {
+                    Some(deleted) => Box::new(SerializeDataMeta::DeletionVector(
+                        SerializeDeletionVector::create(self.index.clone(), deleted),
+                    )),
+                    None => Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
+                        self.index.clone(),
+                        self.stats_type.clone(),
+                    ))),
+                };
                 let meta: BlockMetaInfoPtr = if self.block_reader.update_stream_columns() {
-                    Box::new(gen_mutation_stream_meta(Some(inner_meta), &path)?)
+                    Box::new(gen_mutation_stream_meta(
+                        Some(inner_meta),
+                        &path,
+                        self.row_offsets.take(),
+                    )?)
                 } else {
                     inner_meta
                 };
@@ -393,8 +500,12 @@ impl Processor for MutationSource {
                             && matches!(self.action, MutationAction::Deletion)
                         {
                             // whole block deletion.
+                            let deleted_rows = fuse_part
+                                .deletion_vector
+                                .as_ref()
+                                .map_or(0, |v| v.deleted_rows as usize);
                             let progress_values = ProgressValues {
-                                rows: fuse_part.nums_rows,
+                                rows: fuse_part.nums_rows - deleted_rows,
                                 bytes: 0,
                             };
                             self.ctx.get_write_progress().incr(&progress_values);
@@ -406,6 +517,8 @@ impl Processor for MutationSource {
                                 DataBlock::empty_with_meta(meta),
                             );
                         } else {
+                            self.deletion_vector =
+                                self.block_reader.read_deletion_vector(fuse_part).await?;
                             let read_res = self
                                 .block_reader
                                 .read_columns_data_by_merge_io(
@@ -452,3 +565,14 @@ impl Processor for MutationSource {
         Ok(())
     }
 }
+
+/// Maps the rows selected by the filter to their offsets in the block,
+/// `live_offsets` maps the rows of the data block to the offsets in the block.
+fn selected_offsets(filter: &Bitmap, live_offsets: &Option<Vec<usize>>) -> Vec<usize> {
+    filter
+        .iter()
+        .enumerate()
+        .filter(|(_, selected)| *selected)
+        .map(|(i, _)| live_offsets.as_ref().map_or(i, |offsets| offsets[i]))
+        .collect()
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
@@ -130,6 +131,8 @@ impl AsyncAccumulatingTransform for ReclusterAggregator {
                 replaced_segments,
                 removed_statistics: self.removed_statistics.clone(),
                 merged_statistics,
+                appended_partitions: BTreeMap::new(),
+                detach_partitions: false,
             });
 
         let meta = CommitMeta::new(
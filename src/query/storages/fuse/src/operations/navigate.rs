@@ -93,48 +93,55 @@ impl FuseTable {
         }
 
         if let Some((snapshot, format_version)) = instant {
-            // Load the table instance by the snapshot
+            self.load_table_at_snapshot(&snapshot, format_version)
+        } else {
+            Err(ErrorCode::TableHistoricalDataNotFound(
+                "No historical data found at given point",
+            ))
+        }
+    }
 
-            // The `seq` of ident that we cloned here is JUST a place holder
-            // we should NOT use it other than a pure place holder.
-            let mut table_info = self.table_info.clone();
+    /// Load the table instance by the snapshot.
+    pub fn load_table_at_snapshot(
+        &self,
+        snapshot: &TableSnapshot,
+        format_version: u64,
+    ) -> Result<Arc<FuseTable>> {
+        // The `seq` of ident that we cloned here is JUST a place holder
+        // we should NOT use it other than a pure place holder.
+        let mut table_info = self.table_info.clone();
 
-            // There are more to be kept in snapshot, like engine_options, ordering keys...
-            // or we could just keep a clone of TableMeta in the snapshot.
-            //
-            // currently, here are what we can recovery from the snapshot:
+        // There are more to be kept in snapshot, like engine_options, ordering keys...
+        // or we could just keep a clone of TableMeta in the snapshot.
+        //
+        // currently, here are what we can recovery from the snapshot:
 
-            // 1. the table schema
-            table_info.meta.schema = Arc::new(snapshot.schema.clone());
+        // 1. the table schema
+        table_info.meta.schema = Arc::new(snapshot.schema.clone());
 
-            // 2. the table option `snapshot_location`
-            let loc = self
-                .meta_location_generator
-                .snapshot_location_from_uuid(&snapshot.snapshot_id, format_version)?;
-            table_info
-                .meta
-                .options
-                .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), loc);
+        // 2. the table option `snapshot_location`
+        let loc = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&snapshot.snapshot_id, format_version)?;
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), loc);
 
-            // 3. The statistics
-            let summary = &snapshot.summary;
-            table_info.meta.statistics = TableStatistics {
-                number_of_rows: summary.row_count,
-                data_bytes: summary.uncompressed_byte_size,
-                compressed_data_bytes: summary.compressed_byte_size,
-                index_data_bytes: summary.index_size,
-                number_of_segments: Some(snapshot.segments.len() as u64),
-                number_of_blocks: Some(summary.block_count),
-            };
+        // 3. The statistics
+        let summary = &snapshot.summary;
+        table_info.meta.statistics = TableStatistics {
+            number_of_rows: summary.row_count,
+            data_bytes: summary.uncompressed_byte_size,
+            compressed_data_bytes: summary.compressed_byte_size,
+            index_data_bytes: summary.index_size,
+            number_of_segments: Some(snapshot.segments.len() as u64),
+            number_of_blocks: Some(summary.block_count),
+        };
 
-            // let's instantiate it
-            let table = FuseTable::do_create(table_info)?;
-            Ok(table.into())
-        } else {
-            Err(ErrorCode::TableHistoricalDataNotFound(
-                "No historical data found at given point",
-            ))
-        }
+        // let's instantiate it
+        let table = FuseTable::do_create(table_info)?;
+        Ok(table.into())
     }
 
     #[async_backtrace::framed]
@@ -164,6 +171,11 @@ impl FuseTable {
                 self.list_by_snapshot_id(snapshot_id.as_str(), time_point)
                     .await
             }
+            Some(NavigationPoint::Tag(tag)) => {
+                let snapshot_id = self.read_tag(tag.as_str()).await?.snapshot_id;
+                self.list_by_snapshot_id(snapshot_id.simple().to_string().as_str(), time_point)
+                    .await
+            }
             None => self.list_by_time_point(time_point).await,
         }?;
 
@@ -0,0 +1,375 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfo;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::ColumnId;
+use common_expression::ComputedExpr;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_transforms::processors::AccumulatingTransform;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
+use common_sql::parse_exprs;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::PartitionSummary;
+use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+
+use crate::io::SegmentsIO;
+use crate::statistics::reduce_block_statistics;
+use crate::statistics::reducers::deduct_statistics_mut;
+use crate::statistics::reducers::merge_statistics_mut;
+use crate::FuseTable;
+
+/// The partition of the rows of a block that is about to be appended.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PartitionMeta {
+    pub partition: String,
+}
+
+#[typetag::serde(name = "partition_meta")]
+impl BlockMetaInfo for PartitionMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        PartitionMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// The meta of a newly written block, together with the partition it belongs to.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PartitionedBlockMeta {
+    pub partition: String,
+    pub block_meta: BlockMeta,
+}
+
+#[typetag::serde(name = "partitioned_block_meta")]
+impl BlockMetaInfo for PartitionedBlockMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        PartitionedBlockMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Splits the appended blocks by the partition expression of the table, so that
+/// each output block holds the rows of exactly one partition.
+pub struct TransformPartitionBlock {
+    func_ctx: FunctionContext,
+    partition_expr: Expr,
+}
+
+impl TransformPartitionBlock {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        partition_expr: Expr,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(AccumulatingTransformer::create(
+            input,
+            output,
+            TransformPartitionBlock {
+                func_ctx,
+                partition_expr,
+            },
+        ))
+    }
+}
+
+impl AccumulatingTransform for TransformPartitionBlock {
+    const NAME: &'static str = "TransformPartitionBlock";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let num_rows = data.num_rows();
+        if num_rows == 0 {
+            return Ok(vec![data]);
+        }
+
+        let evaluator = Evaluator::new(&data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let column = evaluator
+            .run(&self.partition_expr)?
+            .convert_to_full_column(self.partition_expr.data_type(), num_rows);
+
+        let mut partitions: Vec<String> = vec![];
+        let mut positions: HashMap<String, u32> = HashMap::new();
+        let mut indices = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let partition = unsafe { column.index_unchecked(row) }.to_string();
+            let position = match positions.get(&partition) {
+                Some(position) => *position,
+                None => {
+                    let position = partitions.len() as u32;
+                    positions.insert(partition.clone(), position);
+                    partitions.push(partition);
+                    position
+                }
+            };
+            indices.push(position);
+        }
+
+        let blocks = if partitions.len() == 1 {
+            vec![data]
+        } else {
+            data.scatter(&indices, partitions.len())?
+        };
+        blocks
+            .into_iter()
+            .zip(partitions)
+            .filter(|(block, _)| !block.is_empty())
+            .map(|(block, partition)| block.add_meta(Some(Box::new(PartitionMeta { partition }))))
+            .collect()
+    }
+}
+
+impl FuseTable {
+    pub fn partition_key(&self) -> Option<&String> {
+        self.table_info.options().get(OPT_KEY_PARTITION_BY)
+    }
+
+    /// The partition expression of the table, bound to the columns of the blocks
+    /// written to storage (virtual computed columns excluded).
+    pub fn partition_expr(&self, ctx: Arc<dyn TableContext>) -> Result<Option<Expr>> {
+        let partition_key = match self.partition_key() {
+            Some(partition_key) => partition_key,
+            None => return Ok(None),
+        };
+
+        let table: Arc<dyn Table> = Arc::new(self.clone());
+        let mut exprs = parse_exprs(ctx, table, partition_key)?;
+        if exprs.len() != 1 {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Invalid partition key '{}' of table {}",
+                partition_key, self.table_info.desc
+            )));
+        }
+
+        let schema = self.schema();
+        let stored_fields = schema
+            .fields()
+            .iter()
+            .filter(|f| !matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_))))
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        let expr = exprs.remove(0).project_column_ref(|index| {
+            let name = schema.field(*index).name();
+            stored_fields
+                .iter()
+                .position(|f| *f == name.as_str())
+                .unwrap()
+        });
+        Ok(Some(expr))
+    }
+
+    /// Ids of the columns the partition expression of the table depends on.
+    ///
+    /// The column statistics of a partition only cover these columns: the other
+    /// columns may be changed by an update, which keeps the rows in their partitions.
+    pub fn partition_column_ids(&self, ctx: Arc<dyn TableContext>) -> Result<HashSet<ColumnId>> {
+        let partition_key = match self.partition_key() {
+            Some(partition_key) => partition_key,
+            None => return Ok(HashSet::new()),
+        };
+
+        let table: Arc<dyn Table> = Arc::new(self.clone());
+        let schema = self.schema();
+        Ok(parse_exprs(ctx, table, partition_key)?
+            .iter()
+            .flat_map(|expr| expr.column_refs().into_keys())
+            .flat_map(|index| schema.field(index).leaf_column_ids())
+            .collect())
+    }
+
+    /// Drop all the rows of a partition, by removing its segments from the snapshot.
+    ///
+    /// No data is rewritten, the blocks of the dropped partition are left to purge.
+    #[async_backtrace::framed]
+    pub async fn do_drop_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition: &str,
+    ) -> Result<()> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => {
+                return Err(ErrorCode::UnknownTablePartition(format!(
+                    "Unknown partition {} of table {}",
+                    partition, self.table_info.desc
+                )));
+            }
+        };
+        let dropped = match snapshot.partitions.get(partition) {
+            Some(dropped) => dropped,
+            None => {
+                return Err(ErrorCode::UnknownTablePartition(format!(
+                    "Unknown partition {} of table {}",
+                    partition, self.table_info.desc
+                )));
+            }
+        };
+
+        let partitioned = snapshot
+            .partitions
+            .values()
+            .flat_map(|summary| summary.segments.iter())
+            .collect::<HashSet<_>>();
+        if snapshot.segments.iter().any(|s| !partitioned.contains(s)) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Table {} has segments that are not mapped to a partition, use DELETE instead",
+                self.table_info.desc
+            )));
+        }
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.get_operator(), self.schema());
+        let mut removed_statistics = Statistics::default();
+        for segment in segments_io
+            .read_segments::<Arc<CompactSegmentInfo>>(&dropped.segments, false)
+            .await?
+        {
+            merge_statistics_mut(
+                &mut removed_statistics,
+                &segment?.summary,
+                self.cluster_key_id(),
+            );
+        }
+
+        let dropped_segments = dropped.segments.iter().collect::<HashSet<_>>();
+        let mut new_snapshot = TableSnapshot::from_previous(snapshot.as_ref());
+        new_snapshot
+            .segments
+            .retain(|s| !dropped_segments.contains(s));
+        new_snapshot.partitions.remove(partition);
+        deduct_statistics_mut(&mut new_snapshot.summary, &removed_statistics);
+        if new_snapshot.segments.is_empty() {
+            // nothing left, there is no meaningful column statistics any more.
+            new_snapshot.summary = Statistics::default();
+        }
+
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &self.table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+}
+
+/// Adds a newly written segment to the partition it belongs to.
+///
+/// Only the statistics of the columns in `partition_columns` are kept in the partition.
+pub fn add_partition_segment(
+    partitions: &mut BTreeMap<String, PartitionSummary>,
+    partition: String,
+    location: Location,
+    col_stats: &StatisticsOfColumns,
+    partition_columns: &HashSet<ColumnId>,
+) {
+    let appended = PartitionSummary {
+        segments: vec![location],
+        col_stats: col_stats
+            .iter()
+            .filter(|(id, _)| partition_columns.contains(id))
+            .map(|(id, stats)| (*id, stats.clone()))
+            .collect(),
+    };
+    merge_partitions(partitions, &BTreeMap::from([(partition, appended)]));
+}
+
+/// Merges the partitions of `appended` into `partitions`.
+pub fn merge_partitions(
+    partitions: &mut BTreeMap<String, PartitionSummary>,
+    appended: &BTreeMap<String, PartitionSummary>,
+) {
+    for (partition, summary) in appended {
+        match partitions.get_mut(partition) {
+            Some(existing) => {
+                existing.segments = summary
+                    .segments
+                    .iter()
+                    .chain(existing.segments.iter())
+                    .cloned()
+                    .collect();
+                // columns unknown to either side (e.g. added later) are left out,
+                // a missing column statistics never prunes the partition.
+                let col_stats = reduce_block_statistics(&[&existing.col_stats, &summary.col_stats]);
+                existing.col_stats = col_stats
+                    .into_iter()
+                    .filter(|(id, _)| {
+                        existing.col_stats.contains_key(id) && summary.col_stats.contains_key(id)
+                    })
+                    .collect();
+            }
+            None => {
+                partitions.insert(partition.clone(), summary.clone());
+            }
+        }
+    }
+}
+
+/// Re-targets the segments of `partitions` after some of them were replaced or removed.
+///
+/// A segment that replaces another one stays in the partition of the replaced
+/// segment if `keep_replaced` is true, otherwise it is not mapped to any partition.
+pub fn rebase_partitions(
+    partitions: &mut BTreeMap<String, PartitionSummary>,
+    replaced: &HashMap<Location, Location>,
+    removed: &HashSet<Location>,
+    keep_replaced: bool,
+) {
+    for summary in partitions.values_mut() {
+        let mut seen = HashSet::new();
+        summary.segments = summary
+            .segments
+            .iter()
+            .filter_map(|location| {
+                if removed.contains(location) {
+                    None
+                } else if let Some(new_location) = replaced.get(location) {
+                    keep_replaced.then(|| new_location.clone())
+                } else {
+                    Some(location.clone())
+                }
+            })
+            .filter(|location| seen.insert(location.clone()))
+            .collect();
+    }
+    partitions.retain(|_, summary| !summary.segments.is_empty());
+}
@@ -18,6 +18,7 @@ use std::fmt::Formatter;
 use common_catalog::plan::PartInfoPtr;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoPtr;
+use roaring::RoaringBitmap;
 use serde::Deserializer;
 use serde::Serializer;
 
@@ -25,7 +26,7 @@ use crate::io::NativeSourceData;
 
 pub enum DataSource {
     AggIndex(NativeSourceData),
-    Normal(NativeSourceData),
+    Normal((NativeSourceData, Option<RoaringBitmap>)),
 }
 
 pub struct NativeDataSourceMeta {
@@ -19,6 +19,7 @@ use std::collections::VecDeque;
 use std::sync::Arc;
 
 use common_arrow::arrow::array::Array;
+use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::native::read::ArrayIter;
 use common_arrow::parquet::metadata::ColumnDescriptor;
@@ -56,10 +57,12 @@ use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
+use roaring::RoaringBitmap;
 
 use super::fuse_source::fill_internal_column_meta;
 use super::native_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
+use crate::io::live_rows_filter;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::VirtualColumnReader;
@@ -92,6 +95,8 @@ pub struct NativeDeserializeDataTransform {
     // The row offset of current part.
     // It's used to compute the row offset in one block (single data file in one segment).
     offset_in_part: usize,
+    // The deleted rows of current part.
+    deletion_vector: Option<RoaringBitmap>,
 
     read_columns: Vec<usize>,
     // Column ids are columns that have been read out,
@@ -243,6 +248,7 @@ impl NativeDeserializeDataTransform {
                 array_iters: BTreeMap::new(),
                 array_skip_pages: BTreeMap::new(),
                 offset_in_part: 0,
+                deletion_vector: None,
 
                 index_reader,
                 virtual_reader,
@@ -403,7 +409,7 @@ impl NativeDeserializeDataTransform {
                 if let Some((_, sorter, index)) = self.top_k.as_mut() {
                     if !self.array_iters.contains_key(index) {
                         let part = FusePartInfo::from_part(&self.parts[0])?;
-                        let num_rows = part.nums_rows;
+                        let num_rows = self.num_live_rows(part.nums_rows);
 
                         let data_type = self.src_schema.field(*index).data_type().clone();
                         let default_val = self.block_reader.default_vals[*index].clone();
@@ -427,6 +433,7 @@ impl NativeDeserializeDataTransform {
         self.array_iters.clear();
         self.array_skip_pages.clear();
         self.offset_in_part = 0;
+        self.deletion_vector = None;
         self.read_column_ids.clear();
         Ok(())
     }
@@ -450,18 +457,23 @@ impl NativeDeserializeDataTransform {
             }
         }
 
+        let offsets = self.live_row_offsets(num_rows);
+        if let Some(filter) = self.deletion_vector_filter(num_rows) {
+            data_block = data_block.filter_with_bitmap(&filter)?;
+        }
+
         if self.block_reader.query_internal_columns() {
             data_block = fill_internal_column_meta(
                 data_block,
                 fuse_part,
-                None,
+                offsets.clone(),
                 self.base_block_ids.clone(),
             )?;
         }
 
         if self.block_reader.update_stream_columns() {
             let inner_meta = data_block.take_meta();
-            let meta = gen_mutation_stream_meta(inner_meta, &fuse_part.location)?;
+            let meta = gen_mutation_stream_meta(inner_meta, &fuse_part.location, offsets)?;
             data_block = data_block.add_meta(Some(Box::new(meta)))?;
         }
 
@@ -472,6 +484,7 @@ impl NativeDeserializeDataTransform {
         self.array_iters.clear();
         self.array_skip_pages.clear();
         self.offset_in_part = 0;
+        self.deletion_vector = None;
         self.read_column_ids.clear();
         Ok(())
     }
@@ -483,17 +496,56 @@ impl NativeDeserializeDataTransform {
         let fuse_part = FusePartInfo::from_part(&part)?;
 
         let num_rows = fuse_part.nums_rows;
-        let data_block = DataBlock::new(vec![], num_rows);
+        let offsets = self.live_row_offsets(num_rows);
+        let data_block = DataBlock::new(vec![], self.num_live_rows(num_rows));
         let data_block = if self.block_reader.query_internal_columns() {
-            fill_internal_column_meta(data_block, fuse_part, None, self.base_block_ids.clone())?
+            fill_internal_column_meta(data_block, fuse_part, offsets, self.base_block_ids.clone())?
         } else {
             data_block
         };
 
+        self.deletion_vector = None;
         self.add_block(data_block)?;
         Ok(())
     }
 
+    fn num_live_rows(&self, num_rows: usize) -> usize {
+        self.deletion_vector_filter(num_rows)
+            .map_or(num_rows, |filter| num_rows - filter.unset_bits())
+    }
+
+    /// The filter of the live rows in the next `num_rows` rows of current part.
+    fn deletion_vector_filter(&self, num_rows: usize) -> Option<Bitmap> {
+        self.deletion_vector
+            .as_ref()
+            .map(|deleted| live_rows_filter(deleted, self.offset_in_part, num_rows))
+    }
+
+    fn live_row_offsets(&self, num_rows: usize) -> Option<Vec<usize>> {
+        self.deletion_vector_filter(num_rows).map(|filter| {
+            filter
+                .iter()
+                .enumerate()
+                .filter(|(_, live)| *live)
+                .map(|(i, _)| self.offset_in_part + i)
+                .collect()
+        })
+    }
+
+    /// Skip the deleted rows by merging the deletion vector into the filter.
+    fn apply_deletion_vector(
+        &self,
+        filter: Value<BooleanType>,
+        num_rows: usize,
+    ) -> Value<BooleanType> {
+        match (self.deletion_vector_filter(num_rows), filter) {
+            (None, filter) => filter,
+            (Some(live), Value::Scalar(true)) => Value::Column(live),
+            (Some(_), Value::Scalar(false)) => Value::Scalar(false),
+            (Some(live), Value::Column(bitmap)) => Value::Column(&bitmap & &live),
+        }
+    }
+
     /// Update the number of pages that can be skipped per column.
     fn finish_process_skip_page(&mut self) -> Result<()> {
         self.skipped_page += 1;
@@ -571,7 +623,12 @@ impl Processor for NativeDeserializeDataTransform {
                     self.output_data = Some(block);
                     return self.finish_process();
                 }
-                DataSource::Normal(data) => data,
+                DataSource::Normal((data, deletion_vector)) => {
+                    if !self.inited {
+                        self.deletion_vector = deletion_vector.take();
+                    }
+                    data
+                }
             };
 
             // this means it's empty projection
@@ -727,6 +784,7 @@ impl Processor for NativeDeserializeDataTransform {
                             .map_err(|e| e.add_message("eval prewhere filter failed:"))?
                             .try_downcast::<BooleanType>()
                             .unwrap();
+                        let filter = self.apply_deletion_vector(filter, prewhere_block.num_rows());
 
                         // Step 3: Apply the filter, if it's all filtered, we can skip the remain columns.
                         if FilterHelpers::is_all_unset(&filter) {
@@ -799,6 +857,12 @@ impl Processor for NativeDeserializeDataTransform {
             self.add_virtual_columns(arrays, &self.src_schema, &self.virtual_columns, &mut block)?;
 
             let origin_num_rows = block.num_rows();
+            let filter = match filter {
+                None if self.deletion_vector.is_some() => {
+                    Some(self.apply_deletion_vector(Value::Scalar(true), origin_num_rows))
+                }
+                filter => filter,
+            };
             let block = if let Some(filter) = &filter {
                 block.filter_boolean_value(filter)?
             } else {
@@ -808,20 +872,19 @@ impl Processor for NativeDeserializeDataTransform {
             // Step 8: Fill `InternalColumnMeta` as `DataBlock.meta` if query internal columns,
             // `TransformAddInternalColumns` will generate internal columns using `InternalColumnMeta` in next pipeline.
             let mut block = block.resort(&self.src_schema, &self.output_schema)?;
+            let offsets = if let Some(Value::Column(bitmap)) = filter.as_ref() {
+                (self.offset_in_part..self.offset_in_part + origin_num_rows)
+                    .filter(|i| unsafe { bitmap.get_bit_unchecked(i - self.offset_in_part) })
+                    .collect::<Vec<_>>()
+            } else {
+                (self.offset_in_part..self.offset_in_part + origin_num_rows).collect()
+            };
             if self.block_reader.query_internal_columns() {
-                let offsets = if let Some(Value::Column(bitmap)) = filter.as_ref() {
-                    (self.offset_in_part..self.offset_in_part + origin_num_rows)
-                        .filter(|i| unsafe { bitmap.get_bit_unchecked(i - self.offset_in_part) })
-                        .collect()
-                } else {
-                    (self.offset_in_part..self.offset_in_part + origin_num_rows).collect()
-                };
-
                 let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
                 block = fill_internal_column_meta(
                     block,
                     fuse_part,
-                    Some(offsets),
+                    Some(offsets.clone()),
                     self.base_block_ids.clone(),
                 )?;
             }
@@ -829,7 +892,9 @@ impl Processor for NativeDeserializeDataTransform {
             if self.block_reader.update_stream_columns() {
                 let inner_meta = block.take_meta();
                 let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
-                let meta = gen_mutation_stream_meta(inner_meta, &fuse_part.location)?;
+                // the rows of the deletion vector are skipped, keep the origin row numbers.
+                let offsets = self.deletion_vector.as_ref().map(|_| offsets);
+                let meta = gen_mutation_stream_meta(inner_meta, &fuse_part.location, offsets)?;
                 block = block.add_meta(Some(Box::new(meta)))?;
             }
 
@@ -141,8 +141,15 @@ impl SyncSource for ReadNativeDataSource<true> {
                 )? {
                     return Ok(Some(DataBlock::empty()));
                 }
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // The aggregating index is built on the whole block,
+                // it can not be used once some rows of the block are deleted.
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| fuse_part.deletion_vector.is_none())
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -158,8 +165,8 @@ impl SyncSource for ReadNativeDataSource<true> {
                     }
                 }
 
+                let deletion_vector = self.block_reader.sync_read_deletion_vector(fuse_part)?;
                 if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
                     let loc =
                         TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
@@ -173,17 +180,18 @@ impl SyncSource for ReadNativeDataSource<true> {
                         source_data.append(&mut virtual_source_data);
                         return Ok(Some(DataBlock::empty_with_meta(
                             NativeDataSourceMeta::create(vec![part.clone()], vec![
-                                DataSource::Normal(source_data),
+                                DataSource::Normal((source_data, deletion_vector)),
                             ]),
                         )));
                     }
                 }
 
                 Ok(Some(DataBlock::empty_with_meta(
-                    NativeDataSourceMeta::create(vec![part.clone()], vec![DataSource::Normal(
+                    NativeDataSourceMeta::create(vec![part.clone()], vec![DataSource::Normal((
                         self.block_reader
                             .sync_read_native_columns_data(&part, &None)?,
-                    )]),
+                        deletion_vector,
+                    ))]),
                 )))
             }
         }
@@ -248,7 +256,11 @@ impl Processor for ReadNativeDataSource<false> {
                     let handler =
                         tokio::spawn(async_backtrace::location!(query_id).frame(async move {
                             let fuse_part = FusePartInfo::from_part(&part)?;
-                            if let Some(index_reader) = index_reader.as_ref() {
+                            if let Some(index_reader) = index_reader
+                                .as_ref()
+                                .as_ref()
+                                .filter(|_| fuse_part.deletion_vector.is_none())
+                            {
                                 let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -260,6 +272,8 @@ impl Processor for ReadNativeDataSource<false> {
                                 }
                             }
 
+                            let deletion_vector =
+                                block_reader.read_deletion_vector(fuse_part).await?;
                             if let Some(virtual_reader) = virtual_reader.as_ref() {
                                 let loc = TableMetaLocationGenerator::gen_virtual_block_location(
                                     &fuse_part.location,
@@ -277,15 +291,16 @@ impl Processor for ReadNativeDataSource<false> {
                                         )
                                         .await?;
                                     source_data.append(&mut virtual_source_data);
-                                    return Ok(DataSource::Normal(source_data));
+                                    return Ok(DataSource::Normal((source_data, deletion_vector)));
                                 }
                             }
 
-                            Ok(DataSource::Normal(
+                            Ok(DataSource::Normal((
                                 block_reader
                                     .async_read_native_columns_data(&part, &ctx, &None)
                                     .await?,
-                            ))
+                                deletion_vector,
+                            )))
                         }));
                     handler.await.unwrap()
                 });
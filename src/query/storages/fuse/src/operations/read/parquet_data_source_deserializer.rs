@@ -39,6 +39,7 @@ use common_pipeline_core::processors::ProcessorPtr;
 use super::fuse_source::fill_internal_column_meta;
 use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
+use crate::io::live_rows_filter;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::UncompressedBuffer;
@@ -193,7 +194,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                DataSource::Normal((data, virtual_data)) => {
+                DataSource::Normal((data, virtual_data, deletion_vector)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FusePartInfo::from_part(&part)?;
@@ -216,6 +217,22 @@ impl Processor for DeserializeDataTransform {
                         )?;
                     }
 
+                    // Skip the rows deleted by the deletion vector, keep the offsets
+                    // of the live rows to generate the internal columns.
+                    let mut offsets = None;
+                    if let Some(deleted) = &deletion_vector {
+                        let filter = live_rows_filter(deleted, 0, part.nums_rows);
+                        offsets = Some(
+                            filter
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, live)| *live)
+                                .map(|(i, _)| i)
+                                .collect::<Vec<_>>(),
+                        );
+                        data_block = data_block.filter_with_bitmap(&filter)?;
+                    }
+
                     // Perf.
                     {
                         metrics_inc_remote_io_deserialize_milliseconds(
@@ -238,14 +255,14 @@ impl Processor for DeserializeDataTransform {
                         data_block = fill_internal_column_meta(
                             data_block,
                             part,
-                            None,
+                            offsets.clone(),
                             self.base_block_ids.clone(),
                         )?;
                     }
 
                     if self.block_reader.update_stream_columns() {
                         let inner_meta = data_block.take_meta();
-                        let meta = gen_mutation_stream_meta(inner_meta, &part.location)?;
+                        let meta = gen_mutation_stream_meta(inner_meta, &part.location, offsets)?;
                         data_block = data_block.add_meta(Some(Box::new(meta)))?;
                     }
 
@@ -130,8 +130,15 @@ impl SyncSource for ReadParquetDataSource<true> {
                     return Ok(Some(DataBlock::empty()));
                 }
 
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // The aggregating index is built on the whole block,
+                // it can not be used once some rows of the block are deleted.
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| fuse_part.deletion_vector.is_none())
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -151,7 +158,6 @@ impl SyncSource for ReadParquetDataSource<true> {
 
                 // If virtual column file exists, read the data from the virtual columns directly.
                 let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
                     let loc =
                         TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
@@ -174,10 +180,15 @@ impl SyncSource for ReadParquetDataSource<true> {
                     &part,
                     ignore_column_ids,
                 )?;
+                let deletion_vector = self.block_reader.sync_read_deletion_vector(fuse_part)?;
 
                 Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
                     vec![part],
-                    vec![DataSource::Normal((source, virtual_source))],
+                    vec![DataSource::Normal((
+                        source,
+                        virtual_source,
+                        deletion_vector,
+                    ))],
                 ))))
             }
         }
@@ -243,7 +254,11 @@ impl Processor for ReadParquetDataSource<false> {
                     tokio::spawn(async_backtrace::location!().frame(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        if let Some(index_reader) = index_reader
+                            .as_ref()
+                            .as_ref()
+                            .filter(|_| part.deletion_vector.is_none())
+                        {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
@@ -285,8 +300,13 @@ impl Processor for ReadParquetDataSource<false> {
                                 ignore_column_ids,
                             )
                             .await?;
+                        let deletion_vector = block_reader.read_deletion_vector(part).await?;
 
-                        Ok(DataSource::Normal((source, virtual_source)))
+                        Ok(DataSource::Normal((
+                            source,
+                            virtual_source,
+                            deletion_vector,
+                        )))
                     }))
                     .await
                     .unwrap()
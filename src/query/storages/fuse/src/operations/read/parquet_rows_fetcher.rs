@@ -181,6 +181,11 @@ impl<const BLOCKING_IO: bool> ParquetRowsFetcher<BLOCKING_IO> {
 
         let arrow_schema = self.schema.to_arrow();
         let column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(&self.schema));
+        let evolved_column_ids = self
+            .reader
+            .evolved_column_ids()
+            .cloned()
+            .collect::<Vec<_>>();
 
         for row_id in row_ids {
             let (prefix, _) = split_row_id(*row_id);
@@ -210,6 +215,7 @@ impl<const BLOCKING_IO: bool> ParquetRowsFetcher<BLOCKING_IO> {
                 &column_nodes,
                 None,
                 &self.projection,
+                &evolved_column_ids,
             );
 
             self.part_map.insert(prefix, part_info);
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -27,8 +28,10 @@ use common_catalog::plan::TopK;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::Scalar;
 use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::field_default_value;
 use common_storage::ColumnNodes;
 use log::debug;
@@ -41,8 +44,12 @@ use storages_common_cache_manager::CachedObject;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
 use storages_common_pruner::BlockMetaIndex;
+use storages_common_pruner::RangePrunerCreator;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::fuse_part::FusePartInfo;
 use crate::pruning::FusePruner;
@@ -78,10 +85,13 @@ impl FuseTable {
                     nodes_num = cluster.nodes.len();
                 }
 
-                if (!dry_run && snapshot.segments.len() > nodes_num) || is_lazy {
-                    let mut segments = Vec::with_capacity(snapshot.segments.len());
-                    for (idx, segment_location) in snapshot.segments.iter().enumerate() {
-                        segments.push(FuseLazyPartInfo::create(idx, segment_location.clone()))
+                let snapshot_segments =
+                    self.prune_table_partitions(&ctx, &snapshot, push_downs.as_ref())?;
+
+                if (!dry_run && snapshot_segments.len() > nodes_num) || is_lazy {
+                    let mut segments = Vec::with_capacity(snapshot_segments.len());
+                    for (idx, segment_location) in snapshot_segments.into_iter() {
+                        segments.push(FuseLazyPartInfo::create(idx, segment_location))
                     }
 
                     return Ok((
@@ -89,7 +99,7 @@ impl FuseTable {
                             Some(snapshot_loc),
                             snapshot.summary.row_count as usize,
                             snapshot.summary.compressed_byte_size as usize,
-                            snapshot.segments.len(),
+                            segments.len(),
                             snapshot.segments.len(),
                         ),
                         Partitions::create(PartitionsShuffleKind::Mod, segments, true),
@@ -99,11 +109,11 @@ impl FuseTable {
                 let snapshot_loc = Some(snapshot_loc);
                 let table_schema = self.schema_with_stream();
                 let summary = snapshot.summary.block_count as usize;
-                let mut segments_location = Vec::with_capacity(snapshot.segments.len());
-                for (idx, segment_location) in snapshot.segments.iter().enumerate() {
+                let mut segments_location = Vec::with_capacity(snapshot_segments.len());
+                for (idx, segment_location) in snapshot_segments.into_iter() {
                     segments_location.push(SegmentLocation {
                         segment_idx: idx,
-                        location: segment_location.clone(),
+                        location: segment_location,
                         snapshot_loc: snapshot_loc.clone(),
                     });
                 }
@@ -122,6 +132,58 @@ impl FuseTable {
         }
     }
 
+    // The segments of the snapshot, with their indexes, that are left after pruning
+    // the partitions of a partitioned table by the filter of the push downs.
+    fn prune_table_partitions(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot: &TableSnapshot,
+        push_downs: Option<&PushDownInfo>,
+    ) -> Result<Vec<(usize, Location)>> {
+        let segments = snapshot.segments.iter().cloned().enumerate();
+        let filter_expr = push_downs
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS));
+        let filter_expr = match filter_expr {
+            Some(filter_expr) if !snapshot.partitions.is_empty() => filter_expr,
+            _ => return Ok(segments.collect()),
+        };
+
+        let table_schema = self.schema_with_stream();
+        let range_pruner = RangePrunerCreator::try_create(
+            ctx.get_function_context()?,
+            &table_schema,
+            Some(&filter_expr),
+        )?;
+        // only the partition-key columns are known to be unchanged by updates, the
+        // statistics of the other columns may be stale.
+        let partition_columns = self.partition_column_ids(ctx.clone())?;
+        let pruned = snapshot
+            .partitions
+            .values()
+            .filter(|summary| {
+                let col_stats = summary
+                    .col_stats
+                    .iter()
+                    .filter(|(id, _)| partition_columns.contains(id))
+                    .map(|(id, stats)| (*id, stats.clone()))
+                    .collect::<StatisticsOfColumns>();
+                !range_pruner.should_keep(&col_stats, None)
+            })
+            .flat_map(|summary| summary.segments.iter())
+            .collect::<HashSet<_>>();
+        if !pruned.is_empty() {
+            info!(
+                "prune {} segments of table {} by partition",
+                pruned.len(),
+                self.table_info.desc
+            );
+        }
+        Ok(segments
+            .filter(|(_, location)| !pruned.contains(location))
+            .collect())
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn prune_snapshot_blocks(
@@ -312,6 +374,7 @@ impl FuseTable {
             Some(extras) => match &extras.projection {
                 None => Self::all_columns_partitions(schema, &block_metas, top_k.clone(), limit),
                 Some(projection) => Self::projection_partitions(
+                    schema,
                     &block_metas,
                     column_nodes,
                     projection,
@@ -350,7 +413,7 @@ impl FuseTable {
 
         let mut remaining = limit;
         for (block_meta_index, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
                 block_meta_index,
@@ -375,6 +438,7 @@ impl FuseTable {
     }
 
     fn projection_partitions(
+        schema: Option<&TableSchemaRef>,
         block_metas: &[(Option<BlockMetaIndex>, Arc<BlockMeta>)],
         column_nodes: &ColumnNodes,
         projection: &Projection,
@@ -389,6 +453,17 @@ impl FuseTable {
         }
 
         let columns = projection.project_column_nodes(column_nodes).unwrap();
+        let evolved_column_ids = schema
+            .filter(|schema| schema.has_evolved_fields())
+            .map(|schema| {
+                columns
+                    .iter()
+                    .filter_map(|column| column.leaf_column_ids.first())
+                    .flat_map(|column_id| schema.previous_fields_of(*column_id))
+                    .flat_map(|field| field.leaf_column_ids())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
         let mut remaining = limit;
 
         for (block_meta_index, block_meta) in block_metas.iter() {
@@ -398,9 +473,10 @@ impl FuseTable {
                 column_nodes,
                 top_k.clone(),
                 projection,
+                &evolved_column_ids,
             ));
 
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
 
             statistics.read_rows += rows;
             for column in &columns {
@@ -473,6 +549,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 
@@ -482,6 +559,7 @@ impl FuseTable {
         column_nodes: &ColumnNodes,
         top_k: Option<(TopK, Scalar)>,
         projection: &Projection,
+        evolved_column_ids: &[ColumnId],
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(projection.len());
         let mut columns_stat = HashMap::with_capacity(projection.len());
@@ -498,6 +576,13 @@ impl FuseTable {
                 }
             }
         }
+        // blocks written before the projected columns evolved only have
+        // the columns of their previous shapes.
+        for column_id in evolved_column_ids {
+            if let Some(column_meta) = meta.col_metas.get(column_id) {
+                columns_meta.insert(*column_id, column_meta.clone());
+            }
+        }
 
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
@@ -521,6 +606,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 }
@@ -162,8 +162,12 @@ impl FuseTable {
 
         pipeline.add_transform(|input, output| {
             let base_segments = base_snapshot.segments.clone();
-            let mutation_aggregator =
-                TableMutationAggregator::new(self, ctx.clone(), base_segments, mutation_kind);
+            let mutation_aggregator = TableMutationAggregator::try_create(
+                self,
+                ctx.clone(),
+                base_segments,
+                mutation_kind,
+            )?;
             Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
                 input,
                 output,
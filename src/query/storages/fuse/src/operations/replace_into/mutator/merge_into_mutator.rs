@@ -48,7 +48,9 @@ use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 
+use crate::io::filter_deleted_rows;
 use crate::io::read::bloom::block_filter_reader::BloomBlockFilterReader;
+use crate::io::read_block_deletion_vector;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
@@ -387,6 +389,9 @@ impl AggregationContext {
             return Ok(None);
         }
 
+        // rows already deleted by the deletion vector are not visible
+        let deletion_vector =
+            read_block_deletion_vector(&self.key_column_reader.operator, block_meta).await?;
         let key_columns_data = read_block(
             self.write_settings.storage_format,
             &self.key_column_reader,
@@ -394,6 +399,7 @@ impl AggregationContext {
             &self.read_settings,
         )
         .await?;
+        let key_columns_data = filter_deleted_rows(key_columns_data, deletion_vector.as_ref())?;
 
         let num_rows = key_columns_data.num_rows();
 
@@ -448,7 +454,7 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == num_rows {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
@@ -476,6 +482,8 @@ impl AggregationContext {
                 // read the remaining columns
                 let remain_columns_data =
                     self.read_block(remain_columns_reader, block_meta).await?;
+                let remain_columns_data =
+                    filter_deleted_rows(remain_columns_data, deletion_vector.as_ref())?;
 
                 // remove the deleted rows
                 let remain_columns_data_after_deletion =
@@ -12,14 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::TableSchema;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
+use futures::future::try_join_all;
+use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::Location;
 
+use crate::io::SegmentsIO;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -73,4 +81,134 @@ impl FuseTable {
 
         reply.map(|_| ())
     }
+
+    /// Restores the table to the given point, both the data and the schema.
+    ///
+    /// Unlike `do_revert_to`, the table meta is committed even if the snapshot of the point
+    /// is the current one, so that the columns dropped (or modified) without writing a new
+    /// snapshot are restored as well. Before committing, all the files referenced by the
+    /// snapshot of the point are checked, restoring to a point whose files have been purged
+    /// is rejected.
+    #[async_backtrace::framed]
+    pub async fn do_restore_to(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        point: &NavigationPoint,
+    ) -> Result<()> {
+        // 1. navigate to the point
+        let table = self.navigate_to(point).await?;
+        let table_restoring_to = FuseTable::try_from_table(table.as_ref())?;
+        let snapshot = table_restoring_to.read_table_snapshot().await?;
+        let snapshot_location = table_restoring_to.snapshot_loc().await?.ok_or_else(|| {
+            ErrorCode::Internal(
+                "internal error, fuse table which navigated to given point has no snapshot location",
+            )
+        })?;
+
+        // 2. make sure that the files of the snapshot are still there
+        if let Some(snapshot) = &snapshot {
+            table_restoring_to
+                .check_snapshot_files(ctx.clone(), &snapshot.segments)
+                .await?;
+        }
+
+        // 3. prepare table meta which being restored to
+        let current_meta = &self.table_info.meta;
+        let mut new_table_meta = table_restoring_to.table_info.meta.clone();
+        let schema = new_table_meta.schema.as_ref();
+        // keep the column ids allocated after the point unused, in case that
+        // there are still blocks (of the snapshots after the point) referencing them.
+        new_table_meta.schema = Arc::new(TableSchema::new_from_column_ids(
+            schema.fields().clone(),
+            schema.metadata.clone(),
+            schema
+                .next_column_id()
+                .max(current_meta.schema.next_column_id()),
+        ));
+        if !current_meta.field_comments.is_empty() {
+            new_table_meta.field_comments = new_table_meta
+                .schema
+                .fields()
+                .iter()
+                .map(|field| {
+                    current_meta
+                        .schema
+                        .index_of(field.name())
+                        .ok()
+                        .and_then(|i| current_meta.field_comments.get(i).cloned())
+                        .unwrap_or_default()
+                })
+                .collect();
+        }
+
+        // 4. commit, using the CURRENT version as the base table version
+        let catalog = ctx.get_catalog(&current_meta.catalog).await?;
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+        catalog.update_table_meta(&self.table_info, req).await?;
+
+        Self::write_last_snapshot_hint(
+            &table_restoring_to.operator,
+            &table_restoring_to.meta_location_generator,
+            snapshot_location,
+        )
+        .await;
+        Ok(())
+    }
+
+    async fn check_snapshot_files(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        segment_locations: &[Location],
+    ) -> Result<()> {
+        let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+
+        let mut locations = vec![];
+        for chunk in segment_locations.chunks(max_io_requests) {
+            let segments = segments_io
+                .read_segments::<Arc<CompactSegmentInfo>>(chunk, false)
+                .await?;
+            for (segment, (segment_location, _)) in segments.into_iter().zip(chunk) {
+                let segment = segment.map_err(|e| {
+                    ErrorCode::TableHistoricalDataNotFound(format!(
+                        "segment {} of the snapshot to restore to is not available: {}",
+                        segment_location,
+                        e.message()
+                    ))
+                })?;
+                for block in segment.block_metas()? {
+                    locations.push(block.location.0.clone());
+                    if let Some((location, _)) = &block.bloom_filter_index_location {
+                        locations.push(location.clone());
+                    }
+                    if let Some(deletion_vector) = &block.deletion_vector {
+                        locations.push(deletion_vector.location.0.clone());
+                    }
+                }
+            }
+        }
+
+        for chunk in locations.chunks(max_io_requests) {
+            let exists = try_join_all(
+                chunk
+                    .iter()
+                    .map(|location| self.operator.is_exist(location)),
+            )
+            .await?;
+            if let Some((location, _)) = chunk.iter().zip(exists).find(|(_, exist)| !exist) {
+                return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "file {} of the snapshot to restore to has been purged",
+                    location
+                )));
+            }
+        }
+        Ok(())
+    }
 }
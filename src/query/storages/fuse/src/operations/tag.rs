@@ -0,0 +1,248 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::info;
+use serde::Deserialize;
+use serde::Serialize;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::SnapshotId;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_TAGS;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+/// A named reference to a snapshot of a table.
+///
+/// Tags are kept in the options of the table. The tagged snapshot, and all the
+/// files it references, are kept by purge and vacuum until the tag is dropped or expires.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SnapshotTag {
+    pub name: String,
+    pub snapshot_id: SnapshotId,
+    pub snapshot_location: String,
+    pub created_on: DateTime<Utc>,
+    pub expire_at: Option<DateTime<Utc>>,
+}
+
+impl SnapshotTag {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expire_at.map_or(false, |expire_at| expire_at <= now)
+    }
+}
+
+impl FuseTable {
+    /// Tag the current snapshot of the table.
+    ///
+    /// The tag is kept in the options of the table, and is committed with the seq of
+    /// the table meta it is checked against, so that concurrent creations of a tag
+    /// with the same name can not both succeed.
+    #[async_backtrace::framed]
+    pub async fn do_create_tag(
+        &self,
+        ctx: &dyn TableContext,
+        name: &str,
+        retain_days: Option<u64>,
+    ) -> Result<()> {
+        check_tag_name(name)?;
+
+        let snapshot_location = match self.snapshot_loc().await? {
+            Some(location) => location,
+            None => {
+                return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "Cannot create tag '{}', table {} has no snapshot",
+                    name, self.table_info.desc
+                )));
+            }
+        };
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: snapshot_location.clone(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(&snapshot_location),
+            put_cache: true,
+        };
+        let snapshot = reader.read(&params).await?;
+
+        let created_on = Utc::now();
+        let tag = SnapshotTag {
+            name: name.to_string(),
+            snapshot_id: snapshot.snapshot_id,
+            snapshot_location,
+            created_on,
+            expire_at: retain_days.map(|days| created_on + Duration::days(days as i64)),
+        };
+        self.update_table_options(ctx, |options| {
+            let mut tags = parse_tags(options)?;
+            if tags.contains_key(name) {
+                return Err(ErrorCode::TableTagAlreadyExists(format!(
+                    "Tag '{}' already exists in table {}",
+                    name, self.table_info.desc
+                )));
+            }
+            tags.insert(name.to_string(), tag.clone());
+            set_tags(options, &tags)
+        })
+        .await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn do_drop_tag(&self, ctx: &dyn TableContext, name: &str) -> Result<()> {
+        self.update_table_options(ctx, |options| {
+            let mut tags = parse_tags(options)?;
+            if tags.remove(name).is_none() {
+                return Err(self.unknown_tag(name));
+            }
+            set_tags(options, &tags)
+        })
+        .await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_tag(&self, name: &str) -> Result<SnapshotTag> {
+        let mut tags = parse_tags(self.table_info.options())?;
+        tags.remove(name).ok_or_else(|| self.unknown_tag(name))
+    }
+
+    /// All the tags of the table, including the expired ones, ordered by name.
+    #[async_backtrace::framed]
+    pub async fn list_tags(&self) -> Result<Vec<SnapshotTag>> {
+        let tags = parse_tags(self.table_info.options())?;
+        Ok(tags.into_values().collect())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn navigate_to_tag(&self, name: &str) -> Result<Arc<FuseTable>> {
+        let tag = self.read_tag(name).await?;
+        if tag.is_expired(Utc::now()) {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "Tag '{}' of table {} has expired",
+                name, self.table_info.desc
+            )));
+        }
+        let snapshot = match self.read_tagged_snapshot(&tag).await? {
+            Some(snapshot) => snapshot,
+            None => {
+                return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "Snapshot of tag '{}' not found",
+                    name
+                )));
+            }
+        };
+        let format_version = TableMetaLocationGenerator::snapshot_version(&tag.snapshot_location);
+        self.load_table_at_snapshot(&snapshot, format_version)
+    }
+
+    /// Read the snapshot referenced by the tag, `None` if it has been purged.
+    #[async_backtrace::framed]
+    pub async fn read_tagged_snapshot(
+        &self,
+        tag: &SnapshotTag,
+    ) -> Result<Option<Arc<TableSnapshot>>> {
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: tag.snapshot_location.clone(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(&tag.snapshot_location),
+            put_cache: false,
+        };
+        match reader.read(&params).await {
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => Ok(None),
+            Err(e) => Err(e),
+            Ok(snapshot) => Ok(Some(snapshot)),
+        }
+    }
+
+    /// Remove the tags whose retention has passed, the snapshots they reference
+    /// can be purged afterwards.
+    #[async_backtrace::framed]
+    pub async fn purge_expired_tags(&self, ctx: &dyn TableContext) -> Result<()> {
+        let now = Utc::now();
+        if !self
+            .list_tags()
+            .await?
+            .iter()
+            .any(|tag| tag.is_expired(now))
+        {
+            return Ok(());
+        }
+        self.update_table_options(ctx, |options| {
+            let mut tags = parse_tags(options)?;
+            tags.retain(|name, tag| {
+                let expired = tag.is_expired(now);
+                if expired {
+                    info!(
+                        "remove expired tag '{}' of table {}",
+                        name, self.table_info.desc
+                    );
+                }
+                !expired
+            });
+            set_tags(options, &tags)
+        })
+        .await
+    }
+
+    fn unknown_tag(&self, name: &str) -> ErrorCode {
+        ErrorCode::UnknownTableTag(format!(
+            "Unknown tag '{}' of table {}",
+            name, self.table_info.desc
+        ))
+    }
+}
+
+fn parse_tags(options: &BTreeMap<String, String>) -> Result<BTreeMap<String, SnapshotTag>> {
+    match options.get(OPT_KEY_SNAPSHOT_TAGS) {
+        Some(value) => Ok(serde_json::from_str(value)?),
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+fn set_tags(
+    options: &mut BTreeMap<String, String>,
+    tags: &BTreeMap<String, SnapshotTag>,
+) -> Result<()> {
+    if tags.is_empty() {
+        options.remove(OPT_KEY_SNAPSHOT_TAGS);
+    } else {
+        options.insert(
+            OPT_KEY_SNAPSHOT_TAGS.to_string(),
+            serde_json::to_string(tags)?,
+        );
+    }
+    Ok(())
+}
+
+fn check_tag_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(ErrorCode::BadArguments(format!(
+            "Invalid tag name '{}', only letters, digits, '_' and '-' are allowed",
+            name
+        )));
+    }
+    Ok(())
+}
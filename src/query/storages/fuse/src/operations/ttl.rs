@@ -0,0 +1,228 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::lock::Lock;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::parse_exprs;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::table::OPT_KEY_TTL;
+
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::operations::common::AbortOperation;
+use crate::operations::rebase_partitions;
+use crate::statistics::reduce_block_metas;
+use crate::statistics::reducers::deduct_statistics_mut;
+use crate::FuseTable;
+
+/// The blocks (and their rows) removed from a table because all of their rows are expired.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExpiredBlockStats {
+    pub block_count: u64,
+    pub row_count: u64,
+    /// The blocks kept because only some of their rows are (or may be) expired.
+    pub partly_expired_block_count: u64,
+}
+
+enum BlockExpiration {
+    None,
+    Partly,
+    All,
+}
+
+impl FuseTable {
+    pub fn ttl(&self) -> Option<&String> {
+        self.table_info.options().get(OPT_KEY_TTL)
+    }
+
+    /// The predicate of the expired rows of the table, if the table has a TTL.
+    pub fn ttl_predicate(&self) -> Option<String> {
+        self.ttl().map(|ttl| format!("({}) < now()", ttl))
+    }
+
+    /// Remove the blocks whose rows are all expired, by removing them from the segments.
+    ///
+    /// A block is expired if the TTL expression of the max value of the TTL column
+    /// is before now, the TTL expression is known to be monotonic (checked while
+    /// binding `TTL`). No data is read or rewritten, the blocks which are only partly
+    /// expired are left to `DELETE`, the removed blocks are left to purge.
+    ///
+    /// The table lock is held while committing, the segments appended concurrently are
+    /// kept by the retries of the commit.
+    #[async_backtrace::framed]
+    pub async fn do_expire_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        lock: Arc<dyn Lock>,
+    ) -> Result<ExpiredBlockStats> {
+        let predicate = match self.ttl_predicate() {
+            Some(predicate) => predicate,
+            None => {
+                return Err(ErrorCode::InvalidTableTtl(format!(
+                    "Table {} has no TTL",
+                    self.table_info.desc
+                )));
+            }
+        };
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(ExpiredBlockStats::default()),
+        };
+
+        let table: Arc<dyn Table> = Arc::new(self.clone());
+        let expr = parse_exprs(ctx.clone(), table, &predicate)?.remove(0);
+        let column_refs = expr.column_refs();
+        let (column_index, data_type) = match column_refs.into_iter().next() {
+            Some(column) => column,
+            None => {
+                return Err(ErrorCode::InvalidTableTtl(format!(
+                    "Invalid TTL '{}' of table {}",
+                    predicate, self.table_info.desc
+                )));
+            }
+        };
+        let column_id = self.schema().field(column_index).column_id();
+        let expr = expr.project_column_ref(|_| 0);
+        let func_ctx = ctx.get_function_context()?;
+        let is_expired = |value: &Scalar| -> Result<bool> {
+            // null values never expire.
+            if *value == Scalar::Null {
+                return Ok(false);
+            }
+            let data = DataBlock::new(
+                vec![BlockEntry::new(
+                    data_type.clone(),
+                    Value::Scalar(value.clone()),
+                )],
+                1,
+            );
+            let evaluator = Evaluator::new(&data, &func_ctx, &BUILTIN_FUNCTIONS);
+            let expired = evaluator.run(&expr)?;
+            Ok(matches!(expired.index(0), Some(ScalarRef::Boolean(true))))
+        };
+        let block_expiration = |block: &BlockMeta| -> Result<BlockExpiration> {
+            let stats = match block.col_stats.get(&column_id) {
+                Some(stats) => stats,
+                // blocks written before the column was added, leave them to `DELETE`.
+                None => return Ok(BlockExpiration::Partly),
+            };
+            Ok(if stats.null_count == 0 && is_expired(&stats.max)? {
+                BlockExpiration::All
+            } else if is_expired(&stats.min)? {
+                BlockExpiration::Partly
+            } else {
+                BlockExpiration::None
+            })
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.get_operator(), self.schema());
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+        let segments = segments_io
+            .read_segments::<Arc<CompactSegmentInfo>>(&snapshot.segments, true)
+            .await?;
+        let mut new_segments = Vec::with_capacity(snapshot.segments.len());
+        let mut replaced = HashMap::new();
+        let mut removed = HashSet::new();
+        let mut expired_blocks = vec![];
+        let mut partly_expired_block_count = 0;
+        for (location, segment) in snapshot.segments.iter().zip(segments) {
+            let blocks = segment?.block_metas()?;
+            let num_blocks = blocks.len();
+            let mut kept = Vec::with_capacity(num_blocks);
+            for block in blocks {
+                match block_expiration(block.as_ref())? {
+                    BlockExpiration::All => expired_blocks.push(block),
+                    BlockExpiration::Partly => {
+                        partly_expired_block_count += 1;
+                        kept.push(block);
+                    }
+                    BlockExpiration::None => kept.push(block),
+                }
+            }
+
+            if kept.len() == num_blocks {
+                new_segments.push(location.clone());
+            } else if kept.is_empty() {
+                removed.insert(location.clone());
+            } else {
+                let summary =
+                    reduce_block_metas(&kept, self.get_block_thresholds(), self.cluster_key_id());
+                let new_location = segment_writer
+                    .write_segment(SegmentInfo::new(kept, summary))
+                    .await?;
+                replaced.insert(location.clone(), new_location.clone());
+                new_segments.push(new_location);
+            }
+        }
+
+        let stats = ExpiredBlockStats {
+            block_count: expired_blocks.len() as u64,
+            row_count: expired_blocks.iter().map(|b| b.live_row_count()).sum(),
+            partly_expired_block_count,
+        };
+        if expired_blocks.is_empty() {
+            return Ok(stats);
+        }
+
+        let removed_statistics = reduce_block_metas(
+            &expired_blocks,
+            self.get_block_thresholds(),
+            self.cluster_key_id(),
+        );
+        let mut partitions = snapshot.partitions.clone();
+        // the rewritten segments hold a subset of the blocks of the replaced ones.
+        rebase_partitions(&mut partitions, &replaced, &removed, true);
+        let summary = if new_segments.is_empty() {
+            // nothing left, there is no meaningful column statistics any more.
+            Statistics::default()
+        } else {
+            let mut summary = snapshot.summary.clone();
+            deduct_statistics_mut(&mut summary, &removed_statistics);
+            summary
+        };
+        let abort_operation = AbortOperation {
+            segments: replaced.into_values().map(|location| location.0).collect(),
+            ..Default::default()
+        };
+
+        let _guard = lock.try_lock(ctx.clone()).await?;
+        self.commit_mutation(
+            &ctx,
+            snapshot,
+            &new_segments,
+            summary,
+            partitions,
+            abort_operation,
+            None,
+        )
+        .await?;
+        Ok(stats)
+    }
+}
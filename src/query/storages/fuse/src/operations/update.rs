@@ -230,6 +230,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     true,
+                    self.deletion_vector_enabled(),
                 )
             },
             max_threads,
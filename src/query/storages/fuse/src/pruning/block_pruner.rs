@@ -111,7 +111,7 @@ impl BlockPruner {
                 }
 
                 let block_meta = block_meta.clone();
-                let row_count = block_meta.row_count;
+                let row_count = block_meta.live_row_count();
                 if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas)) {
                     // Perf.
                     {
@@ -260,7 +260,7 @@ impl BlockPruner {
             if limit_pruner.exceeded() {
                 break;
             }
-            let row_count = block_meta.row_count;
+            let row_count = block_meta.live_row_count();
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
                 && limit_pruner.within_limit(row_count)
             {
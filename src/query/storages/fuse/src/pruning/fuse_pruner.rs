@@ -104,6 +104,14 @@ impl PruningContext {
             .flatten()
             .filter_map(|(name, _)| {
                 let field = table_schema.field_with_name(&name).ok()?;
+                // blocks written before the field evolved have no stats of its new column
+                // ids, which does not mean the column is filled with the default value.
+                if !table_schema
+                    .previous_fields_of(field.column_id())
+                    .is_empty()
+                {
+                    return None;
+                }
                 let default_scalar = field_default_value(ctx.clone(), field).ok()?;
 
                 let stats =
@@ -206,13 +206,15 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
-        row_count += b.row_count;
+        row_count += b.live_row_count();
         block_count += 1;
         uncompressed_byte_size += b.block_size;
         compressed_byte_size += b.file_size;
         index_size += b.bloom_filter_index_size;
-        if thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
-            || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0)
+        // blocks with deleted rows are left to the compaction.
+        if b.deletion_vector.is_none()
+            && (thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
+                || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0))
         {
             perfect_block_count += 1;
         }
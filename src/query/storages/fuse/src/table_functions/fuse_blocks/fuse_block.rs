@@ -111,6 +111,8 @@ impl<'a> FuseBlock<'a> {
         let mut row_count = Vec::with_capacity(len);
         let mut bloom_filter_location = vec![];
         let mut bloom_filter_size = Vec::with_capacity(len);
+        let mut deleted_row_count = Vec::with_capacity(len);
+        let mut deletion_vector_location = vec![];
 
         let segments_io = SegmentsIO::create(
             self.ctx.clone(),
@@ -143,6 +145,14 @@ impl<'a> FuseBlock<'a> {
                             .map(|s| s.0.as_bytes().to_vec()),
                     );
                     bloom_filter_size.push(block.bloom_filter_index_size);
+                    deleted_row_count
+                        .push(block.deletion_vector.as_ref().map_or(0, |v| v.deleted_rows));
+                    deletion_vector_location.push(
+                        block
+                            .deletion_vector
+                            .as_ref()
+                            .map(|v| v.location.0.as_bytes().to_vec()),
+                    );
 
                     row_num += 1;
                     if row_num >= limit {
@@ -191,6 +201,14 @@ impl<'a> FuseBlock<'a> {
                     DataType::Number(NumberDataType::UInt64),
                     Value::Column(UInt64Type::from_data(bloom_filter_size)),
                 ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Column(UInt64Type::from_data(deleted_row_count)),
+                ),
+                BlockEntry::new(
+                    DataType::String.wrap_nullable(),
+                    Value::Column(StringType::from_opt_data(deletion_vector_location)),
+                ),
             ],
             row_num,
         ))
@@ -212,6 +230,14 @@ impl<'a> FuseBlock<'a> {
                 "bloom_filter_size",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new(
+                "deleted_row_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "deletion_vector_location",
+                TableDataType::String.wrap_nullable(),
+            ),
         ])
     }
 }
@@ -12,13 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_arrow::arrow::io::parquet::read as pread;
+use common_arrow::parquet::encoding::Encoding;
 use common_catalog::table::Table;
 use common_exception::Result;
+use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
 use common_expression::types::UInt32Type;
 use common_expression::types::UInt64Type;
 use common_expression::BlockEntry;
@@ -115,6 +120,8 @@ impl<'a> FuseColumn<'a> {
         let mut column_id = vec![];
         let mut block_offset = vec![];
         let mut bytes_compressed = vec![];
+        let mut compression = NullableColumnBuilder::<StringType>::with_capacity(len, &[]);
+        let mut encodings = NullableColumnBuilder::<StringType>::with_capacity(len, &[]);
 
         let segments_io = SegmentsIO::create(
             self.ctx.clone(),
@@ -138,6 +145,12 @@ impl<'a> FuseColumn<'a> {
                 let segment = segment?;
                 for block in segment.blocks.iter() {
                     let block = block.as_ref();
+                    // the native pages keep their own encodings, see `fuse_encoding`.
+                    let column_chunks = if self.table.is_native() {
+                        HashMap::new()
+                    } else {
+                        self.read_parquet_column_chunks(&block.location.0).await?
+                    };
 
                     for (id, column) in block.col_metas.iter() {
                         if let Some(f) = leaf_fields.iter().find(|f| f.column_id == *id) {
@@ -159,6 +172,17 @@ impl<'a> FuseColumn<'a> {
                             block_offset.push(offset);
                             bytes_compressed.push(length);
 
+                            match column_chunks.get(&offset) {
+                                Some((codec, chunk_encodings)) => {
+                                    compression.push(codec.as_bytes());
+                                    encodings.push(chunk_encodings.as_bytes());
+                                }
+                                None => {
+                                    compression.push_null();
+                                    encodings.push_null();
+                                }
+                            }
+
                             row_num += 1;
 
                             if row_num >= limit {
@@ -221,6 +245,14 @@ impl<'a> FuseColumn<'a> {
                     DataType::Number(NumberDataType::UInt64),
                     Value::Column(UInt64Type::from_data(bytes_compressed)),
                 ),
+                BlockEntry::new(
+                    DataType::Nullable(Box::new(DataType::String)),
+                    Value::Column(Column::Nullable(Box::new(compression.build().upcast()))),
+                ),
+                BlockEntry::new(
+                    DataType::Nullable(Box::new(DataType::String)),
+                    Value::Column(Column::Nullable(Box::new(encodings.build().upcast()))),
+                ),
             ],
             row_num,
         ))
@@ -245,6 +277,49 @@ impl<'a> FuseColumn<'a> {
                 "bytes_compressed",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new(
+                "compression",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "encodings",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ])
     }
+
+    /// The codec and the encodings of the column chunks of a parquet block, keyed by
+    /// the offsets of the column chunks.
+    #[async_backtrace::framed]
+    async fn read_parquet_column_chunks(
+        &self,
+        location: &str,
+    ) -> Result<HashMap<u64, (String, String)>> {
+        let mut reader = self.table.get_operator().reader(location).await?;
+        let metadata = pread::read_metadata_async(&mut reader).await?;
+        let mut column_chunks = HashMap::new();
+        for row_group in metadata.row_groups.iter() {
+            for column in row_group.columns() {
+                let mut chunk_encodings = column
+                    .metadata()
+                    .encodings
+                    .iter()
+                    .map(|e| {
+                        Encoding::try_from(*e)
+                            .map_or_else(|_| format!("{e:?}"), |e| format!("{e:?}"))
+                    })
+                    .collect::<Vec<_>>();
+                chunk_encodings.sort();
+                let (offset, _) = column.byte_range();
+                column_chunks.insert(
+                    offset,
+                    (
+                        format!("{:?}", column.compression()),
+                        chunk_encodings.join(","),
+                    ),
+                );
+            }
+        }
+        Ok(column_chunks)
+    }
 }
@@ -45,6 +45,8 @@ use common_expression::TableSchemaRefExt;
 use common_expression::Value;
 use common_functions::BUILTIN_FUNCTIONS;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::table::FIELD_OPT_KEY_COMPRESSION;
+use storages_common_table_meta::table::FIELD_OPT_KEY_ENCODING;
 
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
@@ -109,7 +111,10 @@ impl<'a> FuseEncoding<'a> {
                             }
                             let column_id = field.column_id;
                             let arrow_field: Field = field.into();
-                            let column_meta = block.col_metas.get(&column_id).unwrap();
+                            // blocks written before the column is added don't have it.
+                            let Some(column_meta) = block.col_metas.get(&column_id) else {
+                                continue;
+                            };
                             let (offset, len) = column_meta.offset_length();
                             let ranges = vec![(column_id, offset..(offset + len))];
                             let read_settings = ReadSettings::from_ctx(&self.ctx)?;
@@ -131,7 +136,15 @@ impl<'a> FuseEncoding<'a> {
                             let page_metas = column_meta.as_native().unwrap().pages.clone();
                             let reader = NativeReader::new(pages, page_metas, vec![]);
                             let this_column_info = stat_simple(reader, arrow_field.clone())?;
-                            columns_info.push((field.data_type.sql_name(), this_column_info));
+                            let field_options = schema.field_options(column_id);
+                            columns_info.push(EncodingColumnInfo {
+                                type_str: field.data_type.sql_name(),
+                                compression_option: field_options
+                                    .get(FIELD_OPT_KEY_COMPRESSION)
+                                    .cloned(),
+                                encoding_option: field_options.get(FIELD_OPT_KEY_ENCODING).cloned(),
+                                column_info: this_column_info,
+                            });
                         }
                     }
                 }
@@ -159,7 +172,7 @@ impl<'a> FuseEncoding<'a> {
     }
 
     #[async_backtrace::framed]
-    async fn to_block(&self, info: &Vec<(&str, Vec<(String, ColumnInfo)>)>) -> Result<DataBlock> {
+    async fn to_block(&self, info: &Vec<(&str, Vec<EncodingColumnInfo>)>) -> Result<DataBlock> {
         let mut validity_size = Vec::new();
         let mut compressed_size = Vec::new();
         let mut uncompressed_size = Vec::new();
@@ -168,9 +181,17 @@ impl<'a> FuseEncoding<'a> {
         let mut table_name = StringColumnBuilder::with_capacity(0, 0);
         let mut column_name = StringColumnBuilder::with_capacity(0, 0);
         let mut column_type = StringColumnBuilder::with_capacity(0, 0);
+        let mut compression_option = NullableColumnBuilder::<StringType>::with_capacity(0, &[]);
+        let mut encoding_option = NullableColumnBuilder::<StringType>::with_capacity(0, &[]);
         let mut all_num_rows = 0;
         for (table, columns_info) in info {
-            for (type_str, column_info) in columns_info {
+            for EncodingColumnInfo {
+                type_str,
+                compression_option: column_compression,
+                encoding_option: column_encoding,
+                column_info,
+            } in columns_info
+            {
                 let pages_info = &column_info.pages;
                 let num_row = pages_info.len();
                 all_num_rows += num_row;
@@ -200,6 +221,14 @@ impl<'a> FuseEncoding<'a> {
                     } else {
                         l2.push_null();
                     }
+                    match column_compression {
+                        Some(compression) => compression_option.push(compression.as_bytes()),
+                        None => compression_option.push_null(),
+                    }
+                    match column_encoding {
+                        Some(encoding) => encoding_option.push(encoding.as_bytes()),
+                        None => encoding_option.push_null(),
+                    }
                 }
 
                 table_name.append_column(&tmp_table_name.build());
@@ -239,6 +268,16 @@ impl<'a> FuseEncoding<'a> {
                     DataType::Nullable(Box::new(DataType::String)),
                     Value::Column(Column::Nullable(Box::new(l2.build().upcast()))),
                 ),
+                BlockEntry::new(
+                    DataType::Nullable(Box::new(DataType::String)),
+                    Value::Column(Column::Nullable(Box::new(
+                        compression_option.build().upcast(),
+                    ))),
+                ),
+                BlockEntry::new(
+                    DataType::Nullable(Box::new(DataType::String)),
+                    Value::Column(Column::Nullable(Box::new(encoding_option.build().upcast()))),
+                ),
             ],
             all_num_rows,
         ))
@@ -266,10 +305,26 @@ impl<'a> FuseEncoding<'a> {
                 "level_two",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
+            TableField::new(
+                "compression_option",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "encoding_option",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ])
     }
 }
 
+/// The encodings of the pages of a column, along with the storage options of the column.
+struct EncodingColumnInfo {
+    type_str: String,
+    compression_option: Option<String>,
+    encoding_option: Option<String>,
+    column_info: ColumnInfo,
+}
+
 fn encoding_to_string(page_body: &PageBody) -> String {
     match page_body {
         PageBody::Dict(_) => "Dict".to_string(),
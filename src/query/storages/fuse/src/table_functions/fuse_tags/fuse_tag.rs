@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+
+use crate::FuseTable;
+
+pub struct FuseTag<'a> {
+    pub table: &'a FuseTable,
+}
+
+impl<'a> FuseTag<'a> {
+    pub fn new(table: &'a FuseTable) -> Self {
+        Self { table }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_tags(self) -> Result<DataBlock> {
+        let tags = self.table.list_tags().await?;
+        let len = tags.len();
+        let mut names: Vec<Vec<u8>> = Vec::with_capacity(len);
+        let mut snapshot_ids: Vec<Vec<u8>> = Vec::with_capacity(len);
+        let mut snapshot_locations: Vec<Vec<u8>> = Vec::with_capacity(len);
+        let mut created_on: Vec<i64> = Vec::with_capacity(len);
+        let mut expire_at: Vec<Option<i64>> = Vec::with_capacity(len);
+        for tag in tags {
+            names.push(tag.name.into_bytes());
+            snapshot_ids.push(tag.snapshot_id.simple().to_string().into_bytes());
+            snapshot_locations.push(tag.snapshot_location.into_bytes());
+            created_on.push(tag.created_on.timestamp_micros());
+            expire_at.push(tag.expire_at.map(|dt| dt.timestamp_micros()));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(snapshot_ids),
+            StringType::from_data(snapshot_locations),
+            TimestampType::from_data(created_on),
+            TimestampType::from_opt_data(expire_at),
+        ]))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("snapshot_id", TableDataType::String),
+            TableField::new("snapshot_location", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("expire_at", TableDataType::Timestamp.wrap_nullable()),
+        ])
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt::Display;
 use std::str::FromStr;
@@ -45,6 +46,9 @@ use common_expression::ORIGIN_VERSION_COL_NAME;
 use common_meta_app::schema::TableInfo;
 use common_pipeline_core::Pipeline;
 use common_sql::binder::STREAM_COLUMN_FACTORY;
+use common_sql::CHANGE_TYPE_DELETE;
+use common_sql::CHANGE_TYPE_INSERT;
+use common_sql::CHANGE_TYPE_OPTION;
 use common_storages_fuse::io::SegmentsIO;
 use common_storages_fuse::io::SnapshotsIO;
 use common_storages_fuse::FuseTable;
@@ -62,10 +66,22 @@ pub const OPT_KEY_TABLE_VER: &str = "table_version";
 pub const OPT_KEY_MODE: &str = "mode";
 
 pub const MODE_APPEND_ONLY: &str = "append_only";
+pub const MODE_STANDARD: &str = "standard";
 
 #[derive(Clone)]
 pub enum StreamMode {
     AppendOnly,
+    /// Tracks inserts, updates and deletes, see `Binder::bind_stream_changes`.
+    Standard,
+}
+
+/// Which side of the changes a stream in standard mode reads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeType {
+    /// The rows in the blocks added since the stream offset, read from the latest snapshot.
+    Insert,
+    /// The rows in the blocks removed since the stream offset, read from the base snapshot.
+    Delete,
 }
 
 pub enum StreamStatus {
@@ -78,6 +94,7 @@ impl FromStr for StreamMode {
     fn from_str(s: &str) -> Result<Self> {
         match s {
             MODE_APPEND_ONLY => Ok(StreamMode::AppendOnly),
+            MODE_STANDARD => Ok(StreamMode::Standard),
             _ => Err(ErrorCode::IllegalStream(format!(
                 "invalid stream mode: {}",
                 s
@@ -90,6 +107,7 @@ impl Display for StreamMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
             StreamMode::AppendOnly => MODE_APPEND_ONLY.to_string(),
+            StreamMode::Standard => MODE_STANDARD.to_string(),
         })
     }
 }
@@ -103,10 +121,23 @@ pub struct StreamTable {
     table_version: u64,
     mode: StreamMode,
     snapshot_location: Option<String>,
+    change_type: Option<ChangeType>,
 }
 
 impl StreamTable {
-    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+    pub fn try_create(mut table_info: TableInfo) -> Result<Box<dyn Table>> {
+        // The change type is set by the binder for this read only, do not keep it in the stream options.
+        let change_type = match table_info.meta.options.remove(CHANGE_TYPE_OPTION) {
+            None => None,
+            Some(v) if v == CHANGE_TYPE_INSERT => Some(ChangeType::Insert),
+            Some(v) if v == CHANGE_TYPE_DELETE => Some(ChangeType::Delete),
+            Some(v) => {
+                return Err(ErrorCode::IllegalStream(format!(
+                    "invalid stream change type: {}",
+                    v
+                )));
+            }
+        };
         let options = table_info.options();
         let table_name = options
             .get(OPT_KEY_TABLE_NAME)
@@ -137,6 +168,7 @@ impl StreamTable {
             table_version,
             mode,
             snapshot_location,
+            change_type,
         }))
     }
 
@@ -236,8 +268,10 @@ impl StreamTable {
             HashSet::new()
         };
 
-        let mut base_blocks = HashSet::new();
+        let mut base_blocks = HashMap::new();
         let mut latest_blocks = Vec::new();
+        // The blocks of which some rows are deleted by a deletion vector since the base snapshot.
+        let mut deleted_in_blocks = Vec::new();
         {
             let fuse_segment_io =
                 SegmentsIO::create(ctx.clone(), operator.clone(), fuse_table.schema());
@@ -254,7 +288,7 @@ impl StreamTable {
                 for segment in segments {
                     let segment = segment?;
                     segment.blocks.into_iter().for_each(|block| {
-                        base_blocks.insert(block.location.clone());
+                        base_blocks.insert(block.location.clone(), block);
                     })
                 }
             }
@@ -271,24 +305,44 @@ impl StreamTable {
                 for segment in segments {
                     let segment = segment?;
                     segment.blocks.into_iter().for_each(|block| {
-                        if base_blocks.contains(&block.location) {
-                            base_blocks.remove(&block.location);
-                        } else {
-                            latest_blocks.push(block);
+                        match base_blocks.get(&block.location) {
+                            // Some rows of the block are deleted by a deletion vector, the block
+                            // is changed as if it was rewritten.
+                            Some(base_block)
+                                if base_block.deletion_vector != block.deletion_vector =>
+                            {
+                                deleted_in_blocks.push(block);
+                            }
+                            Some(_) => {
+                                base_blocks.remove(&block.location);
+                            }
+                            None => latest_blocks.push(block),
                         }
                     });
                 }
             }
         }
-        if latest_blocks.is_empty() {
-            return Ok((PartStatistics::default(), Partitions::default()));
-        }
-
         let mut base_block_ids = Vec::with_capacity(base_blocks.len());
-        for base_block in base_blocks {
+        for base_block in base_blocks.keys() {
             let block_id = block_id_from_location(&base_block.0)?;
             base_block_ids.push(block_id);
         }
+
+        let changed_blocks = match self.change_type {
+            Some(ChangeType::Delete) => base_blocks.into_values().collect::<Vec<_>>(),
+            // The rest of the rows of the blocks are read on both sides of the standard
+            // stream, so that the rows deleted by the deletion vectors are the changes.
+            Some(ChangeType::Insert) => {
+                latest_blocks.extend(deleted_in_blocks);
+                latest_blocks
+            }
+            // Deleting rows inserts nothing, the updated rows are in the new blocks.
+            None => latest_blocks,
+        };
+        if changed_blocks.is_empty() {
+            return Ok((PartStatistics::default(), Partitions::default()));
+        }
+
         let base_block_ids_scalar = Scalar::Array(Decimal128Type::from_data(base_block_ids));
         let push_downs = replace_push_downs(push_downs, &base_block_ids_scalar)?;
 
@@ -313,7 +367,7 @@ impl StreamTable {
             bloom_index_cols,
         )?;
 
-        let block_metas = stream_pruner.pruning(latest_blocks).await?;
+        let block_metas = stream_pruner.pruning(changed_blocks).await?;
         let pruning_stats = stream_pruner.pruning_stats();
 
         log::info!(
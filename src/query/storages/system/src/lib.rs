@@ -27,6 +27,7 @@ mod catalogs_table;
 mod clustering_history_table;
 mod clusters_table;
 mod columns_table;
+mod compaction_history_table;
 mod configs_table;
 mod contributors_table;
 mod credits_table;
@@ -37,6 +38,7 @@ mod indexes_table;
 mod log_queue;
 mod malloc_stats_table;
 mod malloc_stats_totals_table;
+mod meta_audit_log_table;
 mod metrics_table;
 mod one_table;
 mod processes_table;
@@ -55,6 +57,7 @@ mod tables_table;
 mod task_history_table;
 mod tasks_table;
 mod temp_files_table;
+mod tenant_usage_table;
 mod tracing_table;
 mod users_table;
 mod util;
@@ -70,6 +73,9 @@ pub use clustering_history_table::ClusteringHistoryQueue;
 pub use clustering_history_table::ClusteringHistoryTable;
 pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
+pub use compaction_history_table::CompactionHistoryLogElement;
+pub use compaction_history_table::CompactionHistoryQueue;
+pub use compaction_history_table::CompactionHistoryTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
@@ -82,6 +88,7 @@ pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
 pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
+pub use meta_audit_log_table::MetaAuditLogTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
@@ -108,5 +115,6 @@ pub use task_history_table::TaskHistoryTable;
 pub use tasks_table::parse_tasks_to_datablock;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
+pub use tenant_usage_table::TenantUsageTable;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
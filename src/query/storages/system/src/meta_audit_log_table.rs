@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_api::AuditApi;
+use common_meta_app::audit::ListAuditRecordsReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The schema and privilege changes of the current tenant, kept in the meta-service.
+///
+/// It requires the `SUPER` privilege to read, see `PrivilegeAccess`.
+pub struct MetaAuditLogTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for MetaAuditLogTable {
+    const NAME: &'static str = "system.meta_audit_log";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let records = meta_api
+            .list_audit_records(ListAuditRecordsReq { tenant })
+            .await?;
+
+        let mut created_on = Vec::with_capacity(records.len());
+        let mut users = Vec::with_capacity(records.len());
+        let mut client_addresses = Vec::with_capacity(records.len());
+        let mut query_ids = Vec::with_capacity(records.len());
+        let mut operations = Vec::with_capacity(records.len());
+        let mut objects = Vec::with_capacity(records.len());
+        let mut befores = Vec::with_capacity(records.len());
+        let mut afters = Vec::with_capacity(records.len());
+        let mut query_texts = Vec::with_capacity(records.len());
+        for record in records {
+            created_on.push(record.created_on.timestamp_micros());
+            users.push(record.user.into_bytes());
+            client_addresses.push(record.client_address.into_bytes());
+            query_ids.push(record.query_id.into_bytes());
+            operations.push(record.operation.into_bytes());
+            objects.push(record.object.into_bytes());
+            befores.push(record.before.map(|x| x.into_bytes()));
+            afters.push(record.after.map(|x| x.into_bytes()));
+            query_texts.push(record.query_text.into_bytes());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            TimestampType::from_data(created_on),
+            StringType::from_data(users),
+            StringType::from_data(client_addresses),
+            StringType::from_data(query_ids),
+            StringType::from_data(operations),
+            StringType::from_data(objects),
+            StringType::from_opt_data(befores),
+            StringType::from_opt_data(afters),
+            StringType::from_data(query_texts),
+        ]))
+    }
+}
+
+impl MetaAuditLogTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("user", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("operation", TableDataType::String),
+            TableField::new("object", TableDataType::String),
+            TableField::new("before", TableDataType::String.wrap_nullable()),
+            TableField::new("after", TableDataType::String.wrap_nullable()),
+            TableField::new("query_text", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'meta_audit_log'".to_string(),
+            name: "meta_audit_log".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemMetaAuditLog".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
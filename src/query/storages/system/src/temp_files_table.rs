@@ -35,7 +35,10 @@ use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_pipeline_core::query_spill_prefix;
+use common_storage::local_spill_prefix;
 use common_storage::DataOperator;
+use common_storage::LocalSpillDisk;
+use common_storage::SpillTier;
 use futures::StreamExt;
 use futures::TryStreamExt;
 use opendal::Metakey;
@@ -62,30 +65,52 @@ impl AsyncSystemTable for TempFilesTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let tenant = ctx.get_tenant();
-        let operator = DataOperator::instance().operator();
+        let limit = push_downs.and_then(|x| x.limit).unwrap_or(usize::MAX);
 
+        let mut temp_files_tier = vec![];
         let mut temp_files_name = vec![];
         let mut temp_files_content_length = vec![];
         let mut temp_files_last_modified = vec![];
 
-        let location_prefix = format!("{}/", query_spill_prefix(&tenant));
-        if let Ok(lister) = operator
-            .lister_with(&location_prefix)
-            .metakey(Metakey::LastModified | Metakey::ContentLength)
-            .await
-        {
-            let limit = push_downs.and_then(|x| x.limit).unwrap_or(usize::MAX);
-            let mut lister = lister.take(limit);
-
-            while let Some(entry) = lister.try_next().await? {
-                let metadata = entry.metadata();
+        let mut sources = vec![(
+            SpillTier::Remote,
+            DataOperator::instance().operator(),
+            format!("{}/", query_spill_prefix(&tenant)),
+        )];
+        if let Some(disk) = LocalSpillDisk::instance() {
+            sources.push((
+                SpillTier::Local,
+                disk.operator(),
+                format!("{}/", local_spill_prefix(&tenant)),
+            ));
+        }
 
-                if metadata.is_file() {
-                    temp_files_name.push(entry.name().as_bytes().to_vec());
+        for (tier, operator, location_prefix) in sources {
+            let remaining = limit.saturating_sub(temp_files_name.len());
+            if remaining == 0 {
+                break;
+            }
 
-                    temp_files_last_modified
-                        .push(metadata.last_modified().map(|x| x.timestamp_micros()));
-                    temp_files_content_length.push(metadata.content_length());
+            // Only the spilled files of the current tenant are listed, they are grouped by query id.
+            if let Ok(lister) = operator
+                .lister_with(&location_prefix)
+                .delimiter("")
+                .metakey(Metakey::LastModified | Metakey::ContentLength)
+                .await
+            {
+                let mut lister = lister.take(remaining);
+
+                while let Some(entry) = lister.try_next().await? {
+                    let metadata = entry.metadata();
+
+                    if metadata.is_file() {
+                        temp_files_tier.push(tier.to_string().as_bytes().to_vec());
+                        temp_files_name.push(entry.name().as_bytes().to_vec());
+
+                        temp_files_last_modified
+                            .push(metadata.last_modified().map(|x| x.timestamp_micros()));
+                        temp_files_content_length.push(metadata.content_length());
+                    }
                 }
             }
         }
@@ -97,6 +122,10 @@ impl AsyncSystemTable for TempFilesTable {
                     DataType::String,
                     Value::Scalar(Scalar::String("Spill".as_bytes().to_owned())),
                 ),
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Column(StringType::from_data(temp_files_tier)),
+                ),
                 BlockEntry::new(
                     DataType::String,
                     Value::Column(StringType::from_data(temp_files_name)),
@@ -121,6 +150,7 @@ impl TempFilesTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
             TableField::new("file_type", TableDataType::String),
+            TableField::new("file_tier", TableDataType::String),
             TableField::new("file_name", TableDataType::String),
             TableField::new(
                 "file_content_length",
@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_types::MatchSeq;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The usage of the resources limited by the quota of the current tenant.
+///
+/// A quota of 0 means no limit.
+pub struct TenantUsageTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TenantUsageTable {
+    const NAME: &'static str = "system.tenant_usage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&tenant)?;
+        let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
+        let usage = quota_api.get_usage().await?;
+
+        let mut resource: Vec<Vec<u8>> = vec![];
+        let mut database: Vec<Vec<u8>> = vec![];
+        let mut used: Vec<u64> = vec![];
+        let mut limit: Vec<u64> = vec![];
+
+        let mut push = |r: &str, db: &str, u: u64, q: u64| {
+            resource.push(r.as_bytes().to_vec());
+            database.push(db.as_bytes().to_vec());
+            used.push(u);
+            limit.push(q);
+        };
+
+        push("databases", "", usage.databases, quota.max_databases as u64);
+        for (db_name, tables) in usage.tables.iter() {
+            push(
+                "tables_per_database",
+                db_name,
+                *tables,
+                quota.max_tables_per_database as u64,
+            );
+        }
+        push("stages", "", usage.stages, quota.max_stages as u64);
+        push("udfs", "", usage.udfs, quota.max_udfs as u64);
+        push("meta_bytes", "", usage.meta_bytes, quota.max_meta_bytes);
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(resource),
+            StringType::from_data(database),
+            UInt64Type::from_data(used),
+            UInt64Type::from_data(limit),
+        ]))
+    }
+}
+
+impl TenantUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("resource", TableDataType::String),
+            // Empty if the resource is not limited per database
+            TableField::new("database", TableDataType::String),
+            TableField::new("used", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("quota", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'tenant_usage'".to_string(),
+            name: "tenant_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTenantUsage".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(TenantUsageTable { table_info })
+    }
+}
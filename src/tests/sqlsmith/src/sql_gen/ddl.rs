@@ -78,6 +78,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 engine: Some(Engine::Fuse),
                 uri_location: None,
                 cluster_by: vec![],
+                partition_by: None,
+                ttl: None,
                 table_options: BTreeMap::new(),
                 as_query: None,
                 transient: false,
@@ -180,6 +182,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             expr: None,
             comment: None,
             nullable_constraint,
+            compression: None,
+            encoding: None,
         }
     }
 
@@ -197,6 +201,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 expr: None,
                 comment: None,
                 nullable_constraint,
+                compression: None,
+                encoding: None,
             };
             column_defs.push(column_def);
         }
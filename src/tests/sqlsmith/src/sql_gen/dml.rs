@@ -453,6 +453,8 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                     expr: None,
                     comment: None,
                     nullable_constraint,
+                    compression: None,
+                    encoding: None,
                 };
                 (
                     AlterTableAction::ModifyColumn {
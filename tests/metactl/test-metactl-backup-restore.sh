@@ -0,0 +1,87 @@
+#!/bin/sh
+
+set -o errexit
+
+SCRIPT_PATH="$(cd "$(dirname "$0")" >/dev/null 2>&1 && pwd)"
+BUILD_PROFILE="${BUILD_PROFILE:-debug}"
+DATABEND_META="./target/${BUILD_PROFILE}/databend-meta"
+DATABEND_METACTL="./target/${BUILD_PROFILE}/databend-metactl"
+BACKUP_DIR="./.databend/backup"
+
+rm -fr .databend/
+
+
+echo " ==="
+echo " === 1. Start a single node meta service and take backups"
+echo " ==="
+
+nohup ${DATABEND_META} --config-file=./tests/metactl/config/databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 1
+
+${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::upsert --key k1 --value v1
+${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::upsert --key k2 --value v2
+
+echo " === Full backup"
+${DATABEND_METACTL} --cmd backup --grpc-api-address "127.0.0.1:9191" --backup-dir ${BACKUP_DIR}
+
+${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::upsert --key k3 --value v3
+
+sleep 1
+RESTORE_TIME="$(date -u +%Y-%m-%dT%H:%M:%SZ)"
+sleep 2
+
+${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::delete --key k1
+
+echo " === Incremental backup"
+${DATABEND_METACTL} --cmd backup --grpc-api-address "127.0.0.1:9191" --backup-dir ${BACKUP_DIR}
+
+ls -l ${BACKUP_DIR}
+
+killall databend-meta
+sleep 2
+
+
+echo " ==="
+echo " === 2. Restore to ${RESTORE_TIME}, before k1 is deleted"
+echo " ==="
+
+rm -fr ./.databend/meta1
+
+${DATABEND_METACTL} --cmd restore --backup-dir ${BACKUP_DIR} --raft-dir ./.databend/meta1 --id=1 --initial-cluster 1=localhost:28103,127.0.0.1:9191 --to-time ${RESTORE_TIME}
+
+nohup ${DATABEND_META} --config-file=./tests/metactl/config/databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 3
+
+echo " === check k1 and k3 are restored"
+${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::get --key k1 | grep '"seq"'
+${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::get --key k3 | grep '"seq"'
+
+killall databend-meta
+sleep 2
+
+
+BASE_NEXT_INDEX="$(ls ${BACKUP_DIR} | grep '^base-' | head -n 1 | cut -d- -f2)"
+TO_SEQ="$(expr ${BASE_NEXT_INDEX} - 1)"
+
+echo " ==="
+echo " === 3. Restore to log index ${TO_SEQ}, the last log in the full backup, before k3 is written"
+echo " ==="
+
+rm -fr ./.databend/meta1
+
+${DATABEND_METACTL} --cmd restore --backup-dir ${BACKUP_DIR} --raft-dir ./.databend/meta1 --id=1 --initial-cluster 1=localhost:28103,127.0.0.1:9191 --to-seq ${TO_SEQ}
+
+nohup ${DATABEND_META} --config-file=./tests/metactl/config/databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 3
+
+echo " === check k2 is restored and k3 is not"
+${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::get --key k2 | grep '"seq"'
+${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::get --key k3 | grep '^null$'
+
+killall databend-meta
@@ -0,0 +1,60 @@
+#!/bin/sh
+
+set -o errexit
+
+BUILD_PROFILE="${BUILD_PROFILE:-debug}"
+DATABEND_META="./target/${BUILD_PROFILE}/databend-meta"
+DATABEND_METACTL="./target/${BUILD_PROFILE}/databend-metactl"
+ADDR="127.0.0.1:9191"
+
+rm -fr .databend/
+
+
+echo " ==="
+echo " === 1. Start a single node meta service"
+echo " ==="
+
+nohup ${DATABEND_META} --config-file=./tests/metactl/config/databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 1
+
+
+echo " ==="
+echo " === 2. list, get, put, delete"
+echo " ==="
+
+${DATABEND_METACTL} --cmd put --grpc-api-address ${ADDR} --key foo/a --value 1
+${DATABEND_METACTL} --cmd put --grpc-api-address ${ADDR} --key foo/b --value 2
+
+${DATABEND_METACTL} --cmd list --grpc-api-address ${ADDR} --prefix foo/ | grep '"key":"foo/b"'
+${DATABEND_METACTL} --cmd get --grpc-api-address ${ADDR} --key foo/a | grep '"value": "1"'
+
+${DATABEND_METACTL} --cmd delete --grpc-api-address ${ADDR} --key foo/a
+if ${DATABEND_METACTL} --cmd get --grpc-api-address ${ADDR} --key foo/a; then
+    echo "foo/a should be deleted"
+    exit 1
+fi
+
+echo " === put and get a protobuf encoded value"
+${DATABEND_METACTL} --cmd put --grpc-api-address ${ADDR} --key __fd_database_id_to_name/999 --value '{"tenant":"t1","db_name":"db1"}'
+${DATABEND_METACTL} --cmd get --grpc-api-address ${ADDR} --key __fd_database_id_to_name/999 | grep '"db_name": "db1"'
+
+
+echo " ==="
+echo " === 3. check and fix dangling entries"
+echo " ==="
+
+${DATABEND_METACTL} --cmd check --grpc-api-address ${ADDR} | grep 'DanglingDatabaseIdToName'
+
+echo " === dry run does not remove anything"
+${DATABEND_METACTL} --cmd get --grpc-api-address ${ADDR} --key __fd_database_id_to_name/999
+
+${DATABEND_METACTL} --cmd check --grpc-api-address ${ADDR} --apply | grep 'removed: __fd_database_id_to_name/999'
+
+if ${DATABEND_METACTL} --cmd check --grpc-api-address ${ADDR} | grep 'DanglingDatabaseIdToName'; then
+    echo "dangling entry should be removed"
+    exit 1
+fi
+
+killall databend-meta
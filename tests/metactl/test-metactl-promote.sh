@@ -0,0 +1,63 @@
+#!/bin/sh
+
+set -o errexit
+
+BUILD_PROFILE="${BUILD_PROFILE:-debug}"
+DATABEND_META="./target/${BUILD_PROFILE}/databend-meta"
+DATABEND_METACTL="./target/${BUILD_PROFILE}/databend-metactl"
+
+rm -fr .databend/
+
+
+echo " ==="
+echo " === 1. Start a single node cluster and a learner"
+echo " ==="
+
+nohup ${DATABEND_META} --config-file=./tests/metactl/config/databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 1
+
+nohup ${DATABEND_META} --config-file=./tests/metactl/config/databend-meta-node-2.toml --as-learner &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 28202
+
+sleep 1
+
+${DATABEND_METACTL} --cmd put --grpc-api-address 127.0.0.1:9191 --key promote/a --value 1
+
+echo " === sleep 3 sec to wait for the learner to catch up"
+sleep 3
+
+echo " === Check /v1/cluster/status start"
+curl -sL http://127.0.0.1:28101/v1/cluster/status
+echo " === Check /v1/cluster/status end"
+
+echo " === Stop the cluster"
+killall databend-meta
+sleep 2
+
+
+echo " ==="
+echo " === 2. Promote the learner to a new standalone cluster"
+echo " ==="
+
+mv ./.databend/meta2 ./.databend/new_meta1
+
+${DATABEND_METACTL} --cmd promote --raft-dir ./.databend/new_meta1 --id=4 --initial-cluster 4=localhost:29103,127.0.0.1:19191
+
+nohup ${DATABEND_META} --config-file=./tests/metactl/config/new-databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 19191
+
+echo " === sleep 3 sec to wait for the new cluster to elect a leader"
+sleep 3
+
+
+echo " === check new cluster state has the voter 4"
+curl -sL http://127.0.0.1:28101/v1/cluster/status |
+    grep '{"name":"4","endpoint":{"addr":"localhost","port":29103},"grpc_api_advertise_address":"127.0.0.1:19191"}'
+echo ""
+
+echo " === check the data replicated to the learner is kept"
+${DATABEND_METACTL} --cmd get --grpc-api-address 127.0.0.1:19191 --key promote/a | grep '"value": "1"'
+
+killall databend-meta